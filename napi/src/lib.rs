@@ -0,0 +1,182 @@
+//! Node.js bindings for supa-mdx-lint, so that Node tooling can run the linter
+//! in-process instead of running the binary and parsing its output.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use glob::{glob_with, MatchOptions};
+// The `napi` attributes register functions with Node.js, whose symbols are
+// only available when the library is loaded by Node, so they're left out of
+// test builds to let the functions be tested as plain Rust.
+#[cfg(not(test))]
+use napi_derive::napi;
+use serde_json::{json, Value};
+use supa_mdx_lint::{output::LintOutput, Config, LintTarget, Linter};
+
+const DEFAULT_CONFIG_FILE: &str = "supa-mdx-lint.config.toml";
+
+#[cfg_attr(not(test), napi(object))]
+#[derive(Debug, Default)]
+pub struct LintOptions {
+    /// Path to the config file. Defaults to `supa-mdx-lint.config.toml` in
+    /// the current directory, if it exists.
+    pub config: Option<String>,
+    /// Named profile from the config file to apply.
+    pub profile: Option<String>,
+}
+
+/// Lint a string of MDX content.
+///
+/// Returns a list of `{ filePath, errors }` objects, where `errors` holds the
+/// diagnostics in the same shape as the Rust `LintError` type, plus an `id`
+/// that can be passed to `applySuggestion`.
+#[cfg_attr(not(test), napi)]
+pub fn lint(content: String, options: Option<LintOptions>) -> napi::Result<Value> {
+    let linter = build_linter(&options.unwrap_or_default()).map_err(to_napi_error)?;
+    let outputs = linter
+        .lint(&LintTarget::String(&content))
+        .map_err(to_napi_error)?;
+    Ok(to_json(&outputs))
+}
+
+/// Lint the files and directories matching the given globs.
+///
+/// Returns the same structure as `lint`, with one entry per linted file,
+/// ordered by path. Files without diagnostics have an empty `errors` list, so
+/// editors can clear diagnostics they showed before.
+#[cfg_attr(not(test), napi(js_name = "lintFiles"))]
+pub fn lint_files(globs: Vec<String>, options: Option<LintOptions>) -> napi::Result<Value> {
+    let linter = build_linter(&options.unwrap_or_default()).map_err(to_napi_error)?;
+
+    let mut outputs = Vec::new();
+    for path in expand_globs(&globs, &linter).map_err(to_napi_error)? {
+        let mut result = linter
+            .lint(&LintTarget::FileOrDirectory(path))
+            .map_err(to_napi_error)?;
+        outputs.append(&mut result);
+    }
+    outputs.sort_by(|a, b| linter.compare_paths(a.file_path(), b.file_path()));
+
+    Ok(to_json(&outputs))
+}
+
+/// Apply one suggestion for a diagnostic in a file, chosen by the
+/// diagnostic's `id` and the suggestion's index.
+///
+/// The file is linted again before applying, and the call fails if the
+/// diagnostic is no longer found, such as after the file was edited.
+#[cfg_attr(not(test), napi(js_name = "applySuggestion"))]
+pub fn apply_suggestion(
+    file: String,
+    error_id: String,
+    suggestion_index: u32,
+    options: Option<LintOptions>,
+) -> napi::Result<Value> {
+    let linter = build_linter(&options.unwrap_or_default()).map_err(to_napi_error)?;
+    let correction = linter
+        .apply_suggestion(&file, &error_id, suggestion_index as usize)
+        .map_err(to_napi_error)?;
+    serde_json::to_value(correction).map_err(|err| to_napi_error(err.into()))
+}
+
+fn build_linter(options: &LintOptions) -> Result<Linter> {
+    let config_path = match &options.config {
+        Some(config) => Some(PathBuf::from(config)),
+        None => Some(PathBuf::from(DEFAULT_CONFIG_FILE)).filter(|path| path.exists()),
+    };
+    let config = match (config_path, &options.profile) {
+        (Some(path), Some(profile)) => Config::from_config_file_with_profile(path, profile)?,
+        (Some(path), None) => Config::from_config_file(path)?,
+        (None, Some(profile)) => {
+            return Err(anyhow::anyhow!(
+                "Profile {profile} requested, but no config file was found"
+            ))
+        }
+        (None, None) => Config::default(),
+    };
+    Linter::builder().config(config).build()
+}
+
+fn expand_globs(globs: &[String], linter: &Linter) -> Result<Vec<PathBuf>> {
+    // Match the file system's case sensitivity, as the CLI does.
+    let options = MatchOptions {
+        case_sensitive: !cfg!(windows),
+        ..Default::default()
+    };
+
+    let mut paths = Vec::new();
+    for pattern in globs {
+        let matches = glob_with(pattern, options)
+            .with_context(|| format!("Failed to parse glob pattern {pattern}"))?;
+        paths.extend(
+            matches
+                .filter_map(|path| path.ok())
+                .filter(|path| linter.is_lintable(path)),
+        );
+    }
+    Ok(paths)
+}
+
+fn to_json(outputs: &[LintOutput]) -> Value {
+    Value::Array(
+        outputs
+            .iter()
+            .map(|output| {
+                json!({
+                    "filePath": output.file_path(),
+                    "errors": output
+                        .errors()
+                        .iter()
+                        .map(|error| {
+                            let mut value = json!(error);
+                            value["id"] = json!(error.id());
+                            value
+                        })
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect(),
+    )
+}
+
+fn to_napi_error(err: anyhow::Error) -> napi::Error {
+    napi::Error::from_reason(format!("{err:#}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_lint_files_includes_files_without_diagnostics() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join("clean.mdx"), "# Hello, world\n").unwrap();
+        fs::write(dir.path().join("invalid.mdx"), "# Hello World\n").unwrap();
+
+        let result = lint_files(
+            vec![dir.path().join("*.mdx").to_string_lossy().into_owned()],
+            None,
+        )
+        .unwrap();
+        let outputs = result.as_array().unwrap();
+        assert_eq!(outputs.len(), 2);
+        assert!(outputs[0]["filePath"]
+            .as_str()
+            .unwrap()
+            .ends_with("clean.mdx"));
+        assert_eq!(outputs[0]["errors"], json!([]));
+        assert_eq!(outputs[1]["errors"][0]["rule"], json!("Rule001HeadingCase"));
+    }
+
+    #[test]
+    fn test_lint_includes_suppression() {
+        let result = lint("Some text.\n\n# Hello World\n".to_string(), None).unwrap();
+        let error = &result[0]["errors"][0];
+        assert_eq!(
+            error["suppression"]["Insert"]["text"],
+            json!("{/* supa-mdx-lint-disable-next-line Rule001HeadingCase */}\n")
+        );
+    }
+}
@@ -46,6 +46,7 @@ fi
 NEW_VERSION=$1
 
 update_cargo_toml "Cargo.toml" "$NEW_VERSION"
+update_cargo_toml "napi/Cargo.toml" "$NEW_VERSION"
 
 update_package_json "npm-binary-distributions/darwin/package.json" "$NEW_VERSION"
 update_package_json "npm-binary-distributions/linux-arm/package.json" "$NEW_VERSION"
@@ -55,6 +56,7 @@ update_package_json "npm-binary-distributions/linux-x64/package.json" "$NEW_VERS
 update_package_json "npm-binary-distributions/win32-i686/package.json" "$NEW_VERSION"
 update_package_json "npm-binary-distributions/win32-x64/package.json" "$NEW_VERSION"
 update_package_json "packages/supa-mdx-lint/package.json" "$NEW_VERSION"
+update_package_json "napi/package.json" "$NEW_VERSION"
 
 update_optional_dependencies "packages/supa-mdx-lint/package.json" "$NEW_VERSION"
 
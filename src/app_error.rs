@@ -2,6 +2,7 @@ use std::fmt::Display;
 
 use thiserror::Error;
 
+#[cfg(feature = "fix")]
 #[derive(Error, Debug)]
 pub(crate) enum AppError {
     #[error("File system error encountered when {0}: {1}")]
@@ -20,6 +21,12 @@ pub(crate) enum ParseError {
         /// Start row (1-indexed)
         usize,
     ),
+    #[error("Not valid MDX: {reason}")]
+    InvalidMdx {
+        reason: String,
+        /// Where the parser failed, as an offset into the full source.
+        offset: Option<usize>,
+    },
 }
 
 #[non_exhaustive]
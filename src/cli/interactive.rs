@@ -15,6 +15,8 @@ use supa_mdx_lint::{
 enum CorrectionStrategy {
     Fix,
     Skip,
+    SkipFile,
+    FixAll,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -33,6 +35,7 @@ struct CachedFile {
     has_diagnostics: bool,
     edited: bool,
     skipped: HashSet<ErrorCacheKey>,
+    fix_all: bool,
 }
 
 impl CachedFile {
@@ -47,6 +50,7 @@ impl CachedFile {
             has_diagnostics: false,
             edited: false,
             skipped: HashSet::new(),
+            fix_all: false,
         })
     }
 
@@ -138,10 +142,24 @@ impl<'a, 'b> InteractiveFixManager<'a, 'b> {
                             continue;
                         }
 
-                        if let Some(CorrectionStrategy::Fix) =
-                            self.prompt_error().error(error).call()?
-                        {
-                            continue 'relint;
+                        if self.curr_file.as_ref().unwrap().fix_all {
+                            // Fixes are applied one error at a time, relinting
+                            // in between, so that overlapping fixes don't
+                            // clobber each other.
+                            if self.apply_fix(error) {
+                                continue 'relint;
+                            }
+                            continue;
+                        }
+
+                        match self.prompt_error().error(error).call()? {
+                            Some(CorrectionStrategy::Fix) => continue 'relint,
+                            Some(CorrectionStrategy::FixAll) => {
+                                self.curr_file.as_mut().unwrap().fix_all = true;
+                                continue 'relint;
+                            }
+                            Some(CorrectionStrategy::SkipFile) => return Ok(()),
+                            Some(CorrectionStrategy::Skip) | None => {}
                         }
                     }
                     return Ok(());
@@ -188,28 +206,39 @@ impl<'a, 'b> InteractiveFixManager<'a, 'b> {
             .collect::<Vec<_>>()
             .join("\n\n");
 
-        let custom_edit_prompt = Self::custom_edit_prompt(suggestions.len() + 1);
-        let skip_for_now_prompt = Self::skip_for_now_prompt(suggestions.len() + 2);
+        let custom_edit_prompt =
+            Self::option_prompt(suggestions.len() + 1, "✍️  Make a custom edit");
+        let skip_for_now_prompt = Self::option_prompt(suggestions.len() + 2, "⏩ Skip for now");
+        let skip_file_prompt =
+            Self::option_prompt(suggestions.len() + 3, "⏭️  Skip the rest of this file");
+        let fix_all_prompt = Self::option_prompt(
+            suggestions.len() + 4,
+            "⚡ Apply all remaining fixes in this file",
+        );
 
         let selection = Select::new()
             .with_prompt(format!(
-                "\n{}\n\n{:?}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
+                "\n{}\n\n{:?}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}",
                 message.bold().red().underline(),
                 pretty_error,
                 suggestions_heading,
                 suggestions_string,
                 custom_edit_prompt,
                 skip_for_now_prompt,
+                skip_file_prompt,
+                fix_all_prompt,
                 "Choose an option"
             ))
             .items(
-                &(0..suggestions.len() + 2)
+                &(0..suggestions.len() + 4)
                     .map(|i| format!("Suggestion {}", i + 1))
                     .collect::<Vec<_>>(),
             )
             .interact()?;
 
         match selection {
+            n if n == suggestions.len() + 3 => Ok(Some(CorrectionStrategy::FixAll)),
+            n if n == suggestions.len() + 2 => Ok(Some(CorrectionStrategy::SkipFile)),
             n if n == suggestions.len() + 1 => {
                 self.curr_file
                     .as_mut()
@@ -259,49 +288,56 @@ impl<'a, 'b> InteractiveFixManager<'a, 'b> {
             .collect::<Vec<_>>()
     }
 
-    fn custom_edit_prompt(number: usize) -> String {
+    fn option_prompt(number: usize, description: &str) -> String {
         let mut result = format!("Suggestion {number}: ").bold().to_string();
-        result.push_str("✍️  Make a custom edit");
+        result.push_str(description);
         result
     }
 
-    fn skip_for_now_prompt(number: usize) -> String {
-        let mut result = format!("Suggestion {number}: ").bold().to_string();
-        result.push_str("⏩ Skip for now");
+    fn format_suggestion(&self, suggestion: &LintCorrection, rope: RopeSlice<'_>) -> String {
+        let mut result = match suggestion {
+            LintCorrection::Insert(insert) => format!("➕  Insert \"{}\"", insert.text()),
+            LintCorrection::Delete(_) => "✂️  Delete text".to_string(),
+            LintCorrection::Replace(replace) => {
+                format!("🔄  Replace text with \"{}\"", replace.text())
+            }
+        };
+        result.push_str("\n\n");
+
+        let (before, after) = suggestion_diff(suggestion, rope);
+        for line in before.lines() {
+            result.push_str(&format!("- {line}").red().to_string());
+            result.push('\n');
+        }
+        for line in after.lines() {
+            result.push_str(&format!("+ {line}").green().to_string());
+            result.push('\n');
+        }
         result
     }
 
-    fn format_suggestion(&self, suggestion: &LintCorrection, rope: RopeSlice<'_>) -> String {
-        match suggestion {
-            LintCorrection::Insert(insert) => {
-                let line_offset_range = Self::bytes_from_offsets(insert, rope);
-
-                let mut result = "➕  Insert text before marked character\n\n".to_string();
-                result.push_str(&Self::mark_position_string(line_offset_range, insert, rope));
-                result
-            }
-            LintCorrection::Delete(delete) => {
-                let line_offset_range = Self::bytes_from_offsets(delete, rope);
+    /// Apply the fix for an error, if it has one that the linter is allowed
+    /// to apply automatically. Returns whether anything was applied.
+    fn apply_fix(&mut self, error: &LintError) -> bool {
+        let Some(fix) = error
+            .fix()
+            .filter(|_| error.is_fixable(self.linter.fix_unsafe()))
+        else {
+            self.curr_file
+                .as_mut()
+                .unwrap()
+                .skipped
+                .insert(error.into());
+            return false;
+        };
 
-                let mut result = "✂️  Delete underlined text\n\n".to_string();
-                result.push_str(&Self::mark_position_string(line_offset_range, delete, rope));
-                result
-            }
-            LintCorrection::Replace(replace) => {
-                let line_offset_range = Self::bytes_from_offsets(replace, rope);
-
-                let mut result = format!(
-                    "🔄  Replace underlined text with \"{}\"\n\n",
-                    replace.text()
-                );
-                result.push_str(&Self::mark_position_string(
-                    line_offset_range,
-                    replace,
-                    rope,
-                ));
-                result
-            }
+        // Apply back to front so that earlier offsets stay valid.
+        let mut fix = fix.iter().collect::<Vec<_>>();
+        fix.sort_by_key(|correction| std::cmp::Reverse(correction_range(correction).start));
+        for correction in fix {
+            self.apply_suggestion(correction);
         }
+        true
     }
 
     fn apply_suggestion(&mut self, suggestion: &LintCorrection) {
@@ -351,48 +387,44 @@ impl<'a, 'b> InteractiveFixManager<'a, 'b> {
     }
 
     fn bytes_from_offsets(offsets: impl Offsets, rope: RopeSlice<'_>) -> Range<usize> {
-        let start_line_byte = rope.byte_of_line(rope.line_of_byte(offsets.start()));
-        let end_line_byte = {
-            let line = rope.line_of_byte(offsets.end());
-            if line == rope.line_len() - 1 {
-                rope.byte_len()
-            } else {
-                rope.byte_of_line(line + 1)
-            }
-        };
-        Range {
-            start: start_line_byte,
-            end: end_line_byte,
-        }
+        line_range(offsets.start()..offsets.end(), rope)
     }
+}
 
-    fn mark_position_string(
-        display_range: Range<usize>,
-        marked_range: impl Offsets,
-        rope: RopeSlice<'_>,
-    ) -> String {
-        // Build in a little extra for formatting markers
-        let mut result = String::with_capacity(display_range.end - display_range.start + 15);
-
-        for char in rope
-            .byte_slice(display_range.start..marked_range.start())
-            .chars()
-        {
-            result.push(char);
-        }
-
-        let marked = rope
-            .byte_slice(marked_range.start()..marked_range.end())
-            .to_string();
-        result.push_str(&format!("{}", marked.underline()));
-
-        for char in rope
-            .byte_slice(marked_range.end()..display_range.end)
-            .chars()
-        {
-            result.push(char);
+/// Expand a byte range to cover the full lines it touches.
+fn line_range(range: Range<usize>, rope: RopeSlice<'_>) -> Range<usize> {
+    let start_line_byte = rope.byte_of_line(rope.line_of_byte(range.start));
+    let end_line_byte = {
+        let line = rope.line_of_byte(range.end);
+        if line == rope.line_len() - 1 {
+            rope.byte_len()
+        } else {
+            rope.byte_of_line(line + 1)
         }
+    };
+    start_line_byte..end_line_byte
+}
 
-        result
+fn correction_range(correction: &LintCorrection) -> Range<usize> {
+    match correction {
+        LintCorrection::Insert(insert) => insert.start()..insert.start(),
+        LintCorrection::Delete(delete) => delete.start()..delete.end(),
+        LintCorrection::Replace(replace) => replace.start()..replace.end(),
     }
 }
+
+/// The lines touched by a correction, before and after applying it.
+fn suggestion_diff(correction: &LintCorrection, rope: RopeSlice<'_>) -> (String, String) {
+    let range = correction_range(correction);
+    let lines = line_range(range.clone(), rope);
+
+    let before = rope.byte_slice(lines.clone()).to_string();
+    let text = match correction {
+        LintCorrection::Insert(insert) => insert.text(),
+        LintCorrection::Delete(_) => "",
+        LintCorrection::Replace(replace) => replace.text(),
+    };
+    let mut after = before.clone();
+    after.replace_range(range.start - lines.start..range.end - lines.start, text);
+    (before, after)
+}
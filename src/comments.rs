@@ -8,15 +8,26 @@ use std::{
 use anyhow::Result;
 use bon::bon;
 use either::Either;
-use markdown::mdast::{MdxFlowExpression, Node};
+use markdown::{
+    mdast::{MdxFlowExpression, Node},
+    unist::Position,
+};
 use regex::Regex;
+use serde_json::{json, Value};
 
 use crate::{
     app_error::{MultiError, ParseError, ResultBoth},
     context::Context,
-    location::{AdjustedOffset, AdjustedPoint, DenormalizedLocation, MaybeEndedLineRange},
+    location::{
+        AdjustedOffset, AdjustedPoint, AdjustedRange, DenormalizedLocation, MaybeEndedLineRange,
+    },
     parser::{CommentString, ParseResult},
-    utils::mdast::{MaybePosition, VariantName},
+    rules::{canonical_rule_name, closest_rule_name, is_known_rule_name},
+    utils::{
+        date::{is_iso_date, today},
+        edit_distance,
+        mdast::{MaybePosition, VariantName},
+    },
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -120,6 +131,12 @@ impl AsRef<str> for RuleKey<'_> {
     }
 }
 
+/// Directives may refer to a rule by its alias or a deprecated name. Unknown
+/// names are kept as written.
+fn resolve_rule_name(rule: &str) -> &str {
+    canonical_rule_name(rule).unwrap_or(rule)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct LintTimeConfigureAttr<'comment> {
     rule_name: &'comment str,
@@ -153,22 +170,22 @@ impl<'comment>
     ) -> Result<Self, Self::Error> {
         match value {
             (_, "configure", Some(rule), Some(attributes)) => Ok(LintTimeConfigureAttr {
-                rule_name: rule,
+                rule_name: resolve_rule_name(rule),
                 attributes: Some(Cow::Borrowed(attributes)),
                 next_line_only: false,
             }),
             (_, "configure", Some(rule), None) => Ok(LintTimeConfigureAttr {
-                rule_name: rule,
+                rule_name: resolve_rule_name(rule),
                 attributes: None,
                 next_line_only: false,
             }),
             (_, "configure-next-line", Some(rule), Some(attributes)) => Ok(LintTimeConfigureAttr {
-                rule_name: rule,
+                rule_name: resolve_rule_name(rule),
                 attributes: Some(Cow::Borrowed(attributes)),
                 next_line_only: true,
             }),
             (_, "configure-next-line", Some(rule), None) => Ok(LintTimeConfigureAttr {
-                rule_name: rule,
+                rule_name: resolve_rule_name(rule),
                 attributes: None,
                 next_line_only: true,
             }),
@@ -191,18 +208,18 @@ impl From<(&str, Option<&str>)> for RuleToggle {
     fn from(value: (&str, Option<&str>)) -> Self {
         match value {
             ("enable", Some(rule)) => RuleToggle::EnableRule {
-                rule: rule.to_string(),
+                rule: resolve_rule_name(rule).to_string(),
             },
             ("enable", _) => RuleToggle::EnableAll,
             ("disable", Some(rule)) => RuleToggle::DisableRule {
-                rule: rule.to_string(),
+                rule: resolve_rule_name(rule).to_string(),
                 next_line_only: false,
             },
             ("disable", None) => RuleToggle::DisableAll {
                 next_line_only: false,
             },
             ("disable-next-line", Some(rule)) => RuleToggle::DisableRule {
-                rule: rule.to_string(),
+                rule: resolve_rule_name(rule).to_string(),
                 next_line_only: true,
             },
             ("disable-next-line", None) => RuleToggle::DisableAll {
@@ -233,9 +250,172 @@ impl NextLineOnly for LintTimeConfigureAttr<'_> {
     }
 }
 
+/// The actions a lint directive can take, such as `supa-mdx-lint-disable`.
+const DIRECTIVES: &[&str] = &[
+    "supa-mdx-lint-enable",
+    "supa-mdx-lint-disable",
+    "supa-mdx-lint-disable-next-line",
+    "supa-mdx-lint-configure",
+    "supa-mdx-lint-configure-next-line",
+    "supa-mdx-lint-allow-next-word",
+];
+
+/// A comment that looks like a lint directive, but couldn't be parsed, so it
+/// has no effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MalformedDirective {
+    pub(crate) directive: String,
+    pub(crate) range: AdjustedRange,
+}
+
+impl MalformedDirective {
+    pub(crate) fn message(&self) -> String {
+        let action = self.directive.split_whitespace().next().unwrap_or_default();
+        if DIRECTIVES.contains(&action) {
+            return format!(
+                "The lint directive \"{action}\" must be followed by a rule name, such as \"{action} Rule001HeadingCase\"."
+            );
+        }
+
+        let closest = DIRECTIVES
+            .iter()
+            .map(|directive| (directive, edit_distance(action, directive)))
+            .filter(|(_, distance)| *distance <= MAX_DIRECTIVE_EDIT_DISTANCE)
+            .min_by_key(|(_, distance)| *distance);
+        match closest {
+            Some((directive, _)) => format!(
+                "Unrecognized lint directive \"{action}\" has no effect. Did you mean \"{directive}\"?"
+            ),
+            None => format!("Unrecognized lint directive \"{action}\" has no effect."),
+        }
+    }
+}
+
+/// Misspelled directives within this many edits of a real one are reported
+/// with a suggestion.
+const MAX_DIRECTIVE_EDIT_DISTANCE: usize = 3;
+
+/// A lint directive naming a rule that doesn't exist, such as a misspelled
+/// rule name, so it has no effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnknownRuleDirective {
+    pub(crate) rule: String,
+    pub(crate) range: AdjustedRange,
+}
+
+impl UnknownRuleDirective {
+    pub(crate) fn message(&self) -> String {
+        match closest_rule_name(&self.rule, MAX_DIRECTIVE_EDIT_DISTANCE) {
+            Some(rule) => format!(
+                "Unknown rule \"{}\" in lint directive has no effect. Did you mean \"{rule}\"?",
+                self.rule
+            ),
+            None => format!(
+                "Unknown rule \"{}\" in lint directive has no effect.",
+                self.rule
+            ),
+        }
+    }
+}
+
+/// The note after `--` in a lint directive, such as
+/// `supa-mdx-lint-disable Rule003Spelling -- reason: vendor name, expires: 2025-12-31`.
+/// A note without any fields is taken as the reason.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct DirectiveAnnotation {
+    reason: Option<String>,
+    expires: Option<String>,
+}
+
+static ANNOTATION_SEPARATOR_REGEX: OnceLock<Regex> = OnceLock::new();
+static ANNOTATION_FIELD_REGEX: OnceLock<Regex> = OnceLock::new();
+
+impl DirectiveAnnotation {
+    /// Split a directive into the directive itself and its annotation.
+    fn split(directive: &str) -> (&str, Option<Self>) {
+        let regex = ANNOTATION_SEPARATOR_REGEX.get_or_init(|| {
+            Regex::new(r"\s+--(?:\s+|$)").expect("Hardcoded regex should not fail")
+        });
+        match regex.find(directive) {
+            Some(separator) => (
+                &directive[..separator.start()],
+                Some(Self::parse(&directive[separator.end()..])),
+            ),
+            None => (directive, None),
+        }
+    }
+
+    fn parse(annotation: &str) -> Self {
+        let regex = ANNOTATION_FIELD_REGEX.get_or_init(|| {
+            Regex::new(r"(?:^|,)\s*(reason|expires)\s*:").expect("Hardcoded regex should not fail")
+        });
+
+        let fields = regex.captures_iter(annotation).collect::<Vec<_>>();
+        let mut result = Self::default();
+        if fields.is_empty() {
+            let reason = annotation.trim();
+            result.reason = (!reason.is_empty()).then(|| reason.to_string());
+            return result;
+        }
+
+        for (idx, field) in fields.iter().enumerate() {
+            let value_end = fields
+                .get(idx + 1)
+                .map(|next| next.get(0).expect("Group 0 is always present").start())
+                .unwrap_or(annotation.len());
+            let value_start = field.get(0).expect("Group 0 is always present").end();
+            let value = annotation[value_start..value_end].trim().to_string();
+            match &field[1] {
+                "reason" => result.reason = Some(value),
+                _ => result.expires = Some(value),
+            }
+        }
+        result
+    }
+}
+
+/// A lint directive whose expiry date has passed, or couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ExpiredDirective {
+    pub(crate) reason: Option<String>,
+    pub(crate) expires: String,
+    pub(crate) range: AdjustedRange,
+}
+
+impl ExpiredDirective {
+    pub(crate) fn message(&self) -> String {
+        if !is_iso_date(&self.expires) {
+            return format!(
+                "Invalid expiry date \"{}\" in lint directive. Use the format YYYY-MM-DD.",
+                self.expires
+            );
+        }
+
+        let mut message = format!(
+            "This lint directive expired on {}. Fix the suppressed issues and remove it, or extend the expiry date.",
+            self.expires
+        );
+        if let Some(reason) = &self.reason {
+            message.push_str(&format!(" Reason given: \"{reason}\"."));
+        }
+        message
+    }
+
+    /// The directive's annotation, so output formats can show the reason.
+    pub(crate) fn details(&self) -> Value {
+        json!({
+            "reason": self.reason,
+            "expires": self.expires,
+        })
+    }
+}
+
 enum ConfigurationComment<'comment> {
     Configure(LintTimeConfigureAttr<'comment>),
     EnableDisable(RuleToggle),
+    /// Suppresses a rule, or all rules if none is given, for the word right
+    /// after the comment.
+    AllowNextWord(Option<String>),
 }
 
 static CONFIG_COMMENT_REGEX: OnceLock<Regex> = OnceLock::new();
@@ -243,11 +423,11 @@ static CONFIG_COMMENT_REGEX: OnceLock<Regex> = OnceLock::new();
 #[bon]
 impl<'comment> ConfigurationComment<'comment> {
     fn parse(value: &'comment str) -> Option<Self> {
-        let comment_string = value.as_comment()?;
+        let (comment_string, _) = DirectiveAnnotation::split(value.as_comment()?);
 
         // supa-mdx-lint configure-next-line Rule001HeadingCase +Supabase +pgjwt
         let regex = CONFIG_COMMENT_REGEX.get_or_init(||
-            Regex::new(r"^supa-mdx-lint-(enable|disable|disable-next-line|configure|configure-next-line)(?:\s+(\S+)(?:\s+(.+))?)?$").expect("Hardcoded regex should not fail")
+            Regex::new(r"^supa-mdx-lint-(enable|disable|disable-next-line|configure|configure-next-line|allow-next-word)(?:\s+(\S+)(?:\s+(.+))?)?$").expect("Hardcoded regex should not fail")
         );
 
         if let Some(captures) = regex.captures(comment_string) {
@@ -268,6 +448,13 @@ impl<'comment> ConfigurationComment<'comment> {
                         .ok()
                         .map(ConfigurationComment::Configure);
                     }
+                    "allow-next-word" => {
+                        return Some(ConfigurationComment::AllowNextWord(
+                            captures
+                                .get(2)
+                                .map(|m| resolve_rule_name(m.as_str()).to_string()),
+                        ));
+                    }
                     _ => {}
                 }
             }
@@ -276,6 +463,25 @@ impl<'comment> ConfigurationComment<'comment> {
         None
     }
 
+    /// The rule the directive refers to, if it names one.
+    fn rule_name(&self) -> Option<&str> {
+        match self {
+            ConfigurationComment::Configure(attr) => Some(attr.rule_name),
+            ConfigurationComment::EnableDisable(
+                RuleToggle::EnableRule { rule } | RuleToggle::DisableRule { rule, .. },
+            ) => Some(rule),
+            ConfigurationComment::EnableDisable(_) => None,
+            ConfigurationComment::AllowNextWord(rule) => rule.as_deref(),
+        }
+    }
+
+    /// Whether the comment looks like it was meant to be a lint directive.
+    fn is_directive_like(value: &str) -> bool {
+        value
+            .as_comment()
+            .is_some_and(|comment| comment.starts_with("supa-mdx-lint"))
+    }
+
     #[builder]
     fn get_covered_range(
         curr: impl MaybePosition + VariantName,
@@ -287,7 +493,8 @@ impl<'comment> ConfigurationComment<'comment> {
             return Err(ParseError::MissingPosition(curr.variant_name()));
         };
 
-        let start_offset = AdjustedOffset::from_unist(&pos.start, parsed.content_start_offset());
+        let start_offset =
+            AdjustedOffset::from_unist(&pos.start, parsed.content_start_offset().into());
         let start_line = AdjustedPoint::from_adjusted_offset(&start_offset, parsed.rope());
 
         if !next_line_only {
@@ -300,8 +507,10 @@ impl<'comment> ConfigurationComment<'comment> {
                     return Err(ParseError::MissingPosition(next.variant_name()));
                 };
 
-                let end_offset =
-                    AdjustedOffset::from_unist(&next_pos.start, parsed.content_start_offset());
+                let end_offset = AdjustedOffset::from_unist(
+                    &next_pos.start,
+                    parsed.content_start_offset().into(),
+                );
                 let end_row =
                     AdjustedPoint::from_adjusted_offset(&end_offset, parsed.rope()).row + 1;
                 let last_row = parsed.rope().line_len() - 1;
@@ -318,71 +527,221 @@ impl<'comment> ConfigurationComment<'comment> {
     }
 }
 
-#[allow(clippy::type_complexity)]
+/// A configure or enable/disable directive, with the lines it covers.
+type CoveredDirective<'comment> =
+    Either<LintTimeConfigureInfo<'comment>, (RuleToggle, MaybeEndedLineRange)>;
+
 #[derive(Debug, Default)]
-pub(crate) struct ConfigurationCommentCollection<'comment>(
-    Vec<
-        Result<
-            Either<LintTimeConfigureInfo<'comment>, (RuleToggle, MaybeEndedLineRange)>,
-            ParseError,
-        >,
-    >,
-);
+pub(crate) struct ConfigurationCommentCollection<'comment> {
+    directives: Vec<Result<CoveredDirective<'comment>, ParseError>>,
+    malformed: Vec<MalformedDirective>,
+    expired: Vec<ExpiredDirective>,
+    unknown_rules: Vec<UnknownRuleDirective>,
+    word_allows: Vec<WordAllow>,
+}
+
+/// The lint directives in a file, sorted by what they do.
+#[derive(Debug, Default)]
+pub(crate) struct CollectedDirectives<'ast> {
+    pub(crate) configs: LintTimeRuleConfigs<'ast>,
+    pub(crate) disables: LintDisables<'ast>,
+    /// Comments that look like lint directives but couldn't be parsed.
+    pub(crate) malformed: Vec<MalformedDirective>,
+    /// Directives whose expiry date has passed.
+    pub(crate) expired: Vec<ExpiredDirective>,
+    /// Directives naming rules that don't exist.
+    pub(crate) unknown_rules: Vec<UnknownRuleDirective>,
+}
+
+/// A word suppressed by a `supa-mdx-lint-allow-next-word` comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct WordAllow {
+    /// The suppressed rule, or `None` for all rules.
+    rule: Option<String>,
+    range: AdjustedRange,
+}
+
+/// The range of a comment in the source.
+fn comment_range(position: &Position, parsed: &ParseResult) -> AdjustedRange {
+    let content_start_offset = parsed.content_start_offset();
+    AdjustedRange::new(
+        AdjustedOffset::from_unist(&position.start, content_start_offset.into()),
+        AdjustedOffset::from_unist(&position.end, content_start_offset.into()),
+    )
+}
+
+/// The range of the first whitespace-delimited word after the offset.
+fn next_word_range(parsed: &ParseResult, offset: AdjustedOffset) -> Option<AdjustedRange> {
+    let offset: usize = offset.into();
+    let rope = parsed.rope();
+    let mut chars = rope.byte_slice(offset..rope.byte_len()).chars().peekable();
+
+    let mut start = offset;
+    while let Some(c) = chars.next_if(|c| c.is_whitespace()) {
+        start += c.len_utf8();
+    }
+    let mut end = start;
+    while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+        end += c.len_utf8();
+    }
+
+    (end > start).then(|| AdjustedRange::new(start.into(), end.into()))
+}
 
 impl<'ast> ConfigurationCommentCollection<'ast> {
     pub(crate) fn from_parse_result(parsed: &'ast ParseResult) -> Self {
         let ast = parsed.ast();
-        let Some(comment_pairs) = collect_comment_pairs(ast) else {
-            return Self::default();
-        };
-        let comment_pairs = comment_pairs
-            .into_iter()
-            .filter_map(|(comment, next_node)| {
-                if let Some(config_comment) = ConfigurationComment::parse(&comment.inner.value) {
-                    match config_comment {
-                        ConfigurationComment::Configure(info) => {
-                            match ConfigurationComment::get_covered_range()
-                                .curr(comment.inner)
-                                .maybe_next(next_node)
-                                .next_line_only(info.next_line_only())
-                                .parsed(parsed)
-                                .call()
-                            {
-                                Ok(range) => Some(Ok(Either::Left(LintTimeConfigureInfo {
-                                    attributes: info,
-                                    covered_range: range,
-                                }))),
-                                Err(err) => Some(Err(err)),
-                            }
-                        }
-                        ConfigurationComment::EnableDisable(info) => {
-                            match ConfigurationComment::get_covered_range()
-                                .curr(comment.inner)
-                                .maybe_next(next_node)
-                                .next_line_only(info.next_line_only())
-                                .parsed(parsed)
-                                .call()
-                            {
-                                Ok(range) => Some(Ok(Either::Right((info, range)))),
-                                Err(err) => Some(Err(err)),
-                            }
-                        }
+        let today = today();
+        let mut collection = Self::default();
+        collection.collect_inline_word_allows(ast, parsed, &today);
+
+        for (comment, next_node) in collect_comment_pairs(ast).unwrap_or_default() {
+            let value = &comment.inner.value;
+            let directive = ConfigurationComment::parse(value);
+            let range = comment
+                .inner
+                .position
+                .as_ref()
+                .map(|position| comment_range(position, parsed));
+            if let Some(range) = &range {
+                match &directive {
+                    Some(directive) => {
+                        collection.check_directive(value, directive.rule_name(), range, &today)
                     }
-                } else {
-                    None
+                    None => collection.check_malformed(value, range),
                 }
-            })
-            .collect();
-        Self(comment_pairs)
+            }
+
+            let covered_range = |next_line_only| {
+                ConfigurationComment::get_covered_range()
+                    .curr(comment.inner)
+                    .maybe_next(next_node)
+                    .next_line_only(next_line_only)
+                    .parsed(parsed)
+                    .call()
+            };
+            match directive {
+                Some(ConfigurationComment::Configure(info)) => {
+                    let directive = covered_range(info.next_line_only()).map(|range| {
+                        Either::Left(LintTimeConfigureInfo {
+                            attributes: info,
+                            covered_range: range,
+                        })
+                    });
+                    collection.directives.push(directive);
+                }
+                Some(ConfigurationComment::EnableDisable(info)) => {
+                    let directive = covered_range(info.next_line_only())
+                        .map(|range| Either::Right((info, range)));
+                    collection.directives.push(directive);
+                }
+                Some(ConfigurationComment::AllowNextWord(rule)) => {
+                    if let Some(range) = &range {
+                        collection.add_word_allow(rule, range, parsed);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        collection
+            .malformed
+            .sort_by_key(|directive| directive.range.start);
+        collection
+            .expired
+            .sort_by_key(|directive| directive.range.start);
+        collection
+            .unknown_rules
+            .sort_by_key(|directive| directive.range.start);
+        collection
+            .word_allows
+            .sort_by_key(|allow| allow.range.start);
+        collection
+    }
+
+    /// Collect the `supa-mdx-lint-allow-next-word` comments written inline in
+    /// a paragraph, right before the word. Comments on their own line are
+    /// collected with the other directives.
+    fn collect_inline_word_allows(&mut self, node: &Node, parsed: &ParseResult, today: &str) {
+        if let Node::MdxTextExpression(expr) = node {
+            if let (Some(ConfigurationComment::AllowNextWord(rule)), Some(position)) =
+                (ConfigurationComment::parse(&expr.value), &expr.position)
+            {
+                let range = comment_range(position, parsed);
+                self.check_directive(&expr.value, rule.as_deref(), &range, today);
+                self.add_word_allow(rule, &range, parsed);
+            }
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                self.collect_inline_word_allows(child, parsed, today);
+            }
+        }
+    }
+
+    /// Record a comment that looks like a lint directive but couldn't be
+    /// parsed.
+    fn check_malformed(&mut self, value: &str, range: &AdjustedRange) {
+        if !ConfigurationComment::is_directive_like(value) {
+            return;
+        }
+        if let Some(directive) = value.as_comment() {
+            self.malformed.push(MalformedDirective {
+                directive: directive.to_string(),
+                range: range.clone(),
+            });
+        }
+    }
+
+    /// Record a directive naming a rule that doesn't exist, or whose expiry
+    /// date has passed.
+    fn check_directive(
+        &mut self,
+        value: &str,
+        rule: Option<&str>,
+        range: &AdjustedRange,
+        today: &str,
+    ) {
+        if let Some(rule) = rule.filter(|rule| !is_known_rule_name(rule)) {
+            self.unknown_rules.push(UnknownRuleDirective {
+                rule: rule.to_string(),
+                range: range.clone(),
+            });
+        }
+
+        let Some((_, Some(annotation))) = value.as_comment().map(DirectiveAnnotation::split) else {
+            return;
+        };
+        let Some(expires) = annotation.expires else {
+            return;
+        };
+        if !is_iso_date(&expires) || expires.as_str() < today {
+            self.expired.push(ExpiredDirective {
+                reason: annotation.reason,
+                expires,
+                range: range.clone(),
+            });
+        }
+    }
+
+    /// Suppress the word after a `supa-mdx-lint-allow-next-word` comment.
+    fn add_word_allow(
+        &mut self,
+        rule: Option<String>,
+        range: &AdjustedRange,
+        parsed: &ParseResult,
+    ) {
+        if let Some(range) = next_word_range(parsed, range.end) {
+            self.word_allows.push(WordAllow { rule, range });
+        }
     }
 
-    pub(crate) fn into_parts(
-        self,
-    ) -> ResultBoth<(LintTimeRuleConfigs<'ast>, LintDisables<'ast>), MultiError> {
+    pub(crate) fn into_parts(self) -> ResultBoth<CollectedDirectives<'ast>, MultiError> {
         let mut configs = LintTimeRuleConfigs::default();
         let mut disables_builder = LintDisablesBuilder::default();
         let mut errors = None::<MultiError>;
-        for res in self.0.into_iter() {
+        for res in self.directives.into_iter() {
             match res {
                 Ok(Either::Left(info)) => {
                     let attributes = info.attributes.attributes.clone();
@@ -415,6 +774,10 @@ impl<'ast> ConfigurationCommentCollection<'ast> {
                 }
             }
         }
+        for allow in self.word_allows {
+            let rule_key = allow.rule.map_or(RuleKey::All, RuleKey::from);
+            disables_builder.add_word_allow(rule_key, allow.range);
+        }
 
         let (disables, build_err) = disables_builder.build().split();
         if let Some(build_err) = build_err {
@@ -423,7 +786,16 @@ impl<'ast> ConfigurationCommentCollection<'ast> {
                 .add_err(Box::new(build_err));
         }
 
-        ResultBoth::new((configs, disables), errors)
+        ResultBoth::new(
+            CollectedDirectives {
+                configs,
+                disables,
+                malformed: self.malformed,
+                expired: self.expired,
+                unknown_rules: self.unknown_rules,
+            },
+            errors,
+        )
     }
 }
 
@@ -453,10 +825,18 @@ enum Switch {
 }
 
 #[derive(Debug, Default)]
-struct LintDisablesBuilder<'key>(HashMap<RuleKey<'key>, Vec<(Switch, MaybeEndedLineRange)>>);
+struct LintDisablesBuilder<'key> {
+    toggles: HashMap<RuleKey<'key>, Vec<(Switch, MaybeEndedLineRange)>>,
+    words: HashMap<RuleKey<'key>, Vec<AdjustedRange>>,
+}
 
+/// The parts of a file where rules are turned off by lint directives: whole
+/// lines, and single words.
 #[derive(Debug, Default)]
-pub struct LintDisables<'key>(HashMap<RuleKey<'key>, Vec<MaybeEndedLineRange>>);
+pub struct LintDisables<'key> {
+    lines: HashMap<RuleKey<'key>, Vec<MaybeEndedLineRange>>,
+    words: HashMap<RuleKey<'key>, Vec<AdjustedRange>>,
+}
 
 #[derive(Debug)]
 enum MergeRangesResult {
@@ -495,14 +875,21 @@ fn maybe_merge_ranges(
 
 impl<'key> LintDisablesBuilder<'key> {
     fn add_toggle(&mut self, rule_key: RuleKey<'key>, switch: Switch, range: MaybeEndedLineRange) {
-        self.0.entry(rule_key).or_default().push((switch, range));
+        self.toggles
+            .entry(rule_key)
+            .or_default()
+            .push((switch, range));
+    }
+
+    fn add_word_allow(&mut self, rule_key: RuleKey<'key>, range: AdjustedRange) {
+        self.words.entry(rule_key).or_default().push(range);
     }
 
     fn build(self) -> ResultBoth<LintDisables<'key>, MultiError> {
         let mut disables = HashMap::new();
         let mut errors = None::<MultiError>;
 
-        for (rule_key, mut toggles) in self.0 {
+        for (rule_key, mut toggles) in self.toggles {
             toggles.sort_by_key(|(_, range)| range.clone());
 
             let mut disabled_ranges = Vec::<(Switch, MaybeEndedLineRange)>::new();
@@ -549,7 +936,13 @@ impl<'key> LintDisablesBuilder<'key> {
             );
         }
 
-        ResultBoth::new(LintDisables(disables), errors)
+        ResultBoth::new(
+            LintDisables {
+                lines: disables,
+                words: self.words,
+            },
+            errors,
+        )
     }
 }
 
@@ -563,14 +956,14 @@ impl<'key> LintDisables<'key> {
         let all_key = RuleKey::All;
         let specific_key = RuleKey::from(rule_name);
 
-        if let Some(disabled_ranges) = self.0.get(&all_key) {
+        if let Some(disabled_ranges) = self.lines.get(&all_key) {
             if disabled_ranges
                 .iter()
                 .any(|range| range.overlaps_lines(&location.offset_range, ctx.rope()))
             {
                 return true;
             }
-        } else if let Some(disabled_ranges) = self.0.get(&specific_key) {
+        } else if let Some(disabled_ranges) = self.lines.get(&specific_key) {
             if disabled_ranges
                 .iter()
                 .any(|range| range.overlaps_lines(&location.offset_range, ctx.rope()))
@@ -579,7 +972,15 @@ impl<'key> LintDisables<'key> {
             }
         }
 
-        false
+        // Word-level directives only cover errors that fall entirely within
+        // the word.
+        [all_key, specific_key]
+            .iter()
+            .filter_map(|key| self.words.get(key))
+            .flatten()
+            .any(|range| {
+                range.start <= location.offset_range.start && location.offset_range.end <= range.end
+            })
     }
 }
 
@@ -615,7 +1016,7 @@ A list:
         let first = comment_pairs.first().unwrap();
         assert_eq!(first.0.inner.value, "/* Comment 1 */");
         match first.1 {
-            Some(Node::Paragraph(Paragraph { children, .. })) => match children.get(0).unwrap() {
+            Some(Node::Paragraph(Paragraph { children, .. })) => match children.first().unwrap() {
                 Node::Text(text) => {
                     assert_eq!(text.value, "Paragraph 1");
                 }
@@ -631,7 +1032,7 @@ A list:
         let second = comment_pairs.get(1).unwrap();
         assert_eq!(second.0.inner.value, "/* Comment 2 */");
         match second.1 {
-            Some(Node::Paragraph(Paragraph { children, .. })) => match children.get(0).unwrap() {
+            Some(Node::Paragraph(Paragraph { children, .. })) => match children.first().unwrap() {
                 Node::Text(text) => {
                     assert_eq!(text.value, "Paragraph 1");
                 }
@@ -647,8 +1048,8 @@ A list:
         let third = comment_pairs.get(2).unwrap();
         assert_eq!(third.0.inner.value, "/* Comment 3 */");
         match third.1 {
-            Some(Node::ListItem(list_item)) => match list_item.children.get(0).unwrap() {
-                Node::Paragraph(Paragraph { children, .. }) => match children.get(0).unwrap() {
+            Some(Node::ListItem(list_item)) => match list_item.children.first().unwrap() {
+                Node::Paragraph(Paragraph { children, .. }) => match children.first().unwrap() {
                     Node::Text(text) => {
                         assert_eq!(text.value, "Item 2");
                     }
@@ -754,13 +1155,56 @@ Some content
 {/* supa-mdx-lint-enable foo */}"#;
 
         let parse_result = parse(input).unwrap();
-        let (_, disables) = ConfigurationCommentCollection::from_parse_result(&parse_result)
+        let CollectedDirectives { disables, .. } =
+            ConfigurationCommentCollection::from_parse_result(&parse_result)
+                .into_parts()
+                .unwrap();
+
+        assert_eq!(disables.lines.len(), 1);
+        assert_eq!(disables.lines[&"foo".into()][0].start, 0);
+        assert_eq!(disables.lines[&"foo".into()][0].end, Some(2));
+    }
+
+    #[test]
+    fn test_collect_word_allows() {
+        let input = "Use Supabse and {/* supa-mdx-lint-allow-next-word Rule003Spelling */} pgjwt's API.\n\n{/* supa-mdx-lint-allow-next-word */}\nFoo bar\n";
+
+        let parse_result = parse(input).unwrap();
+        let CollectedDirectives {
+            disables,
+            malformed,
+            ..
+        } = ConfigurationCommentCollection::from_parse_result(&parse_result)
+            .into_parts()
+            .unwrap();
+
+        assert!(malformed.is_empty());
+        assert!(disables.lines.is_empty());
+        let spelling = &disables.words[&"Rule003Spelling".into()];
+        assert_eq!(spelling.len(), 1);
+        assert_eq!(&input[spelling[0].to_usize_range()], "pgjwt's");
+        let all = &disables.words[&RuleKey::All];
+        assert_eq!(&input[all[0].to_usize_range()], "Foo");
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_collect_lint_disables_rule_alias() {
+        let input = r#"{/* supa-mdx-lint-disable heading-case */}
+Some content
+{/* supa-mdx-lint-enable Rule001HeadingCase */}
+{/* supa-mdx-lint-configure spelling +Supa */}"#;
+
+        let parse_result = parse(input).unwrap();
+        let CollectedDirectives {
+            configs, disables, ..
+        } = ConfigurationCommentCollection::from_parse_result(&parse_result)
             .into_parts()
             .unwrap();
 
-        assert_eq!(disables.0.len(), 1);
-        assert_eq!(disables.0[&"foo".into()][0].start, 0);
-        assert_eq!(disables.0[&"foo".into()][0].end, Some(2));
+        assert_eq!(disables.lines.len(), 1);
+        assert_eq!(disables.lines[&"Rule001HeadingCase".into()][0].end, Some(2));
+        assert!(configs.0.contains_key(&"Rule003Spelling".into()));
     }
 
     #[test]
@@ -773,13 +1217,14 @@ More content
 {/* supa-mdx-lint-enable bar */}"#;
 
         let parse_result = parse(input).unwrap();
-        let (_, disables) = ConfigurationCommentCollection::from_parse_result(&parse_result)
-            .into_parts()
-            .unwrap();
-
-        assert_eq!(disables.0.len(), 2);
-        assert_eq!(disables.0[&"bar".into()][0].start, 2);
-        assert_eq!(disables.0[&"bar".into()][0].end, Some(5));
+        let CollectedDirectives { disables, .. } =
+            ConfigurationCommentCollection::from_parse_result(&parse_result)
+                .into_parts()
+                .unwrap();
+
+        assert_eq!(disables.lines.len(), 2);
+        assert_eq!(disables.lines[&"bar".into()][0].start, 2);
+        assert_eq!(disables.lines[&"bar".into()][0].end, Some(5));
     }
 
     #[test]
@@ -789,12 +1234,13 @@ This line is ignored
 This line is not ignored"#;
 
         let parse_result = parse(input).unwrap();
-        let (_, disables) = ConfigurationCommentCollection::from_parse_result(&parse_result)
-            .into_parts()
-            .unwrap();
+        let CollectedDirectives { disables, .. } =
+            ConfigurationCommentCollection::from_parse_result(&parse_result)
+                .into_parts()
+                .unwrap();
 
-        assert_eq!(disables.0.len(), 1);
-        assert_eq!(disables.0[&"foo".into()][0].end, Some(2));
+        assert_eq!(disables.lines.len(), 1);
+        assert_eq!(disables.lines[&"foo".into()][0].end, Some(2));
     }
 
     #[test]
@@ -805,13 +1251,14 @@ Still ignored
 {/* supa-mdx-lint-enable */}"#;
 
         let parse_result = parse(input).unwrap();
-        let (_, disables) = ConfigurationCommentCollection::from_parse_result(&parse_result)
-            .into_parts()
-            .unwrap();
-
-        assert_eq!(disables.0.len(), 1);
-        assert_eq!(disables.0[&RuleKey::All][0].start, 0);
-        assert_eq!(disables.0[&RuleKey::All][0].end, Some(3));
+        let CollectedDirectives { disables, .. } =
+            ConfigurationCommentCollection::from_parse_result(&parse_result)
+                .into_parts()
+                .unwrap();
+
+        assert_eq!(disables.lines.len(), 1);
+        assert_eq!(disables.lines[&RuleKey::All][0].start, 0);
+        assert_eq!(disables.lines[&RuleKey::All][0].end, Some(3));
     }
 
     #[test]
@@ -820,11 +1267,12 @@ Still ignored
 Never reenabled"#;
 
         let parse_result = parse(input).unwrap();
-        let (_, disables) = ConfigurationCommentCollection::from_parse_result(&parse_result)
-            .into_parts()
-            .unwrap();
+        let CollectedDirectives { disables, .. } =
+            ConfigurationCommentCollection::from_parse_result(&parse_result)
+                .into_parts()
+                .unwrap();
 
-        assert_eq!(disables.0.len(), 1);
+        assert_eq!(disables.lines.len(), 1);
     }
 
     #[test]
@@ -846,12 +1294,13 @@ This line is ignored
 This line is not ignored"#;
 
         let parse_result = parse(input).unwrap();
-        let (_, disables) = ConfigurationCommentCollection::from_parse_result(&parse_result)
-            .into_parts()
-            .unwrap();
+        let CollectedDirectives { disables, .. } =
+            ConfigurationCommentCollection::from_parse_result(&parse_result)
+                .into_parts()
+                .unwrap();
 
-        assert_eq!(disables.0.len(), 1);
-        assert_eq!(disables.0[&"foo".into()][0].end, Some(3));
+        assert_eq!(disables.lines.len(), 1);
+        assert_eq!(disables.lines[&"foo".into()][0].end, Some(3));
     }
 
     #[test]
@@ -866,15 +1315,16 @@ This line is not ignored
 "#;
 
         let parse_result = parse(input).unwrap();
-        let (_, disables) = ConfigurationCommentCollection::from_parse_result(&parse_result)
-            .into_parts()
-            .unwrap();
-
-        assert_eq!(disables.0.len(), 2);
-        assert_eq!(disables.0[&"foo".into()][0].start, 0);
-        assert_eq!(disables.0[&"foo".into()][0].end, Some(6));
-        assert_eq!(disables.0[&"bar".into()][0].start, 3);
-        assert_eq!(disables.0[&"bar".into()][0].end, Some(6));
+        let CollectedDirectives { disables, .. } =
+            ConfigurationCommentCollection::from_parse_result(&parse_result)
+                .into_parts()
+                .unwrap();
+
+        assert_eq!(disables.lines.len(), 2);
+        assert_eq!(disables.lines[&"foo".into()][0].start, 0);
+        assert_eq!(disables.lines[&"foo".into()][0].end, Some(6));
+        assert_eq!(disables.lines[&"bar".into()][0].start, 3);
+        assert_eq!(disables.lines[&"bar".into()][0].end, Some(6));
     }
 
     #[test]
@@ -897,20 +1347,216 @@ This line should not be ignored
 "#;
 
         let parse_result = parse(input).unwrap();
-        let (_, disables) = ConfigurationCommentCollection::from_parse_result(&parse_result)
-            .into_parts()
-            .unwrap();
+        let CollectedDirectives { disables, .. } =
+            ConfigurationCommentCollection::from_parse_result(&parse_result)
+                .into_parts()
+                .unwrap();
 
-        assert_eq!(disables.0.len(), 2);
+        assert_eq!(disables.lines.len(), 2);
 
         // Check foo rule
-        assert_eq!(disables.0[&"foo".into()].len(), 1);
-        assert_eq!(disables.0[&"foo".into()][0].start, 5);
-        assert_eq!(disables.0[&"foo".into()][0].end, Some(7));
+        assert_eq!(disables.lines[&"foo".into()].len(), 1);
+        assert_eq!(disables.lines[&"foo".into()][0].start, 5);
+        assert_eq!(disables.lines[&"foo".into()][0].end, Some(7));
 
         // Check bar rule
-        assert_eq!(disables.0[&"bar".into()].len(), 1);
-        assert_eq!(disables.0[&"bar".into()][0].start, 9);
-        assert_eq!(disables.0[&"bar".into()][0].end, Some(12));
+        assert_eq!(disables.lines[&"bar".into()].len(), 1);
+        assert_eq!(disables.lines[&"bar".into()][0].start, 9);
+        assert_eq!(disables.lines[&"bar".into()][0].end, Some(12));
+    }
+
+    #[test]
+    fn test_collect_malformed_directives() {
+        let input = r#"{/* supa-mdx-lint-disable-nextline foo */}
+Some content
+
+{/* supa-mdx-lint-configure */}
+
+{/* supa-mdx-lint-disable-next-line foo */}
+{/* some other comment */}
+"#;
+
+        let parse_result = parse(input).unwrap();
+        let CollectedDirectives {
+            disables,
+            malformed,
+            ..
+        } = ConfigurationCommentCollection::from_parse_result(&parse_result)
+            .into_parts()
+            .unwrap();
+
+        assert_eq!(disables.lines.len(), 1);
+        assert_eq!(malformed.len(), 2);
+        assert_eq!(malformed[0].directive, "supa-mdx-lint-disable-nextline foo");
+        assert_eq!(malformed[0].range.to_usize_range(), 0..42);
+        assert_eq!(
+            malformed[0].message(),
+            "Unrecognized lint directive \"supa-mdx-lint-disable-nextline\" has no effect. Did you mean \"supa-mdx-lint-disable-next-line\"?"
+        );
+        assert_eq!(
+            malformed[1].message(),
+            "The lint directive \"supa-mdx-lint-configure\" must be followed by a rule name, such as \"supa-mdx-lint-configure Rule001HeadingCase\"."
+        );
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_collect_unknown_rule_directives() {
+        let input = r#"{/* supa-mdx-lint-disable Rule003Speling */}
+Some content
+
+{/* supa-mdx-lint-disable-next-line heading-case */}
+Some content
+
+{/* supa-mdx-lint-configure NotARule +Supabase */}
+
+{/* supa-mdx-lint-allow-next-word headng-case */}
+Word
+
+{/* supa-mdx-lint-disable */}
+"#;
+
+        let parse_result = parse(input).unwrap();
+        let CollectedDirectives { unknown_rules, .. } =
+            ConfigurationCommentCollection::from_parse_result(&parse_result)
+                .into_parts()
+                .unwrap();
+
+        assert_eq!(unknown_rules.len(), 3);
+        assert_eq!(unknown_rules[0].rule, "Rule003Speling");
+        assert_eq!(unknown_rules[0].range.to_usize_range(), 0..44);
+        assert_eq!(
+            unknown_rules[0].message(),
+            "Unknown rule \"Rule003Speling\" in lint directive has no effect. Did you mean \"Rule003Spelling\"?"
+        );
+        assert_eq!(
+            unknown_rules[1].message(),
+            "Unknown rule \"NotARule\" in lint directive has no effect."
+        );
+        assert_eq!(
+            unknown_rules[2].message(),
+            "Unknown rule \"headng-case\" in lint directive has no effect. Did you mean \"heading-case\"?"
+        );
+    }
+
+    #[test]
+    fn test_collect_inline_directive_problems() {
+        let input = "Use {/* supa-mdx-lint-allow-next-word Rule003Speling */} pgjwt and {/* supa-mdx-lint-allow-next-word Rule003Spelling -- expires: 2000-01-01 */} PostgREST.\n";
+
+        let parse_result = parse(input).unwrap();
+        let CollectedDirectives {
+            disables,
+            expired,
+            unknown_rules,
+            ..
+        } = ConfigurationCommentCollection::from_parse_result(&parse_result)
+            .into_parts()
+            .unwrap();
+
+        assert_eq!(unknown_rules.len(), 1);
+        assert_eq!(unknown_rules[0].rule, "Rule003Speling");
+        assert_eq!(
+            &input[unknown_rules[0].range.to_usize_range()],
+            "{/* supa-mdx-lint-allow-next-word Rule003Speling */}"
+        );
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].expires, "2000-01-01");
+        assert!(input[expired[0].range.to_usize_range()].ends_with("2000-01-01 */}"));
+        assert_eq!(disables.words[&"Rule003Spelling".into()].len(), 1);
+    }
+
+    #[test]
+    fn test_malformed_directive_without_suggestion() {
+        let directive = MalformedDirective {
+            directive: "supa-mdx-lint-ignore-everything".to_string(),
+            range: AdjustedRange::default(),
+        };
+        assert_eq!(
+            directive.message(),
+            "Unrecognized lint directive \"supa-mdx-lint-ignore-everything\" has no effect."
+        );
+    }
+
+    #[test]
+    fn test_directive_annotation() {
+        assert_eq!(
+            DirectiveAnnotation::split(
+                "supa-mdx-lint-disable Rule003Spelling -- reason: vendor name, expires: 2025-12-31"
+            ),
+            (
+                "supa-mdx-lint-disable Rule003Spelling",
+                Some(DirectiveAnnotation {
+                    reason: Some("vendor name".to_string()),
+                    expires: Some("2025-12-31".to_string()),
+                })
+            )
+        );
+        assert_eq!(
+            DirectiveAnnotation::split(
+                "supa-mdx-lint-disable foo -- expires: 2025-12-31, reason: a, b"
+            ),
+            (
+                "supa-mdx-lint-disable foo",
+                Some(DirectiveAnnotation {
+                    reason: Some("a, b".to_string()),
+                    expires: Some("2025-12-31".to_string()),
+                })
+            )
+        );
+        assert_eq!(
+            DirectiveAnnotation::split("supa-mdx-lint-disable foo -- vendor name"),
+            (
+                "supa-mdx-lint-disable foo",
+                Some(DirectiveAnnotation {
+                    reason: Some("vendor name".to_string()),
+                    expires: None,
+                })
+            )
+        );
+        assert_eq!(
+            DirectiveAnnotation::split("supa-mdx-lint-configure foo +--flag"),
+            ("supa-mdx-lint-configure foo +--flag", None)
+        );
+    }
+
+    #[test]
+    fn test_collect_expired_directives() {
+        let input = r#"{/* supa-mdx-lint-disable foo -- reason: vendor name, expires: 2000-01-01 */}
+Some content
+
+{/* supa-mdx-lint-disable-next-line bar -- expires: 9999-12-31 */}
+Some content
+
+{/* supa-mdx-lint-configure baz +Supabase -- expires: soon */}
+"#;
+
+        let parse_result = parse(input).unwrap();
+        let CollectedDirectives {
+            configs,
+            disables,
+            malformed,
+            expired,
+            ..
+        } = ConfigurationCommentCollection::from_parse_result(&parse_result)
+            .into_parts()
+            .unwrap();
+
+        assert_eq!(disables.lines.len(), 2);
+        assert_eq!(configs[&"baz".into()][0].0, "+Supabase");
+        assert!(malformed.is_empty());
+        assert_eq!(expired.len(), 2);
+        assert_eq!(expired[0].range.to_usize_range(), 0..77);
+        assert_eq!(
+            expired[0].message(),
+            "This lint directive expired on 2000-01-01. Fix the suppressed issues and remove it, or extend the expiry date. Reason given: \"vendor name\"."
+        );
+        assert_eq!(
+            expired[0].details(),
+            json!({ "reason": "vendor name", "expires": "2000-01-01" })
+        );
+        assert_eq!(
+            expired[1].message(),
+            "Invalid expiry date \"soon\" in lint directive. Use the format YYYY-MM-DD."
+        );
     }
 }
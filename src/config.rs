@@ -1,24 +1,41 @@
 use anyhow::Result;
 use bon::bon;
-use glob::{MatchOptions, Pattern};
+use glob::Pattern;
 use log::{debug, error, warn};
+use serde_json::{json, Value};
 use std::{
     collections::{hash_map, HashMap, HashSet},
     env,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
 use crate::{
     errors::LintLevel,
-    rules::{RuleRegistry, RuleSettings},
+    rules::{canonical_rule_name, rule_settings_schemas, RuleRegistry, RuleSettings},
     utils::{
-        path::{normalize_path, IsGlob},
+        path::{glob_in_dir, glob_match_options, normalize_path, IsGlob, PathSortOrder},
         path_relative_from,
     },
     PhaseReady, PhaseSetup,
 };
 
+mod directory_rules;
+mod template;
+
+pub(crate) use directory_rules::{DirectoryRules, DirectoryRulesCache};
+pub use template::ConfigPreset;
+
 const IGNORE_GLOBS_KEY: &str = "ignore_patterns";
+const PATH_SORT_ORDER_KEY: &str = "path_sort_order";
+const PROFILES_KEY: &str = "profile";
+const STRICT_KEY: &str = "strict";
+const PARALLEL_RULES_KEY: &str = "parallel_rules";
+const MAX_FILE_SIZE_KEY: &str = "max_file_size";
+const FOLLOW_SYMLINKS_KEY: &str = "follow_symlinks";
+const SKIP_COMPONENTS_KEY: &str = "skip_components";
+const LINT_FRONTMATTER_FIELDS_KEY: &str = "lint_frontmatter_fields";
+const EXTENDS_KEY: &str = "extends";
 
 #[derive(Debug, Clone)]
 pub struct ConfigDir(pub Option<PathBuf>);
@@ -49,7 +66,7 @@ impl ConfigFileLocations {
         }
     }
 
-    fn iter(&self) -> ConfigFileLocationsIterator {
+    fn iter(&self) -> ConfigFileLocationsIterator<'_> {
         ConfigFileLocationsIterator {
             inner: self.0.as_ref().map(|map| map.iter()),
         }
@@ -74,6 +91,22 @@ pub struct Config<Phase> {
     pub(crate) rule_specific_settings: HashMap<String, RuleSettings>,
     /// A list of globs to ignore.
     ignore_globs: HashSet<Pattern>,
+    /// How to order files in the output.
+    pub(crate) path_sort_order: PathSortOrder,
+    /// Whether warnings should fail the lint run, as errors do.
+    pub(crate) strict: bool,
+    /// The size in kilobytes above which files are reported instead of
+    /// linted. Files of any size are linted if unset.
+    pub(crate) max_file_size: Option<u64>,
+    /// Whether to follow symlinks when linting directories.
+    pub(crate) follow_symlinks: bool,
+    /// Components whose contents prose rules shouldn't lint.
+    pub(crate) skip_components: Vec<String>,
+    /// Frontmatter fields whose string values prose rules should lint.
+    pub(crate) lint_frontmatter_fields: Vec<String>,
+    /// The absolute path of the config file's directory, if any. Linting
+    /// stops looking for `.supa-mdx-lint` files here.
+    pub(crate) config_dir: Option<PathBuf>,
     config_file_locations: ConfigFileLocations,
 }
 
@@ -83,6 +116,13 @@ impl Default for Config<PhaseSetup> {
             rule_registry: RuleRegistry::<PhaseSetup>::new(),
             rule_specific_settings: HashMap::new(),
             ignore_globs: HashSet::new(),
+            path_sort_order: PathSortOrder::default(),
+            strict: false,
+            max_file_size: None,
+            follow_symlinks: true,
+            skip_components: Vec::new(),
+            lint_frontmatter_fields: Vec::new(),
+            config_dir: None,
             config_file_locations: ConfigFileLocations(None),
         }
     }
@@ -117,22 +157,115 @@ impl Config<PhaseSetup> {
     ///
     /// Rule003NotApplied = false
     /// ```
+    ///
+    /// A rule can include several files, either as
+    /// `include('base.toml', 'product.toml')` or as an array of includes. The
+    /// files are merged in order, and arrays such as allow lists are
+    /// concatenated.
+    ///
+    /// The configuration file can extend other configuration files, or the
+    /// bundled presets, using the `extends` array. The extended configurations
+    /// are merged in order, and the settings in the current file override them.
+    /// Tables are merged key by key, and all other values are replaced.
+    ///
+    /// ```toml
+    /// extends = ["supabase-docs", "./shared/base.toml"]
+    /// ```
+    ///
+    /// The configuration file can also define named profiles under the
+    /// `profile` table, which are applied on top of the rest of the file when
+    /// selected. See [`Config::from_config_file_with_profile`].
     pub fn from_config_file<P: AsRef<Path>>(config_file: P) -> Result<Self> {
-        let config_file = config_file.as_ref();
+        Self::load_config_file(config_file.as_ref(), None)
+    }
+
+    /// Read the rule configuration from a TOML file, applying the named
+    /// profile on top of it.
+    ///
+    /// Profiles override the top-level settings and rule configuration. Rule
+    /// settings are merged with the top-level settings for the rule, and a
+    /// rule can be turned off with `false`, or turned back on with `true`.
+    ///
+    /// Example:
+    ///
+    /// ```toml
+    /// Rule003Spelling = false
+    ///
+    /// [Rule001HeadingCase]
+    /// level = "warn"
+    ///
+    /// [profile.ci]
+    /// Rule003Spelling = true
+    ///
+    /// [profile.ci.Rule001HeadingCase]
+    /// level = "error"
+    /// ```
+    pub fn from_config_file_with_profile<P: AsRef<Path>>(
+        config_file: P,
+        profile: &str,
+    ) -> Result<Self> {
+        Self::load_config_file(config_file.as_ref(), Some(profile))
+    }
+
+    fn load_config_file(config_file: &Path, profile: Option<&str>) -> Result<Self> {
+        let config_dir = config_file.parent().ok_or_else(|| {
+            anyhow::anyhow!("Unable to determine parent directory of config file: {config_file:?}")
+        })?;
+
+        let mut file_locations = ConfigFileLocations::default();
+        let parsed = Self::read_config_file()
+            .config_file(config_file)
+            .file_locations(&mut file_locations)
+            .extended_from(&mut Vec::new())
+            .call()?;
+
+        let config_dir = ConfigDir(Some(config_dir.to_path_buf()));
+        Self::from_serializable()
+            .config(parsed)
+            .config_dir(&config_dir)
+            .config_file_locations(file_locations)
+            .maybe_profile(profile)
+            .call()
+    }
+
+    /// Read a config file, resolving its includes and merging it on top of
+    /// the configs it extends.
+    #[builder]
+    fn read_config_file(
+        config_file: &Path,
+        file_locations: &mut ConfigFileLocations,
+        /// The chain of files being read, used to detect cycles.
+        extended_from: &mut Vec<PathBuf>,
+    ) -> Result<toml::Table> {
         let config_path = config_file.to_path_buf();
         let config_dir = config_path.parent().ok_or_else(|| {
             anyhow::anyhow!("Unable to determine parent directory of config file: {config_path:?}")
         })?;
 
+        let canonical_path = std::fs::canonicalize(&config_path).unwrap_or(config_path.clone());
+        if extended_from.contains(&canonical_path) {
+            return Err(anyhow::anyhow!(
+                "Config file {config_path:?} extends itself"
+            ));
+        }
+
         let config_content = std::fs::read_to_string(&config_path)
             .inspect_err(|_| error!("Failed to read config file at {config_path:?}"))?;
-        let table: toml::Table = toml::from_str(&config_content)?;
-
-        let mut file_locations = ConfigFileLocations::default();
+        let mut table: toml::Table = toml::from_str(&config_content)?;
+        let extends = match table.remove(EXTENDS_KEY) {
+            Some(toml::Value::Array(extends)) => extends,
+            Some(value @ toml::Value::String(_)) => vec![value],
+            Some(_) => {
+                return Err(anyhow::anyhow!(
+                    "Invalid configuration. {EXTENDS_KEY} must be an array of presets or paths."
+                ))
+            }
+            None => Vec::new(),
+        };
 
         let parsed = Self::process_includes()
             .table(&table)
-            .file_locations(&mut file_locations)
+            .file_locations(file_locations)
             .base_dir(config_dir)
             .current_file(config_file)
             .is_top_level(true)
@@ -142,12 +275,46 @@ impl Config<PhaseSetup> {
                 debug!("Config file content:\n\t{config_content}")
             })?;
 
-        let config_dir = ConfigDir(Some(config_dir.to_path_buf()));
-        Self::from_serializable()
-            .config(parsed)
-            .config_dir(&config_dir)
-            .config_file_locations(file_locations)
-            .call()
+        let mut merged = toml::Table::new();
+        extended_from.push(canonical_path);
+        for extended in extends {
+            let toml::Value::String(extended) = extended else {
+                return Err(anyhow::anyhow!(
+                    "Invalid configuration. {EXTENDS_KEY} must be an array of presets or paths."
+                ));
+            };
+
+            let base = if Self::is_preset_name(&extended) {
+                ConfigPreset::from_str(&extended)
+                    .map_err(|_| anyhow::anyhow!("Unknown preset {extended} in {config_path:?}"))?
+                    .settings()
+            } else {
+                Self::read_config_file()
+                    .config_file(&config_dir.join(&extended))
+                    .file_locations(file_locations)
+                    .extended_from(extended_from)
+                    .call()
+                    .map_err(|e| {
+                        anyhow::anyhow!(
+                            "Failed to extend config file {extended} from {config_path:?}: {e}"
+                        )
+                    })?
+            };
+            merge_tables(&mut merged, base, ArrayMerge::Replace);
+        }
+        extended_from.pop();
+
+        merge_tables(
+            &mut merged,
+            normalize_rule_names(parsed),
+            ArrayMerge::Replace,
+        );
+        Ok(merged)
+    }
+
+    /// Entries in `extends` that don't look like paths are preset names.
+    fn is_preset_name(extended: &str) -> bool {
+        !extended.contains(['/', '\\']) && !extended.ends_with(".toml")
     }
 
     #[builder]
@@ -162,24 +329,24 @@ impl Config<PhaseSetup> {
 
         for (key, value) in table {
             let processed_value = match value {
-                toml::Value::String(s) if s.starts_with("include('") && s.ends_with("')") => {
-                    // Extract the path from include('path')
-                    let path_str = s[9..s.len() - 2].to_string();
-                    let include_path = base_dir.join(path_str);
+                value if include_paths(value).is_some() => {
+                    let mut merged = toml::Table::new();
+                    for path_str in include_paths(value).unwrap_or_default() {
+                        let include_path = base_dir.join(path_str);
 
-                    let include_content = std::fs::read_to_string(&include_path).map_err(|e| {
-                        anyhow::anyhow!(
-                            "Failed to read include file at path {:?}: {}",
-                            include_path,
-                            e
-                        )
-                    })?;
+                        let include_content =
+                            std::fs::read_to_string(&include_path).map_err(|e| {
+                                anyhow::anyhow!(
+                                    "Failed to read include file at path {:?}: {}",
+                                    include_path,
+                                    e
+                                )
+                            })?;
 
-                    file_locations.insert(key, include_path.as_path());
+                        file_locations.insert(key, include_path.as_path());
 
-                    let table: toml::Table = toml::from_str(&include_content)?;
-                    toml::Value::Table(
-                        Self::process_includes()
+                        let table: toml::Table = toml::from_str(&include_content)?;
+                        let included = Self::process_includes()
                             .table(&table)
                             .file_locations(file_locations)
                             .base_dir(base_dir)
@@ -191,8 +358,10 @@ impl Config<PhaseSetup> {
                                     include_path,
                                     e
                                 )
-                            })?,
-                    )
+                            })?;
+                        merge_tables(&mut merged, included, ArrayMerge::Append);
+                    }
+                    toml::Value::Table(merged)
                 }
                 toml::Value::Table(table) => {
                     if is_top_level {
@@ -227,22 +396,74 @@ impl Config<PhaseSetup> {
         config_dir: &ConfigDir,
         #[builder(default = ConfigFileLocations::default())]
         config_file_locations: ConfigFileLocations,
+        profile: Option<&str>,
     ) -> Result<Self> {
         let registry = RuleRegistry::new();
         let value = toml::Value::try_from(config)?;
-        let table = Self::validate_config_structure(value)?;
+        let table = normalize_rule_names(Self::validate_config_structure(value)?);
+        let table = Self::apply_profile(table, profile)?;
 
-        let (registry, rule_settings, ignore_globs) =
-            Self::process_config_table(registry, table, config_dir)?;
+        let (
+            registry,
+            rule_settings,
+            ignore_globs,
+            path_sort_order,
+            strict,
+            max_file_size,
+            follow_symlinks,
+            skip_components,
+            lint_frontmatter_fields,
+        ) = Self::process_config_table(registry, table, config_dir)?;
+        let config_dir = match config_dir.0 {
+            Some(ref dir) => Some(env::current_dir()?.join(dir)),
+            None => None,
+        };
 
         Ok(Self {
             rule_registry: registry,
             rule_specific_settings: rule_settings,
             ignore_globs,
+            path_sort_order,
+            strict,
+            max_file_size,
+            follow_symlinks,
+            skip_components,
+            lint_frontmatter_fields,
+            config_dir,
             config_file_locations,
         })
     }
 
+    /// Override the level of a rule, as if it had been set in the config
+    /// file. Rules that were turned off in the config file are turned back
+    /// on.
+    pub fn override_rule_level(&mut self, rule_name: &str, level: LintLevel) -> Result<()> {
+        let rule_name = canonical_rule_name(rule_name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown rule: {rule_name}"))?;
+        if !self.rule_registry.activate_rule(rule_name) {
+            return Err(anyhow::anyhow!("Unknown rule: {rule_name}"));
+        }
+        self.rule_registry.save_configured_level(rule_name, level);
+        self.rule_specific_settings
+            .entry(rule_name.to_string())
+            .or_insert_with(|| RuleSettings::new(toml::Table::new()));
+        Ok(())
+    }
+
+    /// Turn off a rule, overriding the config file.
+    pub fn disable_rule(&mut self, rule_name: &str) -> Result<()> {
+        let rule_name = canonical_rule_name(rule_name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown rule: {rule_name}"))?;
+        self.rule_registry.deactivate_rule(rule_name);
+        Ok(())
+    }
+
+    /// Write a starter config file that lists every rule and its settings,
+    /// optionally filled in with the settings from a bundled preset.
+    pub fn template(preset: Option<ConfigPreset>) -> String {
+        template::config_template(preset)
+    }
+
     fn validate_config_structure(value: toml::Value) -> Result<toml::Table> {
         match value {
             toml::Value::Table(table) => Ok(table),
@@ -252,6 +473,63 @@ impl Config<PhaseSetup> {
         }
     }
 
+    /// Remove the profiles from the config, and merge the selected profile
+    /// into the rest of the config.
+    fn apply_profile(mut table: toml::Table, profile: Option<&str>) -> Result<toml::Table> {
+        let profiles = match table.remove(PROFILES_KEY) {
+            Some(toml::Value::Table(profiles)) => profiles,
+            Some(_) => {
+                return Err(anyhow::anyhow!(
+                    "Invalid configuration. {PROFILES_KEY} must be a table of named profiles."
+                ))
+            }
+            None => toml::Table::new(),
+        };
+
+        let Some(profile) = profile else {
+            return Ok(table);
+        };
+        let overrides = match profiles.get(profile) {
+            Some(toml::Value::Table(overrides)) => overrides,
+            Some(_) => {
+                return Err(anyhow::anyhow!(
+                    "Invalid configuration. Profile {profile} must be a table."
+                ))
+            }
+            None => {
+                let available = profiles.keys().cloned().collect::<Vec<_>>();
+                return Err(anyhow::anyhow!(
+                    "Profile {profile} not found in config. Available profiles: {}",
+                    if available.is_empty() {
+                        "none".to_string()
+                    } else {
+                        available.join(", ")
+                    }
+                ));
+            }
+        };
+        debug!("Applying config profile {profile}");
+
+        for (key, value) in overrides {
+            match (table.get_mut(key), value) {
+                (Some(toml::Value::Table(base)), toml::Value::Table(overrides)) => {
+                    base.extend(overrides.clone());
+                }
+                // Turning a rule back on restores its default settings if the
+                // base config turns it off, and keeps its settings otherwise.
+                (Some(toml::Value::Boolean(false)), toml::Value::Boolean(true)) => {
+                    table.remove(key);
+                }
+                (_, toml::Value::Boolean(true)) => {}
+                _ => {
+                    table.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        Ok(table)
+    }
+
     #[allow(clippy::type_complexity)]
     fn process_config_table(
         mut registry: RuleRegistry<PhaseSetup>,
@@ -261,22 +539,34 @@ impl Config<PhaseSetup> {
         RuleRegistry<PhaseSetup>,
         HashMap<String, RuleSettings>,
         HashSet<Pattern>,
+        PathSortOrder,
+        bool,
+        Option<u64>,
+        bool,
+        Vec<String>,
+        Vec<String>,
     )> {
         let mut filtered_rules: HashSet<String> = HashSet::new();
         let mut rule_specific_settings = HashMap::new();
         let mut ignore_globs = HashSet::<Pattern>::new();
+        let mut path_sort_order = PathSortOrder::default();
+        let mut strict = false;
+        let mut max_file_size = None;
+        let mut follow_symlinks = true;
+        let mut skip_components = Vec::new();
+        let mut lint_frontmatter_fields = Vec::new();
 
         for (key, value) in table {
             match value {
                 toml::Value::Array(arr) if key == IGNORE_GLOBS_KEY => {
                     arr.into_iter().for_each(|glob| {
                         if let toml::Value::String(glob) = glob {
+                            let current_dir = std::env::current_dir().unwrap();
                             let root_dir = match config_dir.0 {
-                                Some(ref dir) => dir,
-                                None => &std::env::current_dir().unwrap(),
+                                Some(ref dir) => current_dir.join(dir),
+                                None => current_dir,
                             };
-                            let glob = root_dir.join(glob);
-                            let glob_str = normalize_path(&glob, IsGlob(true));
+                            let glob_str = glob_in_dir(&root_dir, &glob);
                             match Pattern::new(&glob_str) {
                                 Ok(glob) => {
                                     ignore_globs.insert(glob);
@@ -288,6 +578,49 @@ impl Config<PhaseSetup> {
                         }
                     });
                 }
+                toml::Value::String(order) if key == PATH_SORT_ORDER_KEY => {
+                    match toml::Value::String(order.clone()).try_into() {
+                        Ok(order) => path_sort_order = order,
+                        Err(_) => warn!(
+                            "Invalid {PATH_SORT_ORDER_KEY} \"{order}\". Expected \"lexicographic\" or \"natural\"."
+                        ),
+                    }
+                }
+                toml::Value::Boolean(value) if key == STRICT_KEY => {
+                    strict = value;
+                }
+                toml::Value::Integer(value) if key == MAX_FILE_SIZE_KEY => {
+                    match u64::try_from(value) {
+                        Ok(value) if value > 0 => max_file_size = Some(value),
+                        _ => warn!(
+                            "Invalid {MAX_FILE_SIZE_KEY} {value}. Expected a positive number of kilobytes."
+                        ),
+                    }
+                }
+                toml::Value::Boolean(value) if key == FOLLOW_SYMLINKS_KEY => {
+                    follow_symlinks = value;
+                }
+                toml::Value::Boolean(value) if key == PARALLEL_RULES_KEY => {
+                    registry.set_parallel(value);
+                }
+                toml::Value::Array(arr) if key == SKIP_COMPONENTS_KEY => {
+                    skip_components = arr
+                        .into_iter()
+                        .filter_map(|component| match component {
+                            toml::Value::String(component) => Some(component),
+                            _ => None,
+                        })
+                        .collect();
+                }
+                toml::Value::Array(arr) if key == LINT_FRONTMATTER_FIELDS_KEY => {
+                    lint_frontmatter_fields = arr
+                        .into_iter()
+                        .filter_map(|field| match field {
+                            toml::Value::String(field) => Some(field),
+                            _ => None,
+                        })
+                        .collect();
+                }
                 toml::Value::Boolean(false) if registry.is_valid_rule(&key) => {
                     filtered_rules.insert(key.clone());
                 }
@@ -314,7 +647,17 @@ impl Config<PhaseSetup> {
             registry.deactivate_rule(rule_name);
         });
 
-        Ok((registry, rule_specific_settings, ignore_globs))
+        Ok((
+            registry,
+            rule_specific_settings,
+            ignore_globs,
+            path_sort_order,
+            strict,
+            max_file_size,
+            follow_symlinks,
+            skip_components,
+            lint_frontmatter_fields,
+        ))
     }
 }
 
@@ -329,6 +672,13 @@ impl TryFrom<Config<PhaseSetup>> for Config<PhaseReady> {
             rule_registry: ready_registry,
             rule_specific_settings: old_config.rule_specific_settings,
             ignore_globs: old_config.ignore_globs,
+            path_sort_order: old_config.path_sort_order,
+            strict: old_config.strict,
+            max_file_size: old_config.max_file_size,
+            follow_symlinks: old_config.follow_symlinks,
+            skip_components: old_config.skip_components,
+            lint_frontmatter_fields: old_config.lint_frontmatter_fields,
+            config_dir: old_config.config_dir,
             config_file_locations: old_config.config_file_locations,
         })
     }
@@ -351,16 +701,10 @@ impl<RuleRegistryState> Config<RuleRegistryState> {
         let path_str = normalize_path(path, IsGlob(false));
         debug!("Checking if {path_str} is ignored");
 
-        let is_ignored = self.ignore_globs.iter().any(|pattern| {
-            pattern.matches_with(
-                &path_str,
-                MatchOptions {
-                    case_sensitive: true,
-                    require_literal_separator: true,
-                    require_literal_leading_dot: false,
-                },
-            )
-        });
+        let is_ignored = self
+            .ignore_globs
+            .iter()
+            .any(|pattern| pattern.matches_with(&path_str, glob_match_options()));
         debug!(
             "Path {path_str} is {}ignored",
             if is_ignored { "" } else { "not " }
@@ -369,6 +713,192 @@ impl<RuleRegistryState> Config<RuleRegistryState> {
     }
 }
 
+/// Parse the paths from an include value, either a single string like
+/// `include('a.toml', 'b.toml')`, or an array of such strings.
+fn include_paths(value: &toml::Value) -> Option<Vec<String>> {
+    match value {
+        toml::Value::String(s) => {
+            let inner = s.strip_prefix("include(")?.strip_suffix(')')?;
+            inner
+                .split(',')
+                .map(|path| {
+                    let path = path.trim();
+                    path.strip_prefix('\'')
+                        .and_then(|path| path.strip_suffix('\''))
+                        .or_else(|| {
+                            path.strip_prefix('"')
+                                .and_then(|path| path.strip_suffix('"'))
+                        })
+                        .filter(|path| !path.is_empty())
+                        .map(ToString::to_string)
+                })
+                .collect()
+        }
+        toml::Value::Array(values) if !values.is_empty() => values
+            .iter()
+            .map(include_paths)
+            .collect::<Option<Vec<_>>>()
+            .map(|paths| paths.into_iter().flatten().collect()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArrayMerge {
+    /// Arrays in the overrides replace arrays in the base.
+    Replace,
+    /// Arrays in the overrides are appended to arrays in the base, skipping
+    /// values that are already present.
+    Append,
+}
+
+/// Merge the overrides into the base table. Tables are merged recursively, and
+/// all other values are replaced, except for arrays if they are appended.
+fn merge_tables(base: &mut toml::Table, overrides: toml::Table, arrays: ArrayMerge) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overrides)) => {
+                merge_tables(base, overrides, arrays);
+            }
+            (Some(toml::Value::Array(base)), toml::Value::Array(overrides))
+                if arrays == ArrayMerge::Append =>
+            {
+                for value in overrides {
+                    if !base.contains(&value) {
+                        base.push(value);
+                    }
+                }
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Replace rule aliases and deprecated rule names with the current rule
+/// names, at the top level and in profiles. Settings for the same rule under
+/// different names are merged.
+fn normalize_rule_names(table: toml::Table) -> toml::Table {
+    let mut normalized = toml::Table::new();
+    for (key, value) in table {
+        let (key, value) = match (canonical_rule_name(&key), value) {
+            (Some(rule_name), value) => (rule_name.to_string(), value),
+            (None, toml::Value::Table(profiles)) if key == PROFILES_KEY => {
+                let profiles = profiles
+                    .into_iter()
+                    .map(|(name, profile)| match profile {
+                        toml::Value::Table(profile) => {
+                            (name, toml::Value::Table(normalize_rule_names(profile)))
+                        }
+                        profile => (name, profile),
+                    })
+                    .collect();
+                (key, toml::Value::Table(profiles))
+            }
+            (None, value) => (key, value),
+        };
+
+        let mut entry = toml::Table::new();
+        entry.insert(key, value);
+        merge_tables(&mut normalized, entry, ArrayMerge::Replace);
+    }
+    normalized
+}
+
+/// A JSON-schema-like description of the configuration file, including the
+/// settings accepted by every rule.
+pub(crate) fn config_schema() -> Value {
+    let mut properties = rule_settings_schemas();
+    properties.insert(
+        EXTENDS_KEY.to_string(),
+        json!({
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Bundled presets or paths to config files to extend.",
+        }),
+    );
+    properties.insert(
+        IGNORE_GLOBS_KEY.to_string(),
+        json!({
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Globs for files to ignore.",
+        }),
+    );
+    properties.insert(
+        PATH_SORT_ORDER_KEY.to_string(),
+        json!({
+            "type": "string",
+            "enum": ["lexicographic", "natural"],
+            "default": "lexicographic",
+            "description": "How to order files in the output.",
+        }),
+    );
+    properties.insert(
+        STRICT_KEY.to_string(),
+        json!({
+            "type": "boolean",
+            "default": false,
+            "description": "Fail the lint run on warnings as well as errors. Reported levels are unchanged.",
+        }),
+    );
+    properties.insert(
+        MAX_FILE_SIZE_KEY.to_string(),
+        json!({
+            "type": "integer",
+            "minimum": 1,
+            "examples": [10240],
+            "description": "The size in kilobytes above which files are reported instead of linted. Files of any size are linted by default.",
+        }),
+    );
+    properties.insert(
+        FOLLOW_SYMLINKS_KEY.to_string(),
+        json!({
+            "type": "boolean",
+            "default": true,
+            "description": "Follow symlinks when linting directories. Links back to a parent directory are skipped.",
+        }),
+    );
+    properties.insert(
+        PARALLEL_RULES_KEY.to_string(),
+        json!({
+            "type": "boolean",
+            "default": false,
+            "description": "Run rules on separate threads within each file. Errors are reported in the same order.",
+        }),
+    );
+    properties.insert(
+        SKIP_COMPONENTS_KEY.to_string(),
+        json!({
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Components whose contents aren't checked by prose rules, such as spelling.",
+        }),
+    );
+    properties.insert(
+        LINT_FRONTMATTER_FIELDS_KEY.to_string(),
+        json!({
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Frontmatter fields whose values are checked by prose rules, such as spelling.",
+        }),
+    );
+    properties.insert(
+        PROFILES_KEY.to_string(),
+        json!({
+            "type": "object",
+            "additionalProperties": { "type": "object" },
+            "description": "Named profiles that can be applied with --profile.",
+        }),
+    );
+
+    json!({
+        "type": "object",
+        "properties": properties,
+    })
+}
+
 #[derive(Debug, Default)]
 pub struct ConfigMetadata {
     pub config_file_locations: Option<HashMap<String, String>>,
@@ -429,6 +959,261 @@ option2 = "value"
         assert!(config.rule_registry.is_rule_active(VALID_RULE_NAME));
     }
 
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_config_profile() {
+        let content = format!(
+            r#"
+ignore_patterns = ["drafts/**"]
+{VALID_RULE_NAME_2} = false
+
+[{VALID_RULE_NAME}]
+level = "warn"
+may_uppercase = ["Supabase"]
+
+[profile.ci]
+{VALID_RULE_NAME_2} = true
+
+[profile.ci.{VALID_RULE_NAME}]
+level = "error"
+
+[profile.editor]
+{VALID_RULE_NAME} = false
+"#
+        );
+        let file = create_temp_config_file(&content);
+
+        let config = Config::from_config_file(file.path()).unwrap();
+        assert!(!config.rule_registry.is_rule_active(VALID_RULE_NAME_2));
+        assert!(!config.rule_specific_settings.contains_key(PROFILES_KEY));
+
+        let config = Config::from_config_file_with_profile(file.path(), "ci").unwrap();
+        assert!(config.rule_registry.is_rule_active(VALID_RULE_NAME_2));
+        let settings = config.rule_specific_settings.get(VALID_RULE_NAME).unwrap();
+        assert!(settings.has_key("may_uppercase"));
+        assert_eq!(
+            config.rule_registry.get_configured_level(VALID_RULE_NAME),
+            Some(LintLevel::Error)
+        );
+        assert!(config.is_ignored(file.path().parent().unwrap().join("drafts/page.mdx")));
+
+        let config = Config::from_config_file_with_profile(file.path(), "editor").unwrap();
+        assert!(!config.rule_registry.is_rule_active(VALID_RULE_NAME));
+    }
+
+    #[test]
+    fn test_config_unknown_profile() {
+        let content = "[profile.ci]\nRule001HeadingCase = false\n";
+        let file = create_temp_config_file(content);
+        let err = Config::from_config_file_with_profile(file.path(), "local").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Profile local not found in config. Available profiles: ci"
+        );
+    }
+
+    #[test]
+    fn test_config_extends() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs::create_dir(temp_dir.path().join("shared"))?;
+        fs::write(
+            temp_dir.path().join("shared/base.toml"),
+            format!(
+                r#"
+{VALID_RULE_NAME_2} = false
+
+[{VALID_RULE_NAME}]
+level = "warn"
+may_uppercase = ["Supabase"]
+"#
+            ),
+        )?;
+
+        let main_config_path = temp_dir.path().join("config.toml");
+        fs::write(
+            &main_config_path,
+            format!(
+                r#"
+extends = ["supabase-docs", "./shared/base.toml"]
+
+[{VALID_RULE_NAME}]
+may_lowercase = ["iOS"]
+"#
+            ),
+        )?;
+
+        let config = Config::from_config_file(&main_config_path)?;
+        assert!(!config.rule_registry.is_rule_active(VALID_RULE_NAME_2));
+        assert!(config
+            .rule_specific_settings
+            .contains_key("Rule002AdmonitionTypes"));
+        assert_eq!(
+            config.rule_registry.get_configured_level(VALID_RULE_NAME),
+            Some(LintLevel::Warning)
+        );
+
+        // Rule tables are merged, so settings from the preset, the base
+        // file, and the main file are all kept.
+        let settings = config.rule_specific_settings.get(VALID_RULE_NAME).unwrap();
+        assert!(settings.has_key("presets"));
+        assert!(settings.has_key("may_uppercase"));
+        assert!(settings.has_key("may_lowercase"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_extends_cycle() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let a_path = temp_dir.path().join("a.toml");
+        fs::write(&a_path, "extends = [\"./b.toml\"]\n")?;
+        fs::write(temp_dir.path().join("b.toml"), "extends = [\"./a.toml\"]\n")?;
+
+        let err = Config::from_config_file(&a_path).unwrap_err();
+        assert!(err.to_string().contains("extends itself"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_extends_unknown_preset() {
+        let file = create_temp_config_file("extends = [\"unknown\"]\n");
+        let err = Config::from_config_file(file.path()).unwrap_err();
+        assert!(
+            err.to_string().starts_with("Unknown preset unknown"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_merge_tables() {
+        let mut base: toml::Table = toml::from_str(
+            r#"
+a = [1, 2]
+[b]
+c = 1
+d = 2
+"#,
+        )
+        .unwrap();
+        let overrides: toml::Table = toml::from_str(
+            r#"
+a = [3]
+[b]
+d = 3
+e = 4
+"#,
+        )
+        .unwrap();
+        merge_tables(&mut base, overrides, ArrayMerge::Replace);
+
+        let expected: toml::Table = toml::from_str(
+            r#"
+a = [3]
+[b]
+c = 1
+d = 3
+e = 4
+"#,
+        )
+        .unwrap();
+        assert_eq!(base, expected);
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_config_with_multiple_includes() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs::create_dir(temp_dir.path().join("vocab"))?;
+        fs::write(
+            temp_dir.path().join("vocab/base.toml"),
+            "allow_list = [\"Supabase\", \"Postgres\"]\nprefixes = [\"pre\"]\n",
+        )?;
+        fs::write(
+            temp_dir.path().join("vocab/product.toml"),
+            "allow_list = [\"Postgres\", \"Supavisor\"]\n",
+        )?;
+        fs::write(
+            temp_dir.path().join("vocab/team.toml"),
+            "allow_list = [\"pgvector\"]\n",
+        )?;
+
+        let main_config_path = temp_dir.path().join("config.toml");
+        fs::write(
+            &main_config_path,
+            format!(
+                r#"
+{VALID_RULE_NAME_2} = "include('vocab/base.toml', 'vocab/product.toml')"
+{VALID_RULE_NAME} = ["include('vocab/base.toml')", "include('vocab/team.toml')"]
+"#
+            ),
+        )?;
+
+        let table = Config::read_config_file()
+            .config_file(&main_config_path)
+            .file_locations(&mut ConfigFileLocations::default())
+            .extended_from(&mut Vec::new())
+            .call()?;
+        let expected: toml::Table = toml::from_str(&format!(
+            r#"
+[{VALID_RULE_NAME_2}]
+allow_list = ["Supabase", "Postgres", "Supavisor"]
+prefixes = ["pre"]
+
+[{VALID_RULE_NAME}]
+allow_list = ["Supabase", "Postgres", "pgvector"]
+prefixes = ["pre"]
+"#
+        ))?;
+        assert_eq!(table, expected);
+
+        let config = Config::from_config_file(main_config_path)?;
+        assert!(config.rule_specific_settings.contains_key(VALID_RULE_NAME));
+        assert!(config
+            .rule_specific_settings
+            .contains_key(VALID_RULE_NAME_2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_include_paths() {
+        assert_eq!(
+            include_paths(&toml::Value::String(
+                "include('a.toml', \"b.toml\")".to_string()
+            )),
+            Some(vec!["a.toml".to_string(), "b.toml".to_string()])
+        );
+        assert_eq!(
+            include_paths(&toml::Value::Array(vec![
+                "include('a.toml')".into(),
+                "include('b.toml')".into()
+            ])),
+            Some(vec!["a.toml".to_string(), "b.toml".to_string()])
+        );
+        assert_eq!(
+            include_paths(&toml::Value::String("a.toml".to_string())),
+            None
+        );
+        assert_eq!(
+            include_paths(&toml::Value::Array(vec![
+                "include('a.toml')".into(),
+                "b".into()
+            ])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_merge_tables_append_arrays() {
+        let mut base: toml::Table = toml::from_str("a = [1, 2]\n[b]\nc = [\"x\"]\n").unwrap();
+        let overrides: toml::Table = toml::from_str("a = [2, 3]\n[b]\nc = [\"y\"]\n").unwrap();
+        merge_tables(&mut base, overrides, ArrayMerge::Append);
+
+        let expected: toml::Table =
+            toml::from_str("a = [1, 2, 3]\n[b]\nc = [\"x\", \"y\"]\n").unwrap();
+        assert_eq!(base, expected);
+    }
+
     #[test]
     fn test_config_with_includes() -> Result<()> {
         let temp_dir = tempfile::tempdir()?;
@@ -510,6 +1295,219 @@ option2 = "value"
         assert!(!config.rule_registry.is_rule_active(VALID_RULE_NAME));
     }
 
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_config_override_rule_level() {
+        let config_json = json!({
+            VALID_RULE_NAME: false,
+            VALID_RULE_NAME_2: { "level": "error" },
+        });
+        let mut config = Config::from_serializable()
+            .config(config_json)
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+
+        config
+            .override_rule_level(VALID_RULE_NAME, LintLevel::Warning)
+            .unwrap();
+        config
+            .override_rule_level(VALID_RULE_NAME_2, LintLevel::Warning)
+            .unwrap();
+        assert!(config.rule_registry.is_rule_active(VALID_RULE_NAME));
+        assert_eq!(
+            config.rule_registry.get_configured_level(VALID_RULE_NAME),
+            Some(LintLevel::Warning)
+        );
+        assert_eq!(
+            config.rule_registry.get_configured_level(VALID_RULE_NAME_2),
+            Some(LintLevel::Warning)
+        );
+        assert!(config
+            .override_rule_level("Rule999Unknown", LintLevel::Warning)
+            .is_err());
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_config_disable_rule() {
+        let mut config = Config::default();
+        config.disable_rule(VALID_RULE_NAME).unwrap();
+        assert!(!config.rule_registry.is_rule_active(VALID_RULE_NAME));
+        assert!(config.rule_registry.is_rule_active(VALID_RULE_NAME_2));
+        assert!(config.disable_rule("Rule999Unknown").is_err());
+
+        config.disable_rule("spelling").unwrap();
+        assert!(!config.rule_registry.is_rule_active(VALID_RULE_NAME_2));
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_config_rule_aliases() {
+        let config = Config::from_serializable()
+            .config(json!({
+                "heading-case": false,
+                "spelling": { "level": "warn", "allow_list": ["Supa"] },
+                VALID_RULE_NAME_2: { "prefixes": ["pg_"] },
+                "profile": { "ci": { "spelling": { "level": "error" } } },
+            }))
+            .config_dir(&ConfigDir(None))
+            .profile("ci")
+            .call()
+            .unwrap();
+        assert!(!config.rule_registry.is_rule_active(VALID_RULE_NAME));
+        assert_eq!(
+            config.rule_registry.get_configured_level(VALID_RULE_NAME_2),
+            Some(LintLevel::Error)
+        );
+
+        let settings = &config.rule_specific_settings[VALID_RULE_NAME_2];
+        assert!(settings.has_key("allow_list"));
+        assert!(settings.has_key("prefixes"));
+        assert!(!config.rule_specific_settings.contains_key("spelling"));
+    }
+
+    #[test]
+    fn test_config_ignore_patterns_escape_config_dir() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let config_dir = dir.path().join("[docs]");
+        std::fs::create_dir_all(config_dir.join("drafts"))?;
+        let config_path = config_dir.join("supa-mdx-lint.config.toml");
+        std::fs::write(&config_path, "ignore_patterns = [\"drafts/**\"]")?;
+        std::fs::write(config_dir.join("drafts/page.mdx"), "")?;
+
+        let config = Config::from_config_file(&config_path)?;
+        assert!(config.is_ignored(config_dir.join("drafts/page.mdx")));
+        assert!(config.is_ignored(config_dir.join("drafts/../drafts/missing.mdx")));
+        assert!(!config.is_ignored(config_dir.join("page.mdx")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_path_sort_order() {
+        let config = Config::from_serializable()
+            .config(json!({ "path_sort_order": "natural" }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert_eq!(config.path_sort_order, PathSortOrder::Natural);
+
+        let config = Config::from_serializable()
+            .config(json!({ "path_sort_order": "random" }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert_eq!(config.path_sort_order, PathSortOrder::Lexicographic);
+    }
+
+    #[test]
+    fn test_config_strict() {
+        let config = Config::from_serializable()
+            .config(json!({ "strict": true }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert!(config.strict);
+
+        let config = Config::from_serializable()
+            .config(json!({}))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert!(!config.strict);
+    }
+
+    #[test]
+    fn test_config_max_file_size() {
+        let config = Config::from_serializable()
+            .config(json!({ "max_file_size": 512 }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert_eq!(config.max_file_size, Some(512));
+
+        let config = Config::from_serializable()
+            .config(json!({ "max_file_size": -1 }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert_eq!(config.max_file_size, None);
+    }
+
+    #[test]
+    fn test_config_follow_symlinks() {
+        let config = Config::from_serializable()
+            .config(json!({ "follow_symlinks": false }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert!(!config.follow_symlinks);
+
+        let config = Config::from_serializable()
+            .config(json!({}))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert!(config.follow_symlinks);
+    }
+
+    #[test]
+    fn test_config_parallel_rules() {
+        let config = Config::from_serializable()
+            .config(json!({ "parallel_rules": true }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert!(config.rule_registry.is_parallel());
+
+        let config = Config::from_serializable()
+            .config(json!({}))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert!(!config.rule_registry.is_parallel());
+    }
+
+    #[test]
+    fn test_config_lint_frontmatter_fields() {
+        let config = Config::from_serializable()
+            .config(json!({ "lint_frontmatter_fields": ["title", "description"] }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert_eq!(config.lint_frontmatter_fields, vec!["title", "description"]);
+    }
+
+    #[test]
+    fn test_config_skip_components() {
+        let config = Config::from_serializable()
+            .config(json!({ "skip_components": ["CodeSample", "Terminal"] }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        assert_eq!(config.skip_components, vec!["CodeSample", "Terminal"]);
+    }
+
+    #[test]
+    fn test_config_schema() {
+        let schema = config_schema();
+        let properties = &schema["properties"];
+        assert_eq!(properties["ignore_patterns"]["type"], "array");
+        assert_eq!(
+            properties["path_sort_order"]["enum"],
+            json!(["lexicographic", "natural"])
+        );
+        assert_eq!(properties["profile"]["type"], "object");
+
+        let rule = &properties["Rule002AdmonitionTypes"]["anyOf"];
+        assert_eq!(rule[0]["const"], false);
+        assert_eq!(rule[1]["properties"]["admonition_types"]["type"], "array");
+        assert_eq!(
+            rule[1]["properties"]["level"]["enum"],
+            json!(["error", "warn", "hint"])
+        );
+    }
+
     #[test]
     fn test_from_serializable_invalid() {
         let invalid_config = vec![1, 2, 3]; // Not a table/object
@@ -536,7 +1534,7 @@ option2 = "value"
         let locations = metadata.config_file_locations.unwrap();
 
         assert!(locations.len() == 1);
-        assert!(locations.get(VALID_RULE_NAME).is_some());
+        assert!(locations.contains_key(VALID_RULE_NAME));
     }
 
     #[test]
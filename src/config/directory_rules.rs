@@ -0,0 +1,218 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{self, Path, PathBuf},
+    rc::Rc,
+};
+
+use anyhow::{anyhow, Context as _, Result};
+use log::{debug, warn};
+
+use crate::{errors::LintLevel, rules::canonical_rule_name};
+
+/// Name of the file that turns rules on or off, or changes their levels, for
+/// the files in its directory and its subdirectories.
+pub(crate) const DIRECTORY_RULES_FILE: &str = ".supa-mdx-lint";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirectoryRuleSetting {
+    Off,
+    /// Turns a rule back on after a file further up the tree turned it off.
+    On,
+    Level(LintLevel),
+}
+
+/// The rule settings from the `.supa-mdx-lint` files in the directories
+/// containing a file. Files in deeper directories take precedence.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct DirectoryRules(HashMap<String, DirectoryRuleSetting>);
+
+/// The directory rules for the files in one lint run. Files are only read
+/// from the root directory and the directories under it, and each directory
+/// is only read once.
+#[derive(Debug)]
+pub(crate) struct DirectoryRulesCache {
+    root: PathBuf,
+    rules: HashMap<PathBuf, Rc<DirectoryRules>>,
+}
+
+impl DirectoryRulesCache {
+    pub(crate) fn new(root: &Path) -> Result<Self> {
+        Ok(Self {
+            root: path::absolute(root)?,
+            rules: HashMap::new(),
+        })
+    }
+
+    /// Collect the settings for a file from the `.supa-mdx-lint` files in its
+    /// directory and the directories above it, up to the root. For a file
+    /// outside the root, only its own directory is read.
+    pub(crate) fn for_file(&mut self, path: &Path) -> Result<Rc<DirectoryRules>> {
+        let path = path::absolute(path)?;
+        match path.parent() {
+            Some(directory) => self.for_directory(directory),
+            None => Ok(Rc::default()),
+        }
+    }
+
+    fn for_directory(&mut self, directory: &Path) -> Result<Rc<DirectoryRules>> {
+        if let Some(rules) = self.rules.get(directory) {
+            return Ok(Rc::clone(rules));
+        }
+
+        let mut rules = match directory.parent() {
+            Some(parent) if directory != self.root && directory.starts_with(&self.root) => {
+                DirectoryRules::clone(&*self.for_directory(parent)?)
+            }
+            _ => DirectoryRules::default(),
+        };
+        let file = directory.join(DIRECTORY_RULES_FILE);
+        if file.is_file() {
+            debug!("Applying directory rules from {}", file.display());
+            let content = fs::read_to_string(&file)
+                .with_context(|| format!("Failed to read {}", file.display()))?;
+            let parsed = DirectoryRules::parse(&content)
+                .with_context(|| format!("Invalid directory rules in {}", file.display()))?;
+            rules.0.extend(parsed.0);
+        }
+
+        let rules = Rc::new(rules);
+        self.rules
+            .insert(directory.to_path_buf(), Rc::clone(&rules));
+        Ok(rules)
+    }
+}
+
+impl DirectoryRules {
+    /// Parse the content of a `.supa-mdx-lint` file: a TOML table of rule
+    /// names, each set to `false` to turn the rule off, `true` to turn it back
+    /// on, or a level.
+    fn parse(content: &str) -> Result<Self> {
+        let table = toml::from_str::<toml::Table>(content)?;
+        let mut rules = HashMap::new();
+        for (key, value) in table {
+            let Some(rule) = canonical_rule_name(&key) else {
+                warn!("Ignoring unknown rule {key} in {DIRECTORY_RULES_FILE} file");
+                continue;
+            };
+            let setting = match value {
+                toml::Value::Boolean(false) => DirectoryRuleSetting::Off,
+                toml::Value::Boolean(true) => DirectoryRuleSetting::On,
+                toml::Value::String(level) => {
+                    DirectoryRuleSetting::Level(LintLevel::try_from(level.as_str())?)
+                }
+                value => {
+                    return Err(anyhow!(
+                        "Expected {key} to be true, false, or a level, but found {value}"
+                    ))
+                }
+            };
+            rules.insert(rule.to_string(), setting);
+        }
+        Ok(Self(rules))
+    }
+
+    pub(crate) fn is_disabled(&self, rule: &str) -> bool {
+        self.0.get(rule) == Some(&DirectoryRuleSetting::Off)
+    }
+
+    pub(crate) fn level(&self, rule: &str) -> Option<LintLevel> {
+        match self.0.get(rule) {
+            Some(DirectoryRuleSetting::Level(level)) => Some(*level),
+            _ => None,
+        }
+    }
+}
+
+// The examples use Rule003Spelling, which needs the `spelling` feature.
+#[cfg(all(test, feature = "spelling"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directory_rules_parse() {
+        let rules = DirectoryRules::parse(
+            "Rule003Spelling = false\nheading-case = \"warn\"\nRule004ExcludeWords = true\n",
+        )
+        .unwrap();
+        assert!(rules.is_disabled("Rule003Spelling"));
+        assert!(!rules.is_disabled("Rule004ExcludeWords"));
+        assert_eq!(rules.level("Rule001HeadingCase"), Some(LintLevel::Warning));
+        assert_eq!(rules.level("Rule003Spelling"), None);
+    }
+
+    #[test]
+    fn test_directory_rules_parse_invalid() {
+        assert!(DirectoryRules::parse("Rule003Spelling = 1\n").is_err());
+        assert!(DirectoryRules::parse("Rule003Spelling = \"loud\"\n").is_err());
+        assert!(DirectoryRules::parse("Rule999Unknown = false\n")
+            .unwrap()
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    fn test_directory_rules_nested() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let reference = temp_dir.path().join("reference");
+        let generated = reference.join("generated");
+        fs::create_dir_all(&generated)?;
+        fs::write(
+            reference.join(DIRECTORY_RULES_FILE),
+            "Rule003Spelling = false\nRule001HeadingCase = false\n",
+        )?;
+        fs::write(
+            generated.join(DIRECTORY_RULES_FILE),
+            "Rule001HeadingCase = \"hint\"\n",
+        )?;
+
+        let mut cache = DirectoryRulesCache::new(temp_dir.path())?;
+        let rules = cache.for_file(&reference.join("guide.mdx"))?;
+        assert!(rules.is_disabled("Rule003Spelling"));
+        assert!(rules.is_disabled("Rule001HeadingCase"));
+
+        let rules = cache.for_file(&generated.join("api.mdx"))?;
+        assert!(rules.is_disabled("Rule003Spelling"));
+        assert!(!rules.is_disabled("Rule001HeadingCase"));
+        assert_eq!(rules.level("Rule001HeadingCase"), Some(LintLevel::Hint));
+
+        let rules = cache.for_file(&temp_dir.path().join("index.mdx"))?;
+        assert!(!rules.is_disabled("Rule003Spelling"));
+
+        // Files are read once per run, so later changes aren't picked up.
+        fs::remove_file(generated.join(DIRECTORY_RULES_FILE))?;
+        let rules = cache.for_file(&generated.join("other.mdx"))?;
+        assert_eq!(rules.level("Rule001HeadingCase"), Some(LintLevel::Hint));
+        Ok(())
+    }
+
+    #[test]
+    fn test_directory_rules_stop_at_root() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let docs = temp_dir.path().join("docs");
+        let guides = docs.join("guides");
+        fs::create_dir_all(&guides)?;
+        fs::write(
+            temp_dir.path().join(DIRECTORY_RULES_FILE),
+            "Rule003Spelling = false\n",
+        )?;
+        fs::write(
+            guides.join(DIRECTORY_RULES_FILE),
+            "Rule001HeadingCase = false\n",
+        )?;
+
+        let mut cache = DirectoryRulesCache::new(&docs)?;
+        let rules = cache.for_file(&guides.join("guide.mdx"))?;
+        assert!(rules.is_disabled("Rule001HeadingCase"));
+        assert!(!rules.is_disabled("Rule003Spelling"));
+
+        // Outside the root, only the file's own directory is read.
+        let rules = cache.for_file(&temp_dir.path().join("index.mdx"))?;
+        assert!(rules.is_disabled("Rule003Spelling"));
+
+        let mut cache = DirectoryRulesCache::new(&guides)?;
+        let rules = cache.for_file(&docs.join("index.mdx"))?;
+        assert!(!rules.is_disabled("Rule003Spelling"));
+        Ok(())
+    }
+}
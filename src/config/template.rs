@@ -0,0 +1,272 @@
+use std::{fmt::Write, str::FromStr};
+
+use serde_json::Value;
+
+use crate::app_error::PublicError;
+
+use super::config_schema;
+
+const SUPABASE_PRESET: &str = include_str!("presets/supabase.toml");
+
+/// A bundled set of settings that can be used as the starting point for a
+/// config file.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigPreset {
+    /// The settings used for the Supabase docs. Named `supabase` or
+    /// `supabase-docs`.
+    Supabase,
+}
+
+impl ConfigPreset {
+    pub(super) fn settings(&self) -> toml::Table {
+        let source = match self {
+            ConfigPreset::Supabase => SUPABASE_PRESET,
+        };
+        toml::from_str(source).expect("Bundled presets should be valid TOML")
+    }
+}
+
+impl FromStr for ConfigPreset {
+    type Err = PublicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "supabase" | "supabase-docs" => Ok(ConfigPreset::Supabase),
+            s => Err(PublicError::VariantNotFound(s.to_string())),
+        }
+    }
+}
+
+/// Write a starter config file listing every rule and setting. Settings from
+/// the preset are filled in, and all other settings are commented out with
+/// their defaults.
+pub(crate) fn config_template(preset: Option<ConfigPreset>) -> String {
+    let mut preset = preset.map(|preset| preset.settings()).unwrap_or_default();
+    let schema = config_schema();
+    let properties = schema["properties"]
+        .as_object()
+        .expect("Config schema should have properties");
+    let (rules, top_level): (Vec<_>, Vec<_>) = properties
+        .iter()
+        .partition(|(_, property)| property.get("anyOf").is_some());
+
+    let mut output = String::new();
+    output.push_str("# Configuration for supa-mdx-lint.\n");
+    output.push_str("#\n");
+    output.push_str("# Settings that are commented out show their default values.\n");
+    output.push_str("# Turn a rule off entirely by setting it to false, for example:\n");
+    output.push_str("# Rule001HeadingCase = false\n");
+
+    for (key, property) in top_level {
+        output.push('\n');
+        write_setting(&mut output, key, property, preset.remove(key));
+    }
+
+    let disabled_rules = rules
+        .iter()
+        .filter(|(name, _)| matches!(preset.get(*name), Some(toml::Value::Boolean(false))))
+        .collect::<Vec<_>>();
+    if !disabled_rules.is_empty() {
+        output.push('\n');
+        for (name, _) in disabled_rules {
+            preset.remove(*name);
+            writeln!(output, "{name} = false").unwrap();
+        }
+    }
+
+    for (name, property) in rules {
+        let mut rule_preset = match preset.remove(name) {
+            Some(toml::Value::Table(table)) => table,
+            Some(_) => continue,
+            None => toml::Table::new(),
+        };
+
+        // Rules that aren't configured by the preset are commented out
+        // entirely, since configuring a rule with an empty table can change
+        // its behavior.
+        let prefix = if rule_preset.is_empty() { "# " } else { "" };
+        writeln!(output, "\n{prefix}[{name}]").unwrap();
+        let settings = &property["anyOf"][1]["properties"];
+        let Some(settings) = settings.as_object() else {
+            continue;
+        };
+
+        // The level is shared by all rules, so list it first.
+        let mut keys = settings.keys().collect::<Vec<_>>();
+        keys.sort_by_key(|key| *key != "level");
+
+        let mut subtables = Vec::new();
+        for key in keys {
+            match rule_preset.remove(key) {
+                Some(toml::Value::Table(table))
+                    if !table.is_empty() && table.values().all(toml::Value::is_table) =>
+                {
+                    write_description(&mut output, &settings[key]);
+                    writeln!(output, "# See [{name}.{key}.*] below.").unwrap();
+                    subtables.push((key.clone(), table));
+                }
+                value => write_setting(&mut output, key, &settings[key], value),
+            }
+        }
+        for (key, value) in rule_preset {
+            writeln!(output, "{key} = {}", format_value(&value)).unwrap();
+        }
+
+        for (key, table) in subtables {
+            for (entry, value) in table {
+                writeln!(output, "\n[{name}.{key}.{}]", format_key(&entry)).unwrap();
+                if let toml::Value::Table(value) = value {
+                    for (field, value) in value {
+                        writeln!(output, "{field} = {}", format_value(&value)).unwrap();
+                    }
+                }
+            }
+        }
+    }
+
+    output
+}
+
+fn write_description(output: &mut String, property: &Value) {
+    if let Some(description) = property.get("description").and_then(Value::as_str) {
+        writeln!(output, "# {description}").unwrap();
+    }
+}
+
+fn write_setting(output: &mut String, key: &str, property: &Value, value: Option<toml::Value>) {
+    write_description(output, property);
+    match value {
+        Some(value) => writeln!(output, "{key} = {}", format_value(&value)).unwrap(),
+        None => writeln!(output, "# {key} = {}", default_value(property)).unwrap(),
+    }
+}
+
+fn default_value(property: &Value) -> String {
+    // Settings without a default, such as optional limits, show an example.
+    let example = property
+        .get("examples")
+        .and_then(Value::as_array)
+        .and_then(|examples| examples.first());
+    if let Some(default) = property.get("default").or(example) {
+        if let Ok(default) = toml::Value::try_from(default) {
+            return format_value(&default);
+        }
+    }
+    match property.get("type").and_then(Value::as_str) {
+        Some("array") => "[]".to_string(),
+        Some("boolean") => "false".to_string(),
+        Some("object") => "{}".to_string(),
+        _ => "\"\"".to_string(),
+    }
+}
+
+/// Format a value for the template, putting the items of long arrays on
+/// separate lines.
+fn format_value(value: &toml::Value) -> String {
+    let inline = value.to_string();
+    match value {
+        toml::Value::Array(items) if inline.len() > 80 => {
+            let mut formatted = String::from("[\n");
+            for item in items {
+                writeln!(formatted, "  {},", item).unwrap();
+            }
+            formatted.push(']');
+            formatted
+        }
+        _ => inline,
+    }
+}
+
+fn format_key(key: &str) -> String {
+    if !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        key.to_string()
+    } else {
+        toml::Value::String(key.to_string()).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{config::ConfigDir, Config};
+
+    fn parse_template(template: &str) -> Config<crate::PhaseSetup> {
+        let table: toml::Table = toml::from_str(template).unwrap();
+        Config::from_serializable()
+            .config(table)
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_config_template_lists_all_rules() {
+        let template = config_template(None);
+        let schema = config_schema();
+        for (key, property) in schema["properties"].as_object().unwrap() {
+            if property.get("anyOf").is_some() {
+                assert!(template.contains(&format!("[{key}]")), "Missing {key}");
+            }
+        }
+        assert!(template.contains("# path_sort_order = \"lexicographic\""));
+        assert!(template.contains(
+            "# [Rule011DeepRelativeLinks]\n# Severity level of the rule's diagnostics.\n# level = \"error\"\n"
+        ));
+        assert!(template.contains("# max_depth = 2\n"));
+        assert!(template.contains("# max_file_size = 10240\n"));
+
+        let config = parse_template(&template);
+        assert!(config.rule_specific_settings.is_empty());
+    }
+
+    #[test]
+    fn test_config_template_supabase_preset() {
+        let template = config_template(Some(ConfigPreset::Supabase));
+        assert!(template.contains(
+            "admonition_types = [\"caution\", \"danger\", \"deprecation\", \"note\", \"tip\"]\n"
+        ));
+        assert!(template.contains("\n[Rule004ExcludeWords.rules.simple]\n"));
+        assert!(template.contains("\n# [Rule010UnknownComponents]\n"));
+
+        let table: toml::Table = toml::from_str(&template).unwrap();
+        assert_eq!(
+            table["Rule006NoAbsoluteUrls"]["base_url"].as_str(),
+            Some("https://supabase.com/docs")
+        );
+        assert_eq!(
+            table["Rule004ExcludeWords"]["rules"]["inclusive"]["words"][0][1].as_str(),
+            Some("allow list")
+        );
+
+        let config = parse_template(&template);
+        assert!(config.rule_registry.is_rule_active("Rule006NoAbsoluteUrls"));
+    }
+
+    #[test]
+    fn test_config_preset_from_str() {
+        assert_eq!(
+            ConfigPreset::from_str("supabase").unwrap(),
+            ConfigPreset::Supabase
+        );
+        assert!(ConfigPreset::from_str("unknown").is_err());
+    }
+
+    #[test]
+    fn test_format_value() {
+        let short = toml::Value::try_from(json!(["a", "b"])).unwrap();
+        assert_eq!(format_value(&short), "[\"a\", \"b\"]");
+
+        let long = toml::Value::try_from(json!(["a".repeat(50), "b".repeat(50)])).unwrap();
+        assert_eq!(
+            format_value(&long),
+            format!("[\n  \"{}\",\n  \"{}\",\n]", "a".repeat(50), "b".repeat(50))
+        );
+    }
+}
@@ -1,12 +1,18 @@
-use std::array;
+use std::{array, path::Path};
 
 use anyhow::Result;
 use bon::bon;
 use log::debug;
+use markdown::mdast::Node;
 
 use crate::{
-    comments::{ConfigurationCommentCollection, LintDisables, LintTimeRuleConfigs},
-    location::AdjustedOffset,
+    comments::{
+        CollectedDirectives, ConfigurationCommentCollection, ExpiredDirective, LintDisables,
+        LintTimeRuleConfigs, MalformedDirective, UnknownRuleDirective,
+    },
+    config::DirectoryRules,
+    errors::{LintError, LintLevel, DIRECTIVE_ERROR_RULE},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation, RangeSet},
     parser::ParseResult,
     rope::Rope,
     rules::RuleFilter,
@@ -30,11 +36,26 @@ impl std::fmt::Debug for ContextId {
 pub(crate) struct Context<'ctx> {
     /// Key for caching purposes, so individual rules can cache file-level
     /// calculations.
+    #[cfg_attr(not(feature = "spelling"), allow(dead_code))]
     pub(crate) key: ContextId,
     pub(crate) parse_result: &'ctx ParseResult,
+    /// Path of the file being linted, if linting a file rather than a string.
+    pub(crate) file_path: Option<&'ctx Path>,
     pub(crate) check_only_rules: RuleFilter<'ctx>,
+    /// Rule settings from `.supa-mdx-lint` files in the file's directories.
+    pub(crate) directory_rules: Option<&'ctx DirectoryRules>,
     pub(crate) disables: LintDisables<'ctx>,
+    #[cfg_attr(not(feature = "spelling"), allow(dead_code))]
     pub(crate) lint_time_rule_configs: LintTimeRuleConfigs<'ctx>,
+    /// Comments that look like lint directives but couldn't be parsed.
+    pub(crate) malformed_directives: Vec<MalformedDirective>,
+    /// Directives whose expiry date has passed.
+    pub(crate) expired_directives: Vec<ExpiredDirective>,
+    /// Directives naming rules that don't exist.
+    pub(crate) unknown_rule_directives: Vec<UnknownRuleDirective>,
+    /// Ranges that prose rules shouldn't lint: code blocks, inline code, and
+    /// the configured skip components.
+    skip_ranges: RangeSet,
 }
 
 #[bon]
@@ -42,21 +63,45 @@ impl<'ctx> Context<'ctx> {
     #[builder]
     pub(crate) fn new(
         parse_result: &'ctx ParseResult,
+        file_path: Option<&'ctx Path>,
         check_only_rules: Option<&'ctx [&'ctx str]>,
+        directory_rules: Option<&'ctx DirectoryRules>,
+        /// Names of components whose contents prose rules shouldn't lint.
+        skip_components: Option<&'ctx [String]>,
     ) -> Result<Self> {
-        let (lint_time_rule_configs, disables) =
-            ConfigurationCommentCollection::from_parse_result(parse_result)
-                .into_parts()
-                .unwrap();
+        let CollectedDirectives {
+            configs: lint_time_rule_configs,
+            disables,
+            malformed: malformed_directives,
+            expired: expired_directives,
+            unknown_rules: unknown_rule_directives,
+        } = ConfigurationCommentCollection::from_parse_result(parse_result)
+            .into_parts()
+            .unwrap();
         debug!("Lint time rule configs: {:?}", lint_time_rule_configs);
         debug!("Disables: {:?}", disables);
 
+        let mut skip_ranges = RangeSet::new();
+        collect_skip_ranges(
+            parse_result.ast(),
+            skip_components.unwrap_or_default(),
+            parse_result.content_start_offset().into(),
+            &mut skip_ranges,
+        );
+        debug!("Skip ranges: {:?}", skip_ranges);
+
         Ok(Self {
             key: ContextId::new(),
             parse_result,
+            file_path,
             check_only_rules,
+            directory_rules,
             disables,
             lint_time_rule_configs,
+            malformed_directives,
+            expired_directives,
+            unknown_rule_directives,
+            skip_ranges,
         })
     }
 
@@ -64,7 +109,139 @@ impl<'ctx> Context<'ctx> {
         self.parse_result.rope()
     }
 
+    pub(crate) fn file_path(&self) -> Option<&Path> {
+        self.file_path
+    }
+
     pub fn content_start_offset(&self) -> AdjustedOffset {
-        self.parse_result.content_start_offset()
+        self.parse_result.content_start_offset().into()
+    }
+
+    /// Whether a range lies within code or a skip component, where prose
+    /// rules shouldn't report anything.
+    pub(crate) fn is_skipped(&self, range: &AdjustedRange) -> bool {
+        self.skip_ranges.completely_contains(range)
+    }
+
+    /// Warnings for the comments that look like lint directives but couldn't
+    /// be parsed, or that name rules that don't exist, so that typos in
+    /// suppressions don't go unnoticed, and for directives that have expired.
+    pub(crate) fn directive_errors(&self) -> Vec<LintError> {
+        let malformed = self.malformed_directives.iter().map(|directive| {
+            LintError::from_raw_location()
+                .rule(DIRECTIVE_ERROR_RULE)
+                .level(LintLevel::Warning)
+                .message(directive.message())
+                .location(DenormalizedLocation::from_offset_range(
+                    directive.range.clone(),
+                    self,
+                ))
+                .call()
+        });
+        let expired = self.expired_directives.iter().map(|directive| {
+            LintError::from_raw_location()
+                .rule(DIRECTIVE_ERROR_RULE)
+                .level(LintLevel::Warning)
+                .message(directive.message())
+                .location(DenormalizedLocation::from_offset_range(
+                    directive.range.clone(),
+                    self,
+                ))
+                .call()
+                .with_details(directive.details())
+        });
+        let unknown_rules = self.unknown_rule_directives.iter().map(|directive| {
+            LintError::from_raw_location()
+                .rule(DIRECTIVE_ERROR_RULE)
+                .level(LintLevel::Warning)
+                .message(directive.message())
+                .location(DenormalizedLocation::from_offset_range(
+                    directive.range.clone(),
+                    self,
+                ))
+                .call()
+        });
+        malformed.chain(unknown_rules).chain(expired).collect()
+    }
+}
+
+fn collect_skip_ranges(
+    node: &Node,
+    skip_components: &[String],
+    content_start_offset: AdjustedOffset,
+    ranges: &mut RangeSet,
+) {
+    let skipped = match node {
+        Node::Code(_) | Node::InlineCode(_) => true,
+        Node::MdxJsxFlowElement(element) => element
+            .name
+            .as_ref()
+            .is_some_and(|name| skip_components.contains(name)),
+        Node::MdxJsxTextElement(element) => element
+            .name
+            .as_ref()
+            .is_some_and(|name| skip_components.contains(name)),
+        _ => false,
+    };
+
+    if skipped {
+        if let Some(position) = node.position() {
+            ranges.push(AdjustedRange::new(
+                AdjustedOffset::from_unist(&position.start, content_start_offset),
+                AdjustedOffset::from_unist(&position.end, content_start_offset),
+            ));
+        }
+        return;
+    }
+
+    if let Some(children) = node.children() {
+        for child in children {
+            collect_skip_ranges(child, skip_components, content_start_offset, ranges);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    fn range(start: usize, end: usize) -> AdjustedRange {
+        AdjustedRange::new(start.into(), end.into())
+    }
+
+    #[test]
+    fn test_skip_ranges() {
+        let parse_result =
+            parse("Run `npm install`.\n\n```bash\nnpm test\n```\n\n<CodeSample>\n\nSome text\n\n</CodeSample>\n")
+                .unwrap();
+        let components = vec!["CodeSample".to_string()];
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .skip_components(&components)
+            .build()
+            .unwrap();
+
+        assert!(!context.is_skipped(&range(0, 3)));
+        assert!(context.is_skipped(&range(5, 16)));
+        assert!(context.is_skipped(&range(30, 34)));
+        assert!(context.is_skipped(&range(56, 60)));
+
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+        assert!(!context.is_skipped(&range(56, 60)));
+    }
+
+    #[test]
+    fn test_skip_ranges_after_frontmatter() {
+        let parse_result = parse("---\ntitle: Test\n---\n\nRun `npm install`.\n").unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+        assert!(!context.is_skipped(&range(21, 24)));
+        assert!(context.is_skipped(&range(25, 36)));
     }
 }
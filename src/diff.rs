@@ -0,0 +1,155 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::{Component, Path, PathBuf},
+};
+
+use crate::output::LintOutput;
+
+/// The lines added or modified by a change, read from a unified diff such as
+/// the output of `git diff`. Used to report only the errors on lines that a
+/// change touches.
+#[derive(Debug, Default, Clone)]
+pub struct ChangedLines {
+    files: HashMap<PathBuf, BTreeSet<usize>>,
+}
+
+impl ChangedLines {
+    /// Parse a unified diff. Line numbers refer to the new version of each
+    /// file, and deleted files are left out.
+    pub fn from_unified_diff(diff: &str) -> Self {
+        let mut files: HashMap<PathBuf, BTreeSet<usize>> = HashMap::new();
+        let mut curr_file = None;
+        let mut prev_line = "";
+        let mut line = 0;
+
+        for diff_line in diff.lines() {
+            let new_path = diff_line
+                .strip_prefix("+++ ")
+                .filter(|_| prev_line.starts_with("--- "));
+            prev_line = diff_line;
+
+            if let Some(path) = new_path {
+                // Some diff tools follow the path with a tab and a timestamp.
+                let path = path.split('\t').next().unwrap_or_default().trim_end();
+                curr_file = (path != "/dev/null").then(|| {
+                    let path = normalize(Path::new(path.strip_prefix("b/").unwrap_or(path)));
+                    files.entry(path.clone()).or_default();
+                    path
+                });
+            } else if let Some(hunk) = diff_line.strip_prefix("@@ ") {
+                line = parse_hunk_start(hunk).unwrap_or_default();
+            } else if let Some(lines) = curr_file.as_ref().and_then(|path| files.get_mut(path)) {
+                match diff_line.chars().next() {
+                    Some('+') => {
+                        lines.insert(line);
+                        line += 1;
+                    }
+                    Some(' ') => line += 1,
+                    _ => {}
+                }
+            }
+        }
+
+        Self { files }
+    }
+
+    /// Whether any line from `start_line` to `end_line`, inclusive and
+    /// counting from 1, changed in the file at `path`.
+    ///
+    /// Paths in a diff are relative to the repository, so a file matches if
+    /// its path ends with the changed path.
+    pub fn intersects(&self, path: impl AsRef<Path>, start_line: usize, end_line: usize) -> bool {
+        let path = normalize(path.as_ref());
+        self.files
+            .iter()
+            .filter(|(changed, _)| path.ends_with(changed))
+            .any(|(_, lines)| lines.range(start_line..=end_line).next().is_some())
+    }
+}
+
+impl LintOutput {
+    /// Drop the diagnostics that don't touch any changed lines.
+    pub fn retain_changed_lines(&mut self, changed: &ChangedLines) {
+        let path = self.file_path().to_string();
+        self.retain(|error| {
+            changed.intersects(
+                &path,
+                error.location.start.row + 1,
+                error.location.end.row + 1,
+            )
+        });
+    }
+}
+
+/// Parse the start line of the new file from a hunk header, such as
+/// `-12,3 +14,5 @@`.
+fn parse_hunk_start(hunk: &str) -> Option<usize> {
+    let new_range = hunk.split_whitespace().find(|part| part.starts_with('+'))?;
+    new_range[1..].split(',').next()?.parse().ok()
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{location::DenormalizedLocation, LintError, LintLevel};
+
+    const DIFF: &str = "\
+diff --git a/docs/guide.mdx b/docs/guide.mdx
+index 1234567..89abcde 100644
+--- a/docs/guide.mdx
++++ b/docs/guide.mdx
+@@ -2,3 +2,4 @@ title: Guide
+ Unchanged line.
+-Old line.
++New line.
++Another new line.
+ Unchanged line.
+@@ -20,0 +22 @@
++Added at the end.
+diff --git a/docs/removed.mdx b/docs/removed.mdx
+deleted file mode 100644
+--- a/docs/removed.mdx
++++ /dev/null
+@@ -1 +0,0 @@
+-Gone.
+";
+
+    #[test]
+    fn test_changed_lines_from_unified_diff() {
+        let changed = ChangedLines::from_unified_diff(DIFF);
+        assert!(!changed.intersects("docs/guide.mdx", 2, 2));
+        assert!(changed.intersects("docs/guide.mdx", 3, 3));
+        assert!(changed.intersects("docs/guide.mdx", 4, 4));
+        assert!(!changed.intersects("docs/guide.mdx", 5, 21));
+        assert!(changed.intersects("docs/guide.mdx", 5, 22));
+        assert!(changed.intersects("./docs/guide.mdx", 22, 22));
+        assert!(changed.intersects("/home/me/repo/docs/guide.mdx", 22, 22));
+        assert!(!changed.intersects("guide.mdx", 22, 22));
+        assert!(!changed.intersects("docs/removed.mdx", 1, 1));
+    }
+
+    #[test]
+    fn test_retain_changed_lines() {
+        let error = |start_row, end_row| {
+            LintError::from_raw_location()
+                .rule("MockRule")
+                .level(LintLevel::Error)
+                .message("Mock error")
+                .location(DenormalizedLocation::dummy(0, 1, start_row, 0, end_row, 1))
+                .call()
+        };
+        let mut output = LintOutput::new(
+            "docs/guide.mdx",
+            vec![error(0, 0), error(1, 2), error(9, 9)],
+        );
+        output.retain_changed_lines(&ChangedLines::from_unified_diff(DIFF));
+        assert_eq!(output.errors().len(), 1);
+        assert_eq!(output.errors()[0].location.start.row, 1);
+    }
+}
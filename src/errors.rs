@@ -1,19 +1,40 @@
-use std::{fmt::Display, ops::Range};
+use std::{
+    fmt::Display,
+    hash::{DefaultHasher, Hash, Hasher},
+    ops::Range,
+};
 
 use anyhow::Result;
 use bon::bon;
 use markdown::mdast::Node;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::{
     context::Context,
-    fix::LintCorrection,
-    location::{AdjustedPoint, AdjustedRange, DenormalizedLocation, Offsets},
+    fix::{FixSafety, LintCorrection, LintCorrectionInsert},
+    location::{AdjustedRange, DenormalizedLocation, LocationConverter, Offsets},
+    rules::RuleCategory,
 };
 
+/// Rule name used for diagnostics that report a failure to lint a file,
+/// rather than a problem found by a rule.
+pub(crate) const INTERNAL_ERROR_RULE: &str = "InternalError";
+
+/// Rule name used for diagnostics that report a document that isn't valid
+/// MDX, so couldn't be checked by the rules.
+pub(crate) const PARSE_ERROR_RULE: &str = "ParseError";
+
+/// Rule name used for diagnostics that report a comment that looks like a
+/// lint directive, but couldn't be parsed.
+pub(crate) const DIRECTIVE_ERROR_RULE: &str = "LintDirective";
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum LintLevel {
+    /// An optional suggestion, which is reported but doesn't count as a
+    /// warning or error.
+    Hint,
     Warning,
     #[default]
     Error,
@@ -24,6 +45,7 @@ impl Display for LintLevel {
         match self {
             LintLevel::Error => write!(f, "ERROR"),
             LintLevel::Warning => write!(f, "WARN"),
+            LintLevel::Hint => write!(f, "HINT"),
         }
     }
 }
@@ -36,6 +58,7 @@ impl TryFrom<&str> for LintLevel {
         match value.as_str() {
             "error" => Ok(Self::Error),
             "warn" => Ok(Self::Warning),
+            "hint" => Ok(Self::Hint),
             _ => Err(anyhow::anyhow!("Invalid lint level: {value}")),
         }
     }
@@ -48,7 +71,17 @@ pub struct LintError {
     pub(crate) message: String,
     pub(crate) location: DenormalizedLocation,
     pub(crate) fix: Option<Vec<LintCorrection>>,
+    #[serde(default)]
+    pub(crate) fix_safety: FixSafety,
     pub(crate) suggestions: Option<Vec<LintCorrection>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) suppression: Option<LintCorrection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) details: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) category: Option<RuleCategory>,
 }
 
 // Required to implement sealed trait Offsets
@@ -66,33 +99,92 @@ impl Offsets for LintError {
 
 #[bon]
 impl LintError {
+    /// Create a diagnostic, for example to report problems found by checks
+    /// that run alongside the linter, in the same output as its own errors.
+    ///
+    /// The location is a byte range into the full source of the document,
+    /// including any frontmatter. Returns an error if the range extends past
+    /// the end of the source.
+    ///
+    /// ```
+    /// use supa_mdx_lint::{
+    ///     fix::LintCorrection,
+    ///     location::{AdjustedOffset, AdjustedRange, LocationConverter},
+    ///     LintError, LintLevel,
+    /// };
+    ///
+    /// let source = "# Heading\n\nSee teh docs.\n";
+    /// let converter = LocationConverter::new(source);
+    /// let range = AdjustedRange::new(AdjustedOffset::from(15), AdjustedOffset::from(18));
+    ///
+    /// let error = LintError::builder()
+    ///     .rule("CustomTypos")
+    ///     .message("Did you mean \"the\"?")
+    ///     .level(LintLevel::Warning)
+    ///     .location(range.clone())
+    ///     .fix(vec![LintCorrection::replace(
+    ///         converter.location(range).unwrap(),
+    ///         "the",
+    ///     )])
+    ///     .source(&converter)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(error.location().start.row, 2);
+    /// assert!(error.is_fixable(false));
+    /// ```
     #[builder]
-    #[allow(clippy::needless_lifetimes)]
-    pub(crate) fn new<'ctx>(
+    pub fn new(
         rule: impl AsRef<str>,
         message: impl Into<String>,
         level: LintLevel,
         location: AdjustedRange,
         fix: Option<Vec<LintCorrection>>,
+        #[builder(default)] fix_safety: FixSafety,
         suggestions: Option<Vec<LintCorrection>>,
-        context: &Context<'ctx>,
-    ) -> Self {
-        let start = AdjustedPoint::from_adjusted_offset(&location.start, context.rope());
-        let end = AdjustedPoint::from_adjusted_offset(&location.end, context.rope());
-        let location = DenormalizedLocation {
-            offset_range: location,
-            start,
-            end,
+        /// Converts the location to lines and columns.
+        source: &LocationConverter,
+    ) -> Result<Self> {
+        let Some(location) = source.location(location.clone()) else {
+            return Err(anyhow::anyhow!(
+                "Location {:?} extends past the end of the source",
+                location.to_usize_range()
+            ));
         };
 
-        Self {
+        Ok(Self {
             rule: rule.as_ref().into(),
             level,
             message: message.into(),
             location,
             fix,
+            fix_safety,
             suggestions,
-        }
+            suppression: None,
+            details: None,
+            code: None,
+            category: None,
+        })
+    }
+
+    /// An ID for the error, derived from its rule, message, and location.
+    /// The same error in the same content always has the same ID, so it can
+    /// be used to refer back to the error, for example with
+    /// [`Linter::apply_suggestion`](crate::Linter::apply_suggestion). The ID
+    /// changes if the content before the error is edited.
+    pub fn id(&self) -> String {
+        let mut hasher = DefaultHasher::new();
+        self.rule.hash(&mut hasher);
+        self.message.hash(&mut hasher);
+        self.location
+            .offset_range
+            .to_usize_range()
+            .hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// The name of the rule that reported the error.
+    pub fn rule(&self) -> &str {
+        &self.rule
     }
 
     pub fn level(&self) -> LintLevel {
@@ -107,6 +199,149 @@ impl LintError {
         self.location.offset_range.to_usize_range()
     }
 
+    /// Where the error is, as byte offsets and as lines and columns into the
+    /// full source, including any frontmatter.
+    pub fn location(&self) -> &DenormalizedLocation {
+        &self.location
+    }
+
+    /// The corrections applied when the error is auto-fixed.
+    pub fn fix(&self) -> Option<&[LintCorrection]> {
+        self.fix.as_deref()
+    }
+
+    /// Whether the fix can be applied without review. Unsafe fixes are only
+    /// applied when requested.
+    pub fn fix_safety(&self) -> FixSafety {
+        self.fix_safety
+    }
+
+    /// Whether the error has a fix that would be applied, given whether
+    /// unsafe fixes are allowed.
+    pub fn is_fixable(&self, allow_unsafe: bool) -> bool {
+        self.fix.is_some() && (allow_unsafe || self.fix_safety == FixSafety::Safe)
+    }
+
+    /// Corrections that may fix the error, but need review before being
+    /// applied.
+    pub fn suggestions(&self) -> Option<&[LintCorrection]> {
+        self.suggestions.as_deref()
+    }
+
+    /// An edit that suppresses this error by inserting a
+    /// `supa-mdx-lint-disable-next-line` comment above the line where the
+    /// error starts. Editor integrations can offer it as a quick fix.
+    pub fn suppression(&self) -> Option<&LintCorrection> {
+        self.suppression.as_ref()
+    }
+
+    /// Structured data about the error, for rules that report measurements
+    /// alongside their message.
+    pub fn details(&self) -> Option<&Value> {
+        self.details.as_ref()
+    }
+
+    /// The stable code of the rule that reported the error, such as
+    /// `SMDX001`. Errors that don't come from a rule, such as failures to
+    /// parse a file, have no code.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// The category of the rule that reported the error.
+    pub fn category(&self) -> Option<RuleCategory> {
+        self.category
+    }
+
+    /// The code and category of the error, as shown after the message by the
+    /// output formatters, such as `(SMDX001, style)`.
+    #[cfg(feature = "cli-formats")]
+    pub(crate) fn metadata_suffix(&self) -> String {
+        match (&self.code, self.category) {
+            (Some(code), Some(category)) => format!(" ({code}, {category})"),
+            (Some(code), None) => format!(" ({code})"),
+            (None, Some(category)) => format!(" ({category})"),
+            (None, None) => String::new(),
+        }
+    }
+
+    pub(crate) fn with_rule_metadata(mut self, code: &str, category: RuleCategory) -> Self {
+        self.code = Some(code.to_string());
+        self.category = Some(category);
+        self
+    }
+
+    pub(crate) fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    /// Attach an edit that inserts a `supa-mdx-lint-disable-next-line`
+    /// comment before the top-level block containing the error.
+    ///
+    /// The comment only covers the first line of the block after it, so no
+    /// edit is attached if the error starts on a later line, such as inside a
+    /// code block, or if it isn't in a block at all, such as in frontmatter.
+    /// Inserting the comment there would change the content without
+    /// suppressing anything.
+    pub(crate) fn with_suppression(mut self, context: &Context) -> Self {
+        let start = self.location.offset_range.start;
+        let Some(block) = context
+            .parse_result
+            .ast()
+            .children()
+            .into_iter()
+            .flatten()
+            .filter_map(|node| node.position())
+            .map(|position| AdjustedRange::from_unadjusted_position(position, context))
+            .find(|range| range.start <= start && start < range.end)
+        else {
+            return self;
+        };
+
+        let rope = context.rope();
+        let block_start = DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(block.start, block.start),
+            context,
+        );
+        let row = block_start.start.row;
+        if row != self.location.start.row {
+            return self;
+        }
+
+        let line_start = rope.byte_of_line(row);
+        let indent = rope
+            .line(row)
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect::<String>();
+        let line_ending = if row + 1 < rope.line_len()
+            && rope
+                .byte_slice(line_start..rope.byte_of_line(row + 1))
+                .chars()
+                .rev()
+                .nth(1)
+                == Some('\r')
+        {
+            "\r\n"
+        } else {
+            "\n"
+        };
+
+        let location = DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(line_start.into(), line_start.into()),
+            context,
+        );
+        self.suppression = Some(LintCorrection::Insert(LintCorrectionInsert {
+            location,
+            text: format!(
+                "{indent}{{/* supa-mdx-lint-disable-next-line {} */}}{line_ending}",
+                self.rule
+            ),
+        }));
+        self
+    }
+
     pub fn combined_suggestions(&self) -> Option<Vec<&LintCorrection>> {
         match (self.fix.as_ref(), self.suggestions.as_ref()) {
             (None, None) => None,
@@ -134,26 +369,65 @@ impl LintError {
         message: &str,
         level: LintLevel,
         fix: Option<Vec<LintCorrection>>,
+        #[builder(default)] fix_safety: FixSafety,
         suggestions: Option<Vec<LintCorrection>>,
     ) -> Option<Self> {
         if let Some(position) = node.position() {
             let location = AdjustedRange::from_unadjusted_position(position, context);
             Some(
-                Self::builder()
-                    .location(location)
-                    .context(context)
+                Self::from_raw_location()
+                    .location(DenormalizedLocation::from_offset_range(location, context))
                     .rule(rule)
                     .message(message)
                     .level(level)
                     .maybe_fix(fix)
+                    .fix_safety(fix_safety)
                     .maybe_suggestions(suggestions)
-                    .build(),
+                    .call(),
             )
         } else {
             None
         }
     }
 
+    /// Create a diagnostic for a failure that prevented a file from being
+    /// linted at all, such as a read or parse failure. The diagnostic is
+    /// attached to the very beginning of the file.
+    pub(crate) fn internal_error(error: &anyhow::Error) -> Self {
+        Self {
+            rule: INTERNAL_ERROR_RULE.to_string(),
+            level: LintLevel::Error,
+            message: format!("Failed to lint file: {error:#}"),
+            location: DenormalizedLocation::default(),
+            fix: None,
+            fix_safety: FixSafety::Safe,
+            suggestions: None,
+            suppression: None,
+            details: None,
+            code: None,
+            category: None,
+        }
+    }
+
+    /// Create a diagnostic for a document that couldn't be parsed, at the
+    /// point where the parser failed, or at the beginning of the file if
+    /// the parser didn't say.
+    pub(crate) fn parse_error(
+        reason: &str,
+        offset: Option<usize>,
+        source: &LocationConverter,
+    ) -> Self {
+        let location = offset
+            .and_then(|offset| source.location(AdjustedRange::new(offset.into(), offset.into())))
+            .unwrap_or_default();
+        Self::from_raw_location()
+            .rule(PARSE_ERROR_RULE)
+            .level(LintLevel::Error)
+            .message(format!("Failed to parse MDX: {reason}"))
+            .location(location)
+            .call()
+    }
+
     #[builder]
     pub(crate) fn from_raw_location(
         rule: impl AsRef<str>,
@@ -161,6 +435,7 @@ impl LintError {
         level: LintLevel,
         location: DenormalizedLocation,
         fix: Option<Vec<LintCorrection>>,
+        #[builder(default)] fix_safety: FixSafety,
         suggestions: Option<Vec<LintCorrection>>,
     ) -> Self {
         Self {
@@ -169,7 +444,12 @@ impl LintError {
             message: message.into(),
             location,
             fix,
+            fix_safety,
             suggestions,
+            suppression: None,
+            details: None,
+            code: None,
+            category: None,
         }
     }
 }
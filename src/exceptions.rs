@@ -0,0 +1,161 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Write,
+};
+
+use serde::Serialize;
+
+use crate::output::LintOutput;
+
+const HEADING_CASE_RULE: &str = "Rule001HeadingCase";
+
+/// A word or phrase that headings capitalize mid-sentence, and that might
+/// belong in the `may_uppercase` exceptions of `Rule001HeadingCase`, such as a
+/// product name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExceptionCandidate {
+    phrase: String,
+    headings: usize,
+    files: usize,
+}
+
+impl ExceptionCandidate {
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// The number of headings that capitalize the phrase.
+    pub fn headings(&self) -> usize {
+        self.headings
+    }
+
+    /// The number of files with headings that capitalize the phrase.
+    pub fn files(&self) -> usize {
+        self.files
+    }
+}
+
+/// Collect the phrases that `Rule001HeadingCase` reported as capitalized in
+/// at least `min_headings` headings, most common first.
+pub fn suggest_may_uppercase(
+    outputs: &[LintOutput],
+    min_headings: usize,
+) -> Vec<ExceptionCandidate> {
+    let mut seen: HashMap<&str, (usize, BTreeSet<&str>)> = HashMap::new();
+    for output in outputs {
+        for error in output.errors() {
+            if error.rule() != HEADING_CASE_RULE {
+                continue;
+            }
+            let Some(phrases) = error
+                .details()
+                .and_then(|details| details["capitalized"].as_array())
+            else {
+                continue;
+            };
+            let phrases = phrases
+                .iter()
+                .filter_map(|phrase| phrase.as_str())
+                .collect::<BTreeSet<_>>();
+            for phrase in phrases {
+                let (headings, files) = seen.entry(phrase).or_default();
+                *headings += 1;
+                files.insert(output.file_path());
+            }
+        }
+    }
+
+    let mut candidates = seen
+        .into_iter()
+        .filter(|(_, (headings, _))| *headings >= min_headings)
+        .map(|(phrase, (headings, files))| ExceptionCandidate {
+            phrase: phrase.to_string(),
+            headings,
+            files: files.len(),
+        })
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| {
+        b.headings
+            .cmp(&a.headings)
+            .then_with(|| a.phrase.cmp(&b.phrase))
+    });
+    candidates
+}
+
+/// Format candidates as a config snippet that can be pasted into the config
+/// file, noting how often each phrase was seen.
+pub fn may_uppercase_snippet(candidates: &[ExceptionCandidate]) -> String {
+    let mut snippet = format!("[{HEADING_CASE_RULE}]\nmay_uppercase = [\n");
+    for candidate in candidates {
+        writeln!(
+            snippet,
+            "  {}, # {} heading{} in {} file{}",
+            toml::Value::String(candidate.phrase.clone()),
+            candidate.headings,
+            if candidate.headings == 1 { "" } else { "s" },
+            candidate.files,
+            if candidate.files == 1 { "" } else { "s" },
+        )
+        .unwrap();
+    }
+    snippet.push_str("]\n");
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{location::DenormalizedLocation, LintError, LintLevel};
+
+    fn error(rule: &str, capitalized: &[&str]) -> LintError {
+        LintError::from_raw_location()
+            .rule(rule)
+            .level(LintLevel::Error)
+            .message("Heading should be sentence case")
+            .location(DenormalizedLocation::dummy(0, 1, 0, 0, 0, 1))
+            .call()
+            .with_details(json!({ "capitalized": capitalized }))
+    }
+
+    #[test]
+    fn test_suggest_may_uppercase() {
+        let outputs = vec![
+            LintOutput::new(
+                "a.mdx",
+                vec![
+                    error(HEADING_CASE_RULE, &["Edge Functions"]),
+                    error(HEADING_CASE_RULE, &["Edge Functions", "Realtime"]),
+                    error("Rule023AdmonitionTitleCase", &["Realtime"]),
+                ],
+            ),
+            LintOutput::new(
+                "b.mdx",
+                vec![
+                    error(HEADING_CASE_RULE, &["Realtime", "Realtime"]),
+                    error(HEADING_CASE_RULE, &["Edge Functions", "Storage"]),
+                ],
+            ),
+        ];
+
+        let candidates = suggest_may_uppercase(&outputs, 2);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].phrase(), "Edge Functions");
+        assert_eq!(candidates[0].headings(), 3);
+        assert_eq!(candidates[0].files(), 2);
+        assert_eq!(candidates[1].phrase(), "Realtime");
+        assert_eq!(candidates[1].headings(), 2);
+
+        assert_eq!(
+            may_uppercase_snippet(&candidates),
+            "\
+[Rule001HeadingCase]
+may_uppercase = [
+  \"Edge Functions\", # 3 headings in 2 files
+  \"Realtime\", # 2 headings in 2 files
+]
+"
+        );
+    }
+}
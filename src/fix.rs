@@ -1,20 +1,41 @@
-use std::{borrow::Cow, cmp::Ordering, fs};
+use std::{borrow::Cow, cmp::Ordering};
+#[cfg(feature = "fix")]
+use std::{collections::HashSet, fs, path::Path};
 
+#[cfg(feature = "fix")]
 use anyhow::Result;
 use bon::bon;
-use log::{debug, error, trace};
+#[cfg(feature = "fix")]
+use log::{debug, error};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "fix")]
 use crate::{
     app_error::AppError,
-    context::Context,
-    location::{AdjustedRange, DenormalizedLocation, Offsets},
+    location::{AdjustedOffset, AdjustedPoint},
     output::LintOutput,
     rope::Rope,
+    LintTarget, Linter,
+};
+use crate::{
+    context::Context,
+    location::{AdjustedRange, DenormalizedLocation, Offsets},
     utils::words::{is_sentence_start, WordIterator},
-    Linter,
 };
 
+/// How safe it is to apply a fix without review.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FixSafety {
+    /// The fix doesn't change the meaning of the text, and is applied by
+    /// `--fix`.
+    #[default]
+    Safe,
+    /// The fix might change the meaning of the text, for example by guessing
+    /// at a rewrite, so it is only applied by `--fix-unsafe`.
+    Unsafe,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub enum LintCorrection {
     Insert(LintCorrectionInsert),
@@ -115,7 +136,7 @@ impl Ord for LintCorrection {
                 // The two don't overlap, so the delete is either fully before
                 // or fully after the insert. We can arbitrarily choose between
                 // the start and the end point for comparison.
-                delete.location.start.cmp(&insert.location.start)
+                insert.location.start.cmp(&delete.location.start)
             }
             (LintCorrection::Insert(insert), LintCorrection::Replace(replace)) => {
                 if replace.location.start.le(&insert.location.start)
@@ -129,7 +150,7 @@ impl Ord for LintCorrection {
                 // The two don't overlap, so the replace is either fully before
                 // or fully after the insert. We can arbitrarily choose between
                 // the start and the end point for comparison.
-                replace.location.start.cmp(&insert.location.start)
+                insert.location.start.cmp(&replace.location.start)
             }
             (LintCorrection::Delete(_), LintCorrection::Insert(_)) => other.cmp(self).reverse(),
             (LintCorrection::Delete(delete_a), LintCorrection::Delete(delete_b)) => {
@@ -197,6 +218,29 @@ impl Ord for LintCorrection {
     }
 }
 
+impl LintCorrection {
+    /// Insert text in front of the start of the location.
+    pub fn insert(location: DenormalizedLocation, text: impl Into<String>) -> Self {
+        Self::Insert(LintCorrectionInsert {
+            location,
+            text: text.into(),
+        })
+    }
+
+    /// Delete the text at the location.
+    pub fn delete(location: DenormalizedLocation) -> Self {
+        Self::Delete(LintCorrectionDelete { location })
+    }
+
+    /// Replace the text at the location.
+    pub fn replace(location: DenormalizedLocation, text: impl Into<String>) -> Self {
+        Self::Replace(LintCorrectionReplace {
+            location,
+            text: text.into(),
+        })
+    }
+}
+
 #[bon]
 impl LintCorrection {
     /// Given two conflicting fixes, choose one to apply, or create a new fix
@@ -204,6 +248,7 @@ impl LintCorrection {
     /// apply.
     ///
     /// Should only be called after checking that the fixes do in fact conflict.
+    #[cfg(feature = "fix")]
     fn choose_or_merge(self, other: Self) -> Option<Self> {
         match (self, other) {
             (LintCorrection::Insert(_), LintCorrection::Insert(_)) => {
@@ -329,7 +374,11 @@ impl LintCorrection {
         match replace {
             Some(replace) => {
                 let replace = if is_sentence_start {
-                    replace.chars().next().unwrap().to_uppercase().to_string() + &replace[1..]
+                    let mut chars = replace.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default()
                 } else {
                     replace.to_string()
                 };
@@ -341,7 +390,9 @@ impl LintCorrection {
             }
             None => {
                 let mut iter = WordIterator::new(
-                    context.rope().byte_slice(splice_range.end.into_usize()..),
+                    context
+                        .rope()
+                        .byte_slice(splice_range.end.into_usize()..outer_range.end.into_usize()),
                     splice_range.end.into(),
                     Default::default(),
                 );
@@ -353,20 +404,17 @@ impl LintCorrection {
                         .chars();
                     if between.all(|c| c.is_whitespace()) {
                         if is_sentence_start {
+                            let first = context.rope().byte_slice(offset..).chars().next().unwrap();
                             let location = DenormalizedLocation::from_offset_range(
-                                AdjustedRange::new(splice_range.start, (offset + 1).into()),
+                                AdjustedRange::new(
+                                    splice_range.start,
+                                    (offset + first.len_utf8()).into(),
+                                ),
                                 context,
                             );
                             LintCorrection::Replace(LintCorrectionReplace {
                                 location,
-                                text: context
-                                    .rope()
-                                    .byte_slice(offset..)
-                                    .chars()
-                                    .next()
-                                    .unwrap()
-                                    .to_string()
-                                    .to_uppercase(),
+                                text: first.to_uppercase().to_string(),
                             })
                         } else {
                             LintCorrection::Delete(LintCorrectionDelete {
@@ -387,85 +435,254 @@ impl LintCorrection {
     }
 }
 
+#[cfg(feature = "fix")]
 impl Linter {
     /// Auto-fix any fixable errors.
     ///
     /// Returns a tuple of (number of files fixed, number of errors fixed).
     pub fn fix(&self, diagnostics: &[LintOutput]) -> Result<(usize, usize)> {
-        let mut files_fixed: usize = 0;
+        self.fix_until_stable(diagnostics, 1)
+    }
+
+    /// Auto-fix any fixable errors, then re-lint the fixed files and fix them
+    /// again, since some fixes can introduce new errors. Stops after
+    /// `max_passes` passes, or once a pass has nothing left to fix.
+    ///
+    /// Returns a tuple of (number of files fixed, number of errors fixed).
+    pub fn fix_until_stable(
+        &self,
+        diagnostics: &[LintOutput],
+        max_passes: usize,
+    ) -> Result<(usize, usize)> {
+        let (files_fixed, errors_fixed, _) = self.fix_and_remap(diagnostics, max_passes)?;
+        Ok((files_fixed, errors_fixed))
+    }
+
+    /// Auto-fix any fixable errors as [`Linter::fix_until_stable`] does, and
+    /// also return the diagnostics that remain after fixing, in the same order
+    /// as the input.
+    ///
+    /// After the last pass, the remaining diagnostics in each fixed file are
+    /// moved to account for the text its fixes inserted or removed, rather
+    /// than re-linting the file. A file is only re-linted if one of its
+    /// remaining diagnostics touches a fix, or if one of its fixes was dropped
+    /// because it conflicted with another.
+    ///
+    /// Returns a tuple of (number of files fixed, number of errors fixed,
+    /// remaining diagnostics).
+    pub fn fix_and_remap(
+        &self,
+        diagnostics: &[LintOutput],
+        max_passes: usize,
+    ) -> Result<(usize, usize, Vec<LintOutput>)> {
+        let mut remaining = diagnostics.to_vec();
+        let mut files_fixed = HashSet::<String>::new();
         let mut errors_fixed: usize = 0;
 
-        let fixable_outputs: Vec<&LintOutput> = diagnostics
-            .iter()
-            .filter(|diagnostic| diagnostic.errors().iter().any(|error| error.fix.is_some()))
-            .collect();
-        if fixable_outputs.is_empty() {
-            debug!("No fixable errors found for this set of diagnostics.");
-            trace!("Diagnostics: {:#?}", diagnostics);
-            return Ok((files_fixed, errors_fixed));
-        }
+        for pass in 1..=max_passes.max(1) {
+            let last_pass = pass >= max_passes;
+            let mut fixed_in_pass = false;
 
-        for diagnostic in fixable_outputs {
-            let local_errors_fixed = Self::fix_single_file(diagnostic).inspect_err(|err| {
-                error!("Error fixing file {}: {}", diagnostic.file_path(), err)
-            })?;
-            errors_fixed += local_errors_fixed;
-            files_fixed += 1;
+            for output in remaining.iter_mut() {
+                if !output
+                    .errors()
+                    .iter()
+                    .any(|error| error.is_fixable(self.fix_unsafe))
+                {
+                    continue;
+                }
+
+                let (applied, rope) = self.fix_single_file(output).inspect_err(|err| {
+                    error!("Error fixing file {}: {}", output.file_path(), err)
+                })?;
+                if applied.is_empty() {
+                    continue;
+                }
+                fixed_in_pass = true;
+                errors_fixed += applied.len();
+                files_fixed.insert(output.file_path().to_string());
+
+                // Files fixed before the last pass are always re-linted, to
+                // find any errors the fixes introduced.
+                let remapped = if last_pass {
+                    remap_output(output, &applied, &rope, self.fix_unsafe)
+                } else {
+                    None
+                };
+                *output = match remapped {
+                    Some(remapped) => remapped,
+                    None => {
+                        debug!("Re-linting fixed file {}", output.file_path());
+                        self.relint(output.file_path())?
+                    }
+                };
+            }
+
+            if !fixed_in_pass {
+                debug!("No fixable errors found in fix pass {pass}.");
+                break;
+            }
         }
 
-        Ok((files_fixed, errors_fixed))
+        Ok((files_fixed.len(), errors_fixed, remaining))
+    }
+
+    fn relint(&self, file: &str) -> Result<LintOutput> {
+        Ok(self
+            .lint(&LintTarget::FileOrDirectory(file.into()))?
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| LintOutput::new(file, Vec::new())))
+    }
+
+    /// Lint a single file and fix any fixable errors in it.
+    ///
+    /// The file is read once, so the fixes always apply to the content that
+    /// was linted, and replaced atomically, so it is never left partially
+    /// written. It isn't written at all if there is nothing to fix.
+    ///
+    /// Returns the corrections that were applied.
+    pub fn fix_file(&self, path: impl AsRef<Path>) -> Result<Vec<LintCorrection>> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|err| {
+            AppError::FileSystemError(
+                format!("reading file {} for auto-fixing", path.display()),
+                err,
+            )
+        })?;
+
+        let diagnostic = self
+            .lint_file_content(path, &content)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("No lint output for file {}", path.display()))?;
+        let (applied, _) = self.fix_content(path, &content, &diagnostic)?;
+        Ok(applied)
     }
 
-    fn fix_single_file(diagnostic: &LintOutput) -> Result<usize> {
-        let mut errors_fixed = 0;
+    /// Apply one of the suggestions for an error, chosen by the error's
+    /// [`id`](crate::LintError::id) and the suggestion's index in
+    /// [`combined_suggestions`](crate::LintError::combined_suggestions).
+    ///
+    /// The file is linted again first, so the suggestion always applies to
+    /// its current content. If the file has changed so that the error is no
+    /// longer found with the same ID, nothing is applied and an error is
+    /// returned.
+    ///
+    /// Returns the correction that was applied.
+    pub fn apply_suggestion(
+        &self,
+        path: impl AsRef<Path>,
+        error_id: &str,
+        suggestion_index: usize,
+    ) -> Result<LintCorrection> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|err| {
+            AppError::FileSystemError(
+                format!("reading file {} to apply a suggestion", path.display()),
+                err,
+            )
+        })?;
 
+        let diagnostic = self.lint_file_content(path, &content)?.into_iter().next();
+        let error = diagnostic
+            .iter()
+            .flat_map(|diagnostic| diagnostic.errors())
+            .find(|error| error.id() == error_id)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No error with ID {error_id} in {}. The file may have changed since it was linted.",
+                    path.display()
+                )
+            })?;
+        let suggestion = error
+            .combined_suggestions()
+            .and_then(|suggestions| suggestions.get(suggestion_index).copied())
+            .ok_or_else(|| {
+                anyhow::anyhow!("Error {error_id} has no suggestion at index {suggestion_index}")
+            })?
+            .clone();
+
+        let mut rope = Rope::from(content.as_str());
+        suggestion.apply(&mut rope);
+        write_atomically(path, &rope.to_string()).map_err(|err| {
+            AppError::FileSystemError(
+                format!(
+                    "writing file {} after applying a suggestion",
+                    path.display()
+                ),
+                err,
+            )
+        })?;
+        Ok(suggestion)
+    }
+
+    fn fix_single_file(&self, diagnostic: &LintOutput) -> Result<(Vec<LintCorrection>, Rope)> {
         let file = diagnostic.file_path();
         debug!("Fixing errors in {file}");
 
         let content = fs::read_to_string(file).map_err(|err| {
             AppError::FileSystemError(format!("reading file {file} for auto-fixing"), err)
         })?;
-        let mut rope = Rope::from(content.as_str());
-
-        let fixes_to_apply = Self::calculate_fixes_to_apply(file, diagnostic);
-        debug!("Fixes to apply for file {file}: {fixes_to_apply:#?}");
+        self.fix_content(Path::new(file), &content, diagnostic)
+    }
 
-        for fix in fixes_to_apply {
-            match fix {
-                LintCorrection::Insert(lint_fix_insert) => {
-                    rope.insert(
-                        lint_fix_insert.location.offset_range.start.into(),
-                        lint_fix_insert.text,
-                    );
-                    errors_fixed += 1;
-                }
-                LintCorrection::Delete(lint_fix_delete) => {
-                    let start: usize = lint_fix_delete.location.offset_range.start.into();
-                    let end: usize = lint_fix_delete.location.offset_range.end.into();
-                    rope.replace(start..end, "");
-                    errors_fixed += 1;
-                }
-                LintCorrection::Replace(lint_fix_replace) => {
-                    let start: usize = lint_fix_replace.location.offset_range.start.into();
-                    let end: usize = lint_fix_replace.location.offset_range.end.into();
-                    rope.replace(start..end, lint_fix_replace.text.as_str());
-                    errors_fixed += 1;
-                }
-            }
+    /// Apply the fixes from a diagnostic to the file's content, then write
+    /// the result back if anything changed.
+    ///
+    /// Returns the corrections that were applied, and the fixed content.
+    fn fix_content(
+        &self,
+        path: &Path,
+        content: &str,
+        diagnostic: &LintOutput,
+    ) -> Result<(Vec<LintCorrection>, Rope)> {
+        let file = path.display().to_string();
+        let (fixes_to_apply, rope) = self.apply_fixes(&file, content, diagnostic);
+
+        let fixed = rope.to_string();
+        if fixed == content {
+            debug!("Fixes for file {file} made no changes, skipping write");
+            return Ok((Vec::new(), rope));
         }
 
-        let content = rope.to_string();
-        fs::write(diagnostic.file_path(), content).map_err(|err| {
+        write_atomically(path, &fixed).map_err(|err| {
             AppError::FileSystemError(format!("writing file {file} post-fixing"), err)
         })?;
 
-        Ok(errors_fixed)
+        Ok((fixes_to_apply, rope))
+    }
+
+    /// Apply the fixes from a diagnostic to content, without writing it
+    /// anywhere.
+    ///
+    /// Returns the corrections that were applied, and the fixed content.
+    pub(crate) fn apply_fixes(
+        &self,
+        file: &str,
+        content: &str,
+        diagnostic: &LintOutput,
+    ) -> (Vec<LintCorrection>, Rope) {
+        let mut rope = Rope::from(content);
+
+        let fixes_to_apply = Self::calculate_fixes_to_apply(file, diagnostic, self.fix_unsafe);
+        debug!("Fixes to apply for file {file}: {fixes_to_apply:#?}");
+
+        for fix in &fixes_to_apply {
+            fix.apply(&mut rope);
+        }
+        (fixes_to_apply, rope)
     }
 
-    fn calculate_fixes_to_apply(file: &str, diagnostic: &LintOutput) -> Vec<LintCorrection> {
+    fn calculate_fixes_to_apply(
+        file: &str,
+        diagnostic: &LintOutput,
+        allow_unsafe: bool,
+    ) -> Vec<LintCorrection> {
         let mut requested_fixes: Vec<LintCorrection> = diagnostic
             .errors()
             .iter()
+            .filter(|err| err.is_fixable(allow_unsafe))
             .filter_map(|err| err.fix.clone())
             .flatten()
             .collect();
@@ -498,12 +715,624 @@ impl Linter {
     }
 }
 
+#[cfg(feature = "fix")]
+impl LintCorrection {
+    fn apply(&self, rope: &mut Rope) {
+        match self {
+            LintCorrection::Insert(insert) => {
+                rope.insert(insert.location.offset_range.start.into(), &insert.text);
+            }
+            LintCorrection::Delete(delete) => {
+                rope.replace(delete.location.offset_range.to_usize_range(), "");
+            }
+            LintCorrection::Replace(replace) => {
+                rope.replace(
+                    replace.location.offset_range.to_usize_range(),
+                    replace.text.as_str(),
+                );
+            }
+        }
+    }
+
+    fn location(&self) -> &DenormalizedLocation {
+        match self {
+            LintCorrection::Insert(insert) => &insert.location,
+            LintCorrection::Delete(delete) => &delete.location,
+            LintCorrection::Replace(replace) => &replace.location,
+        }
+    }
+
+    fn location_mut(&mut self) -> &mut DenormalizedLocation {
+        match self {
+            LintCorrection::Insert(insert) => &mut insert.location,
+            LintCorrection::Delete(delete) => &mut delete.location,
+            LintCorrection::Replace(replace) => &mut replace.location,
+        }
+    }
+
+    /// The range of the original content that the correction touches. An
+    /// insert touches only the point it inserts at.
+    fn affected_range(&self) -> AdjustedRange {
+        let range = &self.location().offset_range;
+        match self {
+            LintCorrection::Insert(_) => AdjustedRange::new(range.start, range.start),
+            _ => range.clone(),
+        }
+    }
+
+    /// The change in content length caused by applying the correction.
+    fn length_delta(&self) -> isize {
+        let range = self.affected_range().to_usize_range();
+        let removed = range.len() as isize;
+        match self {
+            LintCorrection::Insert(insert) => insert.text.len() as isize,
+            LintCorrection::Delete(_) => -removed,
+            LintCorrection::Replace(replace) => replace.text.len() as isize - removed,
+        }
+    }
+}
+
+/// Move the diagnostics left in a fixed file to their positions in the fixed
+/// content.
+///
+/// Returns `None` if the file needs to be re-linted instead: if a remaining
+/// diagnostic touches one of the applied corrections, or if a fixable error
+/// wasn't fully fixed.
+#[cfg(feature = "fix")]
+fn remap_output(
+    output: &LintOutput,
+    applied: &[LintCorrection],
+    fixed: &Rope,
+    allow_unsafe: bool,
+) -> Option<LintOutput> {
+    let mut errors = Vec::new();
+    for error in output.errors() {
+        if let Some(fix) = &error.fix {
+            if fix.iter().all(|correction| applied.contains(correction)) {
+                continue;
+            }
+            // Unsafe fixes that weren't allowed are expected to remain, but
+            // any other unapplied fix was dropped in a conflict.
+            if error.is_fixable(allow_unsafe) {
+                return None;
+            }
+        }
+
+        let mut error = error.clone();
+        error.location = remap_location(&error.location, applied, fixed)?;
+        for correction in error
+            .fix
+            .iter_mut()
+            .flatten()
+            .chain(error.suggestions.iter_mut().flatten())
+            .chain(error.suppression.iter_mut())
+        {
+            *correction.location_mut() = remap_location(correction.location(), applied, fixed)?;
+        }
+        errors.push(error);
+    }
+
+    let mut remapped = LintOutput::new(output.file_path(), errors);
+    if let Some(timing) = output.timing() {
+        remapped = remapped.with_timing(timing.clone());
+    }
+    Some(remapped)
+}
+
+/// Shift a location in the original content by the length of the corrections
+/// before it, or return `None` if it touches any of them.
+#[cfg(feature = "fix")]
+fn remap_location(
+    location: &DenormalizedLocation,
+    applied: &[LintCorrection],
+    fixed: &Rope,
+) -> Option<DenormalizedLocation> {
+    let mut delta: isize = 0;
+    for correction in applied {
+        let affected = correction.affected_range();
+        if affected.overlaps_or_abuts(&location.offset_range) {
+            return None;
+        }
+        if affected.end <= location.offset_range.start {
+            delta += correction.length_delta();
+        }
+    }
+
+    let start = AdjustedOffset::from(
+        location
+            .offset_range
+            .start
+            .into_usize()
+            .checked_add_signed(delta)?,
+    );
+    let end = AdjustedOffset::from(
+        location
+            .offset_range
+            .end
+            .into_usize()
+            .checked_add_signed(delta)?,
+    );
+    Some(DenormalizedLocation {
+        start: AdjustedPoint::from_adjusted_offset(&start, fixed),
+        end: AdjustedPoint::from_adjusted_offset(&end, fixed),
+        offset_range: AdjustedRange::new(start, end),
+    })
+}
+
+/// Replace a file's content by writing to a temporary file in the same
+/// directory, then renaming it over the original, so that readers never see
+/// a partially written file.
+///
+/// Symlinks are resolved first, so that the file they point to is updated,
+/// rather than the link being replaced by a regular file.
+#[cfg(feature = "fix")]
+fn write_atomically(path: &Path, content: &str) -> std::io::Result<()> {
+    let path = &fs::canonicalize(path)?;
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| std::io::Error::other(format!("{} is not a file", path.display())))?;
+    let suffix = std::iter::repeat_with(fastrand::alphanumeric)
+        .take(8)
+        .collect::<String>();
+    let temp_path = path.with_file_name(format!(".{}.{suffix}.tmp", file_name.to_string_lossy()));
+
+    let result = fs::write(&temp_path, content)
+        .and_then(|_| {
+            let permissions = fs::metadata(path)?.permissions();
+            fs::set_permissions(&temp_path, permissions)
+        })
+        .and_then(|_| fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "fix")]
+    use std::time::{Duration, SystemTime};
+
+    #[cfg(feature = "fix")]
+    use supa_mdx_macros::RuleName;
+
     use crate::parse;
+    #[cfg(feature = "fix")]
+    use crate::{
+        errors::LintError,
+        rules::{Rule, RuleName},
+        Config, ConfigDir, LintLevel,
+    };
 
     use super::*;
 
+    #[cfg(feature = "fix")]
+    fn heading_case_linter() -> Linter {
+        let mut linter = Linter::builder().build().unwrap();
+        linter
+            .config_mut()
+            .rule_registry
+            .deactivate_all_but("Rule001HeadingCase");
+        linter
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_fix_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.mdx");
+        fs::write(&path, "# Incorrect Heading\n\nSome text.\n").unwrap();
+
+        let applied = heading_case_linter().fix_file(&path).unwrap();
+        assert_eq!(applied.len(), 1);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# Incorrect heading\n\nSome text.\n"
+        );
+
+        // Only the fixed file is left behind.
+        let entries = fs::read_dir(dir.path()).unwrap().count();
+        assert_eq!(entries, 1);
+    }
+
+    /// Replaces the word "one" with "two", and "two" with "three", so that
+    /// fixing "one" introduces a new error. Reports "many" without a fix.
+    #[cfg(feature = "fix")]
+    #[derive(Debug, RuleName)]
+    struct CountingRule;
+
+    #[cfg(feature = "fix")]
+    impl Rule for CountingRule {
+        fn default_level(&self) -> LintLevel {
+            LintLevel::Error
+        }
+
+        fn check(
+            &self,
+            ast: &markdown::mdast::Node,
+            context: &Context,
+            level: LintLevel,
+        ) -> Option<Vec<LintError>> {
+            let markdown::mdast::Node::Text(text) = ast else {
+                return None;
+            };
+            let location = DenormalizedLocation::from_offset_range(
+                AdjustedRange::from_unadjusted_position(text.position.as_ref()?, context),
+                context,
+            );
+            let replacement = match text.value.as_str() {
+                "one" => "two",
+                "two" => "three",
+                "many" => {
+                    return Some(vec![LintError::from_raw_location()
+                        .rule(self.name())
+                        .level(level)
+                        .message("Can't count that high")
+                        .location(location)
+                        .call()])
+                }
+                _ => return None,
+            };
+            Some(vec![LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message("Count higher")
+                .location(location.clone())
+                .fix(vec![LintCorrection::Replace(LintCorrectionReplace {
+                    location,
+                    text: replacement.to_string(),
+                })])
+                .call()])
+        }
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_fix_until_stable() {
+        let mut linter = Linter::builder().build().unwrap();
+        linter
+            .config_mut()
+            .rule_registry
+            .replace_rules(vec![Box::new(CountingRule)]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.mdx");
+        let lint = |linter: &Linter| {
+            linter
+                .lint(&LintTarget::FileOrDirectory(path.clone()))
+                .unwrap()
+        };
+
+        fs::write(&path, "one").unwrap();
+        let (files_fixed, errors_fixed) = linter.fix_until_stable(&lint(&linter), 1).unwrap();
+        assert_eq!((files_fixed, errors_fixed), (1, 1));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "two");
+
+        fs::write(&path, "one").unwrap();
+        let (files_fixed, errors_fixed) = linter.fix_until_stable(&lint(&linter), 5).unwrap();
+        assert_eq!((files_fixed, errors_fixed), (1, 2));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "three");
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_fix_and_remap() {
+        let mut linter = Linter::builder().build().unwrap();
+        linter
+            .config_mut()
+            .rule_registry
+            .replace_rules(vec![Box::new(CountingRule)]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let fixed_path = dir.path().join("fixed.mdx");
+        let unfixed_path = dir.path().join("unfixed.mdx");
+        fs::write(&fixed_path, "two\n\nmany\n").unwrap();
+        fs::write(&unfixed_path, "many\n").unwrap();
+        let lint = |linter: &Linter| {
+            linter
+                .lint(&LintTarget::FileOrDirectory(dir.path().to_path_buf()))
+                .unwrap()
+        };
+
+        let diagnostics = lint(&linter);
+        let (files_fixed, errors_fixed, remaining) = linter.fix_and_remap(&diagnostics, 1).unwrap();
+        assert_eq!((files_fixed, errors_fixed), (1, 1));
+        assert_eq!(fs::read_to_string(&fixed_path).unwrap(), "three\n\nmany\n");
+
+        // The remaining diagnostics match a fresh lint of the fixed files.
+        let relinted = lint(&linter);
+        assert_eq!(remaining.len(), relinted.len());
+        for (remaining, relinted) in remaining.iter().zip(&relinted) {
+            assert_eq!(remaining.file_path(), relinted.file_path());
+            assert_eq!(remaining.errors().len(), 1);
+            assert_eq!(remaining.errors().len(), relinted.errors().len());
+            assert_eq!(
+                remaining.errors()[0].location,
+                relinted.errors()[0].location
+            );
+        }
+        let fixed = remaining
+            .iter()
+            .find(|output| Path::new(output.file_path()).file_name() == Some("fixed.mdx".as_ref()))
+            .unwrap();
+        assert_eq!(fixed.errors()[0].offset_range(), 7..11);
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_fix_from_saved_report() {
+        let mut linter = Linter::builder().build().unwrap();
+        linter
+            .config_mut()
+            .rule_registry
+            .replace_rules(vec![Box::new(CountingRule)]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.mdx");
+        fs::write(&path, "one\n\ntwo\n").unwrap();
+
+        let diagnostics = linter
+            .lint(&LintTarget::FileOrDirectory(path.clone()))
+            .unwrap();
+        let report = serde_json::to_string(&diagnostics).unwrap();
+
+        // Only the fix for the first error is approved.
+        let mut saved: Vec<LintOutput> = serde_json::from_str(&report).unwrap();
+        assert_eq!(saved[0].file_path(), diagnostics[0].file_path());
+        assert_eq!(saved[0].errors().len(), 2);
+        saved[0].retain(|error| error.offset_range().start == 0);
+
+        let (files_fixed, errors_fixed) = linter.fix(&saved).unwrap();
+        assert_eq!((files_fixed, errors_fixed), (1, 1));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "two\n\ntwo\n");
+    }
+
+    /// Replaces the word "guess" with "answer", as an unsafe fix.
+    #[cfg(feature = "fix")]
+    #[derive(Debug, RuleName)]
+    struct GuessingRule;
+
+    #[cfg(feature = "fix")]
+    impl Rule for GuessingRule {
+        fn default_level(&self) -> LintLevel {
+            LintLevel::Error
+        }
+
+        fn check(
+            &self,
+            ast: &markdown::mdast::Node,
+            context: &Context,
+            level: LintLevel,
+        ) -> Option<Vec<LintError>> {
+            let markdown::mdast::Node::Text(text) = ast else {
+                return None;
+            };
+            if text.value != "guess" {
+                return None;
+            }
+            let location = DenormalizedLocation::from_offset_range(
+                AdjustedRange::from_unadjusted_position(text.position.as_ref()?, context),
+                context,
+            );
+            Some(vec![LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message("Don't guess")
+                .location(location.clone())
+                .fix(vec![LintCorrection::Replace(LintCorrectionReplace {
+                    location,
+                    text: "answer".to_string(),
+                })])
+                .fix_safety(FixSafety::Unsafe)
+                .call()])
+        }
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_fix_unsafe() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.mdx");
+        fs::write(&path, "guess\n").unwrap();
+
+        for fix_unsafe in [false, true] {
+            let mut linter = Linter::builder().fix_unsafe(fix_unsafe).build().unwrap();
+            linter
+                .config_mut()
+                .rule_registry
+                .replace_rules(vec![Box::new(GuessingRule)]);
+            let diagnostics = linter
+                .lint(&LintTarget::FileOrDirectory(path.clone()))
+                .unwrap();
+            assert!(!diagnostics[0].errors()[0].is_fixable(false));
+            assert!(diagnostics[0].errors()[0].is_fixable(true));
+
+            let (files_fixed, errors_fixed, remaining) =
+                linter.fix_and_remap(&diagnostics, 1).unwrap();
+            if fix_unsafe {
+                assert_eq!((files_fixed, errors_fixed), (1, 1));
+                assert_eq!(fs::read_to_string(&path).unwrap(), "answer\n");
+                assert!(remaining[0].errors().is_empty());
+            } else {
+                assert_eq!((files_fixed, errors_fixed), (0, 0));
+                assert_eq!(fs::read_to_string(&path).unwrap(), "guess\n");
+                assert_eq!(remaining[0].errors().len(), 1);
+            }
+        }
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_fix_order_mixed_corrections() {
+        let insert = LintCorrection::Insert(LintCorrectionInsert {
+            location: DenormalizedLocation::dummy(20, 20, 3, 0, 3, 0),
+            text: "\n".to_string(),
+        });
+        let replace = LintCorrection::Replace(LintCorrectionReplace {
+            location: DenormalizedLocation::dummy(10, 12, 1, 0, 1, 2),
+            text: "".to_string(),
+        });
+        let delete = LintCorrection::Delete(LintCorrectionDelete {
+            location: DenormalizedLocation::dummy(30, 32, 4, 0, 4, 2),
+        });
+        assert_eq!(insert.cmp(&replace), Ordering::Greater);
+        assert_eq!(replace.cmp(&insert), Ordering::Less);
+        assert_eq!(insert.cmp(&delete), Ordering::Less);
+        assert_eq!(delete.cmp(&insert), Ordering::Greater);
+
+        let mut rope = Rope::from("<Admonition>\n  \nText.\n</Admonition>\n");
+        let output = LintOutput::new(
+            "test.mdx",
+            vec![
+                LintError::from_raw_location()
+                    .rule("Rule005AdmonitionNewlines")
+                    .level(LintLevel::Error)
+                    .message("Missing empty line")
+                    .location(DenormalizedLocation::dummy(0, 35, 0, 0, 3, 13))
+                    .fix(vec![LintCorrection::Insert(LintCorrectionInsert {
+                        location: DenormalizedLocation::dummy(22, 22, 3, 0, 3, 0),
+                        text: "\n".to_string(),
+                    })])
+                    .call(),
+                LintError::from_raw_location()
+                    .rule("Rule022Whitespace")
+                    .level(LintLevel::Warning)
+                    .message("Remove trailing whitespace")
+                    .location(DenormalizedLocation::dummy(13, 15, 1, 0, 1, 2))
+                    .fix(vec![LintCorrection::Replace(LintCorrectionReplace {
+                        location: DenormalizedLocation::dummy(13, 15, 1, 0, 1, 2),
+                        text: "".to_string(),
+                    })])
+                    .call(),
+            ],
+        );
+        for fix in Linter::calculate_fixes_to_apply("test.mdx", &output, false) {
+            fix.apply(&mut rope);
+        }
+        assert_eq!(rope.to_string(), "<Admonition>\n\nText.\n\n</Admonition>\n");
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_fix_reindent_with_fixes_inside_item() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.mdx");
+        let config = Config::from_serializable()
+            .config(serde_json::json!({ "Rule042EmphasisMarkers": { "emphasis": "_" } }))
+            .config_dir(&ConfigDir(None))
+            .call()
+            .unwrap();
+        let linter = Linter::builder()
+            .config(config)
+            .fix_unsafe(true)
+            .build()
+            .unwrap();
+
+        for (content, expected) in [
+            ("- a\n    - b  \n", "- a\n  - b\n"),
+            ("- a\n    - *ïtal*\n", "- a\n  - _ïtal_\n"),
+        ] {
+            fs::write(&path, content).unwrap();
+            linter.fix_file(&path).unwrap();
+            assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+        }
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_apply_suggestion() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.mdx");
+        fs::write(&path, "# Incorrect Heading\n\n## Another Heading\n").unwrap();
+
+        let linter = heading_case_linter();
+        let outputs = linter
+            .lint(&LintTarget::FileOrDirectory(path.clone()))
+            .unwrap();
+        let errors = outputs[0].errors();
+        assert_eq!(errors.len(), 2);
+        assert_ne!(errors[0].id(), errors[1].id());
+        let id = errors[1].id();
+
+        let applied = linter.apply_suggestion(&path, &id, 0).unwrap();
+        assert!(matches!(applied, LintCorrection::Replace(_)));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# Incorrect Heading\n\n## Another heading\n"
+        );
+
+        // The error is gone, so its ID no longer refers to anything.
+        assert!(linter.apply_suggestion(&path, &id, 0).is_err());
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_apply_suggestion_revalidates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.mdx");
+        fs::write(&path, "# Incorrect Heading\n").unwrap();
+
+        let linter = heading_case_linter();
+        let outputs = linter
+            .lint(&LintTarget::FileOrDirectory(path.clone()))
+            .unwrap();
+        let id = outputs[0].errors()[0].id();
+        assert!(linter.apply_suggestion(&path, &id, 1).is_err());
+
+        fs::write(&path, "Intro.\n\n# Incorrect Heading\n").unwrap();
+        assert!(linter.apply_suggestion(&path, &id, 0).is_err());
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "Intro.\n\n# Incorrect Heading\n"
+        );
+    }
+
+    #[cfg(all(feature = "fix", unix))]
+    #[test]
+    fn test_fix_file_through_symlink() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("real")).unwrap();
+        fs::create_dir_all(dir.path().join("docs")).unwrap();
+        let target = dir.path().join("real/page.mdx");
+        let link = dir.path().join("docs/page.mdx");
+        fs::write(&target, "# Incorrect Heading\n").unwrap();
+        std::os::unix::fs::symlink("../real/page.mdx", &link).unwrap();
+
+        let applied = heading_case_linter().fix_file(&link).unwrap();
+        assert_eq!(applied.len(), 1);
+        assert!(fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(
+            fs::read_to_string(&target).unwrap(),
+            "# Incorrect heading\n"
+        );
+        // The temporary file is cleaned up next to the target, not the link.
+        assert_eq!(fs::read_dir(dir.path().join("real")).unwrap().count(), 1);
+        assert_eq!(fs::read_dir(dir.path().join("docs")).unwrap().count(), 1);
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_fix_file_skips_write_without_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.mdx");
+        fs::write(&path, "# Correct heading\n").unwrap();
+
+        let modified = SystemTime::now() - Duration::from_secs(60 * 60);
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+
+        let applied = heading_case_linter().fix_file(&path).unwrap();
+        assert!(applied.is_empty());
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+    }
+
     #[test]
     fn test_create_word_splice_correction_midsentence() {
         let parsed = parse("Here is a simple sentence.").unwrap();
@@ -597,6 +1426,60 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_create_word_splice_correction_multibyte() {
+        let parsed = parse("Please über alles.").unwrap();
+        let context = Context::builder().parse_result(&parsed).build().unwrap();
+
+        let outer_range = AdjustedRange::new(0.into(), 19.into());
+        let splice_range = AdjustedRange::new(0.into(), 6.into());
+
+        let expected = LintCorrection::Replace(LintCorrectionReplace {
+            text: "Ü".to_string(),
+            location: DenormalizedLocation::from_offset_range(
+                AdjustedRange::new(0.into(), 9.into()),
+                &context,
+            ),
+        });
+        let actual = LintCorrection::create_word_splice_correction()
+            .context(&context)
+            .outer_range(&outer_range)
+            .splice_range(&splice_range)
+            .call();
+        assert_eq!(expected, actual);
+
+        let expected = LintCorrection::Replace(LintCorrectionReplace {
+            text: "Élan".to_string(),
+            location: DenormalizedLocation::from_offset_range(splice_range.clone(), &context),
+        });
+        let actual = LintCorrection::create_word_splice_correction()
+            .context(&context)
+            .outer_range(&outer_range)
+            .splice_range(&splice_range)
+            .replace("élan".into())
+            .call();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_create_word_splice_correction_end_of_text() {
+        let parsed = parse("## Rate limits TODO\n\nFIXME: document the limits.").unwrap();
+        let context = Context::builder().parse_result(&parsed).build().unwrap();
+
+        let outer_range = AdjustedRange::new(3.into(), 19.into());
+        let splice_range = AdjustedRange::new(15.into(), 19.into());
+
+        let expected = LintCorrection::Delete(LintCorrectionDelete {
+            location: DenormalizedLocation::from_offset_range(splice_range.clone(), &context),
+        });
+        let actual = LintCorrection::create_word_splice_correction()
+            .context(&context)
+            .outer_range(&outer_range)
+            .splice_range(&splice_range)
+            .call();
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn test_create_word_splice_correction_start() {
         let parsed = parse("Please take a biscuit.").unwrap();
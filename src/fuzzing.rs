@@ -0,0 +1,67 @@
+//! Entry points for the `cargo-fuzz` targets in `fuzz/`. Each one takes
+//! arbitrary input and panics only if it finds a bug.
+//!
+//! Not part of the public API, and only built with the `fuzzing` feature.
+
+use std::sync::OnceLock;
+
+use crate::{parser::parse_lossy, rope::Rope, utils::words::WordIterator, LintTarget, Linter};
+
+/// Parse the input, and check that every node's position maps back to a
+/// valid range of the source.
+pub fn parse(input: &str) {
+    let _ = crate::parse(input);
+
+    let result = parse_lossy(input);
+    let mut stack = vec![result.ast()];
+    while let Some(node) = stack.pop() {
+        if let Some(position) = node.position() {
+            let range = result.source_range(position);
+            assert!(
+                input.get(range.clone()).is_some(),
+                "Node range {range:?} isn't a valid range of the input"
+            );
+        }
+        if let Some(children) = node.children() {
+            stack.extend(children);
+        }
+    }
+}
+
+/// Split the input into words, and check that each word is the text at its
+/// offset.
+pub fn words(input: &str) {
+    let rope = Rope::from(input);
+    let mut previous_end = 0;
+    for (offset, word, _) in WordIterator::new(rope.byte_slice(..), 0, Default::default()) {
+        assert!(
+            offset >= previous_end,
+            "Word at {offset} overlaps the previous word, which ends at {previous_end}"
+        );
+        previous_end = offset + word.byte_len();
+        assert_eq!(
+            input.get(offset..previous_end),
+            Some(word.to_string().as_str()),
+            "Word at {offset} doesn't match the input"
+        );
+    }
+}
+
+/// Lint the input with the default rules, and apply every fix, including
+/// unsafe ones.
+pub fn fix(input: &str) {
+    // Setting up the rules is slow, so the linter is shared between runs.
+    static LINTER: OnceLock<Linter> = OnceLock::new();
+    let linter = LINTER.get_or_init(|| {
+        Linter::builder()
+            .fix_unsafe(true)
+            .build()
+            .expect("The default config should be valid")
+    });
+    let Ok(outputs) = linter.lint(&LintTarget::String(input)) else {
+        return;
+    };
+    for output in &outputs {
+        linter.apply_fixes("", input, output);
+    }
+}
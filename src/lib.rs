@@ -1,13 +1,24 @@
 use anyhow::{Context as _, Result};
 use bon::bon;
 use context::Context;
+use log::warn;
 use rules::RuleFilter;
+use std::cmp::Ordering;
 use std::env;
-use std::path::{Path, PathBuf};
+use std::path::{self, Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
 use std::{fs, io::Read};
 
-use crate::output::LintOutput;
-use crate::parser::parse;
+use crate::app_error::ParseError;
+use crate::config::{DirectoryRules, DirectoryRulesCache};
+use crate::location::LocationConverter;
+use crate::output::{LintOutput, LintTiming, OutputFormatter};
+use crate::stats::DocumentStats;
+use crate::utils::{
+    path::compare_paths,
+    walk::{DirectoryWalker, WalkError},
+};
 
 mod app_error;
 mod comments;
@@ -18,16 +29,35 @@ pub mod location;
 pub(crate) mod parser;
 mod utils;
 
+pub mod diff;
+pub mod exceptions;
 pub mod fix;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
 pub mod output;
 #[doc(hidden)]
 pub mod rope;
 pub mod rules;
+pub mod stats;
+#[cfg(any(feature = "testing", all(test, feature = "fix")))]
+pub mod testing;
 
 #[doc(inline)]
-pub use crate::config::{Config, ConfigDir, ConfigMetadata};
+pub use crate::config::{Config, ConfigDir, ConfigMetadata, ConfigPreset};
 #[doc(inline)]
 pub use crate::errors::{LintError, LintLevel};
+#[doc(inline)]
+pub use crate::parser::{parse, parse_lossy, ParseResult};
+
+/// The rules that check frontmatter fields listed in
+/// `lint_frontmatter_fields`.
+const FRONTMATTER_RULES: &[&str] = &[
+    "Rule001HeadingCase",
+    "Rule003Spelling",
+    "Rule004ExcludeWords",
+];
+const FRONTMATTER_TITLE_FIELD: &str = "title";
 
 #[derive(Debug)]
 pub struct PhaseSetup;
@@ -35,9 +65,22 @@ pub struct PhaseSetup;
 #[derive(Debug)]
 pub struct PhaseReady;
 
-#[derive(Debug)]
+/// Lints MDX files and strings.
+///
+/// Setting up the rules takes a while, so reuse a linter where possible.
+/// Clones share the same set-up rules, so cloning is cheap.
+///
+/// A linter is `Send` and `Sync`, so a single linter can serve lint requests
+/// from several threads at once, such as in a language server. Rules keep
+/// the state of a check local to it, and state that lasts for a whole file,
+/// such as configuration comments, is keyed by the file's lint run, so
+/// concurrent runs don't see each other's state.
+#[derive(Debug, Clone)]
 pub struct Linter {
-    config: Config<PhaseReady>,
+    config: Arc<Config<PhaseReady>>,
+    timing: bool,
+    fix_unsafe: bool,
+    max_depth: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -51,14 +94,56 @@ struct LintSourceReference<'reference>(Option<&'reference Path>);
 #[bon]
 impl Linter {
     #[builder]
-    pub fn new(config: Option<Config<PhaseSetup>>) -> Result<Self> {
+    pub fn new(
+        config: Option<Config<PhaseSetup>>,
+        /// Record the time spent in each rule, available from
+        /// [`LintOutput::timing`].
+        #[builder(default)]
+        timing: bool,
+        /// Apply fixes marked [`FixSafety::Unsafe`](crate::fix::FixSafety)
+        /// when auto-fixing, as well as safe ones.
+        #[builder(default)]
+        fix_unsafe: bool,
+        /// How deep to descend into directories. Files directly in a linted
+        /// directory are at depth 1.
+        max_depth: Option<usize>,
+    ) -> Result<Self> {
         Ok(Self {
-            config: config.unwrap_or_default().try_into()?,
+            config: Arc::new(config.unwrap_or_default().try_into()?),
+            timing,
+            fix_unsafe,
+            max_depth,
         })
     }
 
+    /// Whether fixes marked [`FixSafety::Unsafe`](crate::fix::FixSafety) are
+    /// applied when auto-fixing.
+    pub fn fix_unsafe(&self) -> bool {
+        self.fix_unsafe
+    }
+
     pub fn config_metadata(&self) -> ConfigMetadata {
-        (&self.config).into()
+        self.config.as_ref().into()
+    }
+
+    /// Format lint outputs with one of the built-in formatters, or a custom
+    /// implementation of [`OutputFormatter`].
+    pub fn format(
+        &self,
+        outputs: &[LintOutput],
+        formatter: &dyn OutputFormatter,
+    ) -> Result<String> {
+        formatter.format(outputs, &self.config_metadata())
+    }
+
+    /// Format lint outputs into a writer, such as a file or stdout.
+    pub fn format_to(
+        &self,
+        outputs: &[LintOutput],
+        formatter: &dyn OutputFormatter,
+        writer: &mut dyn std::io::Write,
+    ) -> Result<()> {
+        formatter.format_to(outputs, &self.config_metadata(), writer)
     }
 
     pub fn is_lintable(&self, path: impl AsRef<Path>) -> bool {
@@ -69,11 +154,75 @@ impl Linter {
         self.config.is_ignored(path)
     }
 
+    /// Compare two paths in the order used for output, as set by the
+    /// `path_sort_order` config option. Use this to order outputs from
+    /// multiple lint targets consistently.
+    pub fn compare_paths(&self, a: impl AsRef<Path>, b: impl AsRef<Path>) -> Ordering {
+        compare_paths(a.as_ref(), b.as_ref(), self.config.path_sort_order)
+    }
+
+    /// Whether warnings should fail the lint run, as set by the `strict`
+    /// config option. This doesn't change the levels of the reported
+    /// diagnostics.
+    pub fn is_strict(&self) -> bool {
+        self.config.strict
+    }
+
+    /// A JSON-schema-like description of the configuration accepted by the
+    /// linter, including the settings of every rule. Useful for editor
+    /// tooling and for validating configuration files.
+    pub fn config_schema(&self) -> serde_json::Value {
+        config::config_schema()
+    }
+
+    /// Count the words, headings, and code blocks in each file of the
+    /// target, skipping ignored files, without linting them.
+    pub fn stats(&self, input: &LintTarget) -> Result<Vec<DocumentStats>> {
+        match input {
+            LintTarget::FileOrDirectory(path) => self.stats_file_or_directory(path),
+            LintTarget::String(string) => Ok(vec![DocumentStats::new(
+                "[direct input]".to_string(),
+                &parse(string)?,
+            )]),
+        }
+    }
+
+    fn stats_file_or_directory(&self, path: &Path) -> Result<Vec<DocumentStats>> {
+        if path.is_file() {
+            if self.config.is_ignored(path) {
+                return Ok(Vec::new());
+            }
+            let contents = self
+                .read_source(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let parse_result =
+                parse(&contents).with_context(|| format!("Failed to parse {}", path.display()))?;
+            Ok(vec![DocumentStats::new(
+                Self::source_name(path)?,
+                &parse_result,
+            )])
+        } else if path.is_dir() {
+            let mut collected_vec = Vec::new();
+            for entry_path in self.lintable_files(path)? {
+                collected_vec.append(&mut self.stats_file_or_directory(&entry_path)?);
+            }
+            Ok(collected_vec)
+        } else {
+            Err(anyhow::anyhow!(
+                "Path is neither a file nor a directory: {:?}",
+                path
+            ))
+        }
+    }
+
     pub fn lint(&self, input: &LintTarget) -> Result<Vec<LintOutput>> {
         self.lint_internal(input, None)
     }
 
+    /// Lint with a single rule, given by its name, alias, or a deprecated
+    /// name.
     pub fn lint_only_rule(&self, rule_id: &str, input: &LintTarget) -> Result<Vec<LintOutput>> {
+        let rule_id = rules::canonical_rule_name(rule_id).unwrap_or(rule_id);
         self.lint_internal(input, Some(&[rule_id]))
     }
 
@@ -84,37 +233,76 @@ impl Linter {
     ) -> Result<Vec<LintOutput>> {
         match input {
             LintTarget::FileOrDirectory(path) => {
-                self.lint_file_or_directory(path, check_only_rules)
+                let mut directory_rules = self.directory_rules_cache(path)?;
+                self.lint_file_or_directory(path, check_only_rules, &mut directory_rules)
             }
             LintTarget::String(string) => {
-                self.lint_string(string, LintSourceReference(None), check_only_rules)
+                self.lint_string(string, LintSourceReference(None), check_only_rules, None)
             }
         }
     }
 
+    /// The cache of `.supa-mdx-lint` files for linting `target`. Files are
+    /// read up to the config file's directory if the target is in it, or
+    /// else up to the target, or the directory containing it.
+    fn directory_rules_cache(&self, target: &Path) -> Result<DirectoryRulesCache> {
+        let target = path::absolute(target)?;
+        let root = match &self.config.config_dir {
+            Some(config_dir) if target.starts_with(config_dir) => config_dir.as_path(),
+            _ if target.is_dir() => target.as_path(),
+            _ => target.parent().unwrap_or(&target),
+        };
+        DirectoryRulesCache::new(root)
+    }
+
     fn lint_file_or_directory(
         &self,
         path: &PathBuf,
         check_only_rules: RuleFilter,
+        directory_rules: &mut DirectoryRulesCache,
     ) -> Result<Vec<LintOutput>> {
         if path.is_file() {
             if self.config.is_ignored(path) {
                 return Ok(Vec::new());
             }
 
-            let mut file = fs::File::open(path)?;
-            let mut contents = String::new();
-            file.read_to_string(&mut contents)?;
-            self.lint_string(&contents, LintSourceReference(Some(path)), check_only_rules)
+            // A file that can't be read or parsed shouldn't abort the whole
+            // run, so report the failure as a diagnostic on that file.
+            match self.lint_file(path, check_only_rules, directory_rules) {
+                Ok(result) => Ok(result),
+                Err(err) => {
+                    warn!("Failed to lint file {path:?}: {err:#}");
+                    Ok(vec![LintOutput::from_internal_error(
+                        Self::source_name(path)?,
+                        &err,
+                    )])
+                }
+            }
         } else if path.is_dir() {
-            let collected_vec = fs::read_dir(path)?
-                .filter_map(Result::ok)
-                .filter(|dir_entry| self.is_lintable(dir_entry.path()))
-                .flat_map(|entry| {
-                    self.lint_file_or_directory(&entry.path(), check_only_rules)
-                        .unwrap_or_default()
-                })
-                .collect::<Vec<_>>();
+            let mut collected_vec = Vec::new();
+            for entry in self.directory_files(path)? {
+                let (entry_path, result) = match entry {
+                    Ok(entry_path) => {
+                        let result = self.lint_file_or_directory(
+                            &entry_path,
+                            check_only_rules,
+                            directory_rules,
+                        );
+                        (entry_path, result)
+                    }
+                    Err(WalkError { path, error }) => (path, Err(error)),
+                };
+                match result {
+                    Ok(mut result) => collected_vec.append(&mut result),
+                    Err(err) => {
+                        warn!("Failed to lint {entry_path:?}: {err:#}");
+                        collected_vec.push(LintOutput::from_internal_error(
+                            Self::source_name(&entry_path)?,
+                            &err,
+                        ));
+                    }
+                }
+            }
             Ok(collected_vec)
         } else {
             Err(anyhow::anyhow!(
@@ -124,35 +312,202 @@ impl Linter {
         }
     }
 
+    /// The lintable files under a directory, in output order, with errors
+    /// for any subdirectories that couldn't be read.
+    fn directory_files(&self, path: &Path) -> Result<Vec<Result<PathBuf, WalkError>>> {
+        // Directory iteration order depends on the filesystem, so sort
+        // entries to keep the output stable across runs and platforms.
+        DirectoryWalker::builder()
+            .follow_symlinks(self.config.follow_symlinks)
+            .maybe_max_depth(self.max_depth)
+            .include(&|entry_path| self.is_lintable(entry_path))
+            .compare(&|a, b| self.compare_paths(a, b))
+            .build()
+            .walk(path)
+    }
+
+    /// The lintable files under a directory, in output order. Symlinks and
+    /// the maximum depth are handled as when linting the directory.
+    pub fn lintable_files(&self, path: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+        self.directory_files(path.as_ref())?
+            .into_iter()
+            .map(|entry| {
+                entry.map_err(|WalkError { path, error }| {
+                    error.context(format!("Failed to read directory {}", path.display()))
+                })
+            })
+            .collect()
+    }
+
+    fn lint_file(
+        &self,
+        path: &Path,
+        check_only_rules: RuleFilter,
+        directory_rules: &mut DirectoryRulesCache,
+    ) -> Result<Vec<LintOutput>> {
+        let contents = self.read_source(path)?;
+        let directory_rules = directory_rules.for_file(path)?;
+        self.lint_string(
+            &contents,
+            LintSourceReference(Some(path)),
+            check_only_rules,
+            Some(&directory_rules),
+        )
+    }
+
+    /// Read a source file, refusing files larger than the configured
+    /// `max_file_size`, if any, so that a huge file committed by accident is
+    /// reported rather than read into memory.
+    fn read_source(&self, path: &Path) -> Result<String> {
+        let Some(max_file_size) = self.config.max_file_size else {
+            return Ok(fs::read_to_string(path)?);
+        };
+        let max_bytes = max_file_size.saturating_mul(1024);
+        let file = fs::File::open(path)?;
+        let too_large = || {
+            anyhow::anyhow!(
+                "File is larger than the maximum size of {max_file_size} KB. Raise max_file_size in the config, or add the file to ignore_patterns."
+            )
+        };
+        if file.metadata()?.len() > max_bytes {
+            return Err(too_large());
+        }
+
+        // The file can grow after its size is checked, so never read more
+        // than one byte past the limit.
+        let mut contents = String::new();
+        file.take(max_bytes.saturating_add(1))
+            .read_to_string(&mut contents)?;
+        if contents.len() as u64 > max_bytes {
+            return Err(too_large());
+        }
+        Ok(contents)
+    }
+
+    /// Lint content in place of the file at `path`, such as a version of the
+    /// file staged in git. The path is used for path-specific settings and
+    /// to name the file in the output.
+    pub fn lint_file_content(&self, path: &Path, contents: &str) -> Result<Vec<LintOutput>> {
+        let directory_rules = self.directory_rules_cache(path)?.for_file(path)?;
+        self.lint_string(
+            contents,
+            LintSourceReference(Some(path)),
+            None,
+            Some(&directory_rules),
+        )
+    }
+
+    /// Lint the configured frontmatter fields with the prose rules. The
+    /// `title` field is checked as a heading, and other fields as
+    /// paragraphs.
+    fn lint_frontmatter(
+        &self,
+        parse_result: &ParseResult,
+        path: Option<&Path>,
+        check_only_rules: RuleFilter,
+        directory_rules: Option<&DirectoryRules>,
+    ) -> Result<Vec<LintError>> {
+        let rules = FRONTMATTER_RULES
+            .iter()
+            .copied()
+            .filter(|rule| check_only_rules.is_none_or(|filter| filter.contains(rule)))
+            .collect::<Vec<_>>();
+        if rules.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut errors = Vec::new();
+        for field in &self.config.lint_frontmatter_fields {
+            let Some(document) =
+                parse_result.frontmatter_field_document(field, field == FRONTMATTER_TITLE_FIELD)
+            else {
+                continue;
+            };
+            let context = Context::builder()
+                .parse_result(&document)
+                .maybe_file_path(path)
+                .check_only_rules(&rules)
+                .maybe_directory_rules(directory_rules)
+                .build()?;
+            errors.extend(self.config.rule_registry.run(&context)?);
+        }
+        Ok(errors)
+    }
+
+    /// The name used to identify a linted file in the output: its path
+    /// relative to the current directory if possible, otherwise as given.
+    fn source_name(path: &Path) -> Result<String> {
+        let current_dir = env::current_dir().context("Failed to get current directory")?;
+        let relative_path = path.strip_prefix(&current_dir).unwrap_or(path);
+        Ok(relative_path.to_string_lossy().to_string())
+    }
+
+    #[cfg(test)]
+    fn config_mut(&mut self) -> &mut Config<PhaseReady> {
+        Arc::get_mut(&mut self.config).expect("Config should not be shared in tests")
+    }
+
+    fn lint_source_name(source: LintSourceReference) -> Result<String> {
+        match source.0 {
+            Some(path) => Self::source_name(path),
+            None => Ok("[direct input]".to_string()),
+        }
+    }
+
     fn lint_string(
         &self,
         string: &str,
         source: LintSourceReference,
         check_only_rules: RuleFilter,
+        directory_rules: Option<&DirectoryRules>,
     ) -> Result<Vec<LintOutput>> {
-        let parse_result = parse(string)?;
+        let start = Instant::now();
+        let parse_result = match parse(string) {
+            Ok(parse_result) => parse_result,
+            Err(err) => match err.downcast_ref::<ParseError>() {
+                // Report invalid MDX as a diagnostic at the point where
+                // parsing failed, so it shows up in the output like any
+                // other problem in the file.
+                Some(ParseError::InvalidMdx { reason, offset }) => {
+                    let error =
+                        LintError::parse_error(reason, *offset, &LocationConverter::new(string));
+                    return Ok(vec![LintOutput::new(
+                        Self::lint_source_name(source)?,
+                        vec![error],
+                    )]);
+                }
+                _ => return Err(err),
+            },
+        };
         let rule_context = Context::builder()
             .parse_result(&parse_result)
+            .maybe_file_path(source.0)
             .maybe_check_only_rules(check_only_rules)
+            .maybe_directory_rules(directory_rules)
+            .skip_components(&self.config.skip_components)
             .build()?;
-        match self.config.rule_registry.run(&rule_context) {
-            Ok(diagnostics) => {
-                let source = match source.0 {
-                    Some(path) => {
-                        let current_dir =
-                            env::current_dir().context("Failed to get current directory")?;
-                        let relative_path = match path.strip_prefix(&current_dir) {
-                            Ok(relative_path) => relative_path,
-                            Err(_) => path,
-                        };
-                        &relative_path.to_string_lossy()
-                    }
-                    None => "[direct input]",
-                };
-                Ok(vec![LintOutput::new(source, diagnostics)])
-            }
-            Err(err) => Err(err),
+        let (mut diagnostics, rule_timings) = if self.timing {
+            let (diagnostics, rule_timings) =
+                self.config.rule_registry.run_with_timing(&rule_context)?;
+            (diagnostics, Some(rule_timings))
+        } else {
+            (self.config.rule_registry.run(&rule_context)?, None)
+        };
+        if check_only_rules.is_none() {
+            diagnostics.extend(rule_context.directive_errors());
         }
+        diagnostics.extend(self.lint_frontmatter(
+            &parse_result,
+            source.0,
+            check_only_rules,
+            directory_rules,
+        )?);
+
+        let mut output = LintOutput::new(Self::lint_source_name(source)?, diagnostics);
+        if let Some(rule_timings) = rule_timings {
+            output = output.with_timing(LintTiming::new(rule_timings, start.elapsed()));
+        }
+        Ok(vec![output])
     }
 }
 
@@ -166,6 +521,7 @@ mod tests {
     use super::*;
 
     use ctor::ctor;
+    use fix::LintCorrection;
 
     #[ctor]
     fn init_test_logger() {
@@ -196,15 +552,15 @@ mod tests {
     fn test_lint_valid_string() -> Result<()> {
         let mut linter = Linter::builder().build()?;
         linter
-            .config
+            .config_mut()
             .rule_registry
             .deactivate_all_but("Rule001HeadingCase");
 
         let valid_mdx = "# Hello, world!\n\nThis is a valid document.";
-        let result = linter.lint(&LintTarget::String(&valid_mdx.to_string()))?;
+        let result = linter.lint(&LintTarget::String(valid_mdx))?;
 
         assert!(
-            result.get(0).unwrap().errors().is_empty(),
+            result.first().unwrap().errors().is_empty(),
             "Expected no lint errors for valid MDX, got {:?}",
             result
         );
@@ -215,17 +571,590 @@ mod tests {
     fn test_lint_invalid_string() -> Result<()> {
         let mut linter = Linter::builder().build()?;
         linter
-            .config
+            .config_mut()
             .rule_registry
             .deactivate_all_but("Rule001HeadingCase");
 
         let invalid_mdx = "# Incorrect Heading\n\nThis is an invalid document.";
-        let result = linter.lint(&LintTarget::String(&invalid_mdx.to_string()))?;
+        let result = linter.lint(&LintTarget::String(invalid_mdx))?;
 
         assert!(
-            !result.get(0).unwrap().errors().is_empty(),
+            !result.first().unwrap().errors().is_empty(),
             "Expected lint errors for invalid MDX"
         );
         Ok(())
     }
+
+    #[test]
+    fn test_linter_clone_shares_config() -> Result<()> {
+        let linter = Linter::builder().fix_unsafe(true).build()?;
+        let clone = linter.clone();
+        assert!(Arc::ptr_eq(&linter.config, &clone.config));
+        assert!(clone.fix_unsafe());
+
+        let result = clone.lint(&LintTarget::String("# Incorrect Heading\n"))?;
+        assert_eq!(result[0].errors()[0].rule, "Rule001HeadingCase");
+        Ok(())
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_linter_shared_between_threads() -> Result<()> {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Linter>();
+
+        let linter = Linter::builder().build()?;
+        let results = std::thread::scope(|scope| {
+            let handles = (0..8)
+                .map(|idx| {
+                    let linter = &linter;
+                    scope.spawn(move || {
+                        // Only the even documents allow the misspelling.
+                        let allow = if idx % 2 == 0 {
+                            "{/* supa-mdx-lint-configure Rule003Spelling +Wrnog */}\n\n"
+                        } else {
+                            ""
+                        };
+                        // The headings are in sentence case only if the word
+                        // after the inline code is checked as mid-sentence,
+                        // which other threads' headings mustn't disturb.
+                        let headings = (0..100)
+                            .map(|n| format!("## `npm` install step {n}\n\n"))
+                            .collect::<String>();
+                        linter.lint(&LintTarget::String(&format!(
+                            "{allow}Wrnog text.\n\n{headings}"
+                        )))
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        for (idx, result) in results.iter().enumerate() {
+            let errors = result[0].errors();
+            let misspelled = errors.iter().any(|error| error.rule == "Rule003Spelling");
+            assert_eq!(misspelled, idx % 2 == 1);
+            assert!(!errors
+                .iter()
+                .any(|error| error.rule == "Rule001HeadingCase"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_with_timing() -> Result<()> {
+        let mut linter = Linter::builder().timing(true).build()?;
+        linter
+            .config_mut()
+            .rule_registry
+            .deactivate_all_but("Rule001HeadingCase");
+
+        let result = linter.lint(&LintTarget::String("# Hello, world!\n\nSome text."))?;
+        let timing = result.first().unwrap().timing().unwrap();
+        let rules = timing.rules().map(|(rule, _)| rule).collect::<Vec<_>>();
+        assert_eq!(rules, vec!["Rule001HeadingCase"]);
+        let rules_total: std::time::Duration = timing.rules().map(|(_, duration)| duration).sum();
+        assert!(timing.total() >= rules_total);
+
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::String("# Hello, world!"))?;
+        assert!(result.first().unwrap().timing().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_suppression_edit() -> Result<()> {
+        let mut linter = Linter::builder().build()?;
+        linter
+            .config_mut()
+            .rule_registry
+            .deactivate_all_but("Rule001HeadingCase");
+
+        let invalid_mdx = "Some text.\n\n# Incorrect Heading\n";
+        let result = linter.lint(&LintTarget::String(invalid_mdx))?;
+        let error = result.first().unwrap().errors().first().unwrap();
+
+        let Some(LintCorrection::Insert(suppression)) = error.suppression() else {
+            panic!(
+                "Expected an insert suppression, got {:?}",
+                error.suppression()
+            );
+        };
+        assert_eq!(
+            suppression.text(),
+            "{/* supa-mdx-lint-disable-next-line Rule001HeadingCase */}\n"
+        );
+        assert_eq!(suppression.location.offset_range.to_usize_range(), 12..12);
+
+        let mut suppressed = invalid_mdx.to_string();
+        suppressed.insert_str(12, suppression.text());
+        let result = linter.lint(&LintTarget::String(&suppressed))?;
+        assert!(result.first().unwrap().errors().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_suppression_edit_in_blockquote() -> Result<()> {
+        let mut linter = Linter::builder().build()?;
+        linter
+            .config_mut()
+            .rule_registry
+            .deactivate_all_but("Rule001HeadingCase");
+
+        let invalid_mdx = "Some text.\n\n> # Incorrect Heading\n";
+        let result = linter.lint(&LintTarget::String(invalid_mdx))?;
+        let error = result.first().unwrap().errors().first().unwrap();
+
+        let Some(LintCorrection::Insert(suppression)) = error.suppression() else {
+            panic!(
+                "Expected an insert suppression, got {:?}",
+                error.suppression()
+            );
+        };
+        assert_eq!(suppression.location.offset_range.to_usize_range(), 12..12);
+
+        let mut suppressed = invalid_mdx.to_string();
+        suppressed.insert_str(12, suppression.text());
+        let result = linter.lint(&LintTarget::String(&suppressed))?;
+        assert!(result.first().unwrap().errors().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_no_suppression_edit_after_first_line_of_block() -> Result<()> {
+        let mut linter = Linter::builder().build()?;
+        linter
+            .config_mut()
+            .rule_registry
+            .deactivate_all_but("Rule030UserPaths");
+
+        let invalid_mdx = "Some text,\nsaved to /Users/alice.\n\n```sh\ncd /Users/alice\n```\n";
+        let result = linter.lint(&LintTarget::String(invalid_mdx))?;
+        let errors = result.first().unwrap().errors();
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|error| error.suppression().is_none()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_no_suppression_edit_in_frontmatter() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let file = temp_dir.path().join("page.mdx");
+        fs::write(&file, "---\ndescription: Too short.\n---\n\n# Page\n")?;
+
+        let config = Config::from_serializable()
+            .config(serde_json::json!({
+                "Rule021DescriptionLength": { "files": ["**"] }
+            }))
+            .config_dir(&ConfigDir(None))
+            .call()?;
+        let mut linter = Linter::builder().config(config).build()?;
+        linter
+            .config_mut()
+            .rule_registry
+            .deactivate_all_but("Rule021DescriptionLength");
+
+        let result = linter.lint(&LintTarget::FileOrDirectory(file))?;
+        let errors = result.first().unwrap().errors();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].location.start.row, 1);
+        assert!(errors[0].suppression().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_directory_output_is_sorted() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        fs::create_dir(temp_dir.path().join("a"))?;
+        for file in ["10.mdx", "2.mdx", "a/1.mdx", "b.mdx"] {
+            fs::write(temp_dir.path().join(file), "# Hello, world\n")?;
+        }
+
+        let file_names = |linter: &Linter| -> Result<Vec<String>> {
+            let result =
+                linter.lint(&LintTarget::FileOrDirectory(temp_dir.path().to_path_buf()))?;
+            Ok(result
+                .iter()
+                .map(|output| {
+                    Path::new(output.file_path())
+                        .strip_prefix(temp_dir.path())
+                        .unwrap()
+                        .to_string_lossy()
+                        .replace('\\', "/")
+                })
+                .collect())
+        };
+
+        let linter = Linter::builder().build()?;
+        assert_eq!(
+            file_names(&linter)?,
+            ["10.mdx", "2.mdx", "a/1.mdx", "b.mdx"]
+        );
+
+        let config = Config::from_serializable()
+            .config(serde_json::json!({ "path_sort_order": "natural" }))
+            .config_dir(&ConfigDir::none())
+            .call()?;
+        let linter = Linter::builder().config(config).build()?;
+        assert_eq!(
+            file_names(&linter)?,
+            ["2.mdx", "10.mdx", "a/1.mdx", "b.mdx"]
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_lint_applies_directory_rules() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let reference = temp_dir.path().join("reference");
+        fs::create_dir(&reference)?;
+        fs::write(
+            reference.join(".supa-mdx-lint"),
+            "Rule001HeadingCase = \"warn\"\nRule003Spelling = false\n",
+        )?;
+        let bad_file = "# Heading In Title Case\n\nThis is wrnog.\n";
+        fs::write(temp_dir.path().join("guide.mdx"), bad_file)?;
+        fs::write(reference.join("api.mdx"), bad_file)?;
+
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::FileOrDirectory(temp_dir.path().to_path_buf()))?;
+        let levels = |output: &LintOutput| {
+            output
+                .errors()
+                .iter()
+                .map(|error| (error.rule.clone(), error.level()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(result.len(), 2);
+        assert!(result[0].file_path().ends_with("guide.mdx"));
+        assert_eq!(
+            levels(&result[0]),
+            [
+                ("Rule001HeadingCase".to_string(), LintLevel::Error),
+                ("Rule003Spelling".to_string(), LintLevel::Error),
+            ]
+        );
+        assert!(result[1].file_path().ends_with("api.mdx"));
+        assert_eq!(
+            levels(&result[1]),
+            [("Rule001HeadingCase".to_string(), LintLevel::Warning)]
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_lint_ignores_directory_rules_above_target() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let docs = temp_dir.path().join("docs");
+        fs::create_dir(&docs)?;
+        fs::write(
+            temp_dir.path().join(".supa-mdx-lint"),
+            "Rule003Spelling = false\n",
+        )?;
+        fs::write(docs.join("guide.mdx"), "This is wrnog.\n")?;
+
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::FileOrDirectory(docs.clone()))?;
+        assert_eq!(result.len(), 1);
+        assert!(result[0]
+            .errors()
+            .iter()
+            .any(|error| error.rule == "Rule003Spelling"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_directory_recovers_from_file_errors() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        fs::write(temp_dir.path().join("good.mdx"), "# Hello, world\n")?;
+        fs::write(
+            temp_dir.path().join("unparseable.mdx"),
+            "# Hello\n\n{unclosed\n",
+        )?;
+        fs::write(temp_dir.path().join("unreadable.mdx"), [0xff, 0xfe, 0xfd])?;
+
+        let linter = Linter::builder().build()?;
+        let mut result =
+            linter.lint(&LintTarget::FileOrDirectory(temp_dir.path().to_path_buf()))?;
+        result.sort_by(|a, b| a.file_path().cmp(b.file_path()));
+
+        assert_eq!(result.len(), 3);
+        assert!(result[0].file_path().ends_with("good.mdx"));
+        assert!(result[0].errors().is_empty());
+        let expected_rules = [errors::PARSE_ERROR_RULE, errors::INTERNAL_ERROR_RULE];
+        for (output, expected_rule) in result[1..].iter().zip(expected_rules) {
+            assert_eq!(output.errors().len(), 1);
+            assert_eq!(output.errors()[0].rule, expected_rule);
+            assert_eq!(output.errors()[0].level(), LintLevel::Error);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_reports_parse_error_location() -> Result<()> {
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::String(
+            "---\ntitle: Test\n---\n\n# Hello\n\n<Admonition type=\"note\">\n\nText\n",
+        ))?;
+
+        assert_eq!(result.len(), 1);
+        let errors = result[0].errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].rule, errors::PARSE_ERROR_RULE);
+        assert_eq!(errors[0].level(), LintLevel::Error);
+        assert!(errors[0].message.starts_with("Failed to parse MDX: "));
+        assert!(errors[0].location.start.row > 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_file_too_large() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        fs::write(temp_dir.path().join("small.mdx"), "# Hello, world\n")?;
+        fs::write(
+            temp_dir.path().join("large.mdx"),
+            "Some text.\n\n".repeat(200),
+        )?;
+
+        let config = Config::from_serializable()
+            .config(serde_json::json!({ "max_file_size": 1 }))
+            .config_dir(&ConfigDir(None))
+            .call()?;
+        let linter = Linter::builder().config(config).build()?;
+        let result = linter.lint(&LintTarget::FileOrDirectory(temp_dir.path().to_path_buf()))?;
+
+        assert_eq!(result.len(), 2);
+        assert!(result[0].file_path().ends_with("large.mdx"));
+        let errors = result[0].errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].rule, errors::INTERNAL_ERROR_RULE);
+        assert_eq!(
+            errors[0].message,
+            "Failed to lint file: File is larger than the maximum size of 1 KB. Raise max_file_size in the config, or add the file to ignore_patterns."
+        );
+        assert!(result[1].errors().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_file_size_limit_boundary() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let at_limit = temp_dir.path().join("at_limit.mdx");
+        let over_limit = temp_dir.path().join("over_limit.mdx");
+        fs::write(&at_limit, "a".repeat(1023) + "\n")?;
+        fs::write(&over_limit, "a".repeat(1024) + "\n")?;
+
+        let config = Config::from_serializable()
+            .config(serde_json::json!({ "max_file_size": 1 }))
+            .config_dir(&ConfigDir(None))
+            .call()?;
+        let linter = Linter::builder().config(config).build()?;
+
+        let result = linter.lint(&LintTarget::FileOrDirectory(at_limit))?;
+        assert!(result[0]
+            .errors()
+            .iter()
+            .all(|error| error.rule != errors::INTERNAL_ERROR_RULE));
+
+        let result = linter.lint(&LintTarget::FileOrDirectory(over_limit))?;
+        assert_eq!(result[0].errors().len(), 1);
+        assert_eq!(result[0].errors()[0].rule, errors::INTERNAL_ERROR_RULE);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_file_without_size_limit() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let file = temp_dir.path().join("large.mdx");
+        fs::write(&file, "Some text.\n\n".repeat(200))?;
+
+        let mut linter = Linter::builder().build()?;
+        linter
+            .config_mut()
+            .rule_registry
+            .deactivate_all_but("Rule001HeadingCase");
+        let result = linter.lint(&LintTarget::FileOrDirectory(file))?;
+
+        assert_eq!(result.len(), 1);
+        assert!(result[0].errors().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_reports_malformed_directives() -> Result<()> {
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::String(
+            "# Hello, world\n\n{/* supa-mdx-lint-disable-nextline Rule001HeadingCase */}\nSome text.\n",
+        ))?;
+
+        assert_eq!(result[0].errors().len(), 1);
+        let error = &result[0].errors()[0];
+        assert_eq!(error.rule, errors::DIRECTIVE_ERROR_RULE);
+        assert_eq!(error.level(), LintLevel::Warning);
+        assert_eq!(error.offset_range(), 16..73);
+        Ok(())
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_lint_reports_unknown_rule_directives() -> Result<()> {
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::String(
+            "# Hello, world\n\n{/* supa-mdx-lint-disable-next-line Rule003Speling */}\nHeloo.\n",
+        ))?;
+
+        let errors = result[0].errors();
+        assert_eq!(errors.len(), 2);
+        let error = errors
+            .iter()
+            .find(|error| error.rule == errors::DIRECTIVE_ERROR_RULE)
+            .unwrap();
+        assert_eq!(error.level(), LintLevel::Warning);
+        assert_eq!(error.offset_range(), 16..70);
+        assert!(errors.iter().any(|error| error.rule() == "Rule003Spelling"));
+        Ok(())
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_lint_skips_configured_components() -> Result<()> {
+        let mdx = "# Hello, world\n\n<CodeSample>\n\nheloo wrold\n\n</CodeSample>\n";
+
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::String(mdx))?;
+        assert_eq!(result[0].errors().len(), 2);
+
+        let config = Config::from_serializable()
+            .config(serde_json::json!({ "skip_components": ["CodeSample"] }))
+            .config_dir(&ConfigDir::none())
+            .call()?;
+        let linter = Linter::builder().config(config).build()?;
+        let result = linter.lint(&LintTarget::String(mdx))?;
+        assert!(result[0].errors().is_empty());
+        Ok(())
+    }
+
+    #[derive(Debug)]
+    struct RuleListFormatter;
+
+    impl OutputFormatter for RuleListFormatter {
+        fn id(&self) -> &'static str {
+            "rule-list"
+        }
+
+        fn format(&self, output: &[LintOutput], _metadata: &ConfigMetadata) -> Result<String> {
+            Ok(output
+                .iter()
+                .flat_map(|output| output.errors())
+                .map(|error| format!("{}\n", error.rule()))
+                .collect())
+        }
+    }
+
+    #[test]
+    fn test_custom_formatter() -> Result<()> {
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::String("# Hello World\n"))?;
+        let formatter = RuleListFormatter;
+        assert_eq!(linter.format(&result, &formatter)?, "Rule001HeadingCase\n");
+        assert!(!formatter.should_log_metadata());
+
+        let mut written = Vec::new();
+        linter.format_to(&result, &formatter, &mut written)?;
+        assert_eq!(written, b"Rule001HeadingCase\n");
+        Ok(())
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_lint_frontmatter_fields() -> Result<()> {
+        let mdx = "---\ntitle: Sign In With Heloo\ndescription: \"Sign in with wrold.\"\nauthor: Heloo\n---\n\n# Sign in\n\nText.\n";
+
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::String(mdx))?;
+        assert!(result[0].errors().is_empty());
+
+        let config = Config::from_serializable()
+            .config(serde_json::json!({ "lint_frontmatter_fields": ["title", "description"] }))
+            .config_dir(&ConfigDir::none())
+            .call()?;
+        let linter = Linter::builder().config(config).build()?;
+        let result = linter.lint(&LintTarget::String(mdx))?;
+        let errors = result[0]
+            .errors()
+            .iter()
+            .map(|error| (error.rule(), &mdx[error.offset_range()]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                ("Rule001HeadingCase", "Sign In With Heloo"),
+                ("Rule003Spelling", "Heloo"),
+                ("Rule003Spelling", "wrold"),
+            ]
+        );
+        assert_eq!(result[0].errors()[0].location().start.row, 1);
+        Ok(())
+    }
+
+    #[cfg(feature = "spelling")]
+    #[test]
+    fn test_lint_allow_next_word() -> Result<()> {
+        let mdx = "# Hello, world\n\nSay {/* supa-mdx-lint-allow-next-word Rule003Spelling */} Heloo, not Heloo.\n";
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::String(mdx))?;
+
+        let errors = result[0].errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].rule(), "Rule003Spelling");
+        assert_eq!(&mdx[errors[0].offset_range()], "Heloo");
+        assert_eq!(errors[0].location().start.column, 69);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_reports_expired_directives() -> Result<()> {
+        let linter = Linter::builder().build()?;
+        let result = linter.lint(&LintTarget::String(
+            "# Hello, world\n\n{/* supa-mdx-lint-disable-next-line Rule003Spelling -- reason: vendor name, expires: 2000-01-01 */}\nHeloo.\n",
+        ))?;
+
+        assert_eq!(result[0].errors().len(), 1);
+        let error = &result[0].errors()[0];
+        assert_eq!(error.rule, errors::DIRECTIVE_ERROR_RULE);
+        assert_eq!(error.level(), LintLevel::Warning);
+        assert_eq!(
+            error.details(),
+            Some(&serde_json::json!({ "reason": "vendor name", "expires": "2000-01-01" }))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_stats_directory() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        fs::create_dir(temp_dir.path().join("a"))?;
+        fs::write(temp_dir.path().join("b.mdx"), "# B\n\nOne two three.\n")?;
+        fs::write(temp_dir.path().join("a/c.mdx"), "```js\nconst x = 1\n```\n")?;
+        fs::write(temp_dir.path().join("notes.txt"), "Not a doc")?;
+
+        let linter = Linter::builder().build()?;
+        let stats = linter.stats(&LintTarget::FileOrDirectory(temp_dir.path().to_path_buf()))?;
+        assert_eq!(stats.len(), 2);
+        assert!(stats[0].file_path().ends_with("c.mdx"));
+        assert_eq!(stats[0].code_blocks(), 1);
+        assert_eq!(stats[0].words(), 0);
+        assert!(stats[1].file_path().ends_with("b.mdx"));
+        assert_eq!(stats[1].words(), 4);
+        assert_eq!(stats[1].headings(), 1);
+        Ok(())
+    }
 }
@@ -5,11 +5,18 @@ use std::ops::{Add, Deref, DerefMut, Range, SubAssign};
 use serde::{Deserialize, Serialize};
 
 use crate::context::Context;
+use crate::parser::ParseResult;
 use crate::rope::Rope;
 
-/// An offset in the source document, accounting for frontmatter lines.
+/// A byte offset into the full source of a document, counted from the start
+/// of the file, including any frontmatter.
+///
+/// The parser reports positions relative to the content after the
+/// frontmatter. These are adjusted by adding the offset where the content
+/// starts, so that every offset reported by the linter can be applied to the
+/// file as it is. See [`ParseResult::source_offset`](crate::ParseResult::source_offset).
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
-pub(crate) struct AdjustedOffset(usize);
+pub struct AdjustedOffset(usize);
 
 impl Deref for AdjustedOffset {
     type Target = usize;
@@ -129,9 +136,13 @@ impl From<&markdown::unist::Point> for UnadjustedOffset {
     }
 }
 
-/// A point in the source document, accounting for frontmatter lines.
+/// A line and column in the full source of a document, including any
+/// frontmatter.
+///
+/// Both count from 0. The column is a byte offset from the start of the line,
+/// not a count of characters.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
-pub(crate) struct AdjustedPoint {
+pub struct AdjustedPoint {
     pub row: usize,
     pub column: usize,
 }
@@ -159,10 +170,10 @@ impl AdjustedPoint {
     }
 }
 
-/// A range in the source document, accounting for frontmatter lines.
-/// The start point is inclusive, the end point is exclusive.
+/// A range of byte offsets into the full source of a document, including any
+/// frontmatter. The start is inclusive, the end is exclusive.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
-pub(crate) struct AdjustedRange(Range<AdjustedOffset>);
+pub struct AdjustedRange(Range<AdjustedOffset>);
 
 impl Deref for AdjustedRange {
     type Target = Range<AdjustedOffset>;
@@ -194,7 +205,7 @@ impl From<&AdjustedRange> for Range<usize> {
 }
 
 impl AdjustedRange {
-    pub(crate) fn new(start: AdjustedOffset, end: AdjustedOffset) -> Self {
+    pub fn new(start: AdjustedOffset, end: AdjustedOffset) -> Self {
         Self(Range { start, end })
     }
 
@@ -228,7 +239,7 @@ impl AdjustedRange {
 
     // Helper method to avoid having to call the ridiculous
     // `Into::<Range<usize>>::into` in many places.
-    pub(crate) fn to_usize_range(&self) -> Range<usize> {
+    pub fn to_usize_range(&self) -> Range<usize> {
         Into::<Range<usize>>::into(self)
     }
 }
@@ -287,8 +298,11 @@ impl<T: Ord> MaybeEndedRange<T> {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
-pub(crate) struct DenormalizedLocation {
+/// A range in the full source of a document, with both its byte offsets and
+/// the lines and columns they fall on. This is the location reported for
+/// errors and corrections, and in the JSON output.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct DenormalizedLocation {
     pub offset_range: AdjustedRange,
     pub start: AdjustedPoint,
     pub end: AdjustedPoint,
@@ -306,6 +320,81 @@ impl DenormalizedLocation {
     }
 }
 
+/// Converts between byte offsets and lines and columns in the full source of
+/// a document, the same way the linter does for the locations it reports.
+///
+/// ```
+/// use supa_mdx_lint::location::{AdjustedOffset, AdjustedPoint, LocationConverter};
+///
+/// let converter = LocationConverter::new("---\ntitle: Auth\n---\n\n# Auth\n");
+/// let point = converter.point(AdjustedOffset::from(23)).unwrap();
+/// assert_eq!((point.row, point.column), (4, 2));
+/// assert_eq!(converter.offset(&point), Some(AdjustedOffset::from(23)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocationConverter {
+    rope: Rope,
+}
+
+impl LocationConverter {
+    /// Create a converter for the full source of a document, including any
+    /// frontmatter.
+    pub fn new(source: &str) -> Self {
+        Self {
+            rope: Rope::from(source),
+        }
+    }
+
+    /// The line and column of an offset. Returns `None` if the offset is
+    /// past the end of the source.
+    pub fn point(&self, offset: AdjustedOffset) -> Option<AdjustedPoint> {
+        (offset.0 <= self.rope.byte_len())
+            .then(|| AdjustedPoint::from_adjusted_offset(&offset, &self.rope))
+    }
+
+    /// The offset of a line and column. Returns `None` if the line doesn't
+    /// exist, or the column is past the end of the line.
+    pub fn offset(&self, point: &AdjustedPoint) -> Option<AdjustedOffset> {
+        if point.row > self.rope.line_len() {
+            return None;
+        }
+        let line_start = if point.row == self.rope.line_len() {
+            // The empty line after a trailing newline.
+            self.rope.byte_len()
+        } else {
+            self.rope.byte_of_line(point.row)
+        };
+        // The last column of a line is the position of its newline.
+        let line_end = if point.row + 1 < self.rope.line_len() {
+            self.rope.byte_of_line(point.row + 1) - 1
+        } else {
+            self.rope.byte_len()
+        };
+        let offset = line_start + point.column;
+        (offset <= line_end).then_some(AdjustedOffset(offset))
+    }
+
+    /// The location of a range, with the lines and columns of its ends.
+    /// Returns `None` if the range extends past the end of the source.
+    pub fn location(&self, range: AdjustedRange) -> Option<DenormalizedLocation> {
+        let start = self.point(range.start)?;
+        let end = self.point(range.end)?;
+        Some(DenormalizedLocation {
+            offset_range: range,
+            start,
+            end,
+        })
+    }
+}
+
+impl From<&ParseResult> for LocationConverter {
+    fn from(parse_result: &ParseResult) -> Self {
+        Self {
+            rope: parse_result.rope().clone(),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct RangeSet(Vec<AdjustedRange>);
 
@@ -391,7 +480,10 @@ impl<T: Offsets + crate::private::Sealed> Offsets for &T {
 
 #[cfg(test)]
 mod tests {
-    use super::{AdjustedOffset, AdjustedPoint, AdjustedRange, DenormalizedLocation};
+    use super::{
+        AdjustedOffset, AdjustedPoint, AdjustedRange, DenormalizedLocation, LocationConverter,
+    };
+    use crate::parser::parse;
 
     impl DenormalizedLocation {
         pub fn dummy(
@@ -419,6 +511,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_location_converter_round_trip() {
+        let source = "---\ntitle: Auth\n---\n\n# Auth\n\nSign in.\n";
+        let converter = LocationConverter::new(source);
+        for offset in 0..=source.len() {
+            let point = converter.point(AdjustedOffset::from(offset)).unwrap();
+            assert_eq!(
+                converter.offset(&point),
+                Some(AdjustedOffset::from(offset)),
+                "{point:?}"
+            );
+        }
+        assert!(converter
+            .point(AdjustedOffset::from(source.len() + 1))
+            .is_none());
+        assert!(converter
+            .offset(&AdjustedPoint { row: 0, column: 4 })
+            .is_none());
+        assert!(converter
+            .offset(&AdjustedPoint { row: 9, column: 0 })
+            .is_none());
+    }
+
+    #[test]
+    fn test_location_converter_matches_parse_result() {
+        let source = "---\ntitle: Auth\n---\n\n# Auth\n\nSign in.\n";
+        let result = parse(source).unwrap();
+        let paragraph = &result.ast().children().unwrap()[1];
+        let range = result.source_range(paragraph.position().unwrap());
+
+        let converter = LocationConverter::from(&result);
+        let location = converter
+            .location(AdjustedRange::new(
+                AdjustedOffset::from(range.start),
+                AdjustedOffset::from(range.end),
+            ))
+            .unwrap();
+        assert_eq!(location.start, AdjustedPoint { row: 6, column: 0 });
+        assert_eq!(location.end, AdjustedPoint { row: 6, column: 8 });
+        assert_eq!(location.offset_range.to_usize_range(), range);
+    }
+
     #[test]
     fn test_range_set_merges_overlapping_ranges() {
         let mut set = super::RangeSet::new();
@@ -1,27 +1,35 @@
 use std::{
     env,
-    io::{BufWriter, Write},
-    path::PathBuf,
-    process::ExitCode,
+    fs::File,
+    io::{BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    process::{self, ExitCode},
     time::Instant,
 };
 
 use anyhow::{Context, Result};
 use bon::builder;
-use clap::{error::ErrorKind, ArgGroup, CommandFactory, Parser};
+use clap::{error::ErrorKind, ArgGroup, CommandFactory, Parser, Subcommand};
 #[cfg(feature = "interactive")]
 use cli::InteractiveFixManager;
-use glob::glob;
-use log::{debug, error};
+use glob::{glob_with, MatchOptions, Pattern};
+use log::{debug, error, info, warn};
 use simplelog::{ColorChoice, Config as LogConfig, LevelFilter, TermLogger, TerminalMode};
 use supa_mdx_lint::{
-    output::{internal::NativeOutputFormatter, LintOutput},
-    Config, LintLevel, LintTarget, Linter,
+    diff::ChangedLines,
+    exceptions::{may_uppercase_snippet, suggest_may_uppercase},
+    output::{internal::NativeOutputFormatter, simple::SimpleFormatter, timing_report, LintOutput},
+    rules::RuleCategory,
+    stats::stats_table,
+    Config, ConfigPreset, LintLevel, LintTarget, Linter,
 };
 
 mod cli;
 
 const DEFAULT_CONFIG_FILE: &str = "supa-mdx-lint.config.toml";
+/// How many headings must capitalize a phrase for --suggest-exceptions to
+/// list it.
+const MIN_EXCEPTION_HEADINGS: usize = 3;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -29,26 +37,117 @@ const DEFAULT_CONFIG_FILE: &str = "supa-mdx-lint.config.toml";
             ArgGroup::new("verbosity")
                 .args(&["debug", "silent", "trace"]),
         ))]
+#[clap(group(
+            ArgGroup::new("fix_mode")
+                .args(&["fix", "fix_unsafe"])
+                .multiple(true),
+        ))]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// (Globs of) files or directories to lint
     target: Vec<String>,
 
+    /// Lint the content of files staged in git, rather than the working
+    /// tree. Targets, if given, limit which staged files are linted
+    #[arg(long, conflicts_with_all = ["fix_mode", "diff"])]
+    staged: bool,
+
     /// Sets a custom config file
-    #[arg(short, long, value_name = "FILE")]
+    #[arg(short, long, value_name = "FILE", global = true)]
     config: Option<PathBuf>,
 
-    /// Auto-fix any fixable errors
+    /// Apply a named profile from the config file
+    #[arg(long, value_name = "PROFILE")]
+    profile: Option<String>,
+
+    /// Override the level of a rule, as RULE:LEVEL (for example,
+    /// Rule003Spelling:warn). Can be repeated
+    #[arg(
+        long = "rule",
+        value_name = "RULE:LEVEL",
+        env = "SUPA_MDX_LINT_RULES",
+        value_delimiter = ',',
+        value_parser = parse_rule_level
+    )]
+    rule_levels: Vec<(String, LintLevel)>,
+
+    /// Turn off a rule, overriding the config file. Can be repeated
+    #[arg(
+        long = "disable-rule",
+        value_name = "RULE",
+        env = "SUPA_MDX_LINT_DISABLE_RULES",
+        value_delimiter = ','
+    )]
+    disabled_rules: Vec<String>,
+
+    /// Auto-fix any fixable errors, skipping fixes that need review
     #[arg(short, long)]
     fix: bool,
 
+    /// Auto-fix any fixable errors, including fixes that might change the
+    /// meaning of the text and should be reviewed
+    #[arg(long)]
+    fix_unsafe: bool,
+
+    /// With --fix or --fix-unsafe, re-lint fixed files and fix them again up
+    /// to N times in total, in case fixes introduce new errors
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 1,
+        requires = "fix_mode",
+        value_parser = clap::value_parser!(u16).range(1..)
+    )]
+    fix_passes: u16,
+
     #[cfg(feature = "interactive")]
     #[arg(short, long, requires_all = ["fix", "enable_experimental"], conflicts_with = "silent", hide = true)]
     interactive: bool,
 
+    /// Only report errors on lines added or changed in a unified diff. Runs
+    /// `git diff HEAD` by default, or reads the diff from stdin with
+    /// --diff=-
+    #[arg(
+        long,
+        value_name = "SOURCE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "git",
+        value_parser = ["git", "-"]
+    )]
+    diff: Option<String>,
+
     /// Output format
     #[arg(long, value_name = "FORMAT", default_value = "simple", value_parser = clap::value_parser!(NativeOutputFormatter), help = if cfg!(feature = "pretty") {r#"Output format - one of "simple", "markdown", "pretty", "rdf""#} else {r#"Output format - one of "simple", "markdown", "rdf""#})]
     format: NativeOutputFormatter,
 
+    /// Write the formatted results to a file, and only a summary to the
+    /// console
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Fail if a target doesn't match any lintable files, instead of only
+    /// warning
+    #[arg(long)]
+    error_on_unmatched_target: bool,
+
+    /// Only lint files up to N levels deep in target directories. Files
+    /// directly in a target directory are at level 1
+    #[arg(long, value_name = "N", global = true)]
+    max_depth: Option<usize>,
+
+    /// Instead of reporting errors, list the words and phrases that headings
+    /// often capitalize, as candidate `may_uppercase` exceptions for
+    /// Rule001HeadingCase
+    #[arg(long, conflicts_with_all = ["fix_mode", "diff"])]
+    suggest_exceptions: bool,
+
+    /// Report the time spent in each rule and on each file
+    #[arg(long, env = "SUPA_MDX_LINT_TIMING")]
+    timing: bool,
+
     /// Turn debugging information on
     #[arg(short, long)]
     debug: bool,
@@ -57,6 +156,20 @@ struct Args {
     #[arg(short, long)]
     silent: bool,
 
+    /// Only report errors, leaving out warnings and hints
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Only report errors from rules in the given categories - any of
+    /// "style", "correctness", "accessibility", "links". Can be repeated
+    #[arg(long, value_name = "CATEGORY", value_delimiter = ',')]
+    category: Vec<RuleCategory>,
+
+    /// Exit with an error if there are any warnings, as well as errors. The
+    /// reported levels are unchanged
+    #[arg(long, env = "SUPA_MDX_LINT_STRICT")]
+    strict: bool,
+
     #[cfg(debug_assertions)]
     #[arg(long)]
     trace: bool,
@@ -65,6 +178,45 @@ struct Args {
     enable_experimental: bool,
 }
 
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Write a starter config file listing all rules and their settings
+    Init {
+        /// Fill in the settings from a bundled preset - one of "supabase"
+        #[arg(long, value_name = "PRESET", value_parser = clap::value_parser!(ConfigPreset))]
+        preset: Option<ConfigPreset>,
+
+        /// Overwrite the config file if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Report word counts, heading counts, code block counts, and estimated
+    /// reading time for each file, without linting
+    Stats {
+        /// (Globs of) files or directories to count
+        #[arg(required = true)]
+        target: Vec<String>,
+
+        /// Output format
+        #[arg(long, value_name = "FORMAT", default_value = "table")]
+        format: StatsFormat,
+    },
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum StatsFormat {
+    Table,
+    Json,
+}
+
+fn parse_rule_level(value: &str) -> Result<(String, LintLevel), String> {
+    let (rule, level) = value
+        .split_once(':')
+        .ok_or_else(|| format!("Expected RULE:LEVEL, got \"{value}\""))?;
+    let level = LintLevel::try_from(level).map_err(|err| err.to_string())?;
+    Ok((rule.trim().to_string(), level))
+}
+
 fn setup_logging(args: &Args) -> Result<LevelFilter> {
     #[allow(unused_mut)]
     let mut log_level = if args.silent {
@@ -96,17 +248,34 @@ fn get_targets<'targets>(
     targets: &'targets [String],
     linter: &Linter,
     #[builder(default = false)] expand_dirs: bool,
+    #[builder(default = false)] error_on_unmatched: bool,
 ) -> Result<Vec<LintTarget<'targets>>> {
     let mut all_targets = Vec::new();
 
     for target in targets.iter() {
-        let target = glob(target).context("Failed to parse glob pattern")?;
-        target
+        // Match the file system's case sensitivity, as ignore patterns do.
+        let options = MatchOptions {
+            case_sensitive: !cfg!(windows),
+            ..Default::default()
+        };
+        let paths = glob_with(target, options).context("Failed to parse glob pattern")?;
+        let num_targets = all_targets.len();
+        paths
             .into_iter()
             .filter_map(|res| res.ok())
             .filter(|path| linter.is_lintable(path))
             .map(LintTarget::FileOrDirectory)
             .for_each(|target| all_targets.push(target));
+
+        // A typo in a target path would otherwise lint nothing and pass.
+        if all_targets.len() == num_targets {
+            if error_on_unmatched {
+                return Err(anyhow::anyhow!(
+                    "Target \"{target}\" did not match any lintable files"
+                ));
+            }
+            warn!("Target \"{target}\" did not match any lintable files");
+        }
     }
 
     match expand_dirs {
@@ -121,12 +290,8 @@ fn get_targets<'targets>(
                     .expect("Just checked length of all_targets array");
                 match target {
                     LintTarget::FileOrDirectory(path) if path.is_dir() => {
-                        for entry in std::fs::read_dir(path).context("Failed to read directory")? {
-                            let entry = entry.context("Failed to get directory entry")?;
-                            let path = entry.path();
-                            if linter.is_lintable(&path) {
-                                all_targets.push(LintTarget::FileOrDirectory(path));
-                            }
+                        for path in linter.lintable_files(path)? {
+                            all_targets.push(LintTarget::FileOrDirectory(path));
                         }
 
                         idx += 1;
@@ -139,13 +304,27 @@ fn get_targets<'targets>(
                 }
             }
 
+            new_targets.sort_by(|a, b| match (a, b) {
+                (LintTarget::FileOrDirectory(a), LintTarget::FileOrDirectory(b)) => {
+                    linter.compare_paths(a, b)
+                }
+                _ => std::cmp::Ordering::Equal,
+            });
             Ok(new_targets)
         }
     }
 }
 
-fn get_diagnostics(targets: &[String], linter: &Linter) -> Result<Vec<LintOutput>> {
-    let all_targets = get_targets().targets(targets).linter(linter).call()?;
+fn get_diagnostics(
+    targets: &[String],
+    linter: &Linter,
+    error_on_unmatched: bool,
+) -> Result<Vec<LintOutput>> {
+    let all_targets = get_targets()
+        .targets(targets)
+        .linter(linter)
+        .error_on_unmatched(error_on_unmatched)
+        .call()?;
     debug!("Lint targets: {targets:#?}");
 
     let mut diagnostics = Vec::new();
@@ -161,16 +340,162 @@ fn get_diagnostics(targets: &[String], linter: &Linter) -> Result<Vec<LintOutput
             }
         }
     }
+
+    // Overlapping targets are linted in the order given, so sort the combined
+    // output by path to keep it stable.
+    diagnostics.sort_by(|a, b| linter.compare_paths(a.file_path(), b.file_path()));
     Ok(diagnostics)
 }
 
+fn run_git(args: &[&str]) -> Result<String> {
+    let output = process::Command::new("git")
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run git {}", args[0]))?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "git {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    String::from_utf8(output.stdout)
+        .with_context(|| format!("git {} output is not valid UTF-8", args[0]))
+}
+
+fn read_diff(source: &str) -> Result<ChangedLines> {
+    let diff = if source == "-" {
+        let mut diff = String::new();
+        std::io::stdin()
+            .read_to_string(&mut diff)
+            .context("Failed to read diff from stdin")?;
+        diff
+    } else {
+        // Paths relative to the current directory, to match the targets.
+        run_git(&["diff", "--unified=0", "--relative", "HEAD"])?
+    };
+    Ok(ChangedLines::from_unified_diff(&diff))
+}
+
+/// Lint the staged content of the files staged in git, limited to the given
+/// targets if there are any.
+fn get_staged_diagnostics(targets: &[String], linter: &Linter) -> Result<Vec<LintOutput>> {
+    let targets = targets
+        .iter()
+        .map(|target| Pattern::new(target).context("Failed to parse glob pattern"))
+        .collect::<Result<Vec<_>>>()?;
+    let in_targets = |path: &Path| {
+        targets.is_empty()
+            || targets.iter().any(|target| {
+                target.matches_path(path) || path.starts_with(target.as_str().trim_end_matches('/'))
+            })
+    };
+
+    let staged = run_git(&[
+        "diff",
+        "--cached",
+        "--name-only",
+        "--diff-filter=ACMR",
+        "--relative",
+        "-z",
+    ])?;
+    let mut diagnostics = Vec::new();
+    for path in staged.split('\0').filter(|path| !path.is_empty()) {
+        let path = Path::new(path);
+        if !linter.is_lintable(path) || linter.is_ignored(path) || !in_targets(path) {
+            continue;
+        }
+        debug!("Linting staged content of {path:?}");
+        // `:./path` names the staged blob, relative to the current directory.
+        let content = run_git(&["show", &format!(":./{}", path.display())])?;
+        diagnostics.append(&mut linter.lint_file_content(path, &content)?);
+    }
+
+    diagnostics.sort_by(|a, b| linter.compare_paths(a.file_path(), b.file_path()));
+    Ok(diagnostics)
+}
+
+fn init_config(config_path: PathBuf, preset: Option<ConfigPreset>, force: bool) -> Result<()> {
+    if config_path.exists() && !force {
+        return Err(anyhow::anyhow!(
+            "Config file already exists at {}. Use --force to overwrite it.",
+            config_path.display()
+        ));
+    }
+
+    std::fs::write(&config_path, Config::template(preset))
+        .with_context(|| format!("Failed to write config file at {}", config_path.display()))?;
+    info!("Wrote config file to {}", config_path.display());
+    Ok(())
+}
+
+fn print_exception_candidates(diagnostics: &[LintOutput]) -> Result<()> {
+    let candidates = suggest_may_uppercase(diagnostics, MIN_EXCEPTION_HEADINGS);
+    let mut stdout = std::io::stdout().lock();
+    if candidates.is_empty() {
+        writeln!(
+            stdout,
+            "No words are capitalized in {MIN_EXCEPTION_HEADINGS} or more headings"
+        )?;
+    } else {
+        writeln!(
+            stdout,
+            "Words capitalized in {MIN_EXCEPTION_HEADINGS} or more headings, which may belong in the config file:"
+        )?;
+        writeln!(stdout)?;
+        write!(stdout, "{}", may_uppercase_snippet(&candidates))?;
+    }
+    stdout.flush()?;
+    Ok(())
+}
+
+fn print_stats(targets: &[String], linter: &Linter, format: StatsFormat) -> Result<()> {
+    let mut stats = Vec::new();
+    for target in get_targets().targets(targets).linter(linter).call()? {
+        stats.append(&mut linter.stats(&target)?);
+    }
+    stats.sort_by(|a, b| linter.compare_paths(a.file_path(), b.file_path()));
+
+    let output = match format {
+        StatsFormat::Table => stats_table(&stats),
+        StatsFormat::Json => serde_json::to_string_pretty(&stats)? + "\n",
+    };
+    let mut stdout = std::io::stdout().lock();
+    write!(stdout, "{output}")?;
+    stdout.flush()?;
+    Ok(())
+}
+
 fn execute(args: Args) -> Result<Result<()>> {
     let start = Instant::now();
 
     let log_level = setup_logging(&args)?;
     debug!("Log level set to {log_level}");
 
-    if args.target.is_empty() {
+    let current_dir = env::current_dir().context("Failed to get current directory")?;
+    let config_path = args.config.map_or_else(
+        || current_dir.join(DEFAULT_CONFIG_FILE),
+        |config| current_dir.join(config),
+    );
+    debug!("Config path is {config_path:?}");
+
+    if let Some(Command::Init { preset, force }) = args.command {
+        return init_config(config_path, preset, force).map(Ok);
+    }
+
+    if let Some(Command::Stats { target, format }) = &args.command {
+        let config = match &args.profile {
+            Some(profile) => Config::from_config_file_with_profile(config_path, profile)?,
+            None => Config::from_config_file(config_path)?,
+        };
+        let linter = Linter::builder()
+            .config(config)
+            .maybe_max_depth(args.max_depth)
+            .build()?;
+        return print_stats(target, &linter, *format).map(Ok);
+    }
+
+    if args.target.is_empty() && !args.staged {
         let mut cmd = Args::command();
         cmd.error(
             ErrorKind::MissingRequiredArgument,
@@ -179,15 +504,22 @@ fn execute(args: Args) -> Result<Result<()>> {
         .exit();
     };
 
-    let current_dir = env::current_dir().context("Failed to get current directory")?;
-    let config_path = args.config.map_or_else(
-        || current_dir.join(DEFAULT_CONFIG_FILE),
-        |config| current_dir.join(config),
-    );
-    debug!("Config path is {config_path:?}");
-
-    let config = Config::from_config_file(config_path)?;
-    let linter = Linter::builder().config(config).build()?;
+    let mut config = match &args.profile {
+        Some(profile) => Config::from_config_file_with_profile(config_path, profile)?,
+        None => Config::from_config_file(config_path)?,
+    };
+    for (rule, level) in &args.rule_levels {
+        config.override_rule_level(rule, *level)?;
+    }
+    for rule in &args.disabled_rules {
+        config.disable_rule(rule)?;
+    }
+    let linter = Linter::builder()
+        .config(config)
+        .timing(args.timing)
+        .fix_unsafe(args.fix_unsafe)
+        .maybe_max_depth(args.max_depth)
+        .build()?;
     debug!("Linter built: {linter:#?}");
 
     let stdout = std::io::stdout().lock();
@@ -200,23 +532,52 @@ fn execute(args: Args) -> Result<Result<()>> {
             get_targets()
                 .targets(&args.target)
                 .expand_dirs(true)
+                .error_on_unmatched(args.error_on_unmatched_target)
                 .linter(&linter)
                 .call()?,
         )
         .run());
     }
 
-    let mut diagnostics = get_diagnostics(&args.target, &linter)?;
+    let changed_lines = args.diff.as_deref().map(read_diff).transpose()?;
+    let retain_reported = |diagnostics: &mut Vec<LintOutput>| {
+        for output in diagnostics.iter_mut() {
+            if let Some(changed_lines) = &changed_lines {
+                output.retain_changed_lines(changed_lines);
+            }
+            if !args.category.is_empty() {
+                output.retain(|error| {
+                    error
+                        .category()
+                        .is_some_and(|category| args.category.contains(&category))
+                });
+            }
+        }
+    };
+
+    let mut diagnostics = if args.staged {
+        get_staged_diagnostics(&args.target, &linter)?
+    } else {
+        get_diagnostics(&args.target, &linter, args.error_on_unmatched_target)?
+    };
+    if args.suggest_exceptions {
+        if !args.silent {
+            print_exception_candidates(&diagnostics)?;
+        }
+        return Ok(Ok(()));
+    }
+    retain_reported(&mut diagnostics);
 
     #[allow(unused_mut)]
-    let mut fix_only = args.fix;
+    let mut fix_only = args.fix || args.fix_unsafe;
     #[cfg(feature = "interactive")]
     if args.interactive {
         fix_only = false;
     }
 
     if fix_only {
-        let (num_files_fixed, num_errors_fixed) = linter.fix(&diagnostics)?;
+        let (num_files_fixed, num_errors_fixed, remaining) =
+            linter.fix_and_remap(&diagnostics, args.fix_passes.into())?;
         if !args.silent {
             writeln!(
                 stdout,
@@ -227,35 +588,54 @@ fn execute(args: Args) -> Result<Result<()>> {
             writeln!(stdout, "Checking for oustanding errors...")?;
             writeln!(stdout)?;
         }
-        diagnostics = get_diagnostics(&args.target, &linter)?;
+        diagnostics = remaining;
+        retain_reported(&mut diagnostics);
+    }
+
+    if args.quiet {
+        for output in &mut diagnostics {
+            output.retain_min_level(LintLevel::Error);
+        }
     }
 
-    if !args.silent {
-        let output = args
-            .format
-            .format(&diagnostics, &linter.config_metadata())?;
-        write!(stdout, "{}", output)?;
+    if let Some(output_path) = &args.output {
+        let file = File::create(output_path).with_context(|| {
+            format!("Failed to create output file at {}", output_path.display())
+        })?;
+        let mut file = BufWriter::new(file);
+        linter.format_to(&diagnostics, args.format.as_ref(), &mut file)?;
+        file.flush()?;
+
+        if !args.silent {
+            writeln!(stdout, "📄 Wrote results to {}", output_path.display())?;
+            write!(stdout, "{}", SimpleFormatter.format_summary(&diagnostics))?;
+            write_elapsed(&mut stdout, start)?;
+        }
+    } else if !args.silent {
+        linter.format_to(&diagnostics, args.format.as_ref(), &mut stdout)?;
         if args.format.should_log_metadata() {
-            let millis = start.elapsed().as_millis();
-            if millis < 1000 {
-                writeln!(stdout, "🕚 Done in {:.1} seconds", millis as f64 / 1000.0)?;
-            } else {
-                let seconds = millis / 1000;
-                writeln!(
-                    stdout,
-                    "🕚 Done in {} second{}",
-                    seconds,
-                    if seconds == 1 { "" } else { "s" }
-                )?;
-            }
+            write_elapsed(&mut stdout, start)?;
         }
     }
 
     stdout.flush()?;
 
+    // The report goes to stderr so it doesn't interfere with machine-readable
+    // output formats.
+    if args.timing && !args.silent {
+        if let Some(report) = timing_report(&diagnostics) {
+            eprint!("\n{report}");
+        }
+    }
+
+    let min_failing_level = if args.strict || linter.is_strict() {
+        LintLevel::Warning
+    } else {
+        LintLevel::Error
+    };
     if diagnostics
         .iter()
-        .any(|d| d.errors().iter().any(|e| e.level() == LintLevel::Error))
+        .any(|d| d.errors().iter().any(|e| e.level() >= min_failing_level))
     {
         Ok(Err(anyhow::anyhow!("Linting errors found")))
     } else {
@@ -263,6 +643,22 @@ fn execute(args: Args) -> Result<Result<()>> {
     }
 }
 
+fn write_elapsed(out: &mut impl Write, start: Instant) -> Result<()> {
+    let millis = start.elapsed().as_millis();
+    if millis < 1000 {
+        writeln!(out, "🕚 Done in {:.1} seconds", millis as f64 / 1000.0)?;
+    } else {
+        let seconds = millis / 1000;
+        writeln!(
+            out,
+            "🕚 Done in {} second{}",
+            seconds,
+            if seconds == 1 { "" } else { "s" }
+        )?;
+    }
+    Ok(())
+}
+
 fn main() -> ExitCode {
     let args = Args::parse();
     let silent = args.silent;
@@ -1,29 +1,61 @@
-use std::{collections::HashSet, str::FromStr};
+#[cfg(feature = "cli-formats")]
+use std::str::FromStr;
+use std::{collections::HashSet, io::Write};
 
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
-use crate::{app_error::PublicError, errors::LintError, ConfigMetadata, LintLevel};
+#[cfg(feature = "cli-formats")]
+use crate::app_error::PublicError;
+use crate::{errors::LintError, ConfigMetadata, LintLevel};
 
+#[cfg(feature = "cli-formats")]
 pub mod markdown;
 #[cfg(feature = "pretty")]
 pub mod pretty;
+#[cfg(feature = "cli-formats")]
 pub mod rdf;
+#[cfg(feature = "cli-formats")]
 pub mod simple;
+mod timing;
 
-#[derive(Debug)]
+pub use timing::{timing_report, LintTiming};
+
+/// The diagnostics for a single lint target.
+///
+/// Outputs can be serialized, for example to save a report, and deserialized
+/// again to pass a filtered subset of the diagnostics to
+/// [`Linter::fix`](crate::Linter::fix). Timing isn't serialized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LintOutput {
     file_path: String,
     errors: Vec<LintError>,
+    #[serde(skip)]
+    timing: Option<LintTiming>,
 }
 
 impl LintOutput {
-    pub(crate) fn new(file_path: impl AsRef<str>, errors: Vec<LintError>) -> Self {
+    /// Create an output for the file at the given path from a set of
+    /// diagnostics, such as diagnostics filtered from an earlier output.
+    pub fn new(file_path: impl AsRef<str>, errors: Vec<LintError>) -> Self {
         Self {
             file_path: file_path.as_ref().to_string(),
             errors,
+            timing: None,
         }
     }
 
+    pub(crate) fn with_timing(mut self, timing: LintTiming) -> Self {
+        self.timing = Some(timing);
+        self
+    }
+
+    /// Create an output for a file that could not be linted, containing a
+    /// single internal error diagnostic describing the failure.
+    pub(crate) fn from_internal_error(file_path: impl AsRef<str>, error: &anyhow::Error) -> Self {
+        Self::new(file_path, vec![LintError::internal_error(error)])
+    }
+
     pub fn file_path(&self) -> &str {
         &self.file_path
     }
@@ -31,23 +63,89 @@ impl LintOutput {
     pub fn errors(&self) -> &[LintError] {
         &self.errors
     }
+
+    /// Drop the diagnostics below the given level, such as warnings when
+    /// only errors should be reported.
+    pub fn retain_min_level(&mut self, min_level: LintLevel) {
+        self.retain(|error| error.level >= min_level);
+    }
+
+    /// Keep only the diagnostics for which the predicate returns true, such
+    /// as those whose fixes have been approved.
+    pub fn retain(&mut self, f: impl FnMut(&LintError) -> bool) {
+        self.errors.retain(f);
+    }
+
+    pub fn into_errors(self) -> Vec<LintError> {
+        self.errors
+    }
+
+    /// Time spent linting this file. Only recorded if timing is turned on
+    /// when building the [`Linter`](crate::Linter).
+    pub fn timing(&self) -> Option<&LintTiming> {
+        self.timing.as_ref()
+    }
 }
 
 pub struct OutputSummary {
     pub num_files: usize,
     pub num_warnings: usize,
     pub num_errors: usize,
+    /// Diagnostics with fixes applied by `--fix`.
+    pub num_fixable: usize,
+    /// Diagnostics with fixes that are only applied by `--fix-unsafe`.
+    pub num_unsafe_fixable: usize,
+}
+
+impl OutputSummary {
+    /// A line describing how many diagnostics can be auto-fixed, if any.
+    #[cfg(feature = "cli-formats")]
+    pub(crate) fn fixable_message(&self) -> Option<String> {
+        match (self.num_fixable, self.num_unsafe_fixable) {
+            (0, 0) => None,
+            (num_fixable, 0) => Some(format!("🔧 {num_fixable} fixable with --fix")),
+            (0, num_unsafe) => Some(format!("🔧 {num_unsafe} fixable with --fix-unsafe")),
+            (num_fixable, num_unsafe) => Some(format!(
+                "🔧 {num_fixable} fixable with --fix, and {num_unsafe} more with --fix-unsafe"
+            )),
+        }
+    }
 }
 
+/// Formats lint outputs for display or for other tools.
+///
+/// Implement it for custom formats, such as chat messages or HTML reports,
+/// and pass the formatter to [`Linter::format`](crate::Linter::format).
 pub trait OutputFormatter: Send + Sync + std::fmt::Debug {
+    /// A short name for the format, such as `simple`.
     fn id(&self) -> &'static str;
+
     fn format(&self, output: &[LintOutput], metadata: &ConfigMetadata) -> Result<String>;
-    fn should_log_metadata(&self) -> bool;
+
+    /// Format the outputs into a writer, such as a file.
+    fn format_to(
+        &self,
+        output: &[LintOutput],
+        metadata: &ConfigMetadata,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        writer.write_all(self.format(output, metadata)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// Whether the CLI should follow the output with extra information, such
+    /// as how long the run took. Formats meant for other tools should leave
+    /// this off, so their output stays parseable.
+    fn should_log_metadata(&self) -> bool {
+        false
+    }
 
     fn get_summary(&self, output: &[LintOutput]) -> OutputSummary {
         let mut seen_files = HashSet::<&str>::new();
         let mut num_errors = 0;
         let mut num_warnings = 0;
+        let mut num_fixable = 0;
+        let mut num_unsafe_fixable = 0;
 
         for o in output {
             seen_files.insert(&o.file_path);
@@ -55,6 +153,12 @@ pub trait OutputFormatter: Send + Sync + std::fmt::Debug {
                 match error.level {
                     LintLevel::Error => num_errors += 1,
                     LintLevel::Warning => num_warnings += 1,
+                    LintLevel::Hint => {}
+                }
+                if error.is_fixable(false) {
+                    num_fixable += 1;
+                } else if error.is_fixable(true) {
+                    num_unsafe_fixable += 1;
                 }
             }
         }
@@ -63,10 +167,13 @@ pub trait OutputFormatter: Send + Sync + std::fmt::Debug {
             num_files: seen_files.len(),
             num_warnings,
             num_errors,
+            num_fixable,
+            num_unsafe_fixable,
         }
     }
 }
 
+#[cfg(feature = "cli-formats")]
 #[doc(hidden)]
 pub mod internal {
     //! Contains internal implementations that are needed for the supa-mdx-lint
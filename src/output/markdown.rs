@@ -60,13 +60,26 @@ impl MarkdownFormatter {
             "### {}\n\n",
             match error.level {
                 LintLevel::Warning => "Warning",
+                LintLevel::Hint => "Hint",
                 LintLevel::Error => "Error",
             }
         ));
-        result.push_str("```\n");
-        result.push_str(&self.get_error_snippet(file_path, error)?);
-        result.push_str("```\n\n");
-        result.push_str(&format!("[{}] {}\n", error.rule, error.message));
+        // The snippet is best-effort, since the diagnostic might be reporting
+        // that the file couldn't be read in the first place.
+        match self.get_error_snippet(file_path, error) {
+            Ok(snippet) if !snippet.is_empty() => {
+                result.push_str("```\n");
+                result.push_str(&snippet);
+                result.push_str("```\n\n");
+            }
+            _ => {}
+        }
+        result.push_str(&format!(
+            "[{}] {}{}\n",
+            error.rule,
+            error.message,
+            error.metadata_suffix()
+        ));
         if let Some(config_file_location) =
             config_file_locations.and_then(|locations| locations.get(&error.rule))
         {
@@ -85,6 +98,9 @@ impl MarkdownFormatter {
 
     fn get_error_snippet(&self, file_path: &str, error: &LintError) -> Result<String> {
         let content = Rope::from(fs::read_to_string(file_path)?);
+        if content.line_len() == 0 {
+            return Ok(String::new());
+        }
         let start_row = error.location.start.row;
         let end_row = error
             .location
@@ -121,6 +137,11 @@ impl MarkdownFormatter {
         }?;
 
         let mut result = "#### Recommendations\n\n".to_string();
+        if error.is_fixable(false) {
+            result += "Fixable with `--fix`.\n\n";
+        } else if error.is_fixable(true) {
+            result += "Fixable with `--fix-unsafe`.\n\n";
+        }
         let line_number_width = num_digits(all_recommendations.len());
         all_recommendations
             .iter()
@@ -171,11 +192,13 @@ impl MarkdownFormatter {
 
     fn format_summary(&self, output: &[LintOutput]) -> String {
         let mut result = String::new();
+        let summary = self.get_summary(output);
         let OutputSummary {
             num_files,
             num_errors,
             num_warnings,
-        } = self.get_summary(output);
+            ..
+        } = summary;
         result.push_str("## Summary\n\n");
         result.push_str(&format!(
             "- 🤖 {num_files} file{} linted\n",
@@ -189,6 +212,9 @@ impl MarkdownFormatter {
             "- 🔔 {num_warnings} warning{}\n",
             pluralize(num_warnings)
         ));
+        if let Some(fixable_message) = summary.fixable_message() {
+            result.push_str(&format!("- {fixable_message}\n"));
+        }
         result
     }
 }
@@ -218,7 +244,7 @@ mod tests {
     ) -> Result<String> {
         let temp_dir = TempDir::new().unwrap();
         let file_path = temp_dir.path().join(mock_path);
-        fs::write(&file_path, &contents).unwrap();
+        fs::write(&file_path, contents).unwrap();
 
         let error = LintError::from_raw_location()
             .rule(rule_name)
@@ -233,6 +259,7 @@ mod tests {
         let output = LintOutput {
             file_path: file_path.clone(),
             errors: vec![error],
+            timing: None,
         };
         let output = vec![output];
 
@@ -280,6 +307,8 @@ What a wonderful world!"#;
         assert!(output.contains("Recommendations"));
         assert!(output.contains("Replace the text"));
         assert!(output.contains("Friend"));
+        assert!(output.contains("Fixable with `--fix`."));
+        assert!(output.contains("- 🔧 1 fixable with --fix\n"));
     }
 
     #[test]
@@ -399,6 +428,7 @@ What a wonderful world!"#;
                     .location(DenormalizedLocation::dummy(21, 30, 2, 6, 2, 15))
                     .call(),
             ],
+            timing: None,
         };
 
         let formatter = MarkdownFormatter;
@@ -435,6 +465,7 @@ What a wonderful world!"#;
                 .message("Error in first file")
                 .location(DenormalizedLocation::dummy(0, 10, 0, 0, 0, 10))
                 .call()],
+            timing: None,
         };
 
         let output2 = LintOutput {
@@ -445,6 +476,7 @@ What a wonderful world!"#;
                 .message("Warning in second file")
                 .location(DenormalizedLocation::dummy(0, 12, 0, 0, 0, 12))
                 .call()],
+            timing: None,
         };
 
         let formatter = MarkdownFormatter;
@@ -500,4 +532,21 @@ What a wonderful world!"#;
         assert!(output.contains(&format!("{} | # Line {}", middle_line + 1, middle_line + 1)));
         assert!(output.contains(&format!("{} | # Line {}", middle_line + 2, middle_line + 2)));
     }
+
+    #[test]
+    fn test_markdown_formatter_internal_error_for_unreadable_file() {
+        let output = vec![LintOutput::from_internal_error(
+            "does-not-exist.mdx",
+            &anyhow::anyhow!("Some failure"),
+        )];
+
+        let formatter = MarkdownFormatter;
+        let result = formatter
+            .format(&output, &ConfigMetadata::default())
+            .unwrap();
+
+        assert!(result.contains("## does-not-exist.mdx"));
+        assert!(result.contains("[InternalError] Failed to lint file: Some failure"));
+        assert!(!result.contains("```"));
+    }
 }
@@ -12,6 +12,7 @@ impl From<LintLevel> for Severity {
         match level {
             LintLevel::Error => Severity::Error,
             LintLevel::Warning => Severity::Warning,
+            LintLevel::Hint => Severity::Advice,
         }
     }
 }
@@ -48,7 +49,9 @@ impl OutputFormatter for PrettyFormatter {
             }
             written |= true;
 
-            let content = fs::read_to_string(&curr.file_path)?;
+            // The source is best-effort, since the diagnostic might be
+            // reporting that the file couldn't be read in the first place.
+            let content = fs::read_to_string(&curr.file_path).unwrap_or_default();
 
             for (idx, error) in curr.errors.iter().enumerate() {
                 if idx > 0 {
@@ -57,7 +60,13 @@ impl OutputFormatter for PrettyFormatter {
 
                 let severity: Severity = error.level.into();
                 let mut message = String::new();
-                writeln!(message, "[{}] {}", error.rule, error.message.clone())?;
+                writeln!(
+                    message,
+                    "[{}] {}{}",
+                    error.rule,
+                    error.message,
+                    error.metadata_suffix()
+                )?;
                 if let Some(config_file_location) = metadata
                     .config_file_locations
                     .as_ref()
@@ -92,11 +101,13 @@ impl OutputFormatter for PrettyFormatter {
 impl PrettyFormatter {
     fn format_summary(&self, output: &[LintOutput]) -> String {
         let mut result = String::new();
+        let summary = self.get_summary(output);
         let OutputSummary {
             num_files,
             num_errors,
             num_warnings,
-        } = self.get_summary(output);
+            ..
+        } = summary;
 
         let diagnostic_message = match (num_errors, num_warnings) {
             (0, 0) => "🟢 No errors or warnings found",
@@ -125,6 +136,9 @@ impl PrettyFormatter {
             if num_files != 1 { "s" } else { "" }
         ));
         result.push_str(diagnostic_message);
+        if let Some(fixable_message) = summary.fixable_message() {
+            result.push_str(&format!("\n{}", fixable_message));
+        }
         result
     }
 }
@@ -156,6 +170,7 @@ mod tests {
         let output = LintOutput {
             file_path: file_path.clone(),
             errors: vec![error],
+            timing: None,
         };
         let output = vec![output];
 
@@ -176,6 +191,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![],
+            timing: None,
         };
         let output = vec![output];
 
@@ -211,6 +227,7 @@ mod tests {
         let output = LintOutput {
             file_path: file_path.clone(),
             errors: vec![error_1, error_2],
+            timing: None,
         };
         let output = vec![output];
 
@@ -245,10 +262,12 @@ mod tests {
         let output_1 = LintOutput {
             file_path: file_path_1.clone(),
             errors: vec![error_1.clone()],
+            timing: None,
         };
         let output_2 = LintOutput {
             file_path: file_path_2.clone(),
             errors: vec![error_1],
+            timing: None,
         };
 
         let output = vec![output_1, output_2];
@@ -21,8 +21,11 @@ use super::LintOutput;
 ///
 /// ```text
 /// {"message": "<msg>", "location": {"path": "<file path>", "range": {"start": {"line": 14, "column": 15}}}, "severity": "ERROR"}
-/// {"message": "<msg>", "location": {"path": "<file path>", "range": {"start": {"line": 14, "column": 15}, "end": {"line": 14, "column": 18}}}, "suggestions": [{"range": {"start": {"line": 14, "column": 15}, "end": {"line": 14, "column": 18}}, "text": "<replacement text>"}], "severity": "WARNING"}
+/// {"message": "<msg>", "location": {"path": "<file path>", "range": {"start": {"line": 14, "column": 15}, "end": {"line": 14, "column": 18}}}, "suggestions": [{"range": {"start": {"line": 14, "column": 15}, "end": {"line": 14, "column": 18}}, "text": "<replacement text>"}], "severity": "WARNING", "code": {"value": "SMDX001"}}
 /// ```
+///
+/// The suggestions include the edit that suppresses the diagnostic with a
+/// comment, when there is one, after any fixes.
 #[derive(Debug, Clone)]
 pub struct RdfFormatter;
 
@@ -30,11 +33,38 @@ pub struct RdfFormatter;
 struct RdfOutput<'output> {
     message: &'output str,
     location: RdfLocation<'output>,
-    severity: &'output LintLevel,
+    severity: RdfSeverity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<RdfCode<'output>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     suggestions: Option<Vec<RdfSuggestion<'output>>>,
 }
 
+/// The severities supported by Reviewdog.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+enum RdfSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl From<LintLevel> for RdfSeverity {
+    fn from(level: LintLevel) -> Self {
+        match level {
+            LintLevel::Error => RdfSeverity::Error,
+            LintLevel::Warning => RdfSeverity::Warning,
+            LintLevel::Hint => RdfSeverity::Info,
+        }
+    }
+}
+
+/// The code of the rule that reported a diagnostic.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+struct RdfCode<'code> {
+    value: &'code str,
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize)]
 struct RdfLocation<'location> {
     path: &'location str,
@@ -115,26 +145,23 @@ impl OutputFormatter for RdfFormatter {
         let mut result = String::new();
         for output in outputs.iter() {
             for error in output.errors.iter() {
-                let suggestions = match (error.fix.as_ref(), error.suggestions.as_ref()) {
-                    (None, None) => None,
-                    (fix, suggestions) => {
-                        let mut combined = Vec::new();
-                        if let Some(f) = fix {
-                            combined.extend(f.iter());
-                        }
-                        if let Some(s) = suggestions {
-                            combined.extend(s.iter());
-                        }
-                        Some(combined)
-                    }
-                };
+                // The suppression comes last, so that tools applying the
+                // first suggestion apply a real fix.
+                let suggestions = error
+                    .combined_suggestions()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(error.suppression())
+                    .collect::<Vec<_>>();
+                let suggestions = (!suggestions.is_empty()).then_some(suggestions);
 
                 let mut message = String::new();
                 write!(
                     message,
-                    "[{}] {}{}",
+                    "[{}] {}{}{}",
                     error.rule,
                     error.message,
+                    error.metadata_suffix(),
                     if let Some(location) = metadata
                         .config_file_locations
                         .as_ref()
@@ -152,7 +179,8 @@ impl OutputFormatter for RdfFormatter {
                         path: &output.file_path,
                         range: (&error.location).into(),
                     },
-                    severity: &error.level,
+                    severity: error.level.into(),
+                    code: error.code().map(|value| RdfCode { value }),
                     suggestions: suggestions.map(|fix| {
                         fix.iter()
                             .map(|corr| RdfSuggestion::from_lint_fix(corr))
@@ -198,6 +226,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![error],
+            timing: None,
         };
         let output = vec![output];
 
@@ -225,6 +254,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![error],
+            timing: None,
         };
         let output = vec![output];
 
@@ -257,6 +287,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![error_1, error_2],
+            timing: None,
         };
         let output = vec![output];
 
@@ -290,6 +321,7 @@ mod tests {
         let output_1 = LintOutput {
             file_path: file_path_1,
             errors: vec![error_1.clone(), error_2.clone()],
+            timing: None,
         };
 
         let file_path_2 = "test2.md".to_string();
@@ -297,6 +329,7 @@ mod tests {
         let output_2 = LintOutput {
             file_path: file_path_2,
             errors: vec![error_1, error_2],
+            timing: None,
         };
 
         let output = vec![output_1, output_2];
@@ -333,6 +366,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![error],
+            timing: None,
         };
         let output = vec![output];
 
@@ -362,6 +396,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![error],
+            timing: None,
         };
         let output = vec![output];
 
@@ -374,4 +409,36 @@ mod tests {
         let expected = r#"{"message":"[MockRule] This is an error with only suggestions","location":{"path":"test.md","range":{"start":{"line":1,"column":1},"end":{"line":1,"column":9}}},"severity":"ERROR","suggestions":[{"range":{"start":{"line":1,"column":1},"end":{"line":1,"column":9}},"text":"replacement text"}]}"#;
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_rdf_formatter_with_suppression() {
+        let mut linter = crate::Linter::builder().build().unwrap();
+        linter
+            .config_mut()
+            .rule_registry
+            .deactivate_all_but("Rule001HeadingCase");
+        let output = linter
+            .lint(&crate::LintTarget::String(
+                "Some text.\n\n# Incorrect Heading\n",
+            ))
+            .unwrap();
+
+        let formatter = RdfFormatter;
+        let result = formatter
+            .format(&output, &ConfigMetadata::default())
+            .unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(result.trim()).unwrap();
+        let suggestions = result["suggestions"].as_array().unwrap();
+        assert_eq!(
+            suggestions.last().unwrap(),
+            &serde_json::json!({
+                "range": {
+                    "start": { "line": 3, "column": 1 },
+                    "end": { "line": 3, "column": 1 },
+                },
+                "text": "{/* supa-mdx-lint-disable-next-line Rule001HeadingCase */}\n",
+            })
+        );
+    }
 }
@@ -8,7 +8,7 @@ use super::{LintOutput, OutputSummary};
 /// the structure:
 ///
 /// ```text
-/// <file path>:<line>:<column>: [<severity>] <msg>
+/// <file path>:<line>:<column>: [<severity>] <msg> (<code>, <category>)
 /// ```
 ///
 /// The diagnostics are followed by a summary of the number of linted files,
@@ -32,12 +32,13 @@ impl OutputFormatter for SimpleFormatter {
                 written |= true;
 
                 result.push_str(&format!(
-                    "{}:{}:{}: [{}] {}\n",
+                    "{}:{}:{}: [{}] {}{}\n",
                     output.file_path,
                     error.location.start.row + 1,
                     error.location.start.column + 1,
                     error.level,
                     error.message,
+                    error.metadata_suffix(),
                 ));
             }
         }
@@ -56,13 +57,17 @@ impl OutputFormatter for SimpleFormatter {
 }
 
 impl SimpleFormatter {
-    fn format_summary(&self, output: &[LintOutput]) -> String {
+    /// The summary of the number of linted files, total errors, and total
+    /// warnings, without the diagnostics.
+    pub fn format_summary(&self, output: &[LintOutput]) -> String {
         let mut result = String::new();
+        let summary = self.get_summary(output);
         let OutputSummary {
             num_errors,
             num_files,
             num_warnings,
-        } = self.get_summary(output);
+            ..
+        } = summary;
 
         let diagnostic_message = match (num_errors, num_warnings) {
             (0, 0) => "🟢 No errors or warnings found",
@@ -91,6 +96,9 @@ impl SimpleFormatter {
             if num_files != 1 { "s" } else { "" }
         ));
         result.push_str(&format!("{}\n", diagnostic_message));
+        if let Some(fixable_message) = summary.fixable_message() {
+            result.push_str(&format!("{}\n", fixable_message));
+        }
         result
     }
 }
@@ -100,7 +108,9 @@ mod tests {
     use super::*;
     use crate::{
         errors::{LintError, LintLevel},
+        fix::{FixSafety, LintCorrection, LintCorrectionDelete},
         location::DenormalizedLocation,
+        rules::RuleCategory,
     };
 
     #[test]
@@ -116,6 +126,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![error],
+            timing: None,
         };
         let output = vec![output];
 
@@ -129,6 +140,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_simple_formatter_rule_metadata() {
+        let error = LintError::from_raw_location()
+            .rule("Rule006NoAbsoluteUrls")
+            .level(LintLevel::Error)
+            .message("This is an error")
+            .location(DenormalizedLocation::dummy(0, 7, 0, 0, 1, 0))
+            .call()
+            .with_rule_metadata("SMDX006", RuleCategory::Links);
+        let output = vec![LintOutput {
+            file_path: "test.md".to_string(),
+            errors: vec![error],
+            timing: None,
+        }];
+
+        let result = SimpleFormatter
+            .format(&output, &ConfigMetadata::default())
+            .unwrap();
+        assert!(result.starts_with("test.md:1:1: [ERROR] This is an error (SMDX006, links)\n"));
+    }
+
     #[test]
     fn test_simple_formatter_warning() {
         let file_path = "test.md".to_string();
@@ -141,6 +173,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![error],
+            timing: None,
         };
         let output = vec![output];
 
@@ -172,6 +205,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![error1, error2],
+            timing: None,
         };
         let output = vec![output];
 
@@ -191,6 +225,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![],
+            timing: None,
         };
         let output = vec![output];
 
@@ -223,6 +258,7 @@ mod tests {
         let output = LintOutput {
             file_path,
             errors: vec![error_1, error_2],
+            timing: None,
         };
         let output = vec![output];
 
@@ -255,6 +291,7 @@ mod tests {
         let output_1 = LintOutput {
             file_path: file_path_1,
             errors: vec![error_1, error_2],
+            timing: None,
         };
 
         let file_path_2 = "test2.md".to_string();
@@ -274,6 +311,7 @@ mod tests {
         let output_2 = LintOutput {
             file_path: file_path_2,
             errors: vec![error_3, error_4],
+            timing: None,
         };
 
         let output = vec![output_1, output_2];
@@ -287,4 +325,38 @@ mod tests {
             "test.md:1:1: [ERROR] This is an error\ntest.md:4:1: [ERROR] This is another error\ntest2.md:1:1: [ERROR] This is an error\ntest2.md:4:1: [ERROR] This is another error\n\n🔍 2 sources linted\n🔴 Found 4 errors\n"
         );
     }
+
+    #[test]
+    fn test_simple_formatter_fixable() {
+        let fix = |location: DenormalizedLocation| {
+            vec![LintCorrection::Delete(LintCorrectionDelete { location })]
+        };
+        let safe = LintError::from_raw_location()
+            .rule("MockRule")
+            .level(LintLevel::Error)
+            .message("This is an error")
+            .location(DenormalizedLocation::dummy(0, 7, 0, 0, 1, 0))
+            .fix(fix(DenormalizedLocation::dummy(0, 7, 0, 0, 1, 0)))
+            .call();
+        let unsafe_fix = LintError::from_raw_location()
+            .rule("MockRule")
+            .level(LintLevel::Warning)
+            .message("This is a warning")
+            .location(DenormalizedLocation::dummy(14, 46, 3, 0, 4, 2))
+            .fix(fix(DenormalizedLocation::dummy(14, 46, 3, 0, 4, 2)))
+            .fix_safety(FixSafety::Unsafe)
+            .call();
+        let output = vec![LintOutput {
+            file_path: "test.md".to_string(),
+            errors: vec![safe, unsafe_fix],
+            timing: None,
+        }];
+
+        let result = SimpleFormatter
+            .format(&output, &ConfigMetadata::default())
+            .unwrap();
+        assert!(result.ends_with(
+            "🔴 Found 1 error and 1 warning\n🔧 1 fixable with --fix, and 1 more with --fix-unsafe\n"
+        ));
+    }
 }
@@ -0,0 +1,178 @@
+use std::{cmp::Reverse, fmt::Write, time::Duration};
+
+use indexmap::IndexMap;
+
+use super::LintOutput;
+
+/// The number of files listed in the timing report, slowest first.
+const MAX_FILES_REPORTED: usize = 10;
+
+/// Time spent linting a single file.
+#[derive(Debug, Clone, Default)]
+pub struct LintTiming {
+    rules: IndexMap<&'static str, Duration>,
+    total: Duration,
+}
+
+impl LintTiming {
+    pub(crate) fn new(rules: IndexMap<&'static str, Duration>, total: Duration) -> Self {
+        Self { rules, total }
+    }
+
+    /// Cumulative time spent in each rule, in the order the rules ran.
+    pub fn rules(&self) -> impl Iterator<Item = (&'static str, Duration)> + '_ {
+        self.rules.iter().map(|(rule, duration)| (*rule, *duration))
+    }
+
+    /// Total time spent on the file, including parsing.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+}
+
+/// Summarize the timing recorded in a set of outputs: the cumulative time
+/// spent in each rule, slowest first, followed by the slowest files.
+///
+/// Returns `None` if none of the outputs have timing recorded.
+pub fn timing_report(outputs: &[LintOutput]) -> Option<String> {
+    let timed = outputs
+        .iter()
+        .filter_map(|output| output.timing().map(|timing| (output.file_path(), timing)))
+        .collect::<Vec<_>>();
+    if timed.is_empty() {
+        return None;
+    }
+
+    let mut rules = IndexMap::<&str, Duration>::new();
+    for (_, timing) in &timed {
+        for (rule, duration) in timing.rules() {
+            *rules.entry(rule).or_default() += duration;
+        }
+    }
+    let mut rules = rules.into_iter().collect::<Vec<_>>();
+    rules.sort_by_key(|(_, duration)| Reverse(*duration));
+    let rules_total = rules
+        .iter()
+        .map(|(_, duration)| *duration)
+        .sum::<Duration>();
+
+    let mut report = String::new();
+    let width = rules
+        .iter()
+        .map(|(rule, _)| rule.len())
+        .max()
+        .unwrap_or_default()
+        .max("Rule".len());
+    writeln!(report, "{:<width$} | Time (ms) | Relative", "Rule").unwrap();
+    writeln!(report, ":{:-<width$}|----------:|--------:", "").unwrap();
+    for (rule, duration) in &rules {
+        let relative = if rules_total.is_zero() {
+            0.0
+        } else {
+            duration.as_secs_f64() / rules_total.as_secs_f64() * 100.0
+        };
+        writeln!(
+            report,
+            "{rule:<width$} | {:>9} | {relative:>7.1}%",
+            format_millis(*duration)
+        )
+        .unwrap();
+    }
+
+    let mut files = timed
+        .iter()
+        .map(|(file_path, timing)| (*file_path, timing.total()))
+        .collect::<Vec<_>>();
+    files.sort_by_key(|(_, duration)| Reverse(*duration));
+    let files_total = files
+        .iter()
+        .map(|(_, duration)| *duration)
+        .sum::<Duration>();
+
+    let width = files
+        .iter()
+        .take(MAX_FILES_REPORTED)
+        .map(|(file_path, _)| file_path.len())
+        .max()
+        .unwrap_or_default()
+        .max("File".len());
+    writeln!(report).unwrap();
+    writeln!(report, "{:<width$} | Time (ms)", "File").unwrap();
+    writeln!(report, ":{:-<width$}|----------:", "").unwrap();
+    for (file_path, duration) in files.iter().take(MAX_FILES_REPORTED) {
+        writeln!(
+            report,
+            "{file_path:<width$} | {:>9}",
+            format_millis(*duration)
+        )
+        .unwrap();
+    }
+    writeln!(
+        report,
+        "\nLinted {} file{} in {} ms",
+        files.len(),
+        if files.len() == 1 { "" } else { "s" },
+        format_millis(files_total)
+    )
+    .unwrap();
+
+    Some(report)
+}
+
+fn format_millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timed_output(file_path: &str, rules: &[(&'static str, u64)], total: u64) -> LintOutput {
+        let rules = rules
+            .iter()
+            .map(|(rule, micros)| (*rule, Duration::from_micros(*micros)))
+            .collect();
+        LintOutput::new(file_path, vec![])
+            .with_timing(LintTiming::new(rules, Duration::from_micros(total)))
+    }
+
+    #[test]
+    fn test_timing_report() {
+        let outputs = vec![
+            timed_output(
+                "a.mdx",
+                &[("Rule001HeadingCase", 1000), ("Rule003Spelling", 2000)],
+                4000,
+            ),
+            timed_output(
+                "b.mdx",
+                &[("Rule001HeadingCase", 500), ("Rule003Spelling", 500)],
+                1500,
+            ),
+        ];
+
+        let report = timing_report(&outputs).unwrap();
+        assert_eq!(
+            report,
+            "\
+Rule               | Time (ms) | Relative
+:------------------|----------:|--------:
+Rule003Spelling    |     2.500 |    62.5%
+Rule001HeadingCase |     1.500 |    37.5%
+
+File  | Time (ms)
+:-----|----------:
+a.mdx |     4.000
+b.mdx |     1.500
+
+Linted 2 files in 5.500 ms
+"
+        );
+    }
+
+    #[test]
+    fn test_timing_report_without_timing() {
+        let outputs = vec![LintOutput::new("a.mdx", vec![])];
+        assert!(timing_report(&outputs).is_none());
+    }
+}
@@ -1,44 +1,265 @@
-use std::any::Any;
+use std::{any::Any, ops::Range, sync::LazyLock};
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use log::{debug, trace};
-use markdown::{mdast::Node, to_mdast, Constructs, ParseOptions};
-
-use crate::{location::AdjustedOffset, rope::Rope};
-
-type Frontmatter = Box<dyn Any>;
+use markdown::{
+    mdast::{Heading, Node, Paragraph, Root, Text},
+    message::{Message, Place},
+    to_mdast,
+    unist::Position,
+    Constructs, ParseOptions,
+};
+use regex::{Captures, Regex};
+
+use crate::{
+    app_error::ParseError,
+    location::{AdjustedOffset, UnadjustedOffset},
+    rope::Rope,
+};
+
+type Frontmatter = Box<dyn Any + Send + Sync>;
 
 #[derive(Debug)]
 pub(crate) struct ParseMetadata {
     content_start_offset: AdjustedOffset,
-    #[allow(unused)]
     frontmatter: Option<Frontmatter>,
+    /// The range of the frontmatter in the source, between the delimiters.
+    frontmatter_range: Option<Range<usize>>,
 }
 
+/// The result of parsing an MDX document with [`parse`].
 #[derive(Debug)]
-pub(crate) struct ParseResult {
+pub struct ParseResult {
     ast: Node,
     rope: Rope,
     metadata: ParseMetadata,
 }
 
 impl ParseResult {
-    pub(crate) fn ast(&self) -> &Node {
+    /// The mdast root of the document content, not including the
+    /// frontmatter.
+    ///
+    /// Positions in the tree are relative to the start of the content. Add
+    /// [`ParseResult::content_start_offset`] to get offsets into the full
+    /// source.
+    pub fn ast(&self) -> &Node {
         &self.ast
     }
 
-    pub(crate) fn rope(&self) -> &Rope {
+    /// The full source of the document, including the frontmatter.
+    pub fn rope(&self) -> &Rope {
         &self.rope
     }
 
-    pub(crate) fn content_start_offset(&self) -> AdjustedOffset {
-        self.metadata.content_start_offset
+    /// The byte offset in the full source where the content after the
+    /// frontmatter starts.
+    pub fn content_start_offset(&self) -> usize {
+        self.metadata.content_start_offset.into()
+    }
+
+    /// Convert an offset relative to the content, such as a position in
+    /// [`ParseResult::ast`], to an offset into the full source.
+    ///
+    /// Offsets reported by the linter, including the locations of errors and
+    /// corrections, are already offsets into the full source, so they can be
+    /// applied to an editor buffer as they are.
+    ///
+    /// ```
+    /// let result = supa_mdx_lint::parse("---\ntitle: Auth\n---\n\n# Auth\n").unwrap();
+    /// assert_eq!(result.source_offset(2), 23);
+    /// assert_eq!(result.content_offset(23), Some(2));
+    /// assert_eq!(result.content_offset(4), None);
+    /// ```
+    pub fn source_offset(&self, content_offset: usize) -> usize {
+        AdjustedOffset::from_unadjusted(
+            UnadjustedOffset::from(content_offset),
+            self.metadata.content_start_offset,
+        )
+        .into()
+    }
+
+    /// Convert an offset into the full source to one relative to the
+    /// content, the inverse of [`ParseResult::source_offset`]. Returns `None`
+    /// for offsets within the frontmatter.
+    pub fn content_offset(&self, source_offset: usize) -> Option<usize> {
+        source_offset.checked_sub(self.content_start_offset())
+    }
+
+    /// The range in the full source covered by a position in
+    /// [`ParseResult::ast`].
+    pub fn source_range(&self, position: &Position) -> Range<usize> {
+        self.source_offset(position.start.offset)..self.source_offset(position.end.offset)
+    }
+
+    /// The document's frontmatter, whether it is written in TOML or YAML.
+    ///
+    /// TOML frontmatter is converted to the equivalent YAML value. Returns
+    /// `None` if there is no frontmatter or it couldn't be parsed.
+    pub fn frontmatter(&self) -> Option<serde_yaml::Value> {
+        let frontmatter = self.metadata.frontmatter.as_ref()?;
+        if let Some(toml_frontmatter) = frontmatter.downcast_ref::<toml::Value>() {
+            serde_yaml::to_value(toml_frontmatter).ok()
+        } else {
+            frontmatter.downcast_ref::<serde_yaml::Value>().cloned()
+        }
+    }
+
+    /// Get a top-level string field from the frontmatter, whether it is
+    /// written in TOML or YAML.
+    pub(crate) fn frontmatter_str(&self, key: &str) -> Option<&str> {
+        let frontmatter = self.metadata.frontmatter.as_ref()?;
+        if let Some(toml_frontmatter) = frontmatter.downcast_ref::<toml::Value>() {
+            toml_frontmatter.get(key)?.as_str()
+        } else if let Some(yaml_frontmatter) = frontmatter.downcast_ref::<serde_yaml::Value>() {
+            yaml_frontmatter.get(key)?.as_str()
+        } else {
+            None
+        }
+    }
+
+    /// The range in the source of the value of a top-level string field in
+    /// the frontmatter, not including any quotes.
+    ///
+    /// Only values written on the same line as their key are found, and only
+    /// if they read the same in the source as once parsed, so that offsets
+    /// into the value are offsets into the source.
+    pub(crate) fn frontmatter_field_range(&self, key: &str) -> Option<Range<usize>> {
+        let frontmatter_range = self.metadata.frontmatter_range.clone()?;
+        let value = self.frontmatter_str(key)?;
+        let source = self.rope.byte_slice(frontmatter_range.clone()).to_string();
+
+        let mut line_start = frontmatter_range.start;
+        for line in source.split_inclusive('\n') {
+            let line_offset = line_start;
+            line_start += line.len();
+
+            let Some(rest) = line.strip_prefix(key) else {
+                continue;
+            };
+            let trimmed = rest.trim_start_matches([' ', '\t']);
+            let Some(raw_value) = trimmed
+                .strip_prefix(':')
+                .or_else(|| trimmed.strip_prefix('='))
+            else {
+                continue;
+            };
+            let raw_value = raw_value.trim_start_matches([' ', '\t']);
+            let value_offset = line_offset + (line.len() - raw_value.len());
+
+            let (start, candidate) = match raw_value.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let end = raw_value[1..].find(quote)?;
+                    (value_offset + 1, &raw_value[1..end + 1])
+                }
+                _ => {
+                    let raw_value = raw_value.split(" #").next().unwrap_or_default();
+                    (value_offset, raw_value.trim_end())
+                }
+            };
+            return (candidate == value).then(|| start..start + value.len());
+        }
+        None
+    }
+
+    /// A document made of a single frontmatter field's value, so that rules
+    /// can check it like content. Offsets in the document's tree are
+    /// relative to the start of the value, so the locations the rules report
+    /// point into the frontmatter.
+    ///
+    /// The value is a single text node, in a heading if `as_heading` is set
+    /// and in a paragraph otherwise.
+    pub(crate) fn frontmatter_field_document(
+        &self,
+        key: &str,
+        as_heading: bool,
+    ) -> Option<ParseResult> {
+        let range = self.frontmatter_field_range(key)?;
+        let value = self.frontmatter_str(key)?;
+        if value.trim().is_empty() {
+            return None;
+        }
+
+        let position = Some(Position::new(1, 1, 0, 1, value.len() + 1, value.len()));
+        let text = vec![Node::Text(Text {
+            value: value.to_string(),
+            position: position.clone(),
+        })];
+        let block = if as_heading {
+            Node::Heading(Heading {
+                children: text,
+                position: position.clone(),
+                depth: 1,
+            })
+        } else {
+            Node::Paragraph(Paragraph {
+                children: text,
+                position: position.clone(),
+            })
+        };
+
+        Some(ParseResult {
+            ast: Node::Root(Root {
+                children: vec![block],
+                position,
+            }),
+            rope: self.rope.clone(),
+            metadata: ParseMetadata {
+                content_start_offset: range.start.into(),
+                frontmatter: None,
+                frontmatter_range: None,
+            },
+        })
     }
 }
 
-pub(crate) fn parse(input: &str) -> Result<ParseResult> {
-    let (content, rope, content_start_offset, frontmatter) = process_raw_content_string(input);
-    let ast = parse_internal(content)?;
+/// Parse an MDX document the same way the linter does, splitting off any
+/// frontmatter before parsing the content.
+///
+/// ```
+/// let result = supa_mdx_lint::parse("---\ntitle: Auth\n---\n\n# Auth\n").unwrap();
+/// assert_eq!(result.frontmatter().unwrap()["title"], "Auth");
+/// assert_eq!(result.content_start_offset(), 21);
+/// ```
+pub fn parse(input: &str) -> Result<ParseResult> {
+    Ok(parse_with(input, |content| parse_internal(content, true))?)
+}
+
+/// Parse an MDX document like [`parse`], but never fail. If the content
+/// isn't valid MDX, it's parsed as plain Markdown instead, so JSX and
+/// expressions show up as text.
+///
+/// ```
+/// assert!(supa_mdx_lint::parse("<Admonition>\n").is_err());
+/// let result = supa_mdx_lint::parse_lossy("<Admonition>\n");
+/// assert_eq!(result.ast().children().unwrap().len(), 1);
+/// ```
+pub fn parse_lossy(input: &str) -> ParseResult {
+    parse_with(input, |content| {
+        parse_internal(content, true).or_else(|err| {
+            debug!("Falling back to parsing as Markdown: {err}");
+            parse_internal(content, false)
+        })
+    })
+    .expect("Parsing without MDX constructs can't fail")
+}
+
+fn parse_with(
+    input: &str,
+    parse_content: impl Fn(&str) -> Result<Node, Message>,
+) -> Result<ParseResult, ParseError> {
+    let (content, rope, content_start_offset, frontmatter, frontmatter_range) =
+        process_raw_content_string(input);
+    let ast = parse_content(content).map_err(|message| {
+        let offset = message.place.map(|place| match *place {
+            Place::Position(position) => position.start.offset,
+            Place::Point(point) => point.offset,
+        });
+        let content_start: usize = content_start_offset.into();
+        ParseError::InvalidMdx {
+            reason: shift_reason_lines(&message.reason, rope.line_of_byte(content_start)),
+            offset: offset.map(|offset| content_start + offset),
+        }
+    })?;
 
     trace!("AST: {:#?}", ast);
 
@@ -48,13 +269,37 @@ pub(crate) fn parse(input: &str) -> Result<ParseResult> {
         metadata: ParseMetadata {
             content_start_offset,
             frontmatter,
+            frontmatter_range,
         },
     })
 }
 
-fn process_raw_content_string(input: &str) -> (&str, Rope, AdjustedOffset, Option<Frontmatter>) {
+/// Parser messages can refer to other places in the content, such as the
+/// opening tag of an unclosed element, as `(line:column)`. Shift the lines so
+/// they count from the start of the full source, like the linter's output.
+fn shift_reason_lines(reason: &str, line_offset: usize) -> String {
+    static PLACE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\((\d+):(\d+)\)").unwrap());
+    PLACE
+        .replace_all(reason, |captures: &Captures| {
+            let line = captures[1].parse::<usize>().unwrap_or_default() + line_offset;
+            format!("({line}:{})", &captures[2])
+        })
+        .into_owned()
+}
+
+#[allow(clippy::type_complexity)]
+fn process_raw_content_string(
+    input: &str,
+) -> (
+    &str,
+    Rope,
+    AdjustedOffset,
+    Option<Frontmatter>,
+    Option<Range<usize>>,
+) {
     let rope = Rope::from(input);
     let mut frontmatter = None;
+    let mut frontmatter_range = None;
     let mut content = input;
 
     let mut content_start_offset = AdjustedOffset::default();
@@ -68,6 +313,7 @@ fn process_raw_content_string(input: &str) -> (&str, Rope, AdjustedOffset, Optio
                 (Into::<usize>::into(frontmatter_start_offset) + frontmatter_end_index).into();
 
             let frontmatter_str = &content[frontmatter_start_offset.into()..end_offset.into()];
+            frontmatter_range = Some(frontmatter_start_offset.into()..end_offset.into());
 
             if let Ok(toml_frontmatter) = toml::from_str::<toml::Value>(frontmatter_str) {
                 debug!("Parsed as TOML: {toml_frontmatter:#?}");
@@ -87,16 +333,8 @@ fn process_raw_content_string(input: &str) -> (&str, Rope, AdjustedOffset, Optio
             end_offset.increment(3);
 
             // Skip all whitespace and newlines after the closing "---"
-            let mut remaining_index = 0;
             let remaining = &content[end_offset.into()..];
-            while remaining_index < remaining.len() {
-                if remaining[remaining_index..].starts_with(char::is_whitespace) {
-                    remaining_index += 1;
-                } else {
-                    break;
-                }
-            }
-            end_offset.increment(remaining_index);
+            end_offset.increment(remaining.len() - remaining.trim_start().len());
 
             content_start_offset = end_offset;
         }
@@ -104,11 +342,17 @@ fn process_raw_content_string(input: &str) -> (&str, Rope, AdjustedOffset, Optio
 
     content = &input[content_start_offset.into()..];
 
-    (content, rope, content_start_offset, frontmatter)
+    (
+        content,
+        rope,
+        content_start_offset,
+        frontmatter,
+        frontmatter_range,
+    )
 }
 
-fn parse_internal(input: &str) -> Result<Node> {
-    let mdast = to_mdast(
+fn parse_internal(input: &str, mdx: bool) -> Result<Node, Message> {
+    to_mdast(
         input,
         &ParseOptions {
             constructs: Constructs {
@@ -120,19 +364,16 @@ fn parse_internal(input: &str) -> Result<Node> {
                 gfm_table: true,
                 html_flow: false,
                 html_text: false,
-                mdx_esm: true,
-                mdx_expression_flow: true,
-                mdx_expression_text: true,
-                mdx_jsx_flow: true,
-                mdx_jsx_text: true,
+                mdx_esm: mdx,
+                mdx_expression_flow: mdx,
+                mdx_expression_text: mdx,
+                mdx_jsx_flow: mdx,
+                mdx_jsx_text: mdx,
                 ..Default::default()
             },
             ..Default::default()
         },
     )
-    .map_err(|e| anyhow!("Not valid Markdown: {:?}", e))?;
-
-    Ok(mdast)
 }
 
 pub(crate) trait CommentString {
@@ -206,7 +447,7 @@ Content here."#;
         let yaml = frontmatter.downcast_ref::<serde_yaml::Value>().unwrap();
         if let serde_yaml::Value::Mapping(map) = yaml {
             assert_eq!(map.len(), 1);
-            assert!(map.contains_key(&serde_yaml::Value::String("title".to_string())));
+            assert!(map.contains_key(serde_yaml::Value::String("title".to_string())));
         } else {
             panic!("Expected YAML frontmatter to be a mapping");
         }
@@ -217,6 +458,74 @@ Content here."#;
         assert_eq!(heading.position().unwrap().start.column, 1);
     }
 
+    #[test]
+    fn test_frontmatter_str() {
+        let result = parse("---\ntitle: YAML title\n---\n\nContent").unwrap();
+        assert_eq!(result.frontmatter_str("title"), Some("YAML title"));
+        assert_eq!(result.frontmatter_str("description"), None);
+
+        let result = parse("---\ntitle = \"TOML title\"\n---\n\nContent").unwrap();
+        assert_eq!(result.frontmatter_str("title"), Some("TOML title"));
+
+        let result = parse("Content").unwrap();
+        assert_eq!(result.frontmatter_str("title"), None);
+    }
+
+    #[test]
+    fn test_frontmatter_value() {
+        let result = parse("---\ntitle: YAML title\ntags: [auth]\n---\n\nContent").unwrap();
+        let frontmatter = result.frontmatter().unwrap();
+        assert_eq!(frontmatter["title"], "YAML title");
+        assert_eq!(frontmatter["tags"][0], "auth");
+
+        let result =
+            parse("---\ntitle = \"TOML title\"\ntags = [\"auth\"]\n---\n\nContent").unwrap();
+        let frontmatter = result.frontmatter().unwrap();
+        assert_eq!(frontmatter["title"], "TOML title");
+        assert_eq!(frontmatter["tags"][0], "auth");
+
+        assert!(parse("Content").unwrap().frontmatter().is_none());
+    }
+
+    #[test]
+    fn test_frontmatter_field_range() {
+        let input = "---\ntitle: Sign In\nsubtitle: 'Quoted value' # comment\ndescription: >-\n  Folded\nnested:\n  title: Nested\n---\n\n# Auth\n";
+        let result = parse(input).unwrap();
+        let range = result.frontmatter_field_range("title").unwrap();
+        assert_eq!(&input[range], "Sign In");
+        let range = result.frontmatter_field_range("subtitle").unwrap();
+        assert_eq!(&input[range], "Quoted value");
+        assert!(result.frontmatter_field_range("description").is_none());
+        assert!(result.frontmatter_field_range("missing").is_none());
+
+        let input = "---\ntitle = \"Sign in\"\n---\n\n# Auth\n";
+        let result = parse(input).unwrap();
+        let range = result.frontmatter_field_range("title").unwrap();
+        assert_eq!(&input[range], "Sign in");
+
+        let document = result.frontmatter_field_document("title", true).unwrap();
+        assert!(matches!(
+            document.ast().children().unwrap()[0],
+            Node::Heading(_)
+        ));
+        assert_eq!(document.content_start_offset(), 13);
+    }
+
+    #[test]
+    fn test_offset_mapping() {
+        let input = "---\ntitle: Auth\n---\n\n# Auth\n\nSign in.\n";
+        let result = parse(input).unwrap();
+        let paragraph = &result.ast().children().unwrap()[1];
+        let range = result.source_range(paragraph.position().unwrap());
+        assert_eq!(&input[range.clone()], "Sign in.");
+        assert_eq!(result.content_offset(range.start), Some(8));
+        assert_eq!(result.source_offset(8), range.start);
+
+        let result = parse("# Auth\n").unwrap();
+        assert_eq!(result.source_offset(2), 2);
+        assert_eq!(result.content_offset(2), Some(2));
+    }
+
     #[test]
     fn test_parse_markdown_with_toml_frontmatter() {
         let input = r#"---
@@ -272,4 +581,45 @@ Content here."#;
         assert_eq!(heading.position().unwrap().start.line, 1);
         assert_eq!(heading.position().unwrap().start.column, 1);
     }
+
+    #[test]
+    fn test_parse_frontmatter_followed_by_multibyte_whitespace() {
+        let input = "---\ntitle: Test\n---\u{a0}\u{3000}# Heading";
+        let result = parse(input).unwrap();
+        assert_eq!(result.content_start_offset(), input.find('#').unwrap());
+    }
+
+    #[test]
+    fn test_parse_error_lines_count_from_source_start() {
+        let input = "---\ntitle: Test\n---\n\n# Heading\n\n<Admonition>\n\nText\n";
+        let err = parse(input).unwrap_err();
+        let Some(ParseError::InvalidMdx { reason, offset }) = err.downcast_ref() else {
+            panic!("Expected an MDX parse error");
+        };
+        assert_eq!(reason, "Expected a closing tag for `<Admonition>` (7:1)");
+        assert_eq!(*offset, Some(input.len()));
+    }
+
+    #[test]
+    fn test_parse_lossy_falls_back_to_markdown() {
+        let input = "# Heading\n\n<Admonition type=\"note\">\n\nUnclosed {expression\n";
+        assert!(parse(input).is_err());
+
+        let result = parse_lossy(input);
+        let children = result.ast().children().unwrap();
+        assert!(matches!(children[0], Node::Heading(_)));
+        assert!(children[1..]
+            .iter()
+            .all(|child| matches!(child, Node::Paragraph(_))));
+    }
+
+    #[test]
+    fn test_parse_lossy_valid_mdx() {
+        let input = "<Admonition type=\"note\">\n\nText\n\n</Admonition>\n";
+        let result = parse_lossy(input);
+        assert!(matches!(
+            result.ast().children().unwrap()[0],
+            Node::MdxJsxFlowElement(_)
+        ));
+    }
 }
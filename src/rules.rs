@@ -1,52 +1,372 @@
 use anyhow::Result;
+use glob::Pattern;
+use indexmap::IndexMap;
 use log::{debug, warn};
 use markdown::mdast::Node;
 use regex::Regex;
-use serde::Deserialize;
-use std::{collections::HashMap, fmt::Debug, marker::PhantomData};
-
-#[cfg(test)]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    str::FromStr,
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
 
 use crate::{
     context::Context,
     errors::{LintError, LintLevel},
+    utils::{edit_distance, mdast::NodeKind},
     PhaseReady, PhaseSetup,
 };
 
+#[macro_use]
+mod word_list;
+
 mod rule001_heading_case;
 mod rule002_admonition_types;
+#[cfg(feature = "spelling")]
 mod rule003_spelling;
 mod rule004_exclude_words;
 mod rule005_admonition_newlines;
 mod rule006_no_absolute_urls;
+mod rule007_compound_terms;
+mod rule008_component_props;
+mod rule009_changelog_format;
+mod rule010_unknown_components;
+mod rule011_deep_relative_links;
+mod rule012_no_raw_html;
+mod rule013_duplicate_title_heading;
+mod rule014_html_entities;
+mod rule015_keyboard_shortcuts;
+mod rule016_file_size;
+mod rule017_config_keys;
+mod rule018_placeholder_text;
+mod rule019_numeral_sentence_start;
+mod rule020_noun_chains;
+mod rule021_description_length;
+mod rule022_whitespace;
+mod rule023_admonition_title_case;
+mod rule024_list_marker;
+mod rule025_table_format;
+mod rule026_duplicate_sibling_headings;
+mod rule027_local_images;
+mod rule028_deprecation_notice;
+mod rule029_colon_capitalization;
+mod rule030_user_paths;
+mod rule031_trailing_etc;
+mod rule032_prerequisites;
+mod rule033_list_indentation;
+mod rule034_inline_styles;
+mod rule035_tab_labels;
+mod rule036_heading_component_spacing;
+mod rule037_intro_paragraph;
+mod rule038_heading_ids;
+mod rule039_draft_markers;
+mod rule040_number_format;
+mod rule041_sentence_per_line;
+mod rule042_emphasis_markers;
+mod rule043_vague_link_text;
+mod rule044_heading_length;
 
 pub use rule001_heading_case::Rule001HeadingCase;
 pub use rule002_admonition_types::Rule002AdmonitionTypes;
+#[cfg(feature = "spelling")]
 pub use rule003_spelling::Rule003Spelling;
 pub use rule004_exclude_words::Rule004ExcludeWords;
 pub use rule005_admonition_newlines::Rule005AdmonitionNewlines;
 pub use rule006_no_absolute_urls::Rule006NoAbsoluteUrls;
+pub use rule007_compound_terms::Rule007CompoundTerms;
+pub use rule008_component_props::Rule008ComponentProps;
+pub use rule009_changelog_format::Rule009ChangelogFormat;
+pub use rule010_unknown_components::Rule010UnknownComponents;
+pub use rule011_deep_relative_links::Rule011DeepRelativeLinks;
+pub use rule012_no_raw_html::Rule012NoRawHtml;
+pub use rule013_duplicate_title_heading::Rule013DuplicateTitleHeading;
+pub use rule014_html_entities::Rule014HtmlEntities;
+pub use rule015_keyboard_shortcuts::Rule015KeyboardShortcuts;
+pub use rule016_file_size::Rule016FileSize;
+pub use rule017_config_keys::Rule017ConfigKeys;
+pub use rule018_placeholder_text::Rule018PlaceholderText;
+pub use rule019_numeral_sentence_start::Rule019NumeralSentenceStart;
+pub use rule020_noun_chains::Rule020NounChains;
+pub use rule021_description_length::Rule021DescriptionLength;
+pub use rule022_whitespace::Rule022Whitespace;
+pub use rule023_admonition_title_case::Rule023AdmonitionTitleCase;
+pub use rule024_list_marker::Rule024ListMarker;
+pub use rule025_table_format::Rule025TableFormat;
+pub use rule026_duplicate_sibling_headings::Rule026DuplicateSiblingHeadings;
+pub use rule027_local_images::Rule027LocalImages;
+pub use rule028_deprecation_notice::Rule028DeprecationNotice;
+pub use rule029_colon_capitalization::Rule029ColonCapitalization;
+pub use rule030_user_paths::Rule030UserPaths;
+pub use rule031_trailing_etc::Rule031TrailingEtc;
+pub use rule032_prerequisites::Rule032Prerequisites;
+pub use rule033_list_indentation::Rule033ListIndentation;
+pub use rule034_inline_styles::Rule034InlineStyles;
+pub use rule035_tab_labels::Rule035TabLabels;
+pub use rule036_heading_component_spacing::Rule036HeadingComponentSpacing;
+pub use rule037_intro_paragraph::Rule037IntroParagraph;
+pub use rule038_heading_ids::Rule038HeadingIds;
+pub use rule039_draft_markers::Rule039DraftMarkers;
+pub use rule040_number_format::Rule040NumberFormat;
+pub use rule041_sentence_per_line::Rule041SentencePerLine;
+pub use rule042_emphasis_markers::Rule042EmphasisMarkers;
+pub use rule043_vague_link_text::Rule043VagueLinkText;
+pub use rule044_heading_length::Rule044HeadingLength;
 
 fn get_all_rules() -> Vec<Box<dyn Rule>> {
     vec![
         Box::new(Rule001HeadingCase::default()),
         Box::new(Rule002AdmonitionTypes::default()),
+        #[cfg(feature = "spelling")]
         Box::new(Rule003Spelling::default()),
         Box::new(Rule004ExcludeWords::default()),
         Box::new(Rule005AdmonitionNewlines),
         Box::new(Rule006NoAbsoluteUrls::default()),
+        Box::new(Rule007CompoundTerms::default()),
+        Box::new(Rule008ComponentProps::default()),
+        Box::new(Rule009ChangelogFormat::default()),
+        Box::new(Rule010UnknownComponents::default()),
+        Box::new(Rule011DeepRelativeLinks::default()),
+        Box::new(Rule012NoRawHtml::default()),
+        Box::new(Rule013DuplicateTitleHeading),
+        Box::new(Rule014HtmlEntities::default()),
+        Box::new(Rule015KeyboardShortcuts::default()),
+        Box::new(Rule016FileSize::default()),
+        Box::new(Rule017ConfigKeys::default()),
+        Box::new(Rule018PlaceholderText::default()),
+        Box::new(Rule019NumeralSentenceStart),
+        Box::new(Rule020NounChains::default()),
+        Box::new(Rule021DescriptionLength::default()),
+        Box::new(Rule022Whitespace::default()),
+        Box::new(Rule023AdmonitionTitleCase::default()),
+        Box::new(Rule024ListMarker::default()),
+        Box::new(Rule025TableFormat::default()),
+        Box::new(Rule026DuplicateSiblingHeadings::default()),
+        Box::new(Rule027LocalImages::default()),
+        Box::new(Rule028DeprecationNotice::default()),
+        Box::new(Rule029ColonCapitalization::default()),
+        Box::new(Rule030UserPaths::default()),
+        Box::new(Rule031TrailingEtc::default()),
+        Box::new(Rule032Prerequisites::default()),
+        Box::new(Rule033ListIndentation::default()),
+        Box::new(Rule034InlineStyles::default()),
+        Box::new(Rule035TabLabels),
+        Box::new(Rule036HeadingComponentSpacing::default()),
+        Box::new(Rule037IntroParagraph::default()),
+        Box::new(Rule038HeadingIds::default()),
+        Box::new(Rule039DraftMarkers::default()),
+        Box::new(Rule040NumberFormat::default()),
+        Box::new(Rule041SentencePerLine::default()),
+        Box::new(Rule042EmphasisMarkers::default()),
+        Box::new(Rule043VagueLinkText::default()),
+        Box::new(Rule044HeadingLength::default()),
     ]
 }
 
-pub(crate) trait Rule: Debug + RuleName {
+/// Rule names that have been replaced, paired with their current names.
+/// Configs and lint directives that use an old name keep working, with a
+/// deprecation warning.
+const DEPRECATED_RULE_NAMES: &[(&str, &str)] = &[];
+
+fn all_rule_names() -> &'static [&'static str] {
+    static NAMES: OnceLock<Vec<&'static str>> = OnceLock::new();
+    NAMES.get_or_init(|| get_all_rules().iter().map(|rule| rule.name()).collect())
+}
+
+/// The human-friendly alias for a rule: its name without the numbered
+/// prefix, in kebab case. For example, `heading-case` for
+/// `Rule001HeadingCase`.
+pub fn rule_alias(rule_name: &str) -> String {
+    let name = rule_name
+        .strip_prefix("Rule")
+        .unwrap_or(rule_name)
+        .trim_start_matches(|c: char| c.is_ascii_digit());
+
+    let mut alias = String::with_capacity(name.len() + 4);
+    for (idx, c) in name.chars().enumerate() {
+        if c.is_uppercase() && idx > 0 {
+            alias.push('-');
+        }
+        alias.extend(c.to_lowercase());
+    }
+    alias
+}
+
+/// Resolve a rule name, alias, or deprecated name to the rule's current name.
+/// Logs a warning if the name is deprecated. Returns `None` if there is no
+/// such rule.
+pub fn canonical_rule_name(name: &str) -> Option<&'static str> {
+    canonical_rule_name_in(name, DEPRECATED_RULE_NAMES)
+}
+
+fn canonical_rule_name_in(name: &str, deprecated: &[(&str, &str)]) -> Option<&'static str> {
+    let names = all_rule_names();
+    if let Some(rule_name) = names
+        .iter()
+        .find(|rule_name| **rule_name == name || rule_alias(rule_name) == name)
+    {
+        return Some(rule_name);
+    }
+
+    let (old, new) = deprecated.iter().find(|(old, _)| *old == name)?;
+    let current = canonical_rule_name_in(new, &[])?;
+    warn!("The rule name {old} is deprecated. Use {current} instead.");
+    Some(current)
+}
+
+/// Rules that can be left out of the build with feature flags. Lint
+/// directives may still refer to them.
+const OPTIONAL_RULE_NAMES: &[&str] = &["Rule003Spelling"];
+
+/// Whether a name refers to a rule, by its name, alias, or deprecated name.
+/// Rules left out of this build still count.
+pub(crate) fn is_known_rule_name(name: &str) -> bool {
+    canonical_rule_name(name).is_some()
+        || OPTIONAL_RULE_NAMES
+            .iter()
+            .any(|rule_name| *rule_name == name || rule_alias(rule_name) == name)
+}
+
+/// The rule name or alias closest to a misspelled one, if any is within
+/// `max_distance` edits.
+pub(crate) fn closest_rule_name(name: &str, max_distance: usize) -> Option<String> {
+    all_rule_names()
+        .iter()
+        .flat_map(|rule_name| [rule_name.to_string(), rule_alias(rule_name)])
+        .map(|candidate| (edit_distance(name, &candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+pub(crate) trait Rule: Debug + RuleName + Send + Sync {
     fn default_level(&self) -> LintLevel;
     fn setup(&mut self, _settings: Option<&mut RuleSettings>) {}
     fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>>;
+
+    /// The kinds of node the rule checks. The registry skips calling `check`
+    /// for other nodes. `None`, the default, means every node.
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        None
+    }
+
+    /// A JSON-schema-like description of the settings accepted by the rule.
+    /// The `level` setting is shared by all rules and shouldn't be included.
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({}))
+    }
+}
+
+/// Describe an object with the given properties.
+fn object_schema(properties: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": properties,
+    })
+}
+
+/// Describe an array of strings.
+fn string_array_schema(description: &str) -> Value {
+    json!({
+        "type": "array",
+        "items": { "type": "string" },
+        "description": description,
+    })
+}
+
+/// Describe the configuration accepted by every rule, keyed by rule name.
+/// Each rule can either be turned off with `false`, or configured with a
+/// table of settings.
+pub(crate) fn rule_settings_schemas() -> Map<String, Value> {
+    get_all_rules()
+        .into_iter()
+        .map(|rule| {
+            let mut settings = rule.settings_schema();
+            if let Some(properties) = settings
+                .get_mut("properties")
+                .and_then(Value::as_object_mut)
+            {
+                let default_level = match rule.default_level() {
+                    LintLevel::Error => "error",
+                    LintLevel::Warning => "warn",
+                    LintLevel::Hint => "hint",
+                };
+                properties.insert(
+                    "level".to_string(),
+                    json!({
+                        "type": "string",
+                        "enum": ["error", "warn", "hint"],
+                        "default": default_level,
+                        "description": "Severity level of the rule's diagnostics.",
+                    }),
+                );
+            }
+
+            (
+                rule.name().to_string(),
+                json!({
+                    "anyOf": [
+                        { "type": "boolean", "const": false },
+                        settings,
+                    ],
+                }),
+            )
+        })
+        .collect()
 }
 
 pub(crate) trait RuleName {
     fn name(&self) -> &'static str;
+    /// A stable short code for the rule, such as `SMDX001`.
+    fn code(&self) -> &'static str;
+    fn category(&self) -> RuleCategory;
+}
+
+/// The kind of problem a rule reports, for grouping and filtering errors.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleCategory {
+    /// Wording, capitalization, and formatting.
+    #[default]
+    Style,
+    /// Content that is wrong or broken, such as misspellings or invalid
+    /// component props.
+    Correctness,
+    /// Content that is hard to navigate or read with assistive technology.
+    Accessibility,
+    /// Links, images, and anchors.
+    Links,
+}
+
+impl Display for RuleCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleCategory::Style => write!(f, "style"),
+            RuleCategory::Correctness => write!(f, "correctness"),
+            RuleCategory::Accessibility => write!(f, "accessibility"),
+            RuleCategory::Links => write!(f, "links"),
+        }
+    }
+}
+
+impl FromStr for RuleCategory {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "style" => Ok(Self::Style),
+            "correctness" => Ok(Self::Correctness),
+            "accessibility" => Ok(Self::Accessibility),
+            "links" => Ok(Self::Links),
+            _ => Err(anyhow::anyhow!(
+                "Invalid rule category: {value}. Expected one of style, correctness, accessibility, links"
+            )),
+        }
+    }
 }
 
 impl dyn Rule {
@@ -67,6 +387,7 @@ pub(crate) struct RegexSettings {
 
 pub(crate) enum RegexBeginning {
     VeryBeginning,
+    #[cfg_attr(not(feature = "spelling"), allow(dead_code))]
     WordBoundary,
 }
 
@@ -123,6 +444,54 @@ impl RuleSettings {
         None
     }
 
+    /// Append strings to the array stored under `key`, creating the array if
+    /// it doesn't exist yet.
+    fn extend_array_of_strings<'a>(
+        &mut self,
+        key: &str,
+        values: impl IntoIterator<Item = &'a str>,
+    ) {
+        let toml::Value::Table(ref mut table) = self.0 else {
+            return;
+        };
+
+        match table
+            .entry(key)
+            .or_insert_with(|| toml::Value::Array(Vec::new()))
+        {
+            toml::Value::Array(array) => array.extend(
+                values
+                    .into_iter()
+                    .map(|s| toml::Value::String(s.to_string())),
+            ),
+            _ => warn!("Cannot extend rule setting {key} because it is not an array"),
+        }
+    }
+
+    fn get_array_of_globs(&self, key: &str) -> Option<Vec<Pattern>> {
+        let table = &self.0;
+        if let Some(toml::Value::Array(array)) = table.get(key) {
+            let vec = array
+                .iter()
+                .filter_map(|value| match value {
+                    toml::Value::String(glob) => match Pattern::new(glob) {
+                        Ok(pattern) => Some(pattern),
+                        Err(err) => {
+                            warn!("Encountered invalid glob in rule settings: {glob}: {err}");
+                            None
+                        }
+                    },
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if !vec.is_empty() {
+                return Some(vec);
+            }
+        }
+
+        None
+    }
+
     fn get_array_of_regexes(
         &self,
         key: &str,
@@ -136,32 +505,28 @@ impl RuleSettings {
                     let mut pattern = pattern.to_string();
                     if let Some(settings) = settings {
                         match settings.beginning {
-                            Some(RegexBeginning::VeryBeginning) => {
-                                if !pattern.starts_with('^') {
-                                    pattern = format!("^{}", pattern);
-                                }
+                            Some(RegexBeginning::VeryBeginning) if !pattern.starts_with('^') => {
+                                pattern = format!("^{}", pattern);
                             }
-                            Some(RegexBeginning::WordBoundary) => {
+                            Some(RegexBeginning::WordBoundary)
                                 if !pattern.starts_with("\\b")
                                     && !pattern.starts_with("\\s")
-                                    && !pattern.starts_with("^")
-                                {
-                                    pattern = format!("(?:^|\\s|\\b){}", pattern);
-                                }
+                                    && !pattern.starts_with("^") =>
+                            {
+                                pattern = format!("(?:^|\\s|\\b){}", pattern);
                             }
-                            None => {}
+                            _ => {}
                         }
                         #[allow(clippy::single_match)]
                         match settings.ending {
-                            Some(RegexEnding::WordBoundary) => {
+                            Some(RegexEnding::WordBoundary)
                                 if !pattern.ends_with("\\b")
                                     && !pattern.ends_with("\\s")
-                                    && !pattern.ends_with("$")
-                                {
-                                    pattern = format!(r#"{}(?:\s|\b|$|[.,!?'"-])"#, pattern);
-                                }
+                                    && !pattern.ends_with("$") =>
+                            {
+                                pattern = format!(r#"{}(?:\s|\b|$|[.,!?'"-])"#, pattern);
                             }
-                            None => {}
+                            _ => {}
                         }
                     }
 
@@ -222,6 +587,56 @@ pub(crate) struct RuleRegistry<Phase> {
     _phase: PhantomData<Phase>,
     rules: Vec<Box<dyn Rule>>,
     configured_levels: HashMap<String, LintLevel>,
+    dispatch: RuleDispatch,
+    /// Whether to run independent rules on separate threads.
+    parallel: bool,
+}
+
+/// The rules to call for each kind of node, as indices into the registry's
+/// rules, in registry order.
+#[derive(Debug, Default)]
+struct RuleDispatch {
+    by_kind: HashMap<NodeKind, Vec<usize>>,
+    /// The rules interested in every node, which are also the only rules to
+    /// call for kinds no rule declared an interest in.
+    any_kind: Vec<usize>,
+}
+
+impl RuleDispatch {
+    fn new(rules: &[Box<dyn Rule>]) -> Self {
+        let interests = rules
+            .iter()
+            .map(|rule| rule.interests())
+            .collect::<Vec<_>>();
+        let any_kind = interests
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, kinds)| kinds.is_none().then_some(idx))
+            .collect();
+
+        let mut by_kind = HashMap::new();
+        for kind in interests.iter().flatten().flat_map(|kinds| kinds.iter()) {
+            by_kind.entry(*kind).or_insert_with(|| {
+                interests
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, kinds)| {
+                        kinds
+                            .is_none_or(|kinds| kinds.contains(kind))
+                            .then_some(idx)
+                    })
+                    .collect()
+            });
+        }
+
+        Self { by_kind, any_kind }
+    }
+
+    fn rules_for(&self, node: &Node) -> &[usize] {
+        self.by_kind
+            .get(&NodeKind::from(node))
+            .unwrap_or(&self.any_kind)
+    }
 }
 
 impl RuleRegistry<PhaseSetup> {
@@ -230,13 +645,46 @@ impl RuleRegistry<PhaseSetup> {
             _phase: PhantomData,
             rules: get_all_rules(),
             configured_levels: Default::default(),
+            dispatch: Default::default(),
+            parallel: false,
         }
     }
 
+    pub fn set_parallel(&mut self, parallel: bool) {
+        self.parallel = parallel;
+    }
+
     pub fn save_configured_level(&mut self, rule_name: &str, level: LintLevel) {
         self.configured_levels.insert(rule_name.to_string(), level);
     }
 
+    /// Whether a rule exists, even if it has been deactivated.
+    pub fn is_known_rule(rule_name: &str) -> bool {
+        get_all_rules().iter().any(|rule| rule.name() == rule_name)
+    }
+
+    /// Turn a rule back on after it has been deactivated, keeping the rules
+    /// in their usual order. Returns `false` if there is no such rule.
+    pub fn activate_rule(&mut self, rule_name: &str) -> bool {
+        if self.is_valid_rule(rule_name) {
+            return true;
+        }
+        if !Self::is_known_rule(rule_name) {
+            return false;
+        }
+
+        let active = self
+            .rules
+            .iter()
+            .map(|rule| rule.name())
+            .collect::<Vec<_>>();
+        self.rules = get_all_rules()
+            .into_iter()
+            .filter(|rule| rule.name() == rule_name || active.contains(&rule.name()))
+            .collect();
+        true
+    }
+
     pub fn setup(
         mut self,
         settings: &mut HashMap<String, RuleSettings>,
@@ -248,48 +696,207 @@ impl RuleRegistry<PhaseSetup> {
 
         Ok(RuleRegistry {
             _phase: PhantomData,
+            dispatch: RuleDispatch::new(&self.rules),
             rules: self.rules,
             configured_levels: self.configured_levels,
+            parallel: self.parallel,
         })
     }
 }
 
 impl RuleRegistry<PhaseReady> {
     pub fn run(&self, context: &Context) -> Result<Vec<LintError>> {
+        if self.parallel {
+            return Ok(self.run_parallel(context, None));
+        }
+
         let mut errors = Vec::new();
-        self.check_node(context.parse_result.ast(), context, &mut errors);
+        self.check_node(context.parse_result.ast(), context, &mut errors, None);
         Ok(errors)
     }
 
-    fn check_node(&self, ast: &Node, context: &Context, errors: &mut Vec<LintError>) {
-        for rule in &self.rules {
-            if let Some(filter) = &context.check_only_rules {
-                if !filter.contains(&rule.name()) {
-                    continue;
-                }
+    /// Run the rules, also recording the cumulative time spent in each rule.
+    pub fn run_with_timing(
+        &self,
+        context: &Context,
+    ) -> Result<(Vec<LintError>, IndexMap<&'static str, Duration>)> {
+        let mut timings = IndexMap::new();
+        if self.parallel {
+            let errors = self.run_parallel(context, Some(&mut timings));
+            return Ok((errors, timings));
+        }
+
+        let mut errors = Vec::new();
+        self.check_node(
+            context.parse_result.ast(),
+            context,
+            &mut errors,
+            Some(&mut timings),
+        );
+        Ok((errors, timings))
+    }
+
+    fn check_node(
+        &self,
+        ast: &Node,
+        context: &Context,
+        errors: &mut Vec<LintError>,
+        mut timings: Option<&mut IndexMap<&'static str, Duration>>,
+    ) {
+        for idx in self.dispatch.rules_for(ast) {
+            errors.extend(self.check_rule(*idx, ast, context, timings.as_deref_mut()));
+        }
+
+        if let Some(children) = ast.children() {
+            for child in children {
+                self.check_node(child, context, errors, timings.as_deref_mut());
             }
+        }
+    }
 
-            let rule_level = rule.get_level(self.get_configured_level(rule.name()));
-            if let Some(rule_errors) = rule.check(ast, context, rule_level) {
-                debug!("Rule errors: {:#?}", rule_errors);
-                let filtered_errors: Vec<LintError> = rule_errors
-                    .into_iter()
-                    .filter(|err| {
-                        !context
-                            .disables
-                            .disabled_for_location(rule.name(), &err.location, context)
+    /// Split the rules between threads, each of which walks the whole tree
+    /// with its share of the rules. The errors are merged back into the
+    /// order a sequential run reports them in: by node, then by rule.
+    fn run_parallel(
+        &self,
+        context: &Context,
+        timings: Option<&mut IndexMap<&'static str, Duration>>,
+    ) -> Vec<LintError> {
+        let threads = std::thread::available_parallelism()
+            .map_or(1, |threads| threads.get())
+            .min(self.rules.len())
+            .max(1);
+        let record_timing = timings.is_some();
+
+        let results = std::thread::scope(|scope| {
+            let handles = (0..threads)
+                .map(|thread| {
+                    scope.spawn(move || {
+                        let mut results = Vec::new();
+                        let mut thread_timings = IndexMap::new();
+                        self.check_node_for_rules(
+                            context.parse_result.ast(),
+                            context,
+                            &|idx| idx % threads == thread,
+                            &mut 0,
+                            &mut results,
+                            record_timing.then_some(&mut thread_timings),
+                        );
+                        (results, thread_timings)
                     })
-                    .collect();
-                errors.extend(filtered_errors);
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("Rule thread panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        let mut merged_timings = HashMap::new();
+        let mut errors = Vec::new();
+        for (thread_errors, thread_timings) in results {
+            errors.extend(thread_errors);
+            merged_timings.extend(thread_timings);
+        }
+        if let Some(timings) = timings {
+            timings.extend(self.rules.iter().filter_map(|rule| {
+                merged_timings
+                    .get(rule.name())
+                    .map(|duration| (rule.name(), *duration))
+            }));
+        }
+
+        // The sort is stable, so each rule's errors for a node keep their order.
+        errors.sort_by_key(|(node, rule, _)| (*node, *rule));
+        errors.into_iter().map(|(_, _, error)| error).collect()
+    }
+
+    /// Walk the tree in the same order as `check_node`, running only the
+    /// selected rules, and tag each error with the index of its node in the
+    /// walk and the index of its rule.
+    fn check_node_for_rules(
+        &self,
+        ast: &Node,
+        context: &Context,
+        selected: &dyn Fn(usize) -> bool,
+        node: &mut usize,
+        results: &mut Vec<(usize, usize, LintError)>,
+        mut timings: Option<&mut IndexMap<&'static str, Duration>>,
+    ) {
+        let node_idx = *node;
+        *node += 1;
+        for idx in self.dispatch.rules_for(ast) {
+            if !selected(*idx) {
+                continue;
             }
+            results.extend(
+                self.check_rule(*idx, ast, context, timings.as_deref_mut())
+                    .into_iter()
+                    .map(|error| (node_idx, *idx, error)),
+            );
         }
 
         if let Some(children) = ast.children() {
             for child in children {
-                self.check_node(child, context, errors);
+                self.check_node_for_rules(
+                    child,
+                    context,
+                    selected,
+                    node,
+                    results,
+                    timings.as_deref_mut(),
+                );
             }
         }
     }
+
+    fn check_rule(
+        &self,
+        idx: usize,
+        ast: &Node,
+        context: &Context,
+        timings: Option<&mut IndexMap<&'static str, Duration>>,
+    ) -> Vec<LintError> {
+        let rule = &self.rules[idx];
+        if let Some(filter) = &context.check_only_rules {
+            if !filter.contains(&rule.name()) {
+                return Vec::new();
+            }
+        }
+
+        let directory_rules = context.directory_rules;
+        if directory_rules.is_some_and(|rules| rules.is_disabled(rule.name())) {
+            return Vec::new();
+        }
+
+        let rule_level = rule.get_level(
+            directory_rules
+                .and_then(|rules| rules.level(rule.name()))
+                .or_else(|| self.get_configured_level(rule.name())),
+        );
+        let start = timings.is_some().then(Instant::now);
+        let rule_errors = rule.check(ast, context, rule_level);
+        if let (Some(timings), Some(start)) = (timings, start) {
+            *timings.entry(rule.name()).or_default() += start.elapsed();
+        }
+
+        let Some(rule_errors) = rule_errors else {
+            return Vec::new();
+        };
+        debug!("Rule errors: {:#?}", rule_errors);
+        rule_errors
+            .into_iter()
+            .filter(|err| {
+                !context
+                    .disables
+                    .disabled_for_location(rule.name(), &err.location, context)
+            })
+            .map(|err| {
+                err.with_rule_metadata(rule.code(), rule.category())
+                    .with_suppression(context)
+            })
+            .collect()
+    }
 }
 
 impl<State> RuleRegistry<State> {
@@ -299,12 +906,18 @@ impl<State> RuleRegistry<State> {
 
     pub fn deactivate_rule(&mut self, rule_name: &str) {
         self.rules.retain(|rule| rule.name() != rule_name);
+        self.dispatch = RuleDispatch::new(&self.rules);
     }
 
     pub fn get_configured_level(&self, rule_name: &str) -> Option<LintLevel> {
         self.configured_levels.get(rule_name).cloned()
     }
 
+    #[cfg(test)]
+    pub(crate) fn is_parallel(&self) -> bool {
+        self.parallel
+    }
+
     #[cfg(test)]
     pub(crate) fn is_rule_active(&self, rule_name: &str) -> bool {
         self.rules.iter().any(|rule| rule.name() == rule_name)
@@ -312,7 +925,14 @@ impl<State> RuleRegistry<State> {
 
     #[cfg(test)]
     pub(crate) fn deactivate_all_but(&mut self, rule_name: &str) {
-        self.rules.retain(|rule| rule.name() == rule_name)
+        self.rules.retain(|rule| rule.name() == rule_name);
+        self.dispatch = RuleDispatch::new(&self.rules);
+    }
+
+    #[cfg(all(test, feature = "fix"))]
+    pub(crate) fn replace_rules(&mut self, rules: Vec<Box<dyn Rule>>) {
+        self.dispatch = RuleDispatch::new(&rules);
+        self.rules = rules;
     }
 }
 
@@ -378,10 +998,13 @@ mod tests {
         let check_count_1 = mock_rule_1.check_count.clone();
         let check_count_2 = mock_rule_2.check_count.clone();
 
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(mock_rule_1), Box::new(mock_rule_2)];
         let registry = RuleRegistry {
             _phase: PhantomData,
-            rules: vec![Box::new(mock_rule_1), Box::new(mock_rule_2)],
+            dispatch: RuleDispatch::new(&rules),
+            rules,
             configured_levels: Default::default(),
+            parallel: false,
         };
 
         let mdx = "text";
@@ -393,7 +1016,7 @@ mod tests {
             .unwrap();
 
         let mut errors = Vec::new();
-        registry.check_node(parse_result.ast(), &context, &mut errors);
+        registry.check_node(parse_result.ast(), &context, &mut errors, None);
 
         assert!(check_count_1.load(Ordering::Relaxed) > 1);
         assert_eq!(check_count_2.load(Ordering::Relaxed), 0);
@@ -406,10 +1029,13 @@ mod tests {
         let check_count_1 = mock_rule_1.check_count.clone();
         let check_count_2 = mock_rule_2.check_count.clone();
 
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(mock_rule_1), Box::new(mock_rule_2)];
         let registry = RuleRegistry {
             _phase: PhantomData,
-            rules: vec![Box::new(mock_rule_1), Box::new(mock_rule_2)],
+            dispatch: RuleDispatch::new(&rules),
+            rules,
             configured_levels: Default::default(),
+            parallel: false,
         };
 
         let mdx = "test";
@@ -420,9 +1046,280 @@ mod tests {
             .unwrap();
 
         let mut errors = Vec::new();
-        registry.check_node(parse_result.ast(), &context, &mut errors);
+        registry.check_node(parse_result.ast(), &context, &mut errors, None);
 
         assert!(check_count_1.load(Ordering::Relaxed) > 1);
         assert!(check_count_2.load(Ordering::Relaxed) > 1);
     }
+
+    #[derive(Clone, Default, Debug, RuleName)]
+    struct HeadingRule {
+        check_count: Arc<AtomicUsize>,
+    }
+
+    impl Rule for HeadingRule {
+        fn default_level(&self) -> LintLevel {
+            LintLevel::Error
+        }
+
+        fn interests(&self) -> Option<&'static [NodeKind]> {
+            Some(&[NodeKind::Heading])
+        }
+
+        fn check(
+            &self,
+            _ast: &Node,
+            _context: &Context,
+            _level: LintLevel,
+        ) -> Option<Vec<LintError>> {
+            self.check_count.fetch_add(1, Ordering::Relaxed);
+            None
+        }
+    }
+
+    #[test]
+    fn test_check_node_dispatches_by_interest() {
+        let heading_rule = HeadingRule::default();
+        let mock_rule = MockRule::default();
+        let heading_count = heading_rule.check_count.clone();
+        let mock_count = mock_rule.check_count.clone();
+
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(heading_rule), Box::new(mock_rule)];
+        let registry = RuleRegistry {
+            _phase: PhantomData,
+            dispatch: RuleDispatch::new(&rules),
+            rules,
+            configured_levels: Default::default(),
+            parallel: false,
+        };
+
+        // Root, two headings with a text node each, and a paragraph with a
+        // text node.
+        let mdx = "# One\n\n## Two\n\nText.";
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let mut errors = Vec::new();
+        registry.check_node(parse_result.ast(), &context, &mut errors, None);
+
+        assert_eq!(heading_count.load(Ordering::Relaxed), 2);
+        assert_eq!(mock_count.load(Ordering::Relaxed), 7);
+    }
+
+    #[test]
+    fn test_rules_only_check_their_interests() {
+        let mdx = r#"---
+title: Page
+---
+
+# Heading with `code`
+
+A paragraph with a [link](https://example.com) and ![an image](/image.png).
+
+<Admonition type="note" label="A Label">
+
+Some text &amp; more.
+
+</Admonition>
+
+```toml
+key = "value"
+```
+"#;
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let mut nodes = vec![parse_result.ast()];
+        let mut idx = 0;
+        while idx < nodes.len() {
+            if let Some(children) = nodes[idx].children() {
+                nodes.extend(children);
+            }
+            idx += 1;
+        }
+
+        for rule in get_all_rules() {
+            let Some(interests) = rule.interests() else {
+                continue;
+            };
+            for node in nodes
+                .iter()
+                .filter(|node| !interests.contains(&NodeKind::from(**node)))
+            {
+                assert!(
+                    rule.check(node, &context, LintLevel::Error).is_none(),
+                    "{} reported a {:?} node",
+                    rule.name(),
+                    NodeKind::from(*node)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_run_matches_sequential() {
+        let mdx = r#"# A Heading In Title Case
+
+Visit https://supabase.com/docs for more, etc.
+
+:::note
+An admonition with a typo: teh.
+:::
+
+## Another Heading
+
+- One
+   - Nested
+
+<Admonition type="unknown">
+
+Text.
+
+</Admonition>
+"#;
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let mut registry = RuleRegistry::new().setup(&mut HashMap::new()).unwrap();
+        let (sequential, sequential_timings) = registry.run_with_timing(&context).unwrap();
+        registry.parallel = true;
+        let (parallel, parallel_timings) = registry.run_with_timing(&context).unwrap();
+
+        let summarize = |errors: &[LintError]| {
+            errors
+                .iter()
+                .map(|error| {
+                    (
+                        error.rule.clone(),
+                        error.message.clone(),
+                        error.offset_range(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert!(sequential.len() > 1);
+        assert_eq!(summarize(&parallel), summarize(&sequential));
+        assert_eq!(
+            parallel_timings.len(),
+            sequential_timings.len(),
+            "every rule that ran should be timed"
+        );
+    }
+
+    #[test]
+    fn test_rule_settings_schemas_cover_all_rules() {
+        let schemas = rule_settings_schemas();
+        for rule in get_all_rules() {
+            let settings = &schemas[rule.name()]["anyOf"][1];
+            assert_eq!(settings["type"], "object", "{}", rule.name());
+            assert!(settings["properties"]["level"].is_object());
+        }
+
+        let presets = &schemas["Rule001HeadingCase"]["anyOf"][1]["properties"]["presets"];
+        assert!(presets["items"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("tech-acronyms")));
+    }
+
+    #[test]
+    fn test_rule_codes_and_categories() {
+        let rules = get_all_rules();
+        assert_eq!(rules[0].code(), "SMDX001");
+        assert_eq!(rules[0].category(), RuleCategory::Style);
+        assert_eq!(MockRule::default().code(), "SMDX000");
+
+        let codes = rules
+            .iter()
+            .map(|rule| rule.code())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(codes.len(), rules.len());
+
+        let links = rules
+            .iter()
+            .find(|rule| rule.name() == "Rule006NoAbsoluteUrls")
+            .unwrap();
+        assert_eq!(links.code(), "SMDX006");
+        assert_eq!(links.category(), RuleCategory::Links);
+
+        assert_eq!(
+            "Accessibility".parse::<RuleCategory>().unwrap(),
+            RuleCategory::Accessibility
+        );
+        assert_eq!(RuleCategory::Correctness.to_string(), "correctness");
+        assert!("grammar".parse::<RuleCategory>().is_err());
+    }
+
+    #[test]
+    fn test_run_sets_rule_metadata() {
+        let parse_result = parse(
+            "# A Heading In Title Case
+",
+        )
+        .unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+        let registry = RuleRegistry::new().setup(&mut HashMap::new()).unwrap();
+        let errors = registry.run(&context).unwrap();
+        let error = errors
+            .iter()
+            .find(|error| error.rule() == "Rule001HeadingCase")
+            .unwrap();
+        assert_eq!(error.code(), Some("SMDX001"));
+        assert_eq!(error.category(), Some(RuleCategory::Style));
+    }
+
+    #[test]
+    fn test_rule_alias() {
+        assert_eq!(rule_alias("Rule001HeadingCase"), "heading-case");
+        assert_eq!(rule_alias("Rule006NoAbsoluteUrls"), "no-absolute-urls");
+        assert_eq!(rule_alias("Rule031TrailingEtc"), "trailing-etc");
+
+        let aliases = all_rule_names()
+            .iter()
+            .map(|name| rule_alias(name))
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(aliases.len(), all_rule_names().len());
+    }
+
+    #[test]
+    fn test_canonical_rule_name() {
+        assert_eq!(
+            canonical_rule_name("Rule001HeadingCase"),
+            Some("Rule001HeadingCase")
+        );
+        assert_eq!(
+            canonical_rule_name("heading-case"),
+            Some("Rule001HeadingCase")
+        );
+        assert_eq!(canonical_rule_name("HeadingCase"), None);
+        assert_eq!(canonical_rule_name("Rule999Nonexistent"), None);
+
+        let deprecated = [
+            ("Rule001Headings", "Rule001HeadingCase"),
+            ("old-spelling", "spelling"),
+            ("Rule999Removed", "Rule999Nonexistent"),
+        ];
+        assert_eq!(
+            canonical_rule_name_in("Rule001Headings", &deprecated),
+            Some("Rule001HeadingCase")
+        );
+        #[cfg(feature = "spelling")]
+        assert_eq!(
+            canonical_rule_name_in("old-spelling", &deprecated),
+            Some("Rule003Spelling")
+        );
+        assert_eq!(canonical_rule_name_in("Rule999Removed", &deprecated), None);
+    }
 }
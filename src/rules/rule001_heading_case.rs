@@ -1,9 +1,10 @@
-use std::{cell::RefCell, ops::Range};
+use std::ops::Range;
 
 use crop::RopeSlice;
-use log::debug;
+use log::{debug, warn};
 use markdown::mdast::{Node, Text};
 use regex::Regex;
+use serde_json::{json, Value};
 use supa_mdx_macros::RuleName;
 
 use crate::{
@@ -17,7 +18,14 @@ use crate::{
     },
 };
 
-use super::{RegexBeginning, RegexEnding, RegexSettings, Rule, RuleName, RuleSettings};
+use super::{
+    object_schema, string_array_schema, NodeKind, RegexBeginning, RegexEnding, RegexSettings, Rule,
+    RuleName, RuleSettings,
+};
+
+mod presets;
+
+use presets::{get_preset, preset_names};
 
 /// Headings should be in sentence case.
 ///
@@ -42,21 +50,23 @@ use super::{RegexBeginning, RegexEnding, RegexSettings, Rule, RuleName, RuleSett
 /// - `may_lowercase`: Words that may be lowercased even if they are the first word in the heading.
 ///
 /// See an  [example from the Supabase repo](https://github.com/supabase/supabase/blob/master/supa-mdx-lint/Rule001HeadingCase.toml).
-#[derive(Debug, RuleName)]
+///
+/// ### Presets
+///
+/// Common exceptions are bundled as presets, which are added on top of the
+/// configured `may_uppercase` and `may_lowercase` arrays:
+/// - `tech-acronyms`: Technical acronyms such as API, JSON, and OAuth.
+/// - `product-names`: Common product, language, and platform names such as GitHub, PostgreSQL, and iOS.
+/// - `small-words`: Connecting words such as "vs", "and", and "or", which may stay lowercase after a colon.
+///
+/// ```toml
+/// [Rule001HeadingCase]
+/// presets = ["tech-acronyms", "product-names"]
+/// ```
+#[derive(Debug, Default, RuleName)]
 pub struct Rule001HeadingCase {
     may_uppercase: Vec<Regex>,
     may_lowercase: Vec<Regex>,
-    next_word_capital: RefCell<Capitalize>,
-}
-
-impl Default for Rule001HeadingCase {
-    fn default() -> Self {
-        Self {
-            may_uppercase: Vec::new(),
-            may_lowercase: Vec::new(),
-            next_word_capital: RefCell::new(Capitalize::True),
-        }
-    }
 }
 
 impl Rule for Rule001HeadingCase {
@@ -64,6 +74,10 @@ impl Rule for Rule001HeadingCase {
         LintLevel::Error
     }
 
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Heading])
+    }
+
     fn setup(&mut self, settings: Option<&mut RuleSettings>) {
         if let Some(settings) = settings {
             let regex_settings = RegexSettings {
@@ -71,6 +85,24 @@ impl Rule for Rule001HeadingCase {
                 ending: Some(RegexEnding::WordBoundary),
             };
 
+            if let Some(preset_names) = settings.get_array_of_strings("presets") {
+                for name in preset_names {
+                    match get_preset(&name) {
+                        Some(preset) => {
+                            settings.extend_array_of_strings(
+                                "may_uppercase",
+                                preset.may_uppercase.iter().copied(),
+                            );
+                            settings.extend_array_of_strings(
+                                "may_lowercase",
+                                preset.may_lowercase.iter().copied(),
+                            );
+                        }
+                        None => warn!("Unknown preset for {}: {name}", self.name()),
+                    }
+                }
+            }
+
             if let Some(vec) = settings.get_array_of_regexes("may_uppercase", Some(&regex_settings))
             {
                 self.may_uppercase = vec;
@@ -82,17 +114,32 @@ impl Rule for Rule001HeadingCase {
         }
     }
 
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "presets": {
+                "type": "array",
+                "items": { "type": "string", "enum": preset_names() },
+                "description": "Named sets of exceptions to enable.",
+            },
+            "may_uppercase": string_array_schema(
+                "Regex patterns for words that may be capitalized anywhere in a heading."
+            ),
+            "may_lowercase": string_array_schema(
+                "Regex patterns for words that may be lowercase at the start of a heading."
+            ),
+        }))
+    }
+
     fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
         if !matches!(ast, Node::Heading(_)) {
             return None;
         };
 
-        self.reset_mutable_state();
-
         let mut fixes: Option<Vec<LintCorrection>> = None;
-        self.check_ast(ast, &mut fixes, context);
+        self.check_ast(ast, &mut Capitalize::True, &mut fixes, context);
         fixes
             .and_then(|fixes| {
+                let capitalized = capitalized_phrases(&fixes, context);
                 LintError::from_node()
                     .node(ast)
                     .context(context)
@@ -101,6 +148,13 @@ impl Rule for Rule001HeadingCase {
                     .message(&self.message())
                     .fix(fixes)
                     .call()
+                    .map(|error| {
+                        if capitalized.is_empty() {
+                            error
+                        } else {
+                            error.with_details(json!({ "capitalized": capitalized }))
+                        }
+                    })
             })
             .map(|error| vec![error])
     }
@@ -111,90 +165,111 @@ impl Rule001HeadingCase {
         "Heading should be sentence case".to_string()
     }
 
-    fn reset_mutable_state(&self) {
-        self.next_word_capital.replace(Capitalize::True);
+    /// Check that a span of source text, such as a JSX attribute value, is in
+    /// sentence case on its own. Returns the corrections needed, if any.
+    pub(super) fn check_range_sentence_case(
+        &self,
+        range: AdjustedRange,
+        context: &Context,
+    ) -> Option<Vec<LintCorrection>> {
+        let mut fixes = None;
+        self.check_sentence_case(range, &mut Capitalize::True, &mut fixes, context);
+        fixes
     }
 
     fn check_text_sentence_case(
         &self,
         text: &Text,
+        next_word_capital: &mut Capitalize,
         fixes: &mut Option<Vec<LintCorrection>>,
         context: &Context,
     ) {
         if let Some(position) = text.position.as_ref() {
             let range = AdjustedRange::from_unadjusted_position(position, context);
-            let rope = context.rope().byte_slice(Into::<Range<usize>>::into(range));
-
-            let mut word_iterator = WordIterator::new(
-                rope,
-                0,
-                WordIteratorOptions {
-                    initial_capitalize: *self.next_word_capital.borrow(),
-                    capitalize_trigger_punctuation: CapitalizeTriggerPunctuation::PlusColon,
-                    ..Default::default()
-                },
-            );
-
-            let mut first_word = *self.next_word_capital.borrow() == Capitalize::True;
-
-            while let Some((offset, word, cap)) = word_iterator.next() {
-                debug!("Got next word: {word:?} at offset {offset} with capitalization {cap:?}");
-                if word.is_empty() {
-                    continue;
-                }
+            self.check_sentence_case(range, next_word_capital, fixes, context);
+        }
+    }
 
-                match cap {
-                    Capitalize::True => {
-                        if word.chars().next().unwrap().is_lowercase()
-                            && !self.handle_exception_match(
-                                rope.byte_slice(offset..),
-                                offset,
-                                cap,
-                                &mut word_iterator,
-                            )
-                        {
-                            self.create_text_lint_fix(
-                                word.to_string(),
-                                text,
-                                offset,
-                                cap,
-                                context,
-                                fixes,
-                            );
-                        } else if first_word {
-                            self.handle_exception_match(
-                                rope.byte_slice(offset..),
-                                offset,
-                                Capitalize::False,
-                                &mut word_iterator,
-                            );
-                        }
+    /// Check the words in a range, starting with the capitalization that
+    /// `next_word_capital` expects. Afterwards, `next_word_capital` holds the
+    /// capitalization expected of the word that follows the range, so that a
+    /// heading split across several nodes is checked as one sentence.
+    fn check_sentence_case(
+        &self,
+        range: AdjustedRange,
+        next_word_capital: &mut Capitalize,
+        fixes: &mut Option<Vec<LintCorrection>>,
+        context: &Context,
+    ) {
+        let range_start: usize = range.start.into();
+        let rope = context.rope().byte_slice(Into::<Range<usize>>::into(range));
+
+        let mut word_iterator = WordIterator::new(
+            rope,
+            0,
+            WordIteratorOptions {
+                initial_capitalize: *next_word_capital,
+                capitalize_trigger_punctuation: CapitalizeTriggerPunctuation::PlusColon,
+                ..Default::default()
+            },
+        );
+
+        let mut first_word = *next_word_capital == Capitalize::True;
+
+        while let Some((offset, word, cap)) = word_iterator.next() {
+            debug!("Got next word: {word:?} at offset {offset} with capitalization {cap:?}");
+            if word.is_empty() {
+                continue;
+            }
+
+            match cap {
+                Capitalize::True => {
+                    if word.chars().next().unwrap().is_lowercase()
+                        && !self.handle_exception_match(
+                            rope.byte_slice(offset..),
+                            offset,
+                            cap,
+                            &mut word_iterator,
+                        )
+                    {
+                        self.create_text_lint_fix(
+                            word.to_string(),
+                            range_start + offset,
+                            cap,
+                            context,
+                            fixes,
+                        );
+                    } else if first_word {
+                        self.handle_exception_match(
+                            rope.byte_slice(offset..),
+                            offset,
+                            Capitalize::False,
+                            &mut word_iterator,
+                        );
                     }
-                    Capitalize::False => {
-                        if word.chars().next().unwrap().is_uppercase()
-                            && !self.handle_exception_match(
-                                rope.byte_slice(offset..),
-                                offset,
-                                cap,
-                                &mut word_iterator,
-                            )
-                        {
-                            self.create_text_lint_fix(
-                                word.to_string(),
-                                text,
-                                offset,
-                                cap,
-                                context,
-                                fixes,
-                            );
-                        }
+                }
+                Capitalize::False => {
+                    if word.chars().next().unwrap().is_uppercase()
+                        && !self.handle_exception_match(
+                            rope.byte_slice(offset..),
+                            offset,
+                            cap,
+                            &mut word_iterator,
+                        )
+                    {
+                        self.create_text_lint_fix(
+                            word.to_string(),
+                            range_start + offset,
+                            cap,
+                            context,
+                            fixes,
+                        );
                     }
                 }
-
-                first_word = false;
-                self.next_word_capital
-                    .replace(word_iterator.next_capitalize().unwrap());
             }
+
+            first_word = false;
+            *next_word_capital = word_iterator.next_capitalize().unwrap();
         }
     }
 
@@ -235,8 +310,7 @@ impl Rule001HeadingCase {
     fn create_text_lint_fix(
         &self,
         word: String,
-        node: &Text,
-        offset: usize,
+        start: usize,
         capitalize: Capitalize,
         context: &Context,
         fixes: &mut Option<Vec<LintCorrection>>,
@@ -250,65 +324,96 @@ impl Rule001HeadingCase {
             Capitalize::False => word.to_lowercase(),
         };
 
-        let start_point = node
-            .position
-            .as_ref()
-            .map(|p| AdjustedOffset::from_unist(&p.start, context.content_start_offset()))
-            .map(|mut p| {
-                p.increment(offset);
-                p
-            });
-        let end_point = start_point.map(|mut p| {
-            p.increment(word.len());
-            p
-        });
-
-        if let (Some(start), Some(end)) = (start_point, end_point) {
-            let location = AdjustedRange::new(start, end);
-            let location = DenormalizedLocation::from_offset_range(location, context);
+        let location = AdjustedRange::new(
+            AdjustedOffset::from(start),
+            AdjustedOffset::from(start + word.len()),
+        );
+        let location = DenormalizedLocation::from_offset_range(location, context);
 
-            let fix = LintCorrection::Replace(LintCorrectionReplace {
-                location,
-                text: replacement_word,
-            });
-            fixes.get_or_insert_with(Vec::new).push(fix);
-        }
+        let fix = LintCorrection::Replace(LintCorrectionReplace {
+            location,
+            text: replacement_word,
+        });
+        fixes.get_or_insert_with(Vec::new).push(fix);
     }
 
-    fn check_ast(&self, node: &Node, fixes: &mut Option<Vec<LintCorrection>>, context: &Context) {
-        debug!(
-            "Checking ast for node: {node:?} with next word capital: {:?}",
-            self.next_word_capital
-        );
+    fn check_ast(
+        &self,
+        node: &Node,
+        next_word_capital: &mut Capitalize,
+        fixes: &mut Option<Vec<LintCorrection>>,
+        context: &Context,
+    ) {
+        debug!("Checking ast for node: {node:?} with next word capital: {next_word_capital:?}");
 
         fn check_children<T: HasChildren>(
             rule: &Rule001HeadingCase,
             node: &T,
+            next_word_capital: &mut Capitalize,
             fixes: &mut Option<Vec<LintCorrection>>,
             context: &Context,
         ) {
             node.get_children()
                 .iter()
-                .for_each(|child| rule.check_ast(child, fixes, context));
+                .for_each(|child| rule.check_ast(child, next_word_capital, fixes, context));
         }
 
         match node {
-            Node::Text(text) => self.check_text_sentence_case(text, fixes, context),
-            Node::Emphasis(emphasis) => check_children(self, emphasis, fixes, context),
-            Node::Link(link) => check_children(self, link, fixes, context),
+            Node::Text(text) => {
+                self.check_text_sentence_case(text, next_word_capital, fixes, context)
+            }
+            Node::Emphasis(emphasis) => {
+                check_children(self, emphasis, next_word_capital, fixes, context)
+            }
+            Node::Link(link) => check_children(self, link, next_word_capital, fixes, context),
             Node::LinkReference(link_reference) => {
-                check_children(self, link_reference, fixes, context)
+                check_children(self, link_reference, next_word_capital, fixes, context)
+            }
+            Node::Strong(strong) => check_children(self, strong, next_word_capital, fixes, context),
+            Node::Heading(heading) => {
+                check_children(self, heading, next_word_capital, fixes, context)
             }
-            Node::Strong(strong) => check_children(self, strong, fixes, context),
-            Node::Heading(heading) => check_children(self, heading, fixes, context),
             Node::InlineCode(_) => {
-                self.next_word_capital.replace(Capitalize::False);
+                *next_word_capital = Capitalize::False;
             }
             _ => {}
         }
     }
 }
 
+/// The runs of words that the fixes lowercase, as written in the heading. For
+/// example, "Edge Functions" in "Deploy Edge Functions". Used to suggest
+/// `may_uppercase` exceptions.
+fn capitalized_phrases(fixes: &[LintCorrection], context: &Context) -> Vec<String> {
+    let mut phrases: Vec<String> = Vec::new();
+    let mut prev_end: Option<usize> = None;
+    for fix in fixes {
+        let LintCorrection::Replace(replace) = fix else {
+            continue;
+        };
+        let range = replace.location.offset_range.to_usize_range();
+        let word = context.rope().byte_slice(range.clone()).to_string();
+        if word == replace.text || word.to_lowercase() != replace.text {
+            prev_end = None;
+            continue;
+        }
+
+        let joined = prev_end
+            .filter(|end| *end <= range.start)
+            .map(|end| context.rope().byte_slice(end..range.start).to_string())
+            .filter(|between| !between.is_empty() && between.chars().all(|c| c == ' '));
+        match (joined, phrases.last_mut()) {
+            (Some(_), Some(phrase)) => {
+                phrase.push(' ');
+                phrase.push_str(&word);
+            }
+            _ => phrases.push(word),
+        }
+        prev_end = Some(range.end);
+    }
+    phrases
+}
+
 #[cfg(test)]
 mod tests {
     use crate::parser::parse;
@@ -353,13 +458,13 @@ mod tests {
         let errors = result.unwrap();
         assert_eq!(errors.len(), 1);
 
-        let fixes = errors.get(0).unwrap().fix.clone();
+        let fixes = errors.first().unwrap().fix.clone();
         assert!(fixes.is_some());
 
         let fixes = fixes.unwrap();
         assert_eq!(fixes.len(), 1);
 
-        let fix = fixes.get(0).unwrap();
+        let fix = fixes.first().unwrap();
         match fix {
             LintCorrection::Replace(fix) => {
                 assert_eq!(fix.text, "This");
@@ -394,13 +499,13 @@ mod tests {
         let errors = result.unwrap();
         assert_eq!(errors.len(), 1);
 
-        let fixes = errors.get(0).unwrap().fix.clone();
+        let fixes = errors.first().unwrap().fix.clone();
         assert!(fixes.is_some());
 
         let fixes = fixes.unwrap();
         assert_eq!(fixes.len(), 2);
 
-        let fix_one = fixes.get(0).unwrap();
+        let fix_one = fixes.first().unwrap();
         match fix_one {
             LintCorrection::Replace(fix) => {
                 assert_eq!(fix.text, "should");
@@ -429,6 +534,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rule001_capitalized_details() {
+        let rule = Rule001HeadingCase::default();
+        let mdx = "# deploy Edge Functions with Realtime";
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let errors = rule
+            .check(
+                parse_result.ast().children().unwrap().first().unwrap(),
+                &context,
+                LintLevel::Error,
+            )
+            .unwrap();
+        assert_eq!(
+            errors[0].details(),
+            Some(&json!({ "capitalized": ["Edge Functions", "Realtime"] }))
+        );
+    }
+
     #[test]
     fn test_rule001_may_uppercase() {
         let mut rule = Rule001HeadingCase::default();
@@ -598,11 +726,11 @@ mod tests {
         let result = result.unwrap();
         assert_eq!(result.len(), 1);
 
-        let error = result.get(0).unwrap();
+        let error = result.first().unwrap();
         assert_eq!(error.fix.as_ref().unwrap().len(), 1);
 
         let fixes = error.fix.clone().unwrap();
-        let fix = fixes.get(0).unwrap();
+        let fix = fixes.first().unwrap();
         match fix {
             LintCorrection::Replace(fix) => {
                 assert_eq!(fix.text, "api");
@@ -826,8 +954,8 @@ mod tests {
             )
             .unwrap();
 
-        let fixes = result.get(0).unwrap().fix.as_ref().unwrap();
-        let fix = fixes.get(0).unwrap();
+        let fixes = result.first().unwrap().fix.as_ref().unwrap();
+        let fix = fixes.first().unwrap();
         match fix {
             LintCorrection::Replace(fix) => {
                 assert_eq!(fix.location.start.column, 8);
@@ -855,4 +983,69 @@ mod tests {
         );
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_rule001_tech_acronyms_preset() {
+        let mut rule = Rule001HeadingCase::default();
+        let mut settings = RuleSettings::with_array_of_strings("presets", vec!["tech-acronyms"]);
+        rule.setup(Some(&mut settings));
+
+        let markdown = "## Calling the REST API with JSON payloads";
+        let parse_result = parse(markdown).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let result = rule.check(
+            parse_result.ast().children().unwrap().first().unwrap(),
+            &context,
+            LintLevel::Error,
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_rule001_presets_extend_configured_exceptions() {
+        let mut rule = Rule001HeadingCase::default();
+        let mut settings =
+            RuleSettings::with_array_of_strings("may_uppercase", vec!["Edge Functions"]);
+        settings.extend_array_of_strings("presets", ["product-names", "small-words"]);
+        rule.setup(Some(&mut settings));
+
+        let markdown = "## Edge Functions on GitHub: vs Vercel";
+        let parse_result = parse(markdown).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let result = rule.check(
+            parse_result.ast().children().unwrap().first().unwrap(),
+            &context,
+            LintLevel::Error,
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_rule001_unknown_preset_is_ignored() {
+        let mut rule = Rule001HeadingCase::default();
+        let mut settings = RuleSettings::with_array_of_strings("presets", vec!["not-a-preset"]);
+        rule.setup(Some(&mut settings));
+
+        let markdown = "## Calling the REST API";
+        let parse_result = parse(markdown).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let result = rule.check(
+            parse_result.ast().children().unwrap().first().unwrap(),
+            &context,
+            LintLevel::Error,
+        );
+        assert!(result.is_some());
+    }
 }
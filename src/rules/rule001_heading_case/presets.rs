@@ -0,0 +1,127 @@
+/// A maintained set of exceptions that can be enabled for
+/// [`Rule001HeadingCase`](super::Rule001HeadingCase) by name, instead of
+/// listing every pattern in the rule configuration.
+///
+/// Patterns are regexes, and are processed exactly like the user-provided
+/// `may_uppercase` and `may_lowercase` patterns.
+#[derive(Debug)]
+pub(super) struct Preset {
+    pub(super) name: &'static str,
+    pub(super) may_uppercase: &'static [&'static str],
+    pub(super) may_lowercase: &'static [&'static str],
+}
+
+const TECH_ACRONYMS: Preset = Preset {
+    name: "tech-acronyms",
+    may_uppercase: &[
+        "ACID", "AI", "APIs?", "ARM", "AST", "CDNs?", "CI/CD", "CI", "CLIs?", "CORS", "CPUs?",
+        "CRUD", "CSS", "CSV", "DDL", "DML", "DNS", "ESM", "FAQs?", "GPUs?", "GraphQL", "HTML",
+        "HTTPS?", "IDEs?", "IPv4", "IPv6", "IP", "JSON", "JWTs?", "LLMs?", "MDX", "MFA", "OAuth",
+        "OIDC", "ORMs?", "OTP", "PKCE", "PR", "RAM", "REST", "RLS", "RPCs?", "S3", "SAML", "SDKs?",
+        "SMS", "SMTP", "SQL", "SSH", "SSL", "SSO", "SSR", "TCP", "TLS", "TOTP", "TTL", "UIs?",
+        "URLs?", "URIs?", "UTC", "UUIDs?", "UX", "VPC", "WAL", "XML", "YAML",
+    ],
+    may_lowercase: &[],
+};
+
+const PRODUCT_NAMES: Preset = Preset {
+    name: "product-names",
+    may_uppercase: &[
+        "Android",
+        "Angular",
+        "Apple",
+        "AWS",
+        "Azure",
+        "Bitbucket",
+        "Cloudflare",
+        "Deno",
+        "Discord",
+        "Docker",
+        "Expo",
+        "Facebook",
+        "Firebase",
+        "Flutter",
+        "Git",
+        "GitHub",
+        "GitLab",
+        "Google",
+        "Heroku",
+        "Ionic",
+        "Java",
+        "JavaScript",
+        "Kotlin",
+        "Kubernetes",
+        "Linux",
+        "Microsoft",
+        "MySQL",
+        "Netlify",
+        "Next\\.js",
+        "Node\\.js",
+        "Nuxt",
+        "OpenAI",
+        "Postgres",
+        "PostgreSQL",
+        "Prisma",
+        "Python",
+        "React Native",
+        "React",
+        "Redis",
+        "Remix",
+        "Rust",
+        "Slack",
+        "Stripe",
+        "Supabase",
+        "Svelte",
+        "SvelteKit",
+        "Swift",
+        "Terraform",
+        "TypeScript",
+        "Vercel",
+        "Vite",
+        "Vue",
+        "Windows",
+    ],
+    may_lowercase: &["iOS", "iPadOS", "macOS", "npm", "pnpm", "pgvector", "psql"],
+};
+
+const SMALL_WORDS: Preset = Preset {
+    name: "small-words",
+    may_uppercase: &[],
+    may_lowercase: &["and", "e\\.g\\.", "etc\\.", "i\\.e\\.", "or", "vs\\.?"],
+};
+
+const PRESETS: &[Preset] = &[TECH_ACRONYMS, PRODUCT_NAMES, SMALL_WORDS];
+
+pub(super) fn get_preset(name: &str) -> Option<&'static Preset> {
+    PRESETS.iter().find(|preset| preset.name == name)
+}
+
+pub(super) fn preset_names() -> Vec<&'static str> {
+    PRESETS.iter().map(|preset| preset.name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+
+    #[test]
+    fn test_presets_are_valid_regexes() {
+        for preset in PRESETS {
+            for pattern in preset.may_uppercase.iter().chain(preset.may_lowercase) {
+                assert!(
+                    Regex::new(pattern).is_ok(),
+                    "Invalid pattern {pattern} in preset {}",
+                    preset.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_preset() {
+        assert!(get_preset("tech-acronyms").is_some());
+        assert!(get_preset("not-a-preset").is_none());
+    }
+}
@@ -1,24 +1,35 @@
 use log::{trace, warn};
 use markdown::mdast::Node;
 use regex::Regex;
+use serde_json::{json, Value};
 use supa_mdx_macros::RuleName;
 
 use crate::{
     context::Context,
     errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
     location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::edit_distance,
 };
 
-use super::{Rule, RuleName, RuleSettings};
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+/// Maximum edit distance for an unknown admonition type to be autofixed to an
+/// allowed type.
+const MAX_FIX_DISTANCE: usize = 2;
 
 /// Admonitions must have a single valid type.
 ///
+/// Unknown types that are close to an allowed type, such as typos or
+/// abbreviations like `warn` for `warning`, are autofixed to that type.
+///
 /// ## Configuration
 ///
 /// Valid admonition types are enumerated in the `admonition_types` array.
 ///
 /// See an  [example from the Supabase repo](https://github.com/supabase/supabase/blob/master/supa-mdx-lint.config.toml#L12).
 #[derive(Debug, Default, RuleName)]
+#[rule_category(Correctness)]
 pub struct Rule002AdmonitionTypes {
     admonition_types: Vec<String>,
 }
@@ -28,6 +39,10 @@ impl Rule for Rule002AdmonitionTypes {
         LintLevel::Error
     }
 
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::MdxJsxFlowElement])
+    }
+
     fn setup(&mut self, settings: Option<&mut RuleSettings>) {
         if let Some(settings) = settings {
             if let Some(vec) = settings.get_array_of_strings("admonition_types") {
@@ -36,6 +51,12 @@ impl Rule for Rule002AdmonitionTypes {
         }
     }
 
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "admonition_types": string_array_schema("Allowed admonition types."),
+        }))
+    }
+
     fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
         if !matches!(ast, Node::MdxJsxFlowElement(_)) {
             return None;
@@ -57,6 +78,26 @@ impl Rule002AdmonitionTypes {
         }
     }
 
+    /// Find the allowed type closest to an unknown one, if any is close enough
+    /// to be a likely typo or abbreviation.
+    fn closest_type(&self, type_name: &str) -> Option<&str> {
+        let type_name = type_name.trim().to_lowercase();
+        if type_name.is_empty() {
+            return None;
+        }
+
+        self.admonition_types
+            .iter()
+            .filter_map(|allowed| {
+                let distance = edit_distance(&type_name, allowed);
+                let is_abbreviation =
+                    allowed.starts_with(&type_name) || type_name.starts_with(allowed.as_str());
+                (distance <= MAX_FIX_DISTANCE || is_abbreviation).then_some((distance, allowed))
+            })
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, allowed)| allowed.as_str())
+    }
+
     fn check_ast(&self, node: &Node, context: &Context, level: LintLevel) -> Option<LintError> {
         trace!("Checking AST for node: {node:#?}");
 
@@ -130,9 +171,8 @@ impl Rule002AdmonitionTypes {
         if let Some(node_source) = node_source {
             match Regex::new(r#"\b(type)\s*=\s*["']"#) {
                 Ok(type_regex) => {
-                    if let Some(match_result) =
-                        type_regex.captures(&node_source).and_then(|cap| cap.get(1))
-                    {
+                    if let Some(captures) = type_regex.captures(&node_source) {
+                        let match_result = captures.get(1).unwrap();
                         let mut start_point = AdjustedOffset::from_unist(
                             &node.position().unwrap().start,
                             context.content_start_offset(),
@@ -144,12 +184,31 @@ impl Rule002AdmonitionTypes {
                         let range = AdjustedRange::new(start_point, end_point);
                         let location = DenormalizedLocation::from_offset_range(range, context);
 
+                        let fix = self.closest_type(type_name).map(|closest_type| {
+                            let mut value_start = AdjustedOffset::from_unist(
+                                &node.position().unwrap().start,
+                                context.content_start_offset(),
+                            );
+                            value_start.increment(captures.get(0).unwrap().end());
+                            let mut value_end = value_start;
+                            value_end.increment(type_name.len());
+
+                            vec![LintCorrection::Replace(LintCorrectionReplace {
+                                location: DenormalizedLocation::from_offset_range(
+                                    AdjustedRange::new(value_start, value_end),
+                                    context,
+                                ),
+                                text: closest_type.to_string(),
+                            })]
+                        });
+
                         return Some(
                             LintError::from_raw_location()
                                 .rule(self.name())
                                 .level(level)
                                 .message(self.message(Some(type_name)))
                                 .location(location)
+                                .maybe_fix(fix)
                                 .call(),
                         );
                     }
@@ -172,7 +231,13 @@ impl Rule002AdmonitionTypes {
 
 #[cfg(test)]
 mod tests {
-    use crate::{context::Context, parser::parse, rules::Rule, LintLevel};
+    use crate::{
+        context::Context,
+        fix::LintCorrection,
+        parser::parse,
+        rules::{Rule, RuleSettings},
+        LintLevel,
+    };
 
     use super::Rule002AdmonitionTypes;
 
@@ -198,13 +263,13 @@ Some text.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
         assert!(result.is_some());
         assert!(result.as_ref().unwrap().len() == 1);
-        let location = &result.as_ref().unwrap().get(0).unwrap().location;
+        let location = &result.as_ref().unwrap().first().unwrap().location;
         assert!(location.start.row == 4);
         assert!(location.start.column == 12);
         assert!(location.end.row == 4);
@@ -229,7 +294,7 @@ Some text.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
@@ -243,8 +308,9 @@ Some text.
 Some text.
 </Admonition>"#;
 
-        let mut rule = Rule002AdmonitionTypes::default();
-        rule.admonition_types = vec!["note".to_string()];
+        let rule = Rule002AdmonitionTypes {
+            admonition_types: vec!["note".to_string()],
+        };
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -256,10 +322,72 @@ Some text.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
         assert!(result.is_none());
     }
+
+    fn check_with_types(mdx: &str, types: Vec<&str>) -> Option<Vec<crate::LintError>> {
+        let mut rule = Rule002AdmonitionTypes::default();
+        let mut settings = RuleSettings::with_array_of_strings("admonition_types", types);
+        rule.setup(Some(&mut settings));
+
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let admonition = context
+            .parse_result
+            .ast()
+            .children()
+            .unwrap()
+            .first()
+            .unwrap();
+        rule.check(admonition, &context, LintLevel::Error)
+    }
+
+    #[test]
+    fn test_admonition_types_fix_to_closest_type() {
+        let mdx = r#"<Admonition type="cuation">
+Some text.
+</Admonition>"#;
+
+        let result = check_with_types(mdx, vec!["note", "caution", "danger"]).unwrap();
+        assert_eq!(result.len(), 1);
+        match result[0].fix.as_ref().unwrap().first().unwrap() {
+            LintCorrection::Replace(fix) => {
+                assert_eq!(fix.text, "caution");
+                assert_eq!(fix.location.offset_range.to_usize_range(), 18..25);
+            }
+            _ => panic!("Unexpected fix type"),
+        }
+    }
+
+    #[test]
+    fn test_admonition_types_fix_abbreviation() {
+        let mdx = r#"<Admonition type='warn'>
+Some text.
+</Admonition>"#;
+
+        let result = check_with_types(mdx, vec!["note", "warning"]).unwrap();
+        match result[0].fix.as_ref().unwrap().first().unwrap() {
+            LintCorrection::Replace(fix) => assert_eq!(fix.text, "warning"),
+            _ => panic!("Unexpected fix type"),
+        }
+    }
+
+    #[test]
+    fn test_admonition_types_no_fix_without_close_type() {
+        let mdx = r#"<Admonition type="notfound">
+Some text.
+</Admonition>"#;
+
+        let result = check_with_types(mdx, vec!["note", "caution", "danger"]).unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(result[0].fix.is_none());
+    }
 }
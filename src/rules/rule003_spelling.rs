@@ -1,15 +1,15 @@
 use std::{
     borrow::Cow,
-    cell::RefCell,
     collections::{HashMap, HashSet},
     ops::Range,
-    rc::Rc,
+    sync::{Arc, LazyLock, Mutex},
 };
 
 use crop::RopeSlice;
 use log::{debug, trace};
 use markdown::mdast;
 use regex::Regex;
+use serde_json::{json, Value};
 use suggestions::SuggestionMatcher;
 use supa_mdx_macros::RuleName;
 
@@ -30,12 +30,34 @@ use crate::{
     LintLevel,
 };
 
-use super::{RegexBeginning, RegexEnding, RegexSettings, Rule, RuleName, RuleSettings};
+use super::{
+    object_schema, rule039_draft_markers::DRAFT_MARKERS, string_array_schema, NodeKind,
+    RegexBeginning, RegexEnding, RegexSettings, Rule, RuleName, RuleSettings,
+};
 
 mod suggestions;
 
 const DICTIONARY: &str = include_str!("./rule003_spelling/dictionary.txt");
 
+/// Dictionary words, with how often they occur in the corpus the dictionary
+/// was built from. Parsed once, and shared by every instance of the rule.
+static DICTIONARY_WORDS: LazyLock<HashMap<String, u64>> = LazyLock::new(|| {
+    DICTIONARY
+        .lines()
+        .map(|line| {
+            let (word, frequency) = line
+                .split_once(' ')
+                .expect("Every line in static dictionary file should have a space");
+            (word.to_owned(), frequency.parse().unwrap_or_default())
+        })
+        .collect()
+});
+
+/// The shortest word that a misspelling is split into when suggesting a
+/// compound split. Shorter words, such as "he" or "lo", match too many
+/// misspellings by chance.
+const MIN_COMPOUND_PART_LEN: usize = 3;
+
 #[derive(Debug, Clone)]
 enum HyphenatedPart {
     MaybePrefix,
@@ -50,7 +72,7 @@ struct LintTimeVocabAllowed(HashMap<String, Vec<MaybeEndedLineRange>>);
 /// There are four ways to deal with words that are flagged, even though you're spelling them as intended:
 ///
 /// 1. For proper nouns and jargon, you can add them to the [Vocabulary](#vocabulary).
-/// 2. For function, method, and variable names, you can format them as inline code. For example, instead of `foo`, write `` `foo` ``.
+/// 2. For function, method, and variable names, you can format them as inline code. For example, instead of `foo`, write `` `foo` ``. Words that look like identifiers are skipped anyway, see [Identifiers](#identifiers).
 /// 3. You can add a temporary configuration, which will take effect for either the next line or the rest of the file. This configuration adds the specified words to the vocabulary temporarily. Words added are case-sensitive.
 ///    ```markdown
 ///    {/* supa-mdx-lint-configure Rule003Spelling +Supabase */}
@@ -62,6 +84,12 @@ struct LintTimeVocabAllowed(HashMap<String, Vec<MaybeEndedLineRange>>);
 ///    {/* supa-mdx-lint-disable-next-line Rule003Spelling */}
 ///    ```
 ///
+/// Misspelled words come with suggested corrections. Words that are missing a
+/// space, such as `databasemigration`, are also suggested as two words.
+///
+/// Draft markers such as `TODO` and `FIXME` are left to
+/// `Rule039DraftMarkers`.
+///
 /// ## Examples
 ///
 /// ### Valid
@@ -84,12 +112,31 @@ struct LintTimeVocabAllowed(HashMap<String, Vec<MaybeEndedLineRange>>);
 /// - `prefixes`: A list of prefixes that are not standalone words, but that can be used in a prefix before a hyphen (e.g., `pre`, `bi`).
 ///
 /// See an  [example from the Supabase repo](https://github.com/supabase/supabase/blob/master/supa-mdx-lint/Rule003Spelling.toml).
+///
+/// ## Identifiers
+///
+/// Words that aren't in the dictionary, but look like code identifiers, are
+/// skipped:
+///
+/// - camelCase, such as `getUserData`
+/// - PascalCase with a capital inside the word, such as `XMLHttpRequest`
+/// - dotted paths, such as `auth.users` or `supabase.auth.signUp`
+///
+/// Words containing underscores or digits, such as `snake_case` or
+/// `SCREAMING_CASE`, are never checked. To check identifier-like words too,
+/// turn the heuristic off with `check_identifiers`:
+///
+/// ```toml
+/// [Rule003Spelling]
+/// check_identifiers = true
+/// ```
 #[derive(Default, RuleName)]
+#[rule_category(Correctness)]
 pub struct Rule003Spelling {
     allow_list: Vec<Regex>,
     prefixes: HashSet<String>,
-    dictionary: HashSet<String>,
-    config_cache: Rc<RefCell<LruCache<ContextId, Option<LintTimeVocabAllowed>>>>,
+    check_identifiers: bool,
+    config_cache: Arc<Mutex<LruCache<ContextId, Option<LintTimeVocabAllowed>>>>,
     suggestion_matcher: SuggestionMatcher,
 }
 
@@ -98,8 +145,8 @@ impl std::fmt::Debug for Rule003Spelling {
         f.debug_struct("Rule003Spelling")
             .field("allow_list", &self.allow_list)
             .field("prefixes", &self.prefixes)
+            .field("check_identifiers", &self.check_identifiers)
             .field("configuration_cache", &self.config_cache)
-            .field("dictionary", &"[OMITTED (too large)]")
             .finish()
     }
 }
@@ -109,6 +156,10 @@ impl Rule for Rule003Spelling {
         LintLevel::Error
     }
 
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Text])
+    }
+
     fn setup(&mut self, settings: Option<&mut RuleSettings>) {
         if let Some(settings) = settings {
             if let Some(vec) = settings.get_array_of_regexes(
@@ -124,11 +175,33 @@ impl Rule for Rule003Spelling {
             if let Some(vec) = settings.get_array_of_strings("prefixes") {
                 self.prefixes = HashSet::from_iter(vec);
             }
+
+            if let Some(check_identifiers) =
+                settings.get_deserializable::<bool>("check_identifiers")
+            {
+                self.check_identifiers = check_identifiers;
+            }
         }
 
         self.setup_dictionary();
     }
 
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "allow_list": string_array_schema(
+                "Regex patterns for words that are spelled correctly."
+            ),
+            "prefixes": string_array_schema(
+                "Prefixes that may be attached to dictionary words, such as `pre`."
+            ),
+            "check_identifiers": {
+                "type": "boolean",
+                "default": false,
+                "description": "Check words that look like code identifiers, such as camelCase names and dotted paths, instead of skipping them.",
+            },
+        }))
+    }
+
     fn check(
         &self,
         ast: &mdast::Node,
@@ -145,18 +218,7 @@ impl Rule003Spelling {
     }
 
     fn setup_dictionary(&mut self) {
-        let mut set: HashSet<String> = HashSet::new();
-        DICTIONARY
-            .lines()
-            .map(|line| {
-                line.split_once(' ')
-                    .expect("Every line in static dictionary file should have a space")
-                    .0
-            })
-            .for_each(|word| {
-                set.insert(word.to_owned());
-            });
-        self.dictionary = set;
+        LazyLock::force(&DICTIONARY_WORDS);
 
         let custom_words = self
             .allow_list
@@ -186,7 +248,7 @@ impl Rule003Spelling {
     ///    {/* supa-mdx-lint-configure-next-line Rule003Spelling +Supabase */}
     ///    ```
     fn parse_lint_time_config(&self, cache_key: &ContextId, config: &LintTimeRuleConfigs) {
-        if self.config_cache.borrow().contains_key(cache_key) {
+        if self.config_cache.lock().unwrap().contains_key(cache_key) {
             return;
         }
 
@@ -204,7 +266,8 @@ impl Rule003Spelling {
             LintTimeVocabAllowed(map)
         });
         self.config_cache
-            .borrow_mut()
+            .lock()
+            .unwrap()
             .insert(cache_key.clone(), map);
     }
 
@@ -213,7 +276,8 @@ impl Rule003Spelling {
         F: FnOnce(&LintTimeVocabAllowed) -> R,
     {
         self.config_cache
-            .borrow_mut()
+            .lock()
+            .unwrap()
             .get(cache_key)?
             .as_ref()
             .map(f)
@@ -238,6 +302,9 @@ impl Rule003Spelling {
                 self.parse_lint_time_config(&context.key, &context.lint_time_rule_configs);
 
                 let range = AdjustedRange::from_unadjusted_position(position, context);
+                if context.is_skipped(&range) {
+                    return None;
+                }
                 let text = context
                     .rope()
                     .byte_slice(Into::<Range<usize>>::into(range.clone()));
@@ -386,7 +453,12 @@ impl Rule003Spelling {
 
         let suggestions = match hyphenation {
             None => {
-                let suggestions = self.suggestion_matcher.suggest(word);
+                let mut suggestions = self.compound_split(word).into_iter().collect::<Vec<_>>();
+                for suggestion in self.suggestion_matcher.suggest(word) {
+                    if !suggestions.contains(&suggestion) {
+                        suggestions.push(suggestion);
+                    }
+                }
                 if suggestions.is_empty() {
                     None
                 } else {
@@ -409,14 +481,13 @@ impl Rule003Spelling {
             Some(_) => None,
         };
 
-        let error = LintError::builder()
+        let error = LintError::from_raw_location()
             .rule(self.name())
             .message(Rule003Spelling::message(word))
             .level(level)
-            .location(location)
-            .context(context)
+            .location(DenormalizedLocation::from_offset_range(location, context))
             .maybe_suggestions(suggestions)
-            .build();
+            .call();
         errors.get_or_insert_with(Vec::new).push(error);
     }
 
@@ -433,8 +504,13 @@ impl Rule003Spelling {
             return true;
         }
 
+        if DRAFT_MARKERS.contains(&word) {
+            return true;
+        }
+
+        let original = word;
         let word = Self::normalize_word(word);
-        if self.dictionary.contains(word.as_ref()) {
+        if DICTIONARY_WORDS.contains_key(word.as_ref()) {
             return true;
         }
 
@@ -444,6 +520,56 @@ impl Rule003Spelling {
             }
         }
 
+        !self.check_identifiers && Self::is_identifier(original)
+    }
+
+    /// The most likely split of a word into two dictionary words, for words
+    /// that are missing a space, such as "databasemigration". Splits are
+    /// ranked by the frequency of their rarer word.
+    fn compound_split(&self, word: &str) -> Option<String> {
+        if word.len() < 2 * MIN_COMPOUND_PART_LEN || !word.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return None;
+        }
+
+        (MIN_COMPOUND_PART_LEN..=word.len() - MIN_COMPOUND_PART_LEN)
+            .filter_map(|idx| {
+                let (first, second) = word.split_at(idx);
+                let first_frequency = DICTIONARY_WORDS.get(&first.to_lowercase())?;
+                let second_frequency = DICTIONARY_WORDS.get(&second.to_lowercase())?;
+                Some((
+                    first_frequency.min(second_frequency),
+                    format!("{first} {second}"),
+                ))
+            })
+            .max_by_key(|(frequency, _)| *frequency)
+            .map(|(_, split)| split)
+    }
+
+    /// Whether the word looks like a code identifier rather than prose:
+    /// camelCase, PascalCase with a capital inside the word, or a dotted path.
+    fn is_identifier(word: &str) -> bool {
+        let segments = word.split('.').collect::<Vec<_>>();
+        if segments.len() > 1 {
+            return segments.iter().all(|segment| {
+                !segment.is_empty() && segment.chars().all(|c| c.is_ascii_alphabetic())
+            });
+        }
+
+        let mut chars = word.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        if !first.is_ascii_alphabetic() {
+            return false;
+        }
+        let mut prev = first;
+        for c in chars {
+            if c.is_ascii_uppercase() && (first.is_ascii_lowercase() || prev.is_ascii_lowercase()) {
+                return true;
+            }
+            prev = c;
+        }
         false
     }
 
@@ -477,7 +603,7 @@ impl Rule003Spelling {
         AdjustedRange::new(start, end)
     }
 
-    fn normalize_word(word: &str) -> Cow<str> {
+    fn normalize_word(word: &str) -> Cow<'_, str> {
         let mut word = Cow::Borrowed(word);
 
         let quote_chars = ['‘', '’', '“', '”'];
@@ -545,11 +671,11 @@ mod tests {
                 .ast()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap(),
             &context,
             LintLevel::Error,
@@ -575,11 +701,11 @@ mod tests {
                     .ast()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap(),
                 &context,
                 LintLevel::Error,
@@ -593,6 +719,38 @@ mod tests {
         assert_eq!(error.location.offset_range.end, AdjustedOffset::from(5));
     }
 
+    #[test]
+    fn test_rule003_leaves_draft_markers_to_rule039() {
+        let mdx = "TODO: check the FIXME and todoo notes.";
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let mut rule = Rule003Spelling::default();
+        rule.setup(None);
+
+        let errors = rule
+            .check(
+                parse_result
+                    .ast()
+                    .children()
+                    .unwrap()
+                    .first()
+                    .unwrap()
+                    .children()
+                    .unwrap()
+                    .first()
+                    .unwrap(),
+                &context,
+                LintLevel::Error,
+            )
+            .unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Word not found in dictionary: todoo");
+    }
+
     #[test]
     fn test_rule003_with_exception() {
         let mdx = "heloo world";
@@ -611,11 +769,11 @@ mod tests {
                 .ast()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap(),
             &context,
             LintLevel::Error,
@@ -641,11 +799,11 @@ mod tests {
                 .ast()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap(),
             &context,
             LintLevel::Error,
@@ -671,11 +829,11 @@ mod tests {
                 .ast()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap(),
             &context,
             LintLevel::Error,
@@ -701,11 +859,11 @@ mod tests {
                     .ast()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap(),
                 &context,
                 LintLevel::Error,
@@ -743,11 +901,11 @@ mod tests {
                     .ast()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap(),
                 &context,
                 LintLevel::Error,
@@ -779,11 +937,11 @@ mod tests {
                     .ast()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap(),
                 &context,
                 LintLevel::Error,
@@ -815,11 +973,11 @@ mod tests {
                 .ast()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap(),
             &context,
             LintLevel::Error,
@@ -845,11 +1003,11 @@ mod tests {
                 .ast()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap(),
             &context,
             LintLevel::Error,
@@ -876,11 +1034,11 @@ mod tests {
                 .ast()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap(),
             &context,
             LintLevel::Error,
@@ -905,11 +1063,11 @@ mod tests {
                 .ast()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap(),
             &context,
             LintLevel::Error,
@@ -935,11 +1093,11 @@ mod tests {
                 .ast()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap()
                 .children()
                 .unwrap()
-                .get(0)
+                .first()
                 .unwrap(),
             &context,
             LintLevel::Error,
@@ -947,6 +1105,96 @@ mod tests {
         assert!(errors.is_none());
     }
 
+    #[test]
+    fn test_rule003_compound_split_suggestions() {
+        let mdx = "Run the databasemigration first.";
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let mut rule = Rule003Spelling::default();
+        rule.setup(None);
+
+        let errors = rule
+            .check(
+                parse_result
+                    .ast()
+                    .children()
+                    .unwrap()
+                    .first()
+                    .unwrap()
+                    .children()
+                    .unwrap()
+                    .first()
+                    .unwrap(),
+                &context,
+                LintLevel::Error,
+            )
+            .unwrap();
+        assert_eq!(errors.len(), 1);
+        let Some(LintCorrection::Replace(replace)) =
+            errors[0].suggestions.as_ref().and_then(|s| s.first())
+        else {
+            panic!("Expected a replace suggestion");
+        };
+        assert_eq!(replace.text, "database migration");
+        assert_eq!(
+            replace.location.offset_range.to_usize_range(),
+            8..8 + "databasemigration".len()
+        );
+
+        assert_eq!(rule.compound_split("heloo"), None);
+        assert_eq!(rule.compound_split("DataBase").unwrap(), "Data Base");
+    }
+
+    #[test]
+    fn test_rule003_identifiers() {
+        let mdx = "Call getUserData or XMLHttpRequest on auth.users, not heloo.";
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+        let text = parse_result
+            .ast()
+            .children()
+            .unwrap()
+            .first()
+            .unwrap()
+            .children()
+            .unwrap()
+            .first()
+            .unwrap();
+
+        let mut rule = Rule003Spelling::default();
+        rule.setup(None);
+        let errors = rule.check(text, &context, LintLevel::Error).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Word not found in dictionary: heloo");
+
+        let mut rule = Rule003Spelling::default();
+        let table: toml::Table = toml::from_str("check_identifiers = true").unwrap();
+        let mut settings = RuleSettings::new(table);
+        rule.setup(Some(&mut settings));
+        let errors = rule.check(text, &context, LintLevel::Error).unwrap();
+        assert_eq!(errors.len(), 4);
+    }
+
+    #[test]
+    fn test_rule003_is_identifier() {
+        assert!(Rule003Spelling::is_identifier("getUserData"));
+        assert!(Rule003Spelling::is_identifier("pgTAP"));
+        assert!(Rule003Spelling::is_identifier("XMLHttpRequest"));
+        assert!(Rule003Spelling::is_identifier("auth.users"));
+        assert!(Rule003Spelling::is_identifier("supabase.auth.signUp"));
+        assert!(!Rule003Spelling::is_identifier("Heloo"));
+        assert!(!Rule003Spelling::is_identifier("HELOO"));
+        assert!(!Rule003Spelling::is_identifier("heloo."));
+        assert!(!Rule003Spelling::is_identifier("heloo"));
+    }
+
     #[test]
     fn test_rule003_suggestions() {
         let mdx = "heloo wrld";
@@ -965,11 +1213,11 @@ mod tests {
                     .ast()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap()
                     .children()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap(),
                 &context,
                 LintLevel::Error,
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{cmp::Reverse, path::PathBuf, sync::LazyLock};
 
 use gag::Gag;
 use symspell::{AsciiStringStrategy, SymSpell, Verbosity};
@@ -9,22 +9,36 @@ const DICTIONARY_PATH: &str = "src/rules/rule003_spelling/dictionary.txt";
 #[cfg(test)]
 const DICTIONARY_PATH: &str = "src/rules/rule003_spelling/test_dictionary.txt";
 
+/// Loading the dictionary precomputes every deletion of every word, which
+/// takes a while, so it's done once and shared by every matcher.
+static DICTIONARY: LazyLock<SymSpell<AsciiStringStrategy>> = LazyLock::new(|| {
+    let mut symspell = SymSpell::default();
+
+    let dictionary_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(DICTIONARY_PATH);
+    // Symspell prints to stderr, which affects the output format and
+    // guarantees of this tool (e.g., silencing). Temporarily redirect
+    // stderr to silence the output.
+    {
+        let _silencer = Gag::stderr();
+        symspell.load_dictionary(dictionary_path.to_str().unwrap(), 0, 1, " ");
+    }
+    symspell
+});
+
 #[derive(Default)]
 pub struct SuggestionMatcher {
-    dictionary: SymSpell<AsciiStringStrategy>,
+    /// The configured exceptions, which are looked up alongside the shared
+    /// dictionary.
+    exceptions: Option<SymSpell<AsciiStringStrategy>>,
 }
 
 impl SuggestionMatcher {
     pub fn new(exceptions: &[impl AsRef<str>]) -> Self {
-        let mut symspell = SymSpell::default();
-
-        let dictionary_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(DICTIONARY_PATH);
-        // Symspell prints to stderr, which affects the output format and
-        // guarantees of this tool (e.g., silencing). Temporarily redirect
-        // stderr to silence the output.
-        {
-            let _silencer = Gag::stderr();
-            symspell.load_dictionary(dictionary_path.to_str().unwrap(), 0, 1, " ");
+        // Load the dictionary during setup, rather than on the first
+        // misspelling.
+        LazyLock::force(&DICTIONARY);
+        if exceptions.is_empty() {
+            return Self { exceptions: None };
         }
 
         // Symspell dictionaries require a frequency to be associated with each
@@ -32,6 +46,7 @@ impl SuggestionMatcher {
         // frequencies, we'll just use a dummy value. This is set relatively
         // high since any custom exceptions are likely to be highly relevant.
         let dummy_frequency = 1_000_000_000;
+        let mut symspell = SymSpell::default();
         for exception in exceptions {
             symspell.load_dictionary_line(
                 &format!("{}\t{}", exception.as_ref(), dummy_frequency),
@@ -42,15 +57,25 @@ impl SuggestionMatcher {
         }
 
         Self {
-            dictionary: symspell,
+            exceptions: Some(symspell),
         }
     }
 
     pub fn suggest(&self, word: &str) -> Vec<String> {
-        self.dictionary
-            .lookup(word, Verbosity::Top, 2)
+        let lookup =
+            |dictionary: &SymSpell<AsciiStringStrategy>| dictionary.lookup(word, Verbosity::Top, 2);
+        let mut suggestions = lookup(&DICTIONARY);
+        if let Some(exceptions) = &self.exceptions {
+            suggestions.extend(lookup(exceptions));
+        }
+
+        // Keep the closest, most frequent suggestion, as a single dictionary
+        // would with `Verbosity::Top`.
+        suggestions
             .into_iter()
+            .min_by_key(|s| (s.distance, Reverse(s.count)))
             .map(|s| s.term)
+            .into_iter()
             .collect()
     }
 }
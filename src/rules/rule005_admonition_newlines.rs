@@ -11,7 +11,7 @@ use crate::{
     location::{AdjustedRange, DenormalizedLocation},
 };
 
-use super::{Rule, RuleName, RuleSettings};
+use super::{NodeKind, Rule, RuleName, RuleSettings};
 
 #[derive(Debug)]
 struct ErrorInfo {
@@ -20,8 +20,7 @@ struct ErrorInfo {
 }
 
 static ADMONITION_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"(?s)<Admonition[^>]*>\s*\r?\n\s*\r?\n.*?\r?\n\s*\r?\n\s*</Admonition>")
-        .unwrap()
+    Regex::new(r"(?s)<Admonition[^>]*>\s*\r?\n\s*\r?\n.*?\r?\n\s*\r?\n\s*</Admonition>").unwrap()
 });
 
 /// Admonition JSX tags must have empty line separation from their content.
@@ -61,6 +60,10 @@ impl Rule for Rule005AdmonitionNewlines {
         LintLevel::Error
     }
 
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::MdxJsxFlowElement])
+    }
+
     fn setup(&mut self, _settings: Option<&mut RuleSettings>) {
         // No configuration options for this rule
     }
@@ -79,7 +82,7 @@ impl Rule for Rule005AdmonitionNewlines {
                         .rule(self.name())
                         .level(level)
                         .message(&error_info.message)
-                        .fix(error_info.fixes)
+                        .maybe_fix((!error_info.fixes.is_empty()).then_some(error_info.fixes))
                         .call()
                         .map(|error| vec![error]);
                 }
@@ -237,7 +240,7 @@ This is the content.
 
 </Admonition>"#;
 
-        let rule = Rule005AdmonitionNewlines::default();
+        let rule = Rule005AdmonitionNewlines;
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -249,7 +252,7 @@ This is the content.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
@@ -265,7 +268,7 @@ This is the content.
 This is the content.
 </Admonition>"#;
 
-        let rule = Rule005AdmonitionNewlines::default();
+        let rule = Rule005AdmonitionNewlines;
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -277,7 +280,7 @@ This is the content.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
@@ -300,7 +303,7 @@ This is the content.
 
 </Admonition>"#;
 
-        let rule = Rule005AdmonitionNewlines::default();
+        let rule = Rule005AdmonitionNewlines;
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -312,7 +315,7 @@ This is the content.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
@@ -335,7 +338,7 @@ This is the content.
 This is the content.
 </Admonition>"#;
 
-        let rule = Rule005AdmonitionNewlines::default();
+        let rule = Rule005AdmonitionNewlines;
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -347,7 +350,7 @@ This is the content.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
@@ -370,7 +373,7 @@ This is the content.
 
 </Admonition>"#;
 
-        let rule = Rule005AdmonitionNewlines::default();
+        let rule = Rule005AdmonitionNewlines;
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -382,7 +385,7 @@ This is the content.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
@@ -418,7 +421,7 @@ This is the content.
 This is the content.
 </Admonition>"#;
 
-        let rule = Rule005AdmonitionNewlines::default();
+        let rule = Rule005AdmonitionNewlines;
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -430,7 +433,7 @@ This is the content.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
@@ -465,7 +468,7 @@ This is the content.
 This is the content.
 </Admonition>"#;
 
-        let rule = Rule005AdmonitionNewlines::default();
+        let rule = Rule005AdmonitionNewlines;
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -477,7 +480,7 @@ This is the content.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
@@ -525,7 +528,7 @@ This is the content.
 
 </Admonition>"#;
 
-        let rule = Rule005AdmonitionNewlines::default();
+        let rule = Rule005AdmonitionNewlines;
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -537,7 +540,7 @@ This is the content.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
@@ -552,7 +555,7 @@ This is the content.
         let mdx =
             r#"<Admonition type="note" label="Data changes are not merged into production." />"#;
 
-        let rule = Rule005AdmonitionNewlines::default();
+        let rule = Rule005AdmonitionNewlines;
         let parse_result = parse(mdx).unwrap();
         let context = Context::builder()
             .parse_result(&parse_result)
@@ -564,7 +567,7 @@ This is the content.
             .ast()
             .children()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap();
         let result = rule.check(admonition, &context, LintLevel::Error);
 
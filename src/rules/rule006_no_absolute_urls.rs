@@ -1,4 +1,5 @@
 use markdown::mdast::{Image, Link, Node};
+use serde_json::{json, Value};
 use supa_mdx_macros::RuleName;
 
 use crate::{
@@ -6,12 +7,17 @@ use crate::{
     errors::{LintError, LintLevel},
     fix::LintCorrectionReplace,
     location::{AdjustedRange, DenormalizedLocation},
+    utils::mdast::{jsx_url_attributes, DEFAULT_JSX_URL_ATTRIBUTES},
 };
 
-use super::{Rule, RuleName, RuleSettings};
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
 
 /// Links and images should use relative URLs instead of absolute URLs that match the configured base URL.
 ///
+/// Besides Markdown links and images, string URL attributes of JSX
+/// components, such as `<Button href="https://supabase.com/docs">`, are
+/// checked.
+///
 /// ## Examples
 ///
 /// ### Valid
@@ -36,9 +42,45 @@ use super::{Rule, RuleName, RuleSettings};
 /// [rule006_no_absolute_urls]
 /// base_url = "https://supabase.com"
 /// ```
-#[derive(Debug, Default, RuleName)]
+///
+/// Each kind of URL can be turned off. All default to true:
+///
+/// - `check_links`: Check Markdown links.
+/// - `check_images`: Check Markdown images.
+/// - `check_jsx_attributes`: Check URL attributes of JSX components.
+///
+/// `jsx_url_attributes` sets the names of the JSX attributes that hold URLs.
+/// Defaults to `href` and `src`.
+///
+/// ```toml
+/// [rule006_no_absolute_urls]
+/// base_url = "https://supabase.com"
+/// check_images = false
+/// jsx_url_attributes = ["href", "src", "to"]
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Links)]
 pub struct Rule006NoAbsoluteUrls {
     base_url: Option<String>,
+    check_links: bool,
+    check_images: bool,
+    check_jsx_attributes: bool,
+    jsx_url_attributes: Vec<String>,
+}
+
+impl Default for Rule006NoAbsoluteUrls {
+    fn default() -> Self {
+        Self {
+            base_url: None,
+            check_links: true,
+            check_images: true,
+            check_jsx_attributes: true,
+            jsx_url_attributes: DEFAULT_JSX_URL_ATTRIBUTES
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
 }
 
 impl Rule for Rule006NoAbsoluteUrls {
@@ -46,59 +88,157 @@ impl Rule for Rule006NoAbsoluteUrls {
         LintLevel::Error
     }
 
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[
+            NodeKind::Link,
+            NodeKind::Image,
+            NodeKind::MdxJsxFlowElement,
+            NodeKind::MdxJsxTextElement,
+        ])
+    }
+
     fn setup(&mut self, settings: Option<&mut RuleSettings>) {
         if let Some(settings) = settings {
             if let Some(toml::Value::String(base_url)) = settings.0.get("base_url") {
                 let base_url = base_url.trim_end_matches('/').to_string();
                 self.base_url = Some(base_url);
             }
+            if let Some(check_links) = settings.get_deserializable::<bool>("check_links") {
+                self.check_links = check_links;
+            }
+            if let Some(check_images) = settings.get_deserializable::<bool>("check_images") {
+                self.check_images = check_images;
+            }
+            if let Some(check_jsx_attributes) =
+                settings.get_deserializable::<bool>("check_jsx_attributes")
+            {
+                self.check_jsx_attributes = check_jsx_attributes;
+            }
+            if let Some(names) = settings.get_array_of_strings("jsx_url_attributes") {
+                self.jsx_url_attributes = names;
+            }
         }
     }
 
-    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
-        let url = match ast {
-            Node::Link(link) => &link.url,
-            Node::Image(image) => &image.url,
-            _ => return None,
-        };
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "base_url": {
+                "type": "string",
+                "description": "Base URL of the site, used to detect absolute links to it.",
+            },
+            "check_links": {
+                "type": "boolean",
+                "default": true,
+                "description": "Check the URLs of Markdown links.",
+            },
+            "check_images": {
+                "type": "boolean",
+                "default": true,
+                "description": "Check the URLs of Markdown images.",
+            },
+            "check_jsx_attributes": {
+                "type": "boolean",
+                "default": true,
+                "description": "Check the URL attributes of JSX components.",
+            },
+            "jsx_url_attributes": string_array_schema(
+                "Names of the JSX attributes that hold URLs. Defaults to `href` and `src`."
+            ),
+        }))
+    }
 
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
         // Skip if no base URL is configured
-        let base_url = self.base_url.as_ref()?;
-
-        if url.starts_with(base_url) {
-            let relative_path = &url[base_url.len()..];
-
-            let relative_path = if relative_path.starts_with('/') {
-                relative_path
-            } else {
-                // This shouldn't happen, but fail gracefully
-                return None;
-            };
-
-            if let Some(url_location) = self.find_url_location(ast, context) {
-                let correction = LintCorrectionReplace {
-                    location: url_location,
-                    text: relative_path.to_string(),
-                };
-
-                let error = LintError::from_node()
-                    .node(ast)
-                    .context(context)
-                    .rule(self.name())
-                    .level(level)
-                    .message(&self.message(url, relative_path))
-                    .fix(vec![crate::fix::LintCorrection::Replace(correction)])
-                    .call();
-
-                return error.map(|err| vec![err]);
+        self.base_url.as_ref()?;
+
+        match ast {
+            Node::Link(Link { url, .. }) if self.check_links => {
+                self.check_node_url(ast, url, context, level)
             }
+            Node::Image(Image { url, .. }) if self.check_images => {
+                self.check_node_url(ast, url, context, level)
+            }
+            Node::MdxJsxFlowElement(_) | Node::MdxJsxTextElement(_)
+                if self.check_jsx_attributes =>
+            {
+                self.check_jsx_attributes(ast, context, level)
+            }
+            _ => None,
         }
-
-        None
     }
 }
 
 impl Rule006NoAbsoluteUrls {
+    /// The path of a URL relative to the base URL, if it's an absolute URL
+    /// on the same site.
+    fn relative_path<'url>(&self, url: &'url str) -> Option<&'url str> {
+        let relative_path = url.strip_prefix(self.base_url.as_deref()?)?;
+        // Anything else is a different host, such as `https://supabase.com.au`.
+        relative_path.starts_with('/').then_some(relative_path)
+    }
+
+    fn check_node_url(
+        &self,
+        ast: &Node,
+        url: &str,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<Vec<LintError>> {
+        let relative_path = self.relative_path(url)?;
+        let url_location = self.find_url_location(ast, context)?;
+        let correction = LintCorrectionReplace {
+            location: url_location,
+            text: relative_path.to_string(),
+        };
+
+        let error = LintError::from_node()
+            .node(ast)
+            .context(context)
+            .rule(self.name())
+            .level(level)
+            .message(&self.message(url, relative_path))
+            .fix(vec![crate::fix::LintCorrection::Replace(correction)])
+            .call();
+
+        error.map(|err| vec![err])
+    }
+
+    fn check_jsx_attributes(
+        &self,
+        ast: &Node,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<Vec<LintError>> {
+        let errors = jsx_url_attributes(ast, &self.jsx_url_attributes, context)
+            .into_iter()
+            .filter_map(|attribute| {
+                let relative_path = self.relative_path(attribute.url)?;
+                let location = DenormalizedLocation::from_offset_range(attribute.range, context);
+
+                Some(
+                    LintError::from_raw_location()
+                        .rule(self.name())
+                        .level(level)
+                        .message(self.message(attribute.url, relative_path))
+                        .location(location.clone())
+                        .fix(vec![crate::fix::LintCorrection::Replace(
+                            LintCorrectionReplace {
+                                location,
+                                text: relative_path.to_string(),
+                            },
+                        )])
+                        .call(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+
     fn message(&self, absolute_url: &str, relative_url: &str) -> String {
         format!(
             "Use relative URL '{}' instead of absolute URL '{}'",
@@ -303,25 +443,25 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert!(errors[0].message.contains("/docs/auth"));
 
-        // Verify the fix would only replace the href part  
+        // Verify the fix would only replace the href part
         assert!(errors[0].fix.is_some(), "Expected fix to be present");
         let fixes = errors[0].fix.as_ref().unwrap();
         assert_eq!(fixes.len(), 1);
         if let crate::fix::LintCorrection::Replace(replace_fix) = &fixes[0] {
             assert_eq!(replace_fix.text(), "/docs/auth");
-            
+
             // Verify the location is correct - should target only the URL in parentheses
             let location = &replace_fix.location;
-            
-            // The original text is "[https://supabase.com](https://supabase.com/docs/auth)"  
+
+            // The original text is "[https://supabase.com](https://supabase.com/docs/auth)"
             // Position of the URL in parentheses starts at index 23 and ends at 53
             // [https://supabase.com](https://supabase.com/docs/auth)
             // 012345678901234567890123456789012345678901234567890123456789
-            //                        ^                             ^  
+            //                        ^                             ^
             //                        23                            53
             let expected_start = 23_usize;
             let expected_end = 53_usize;
-            
+
             let actual_start: usize = location.offset_range.start.into();
             let actual_end: usize = location.offset_range.end.into();
             assert_eq!(actual_start, expected_start);
@@ -377,17 +517,17 @@ mod tests {
         let errors = errors.unwrap();
         assert_eq!(errors.len(), 1);
         assert!(errors[0].message.contains("/logo.png"));
-        
+
         // Verify the fix would only replace the src part
         assert!(errors[0].fix.is_some(), "Expected fix to be present");
         let fixes = errors[0].fix.as_ref().unwrap();
         assert_eq!(fixes.len(), 1);
         if let crate::fix::LintCorrection::Replace(replace_fix) = &fixes[0] {
             assert_eq!(replace_fix.text(), "/logo.png");
-            
+
             // Verify the location is correct - should target only the URL in parentheses
             let location = &replace_fix.location;
-            
+
             // The original text is "![https://supabase.com](https://supabase.com/logo.png)"
             // Position of the URL in parentheses starts at index 24 and ends at 53
             // ![https://supabase.com](https://supabase.com/logo.png)
@@ -396,7 +536,7 @@ mod tests {
             //                         24                           53
             let expected_start = 24_usize;
             let expected_end = 53_usize;
-            
+
             let actual_start: usize = location.offset_range.start.into();
             let actual_end: usize = location.offset_range.end.into();
             assert_eq!(actual_start, expected_start);
@@ -405,4 +545,95 @@ mod tests {
             panic!("Expected Replace correction");
         }
     }
+
+    fn check_all(rule: &Rule006NoAbsoluteUrls, markdown: &str) -> Vec<LintError> {
+        let parse_result = parse(markdown).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+
+        let mut errors = Vec::new();
+        let mut stack = vec![parse_result.ast()];
+        while let Some(node) = stack.pop() {
+            errors.extend(
+                rule.check(node, &context, LintLevel::Error)
+                    .unwrap_or_default(),
+            );
+            if let Some(children) = node.children() {
+                stack.extend(children.iter().rev());
+            }
+        }
+        errors
+    }
+
+    fn setup_rule(options: &[(&str, bool)]) -> Rule006NoAbsoluteUrls {
+        let mut table = toml::Table::new();
+        table.insert(
+            "base_url".to_string(),
+            toml::Value::String("https://supabase.com".to_string()),
+        );
+        for (key, value) in options {
+            table.insert(key.to_string(), toml::Value::Boolean(*value));
+        }
+        let mut rule = Rule006NoAbsoluteUrls::default();
+        rule.setup(Some(&mut super::super::RuleSettings::new(table)));
+        rule
+    }
+
+    #[test]
+    fn test_jsx_attributes() {
+        let rule = setup_rule(&[]);
+        let markdown = r#"<Button href="https://supabase.com/docs" target="_blank">Docs</Button>
+
+Inline <Image src="https://supabase.com/logo.png" /> and <Button href="https://example.com/docs">External</Button>.
+
+<Card title="https://supabase.com/not-a-url-attribute" href={url} />"#;
+        let errors = check_all(&rule, markdown);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0].message,
+            "Use relative URL '/docs' instead of absolute URL 'https://supabase.com/docs'"
+        );
+        assert_eq!(errors[0].offset_range(), 14..39);
+        let Some([crate::fix::LintCorrection::Replace(replace_fix)]) = errors[0].fix.as_deref()
+        else {
+            panic!("Expected a single Replace correction");
+        };
+        assert_eq!(replace_fix.text(), "/docs");
+        assert!(errors[1].message.contains("'/logo.png'"));
+
+        let mut rule = setup_rule(&[]);
+        rule.setup(Some(&mut super::super::RuleSettings::from_key_value(
+            "jsx_url_attributes",
+            toml::Value::Array(vec![toml::Value::String("to".to_string())]),
+        )));
+        let errors = check_all(
+            &rule,
+            r#"<Link to="https://supabase.com/docs" href="https://supabase.com/blog">Docs</Link>"#,
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("'/docs'"));
+    }
+
+    #[test]
+    fn test_check_toggles() {
+        let markdown = r#"[Docs](https://supabase.com/docs)
+
+![Logo](https://supabase.com/logo.png)
+
+<Button href="https://supabase.com/docs">Docs</Button>"#;
+        assert_eq!(check_all(&setup_rule(&[]), markdown).len(), 3);
+
+        let errors = check_all(&setup_rule(&[("check_links", false)]), markdown);
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].message.contains("'/logo.png'"));
+
+        let errors = check_all(&setup_rule(&[("check_images", false)]), markdown);
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|error| error.message.contains("'/docs'")));
+
+        let errors = check_all(&setup_rule(&[("check_jsx_attributes", false)]), markdown);
+        assert_eq!(errors.len(), 2);
+    }
 }
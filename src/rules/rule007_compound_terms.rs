@@ -0,0 +1,345 @@
+use log::warn;
+use markdown::mdast::Node;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const NON_BREAKING_SPACE: char = '\u{a0}';
+
+/// Multi-word product names should be written exactly as configured, with the
+/// configured capitalization and spacing.
+///
+/// ## Examples
+///
+/// ### Valid (assuming `terms` includes `Supabase CLI`)
+///
+/// ```markdown
+/// Install the Supabase CLI to get started.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// Install the supabase cli to get started.
+/// Install the Supabase  CLI to get started.
+/// Install the Supabase
+/// CLI to get started.
+/// ```
+///
+/// ## Configuration
+///
+/// Configure the terms via the `terms` array. Terms can optionally require a
+/// non-breaking space between their words, which prevents them from being
+/// split across lines when rendered. Set `non_breaking_space` at the top level
+/// to require it for all terms, or per term to override it.
+///
+/// ```toml
+/// [Rule007CompoundTerms]
+/// terms = [
+///   "Supabase CLI",
+///   { term = "Edge Functions", non_breaking_space = true },
+/// ]
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule007CompoundTerms {
+    terms: Vec<CompoundTerm>,
+}
+
+#[derive(Debug)]
+struct CompoundTerm {
+    expected: String,
+    pattern: Regex,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CompoundTermSetting {
+    Term(String),
+    Detailed {
+        term: String,
+        non_breaking_space: Option<bool>,
+    },
+}
+
+impl Rule for Rule007CompoundTerms {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Text])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        let default_non_breaking_space = settings
+            .get_deserializable::<bool>("non_breaking_space")
+            .unwrap_or(false);
+        let Some(terms) = settings.get_deserializable::<Vec<CompoundTermSetting>>("terms") else {
+            return;
+        };
+
+        self.terms = terms
+            .into_iter()
+            .filter_map(|setting| {
+                let (term, non_breaking_space) = match setting {
+                    CompoundTermSetting::Term(term) => (term, default_non_breaking_space),
+                    CompoundTermSetting::Detailed {
+                        term,
+                        non_breaking_space,
+                    } => (
+                        term,
+                        non_breaking_space.unwrap_or(default_non_breaking_space),
+                    ),
+                };
+                CompoundTerm::new(&term, non_breaking_space)
+            })
+            .collect();
+        // Check longer terms first, so a term like "Supabase CLI" takes
+        // precedence over a term like "Supabase".
+        self.terms
+            .sort_by_key(|term| std::cmp::Reverse(term.expected.len()));
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "terms": {
+                "type": "array",
+                "description": "Multi-word terms that must be written consistently.",
+                "items": {
+                    "anyOf": [
+                        { "type": "string" },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "term": { "type": "string" },
+                                "non_breaking_space": { "type": "boolean" },
+                            },
+                            "required": ["term"],
+                        },
+                    ],
+                },
+            },
+            "non_breaking_space": {
+                "type": "boolean",
+                "default": false,
+                "description": "Require non-breaking spaces between the words of every term.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Text(text) = ast else {
+            return None;
+        };
+        let position = text.position.as_ref()?;
+        if self.terms.is_empty() {
+            return None;
+        }
+
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let node_start: usize = range.start.into();
+        let source = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+
+        let mut matched_ranges: Vec<std::ops::Range<usize>> = Vec::new();
+        let mut errors = Vec::new();
+        for term in &self.terms {
+            for found in term.pattern.find_iter(&source) {
+                if matched_ranges
+                    .iter()
+                    .any(|matched| matched.start < found.end() && found.start() < matched.end)
+                {
+                    continue;
+                }
+                matched_ranges.push(found.range());
+
+                if found.as_str() == term.expected {
+                    continue;
+                }
+
+                let location = DenormalizedLocation::from_offset_range(
+                    AdjustedRange::new(
+                        AdjustedOffset::from(node_start + found.start()),
+                        AdjustedOffset::from(node_start + found.end()),
+                    ),
+                    context,
+                );
+                let fix = LintCorrection::Replace(LintCorrectionReplace {
+                    location: location.clone(),
+                    text: term.expected.clone(),
+                });
+                errors.push(
+                    LintError::from_raw_location()
+                        .rule(self.name())
+                        .level(level)
+                        .message(self.message(found.as_str(), term))
+                        .location(location)
+                        .fix(vec![fix])
+                        .call(),
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule007CompoundTerms {
+    fn message(&self, found: &str, term: &CompoundTerm) -> String {
+        let display_term = term.expected.replace(NON_BREAKING_SPACE, " ");
+        if found.contains('\n') {
+            format!("Don't split \"{display_term}\" across lines")
+        } else if found.to_lowercase() != term.expected.to_lowercase() {
+            if term.expected.contains(NON_BREAKING_SPACE) {
+                format!("Use non-breaking spaces between the words in \"{display_term}\"")
+            } else {
+                format!("Use single spaces between the words in \"{display_term}\"")
+            }
+        } else {
+            format!("Write \"{display_term}\" with the correct capitalization")
+        }
+    }
+}
+
+impl CompoundTerm {
+    fn new(term: &str, non_breaking_space: bool) -> Option<Self> {
+        let words = term.split_whitespace().collect::<Vec<_>>();
+        if words.is_empty() {
+            warn!("Ignoring empty term in Rule007CompoundTerms configuration");
+            return None;
+        }
+
+        let separator = if non_breaking_space {
+            NON_BREAKING_SPACE.to_string()
+        } else {
+            " ".to_string()
+        };
+        let expected = words.join(&separator);
+
+        // Only require word boundaries at the edges if the term starts or ends
+        // with a word character, since `\b` can't match between two non-word
+        // characters.
+        let is_word_char = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        let pattern = format!(
+            "(?i){}{}{}",
+            if is_word_char(term.trim().chars().next()) {
+                "\\b"
+            } else {
+                ""
+            },
+            words
+                .iter()
+                .map(|word| regex::escape(word))
+                .collect::<Vec<_>>()
+                .join("\\s+"),
+            if is_word_char(term.trim().chars().last()) {
+                "\\b"
+            } else {
+                ""
+            },
+        );
+
+        match Regex::new(&pattern) {
+            Ok(pattern) => Some(Self { expected, pattern }),
+            Err(err) => {
+                warn!("Ignoring invalid term {term} in Rule007CompoundTerms configuration: {err}");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule007_correct_term() {
+        assert_lint_snapshot!(
+            "Rule007CompoundTerms",
+            "Install the Supabase CLI first.\n",
+            Some(json!({ "terms": ["Supabase CLI"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule007_wrong_capitalization() {
+        assert_lint_snapshot!(
+            "Rule007CompoundTerms",
+            "Install the supabase cli first.\n",
+            Some(json!({ "terms": ["Supabase CLI"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule007_split_across_lines() {
+        assert_lint_snapshot!(
+            "Rule007CompoundTerms",
+            "Deploy your Edge\nFunctions today.\n",
+            Some(json!({ "terms": ["Edge Functions"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule007_extra_spaces() {
+        assert_lint_snapshot!(
+            "Rule007CompoundTerms",
+            "Deploy your Edge  Functions today.\n",
+            Some(json!({ "terms": ["Edge Functions"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule007_non_breaking_space() {
+        assert_lint_snapshot!(
+            "Rule007CompoundTerms",
+            "Deploy your Edge\u{a0}Functions today.\n\nDeploy your Edge Functions today.\n",
+            Some(json!({ "terms": [{ "term": "Edge Functions", "non_breaking_space": true }] }))
+        );
+    }
+
+    #[test]
+    fn test_rule007_longer_term_takes_precedence() {
+        assert_lint_snapshot!(
+            "Rule007CompoundTerms",
+            "Install the supabase cli first.\n",
+            Some(json!({ "terms": ["Supabase", "Supabase CLI"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule007_partial_word_not_matched() {
+        assert_lint_snapshot!(
+            "Rule007CompoundTerms",
+            "The hedge functions are fine.\n",
+            Some(json!({ "terms": ["Edge Functions"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule007_no_terms_configured() {
+        assert_lint_snapshot!("Rule007CompoundTerms", "Install the supabase cli first.\n");
+    }
+}
@@ -0,0 +1,340 @@
+use std::collections::{BTreeMap, HashMap};
+
+use log::warn;
+use markdown::mdast::{AttributeContent, AttributeValue, Node};
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::DenormalizedLocation,
+    utils::mdast::find_jsx_attribute_value_range,
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+/// Props on known MDX components must match the configured schema.
+///
+/// ## Examples
+///
+/// ### Valid (assuming the configuration below)
+///
+/// ```markdown
+/// <Admonition type="note">
+///   Some text.
+/// </Admonition>
+///
+/// <Tabs queryGroup="language">
+///   ...
+/// </Tabs>
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// <Admonition type="warning">
+///   Some text.
+/// </Admonition>
+///
+/// <Tabs>
+///   ...
+/// </Tabs>
+/// ```
+///
+/// ## Configuration
+///
+/// Configure a schema for each component under `components`:
+/// - `required`: Props that must be present on the component.
+/// - `allowed`: Maps prop names to a regex that string values of the prop
+///   must fully match. Values passed as expressions aren't checked.
+///
+/// ```toml
+/// [Rule008ComponentProps.components.Admonition]
+/// required = ["type"]
+/// allowed = { type = "note|tip|caution|danger" }
+///
+/// [Rule008ComponentProps.components.Tabs]
+/// required = ["queryGroup"]
+/// ```
+#[derive(Debug, Default, RuleName)]
+#[rule_category(Correctness)]
+pub struct Rule008ComponentProps {
+    components: HashMap<String, ComponentSchema>,
+}
+
+#[derive(Debug)]
+struct ComponentSchema {
+    required: Vec<String>,
+    allowed: Vec<AllowedValues>,
+}
+
+#[derive(Debug)]
+struct AllowedValues {
+    prop: String,
+    pattern: String,
+    regex: Regex,
+}
+
+#[derive(Debug, Deserialize)]
+struct ComponentSchemaSetting {
+    #[serde(default)]
+    required: Vec<String>,
+    #[serde(default)]
+    allowed: BTreeMap<String, String>,
+}
+
+impl Rule for Rule008ComponentProps {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::MdxJsxFlowElement, NodeKind::MdxJsxTextElement])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+        let Some(components) =
+            settings.get_deserializable::<HashMap<String, ComponentSchemaSetting>>("components")
+        else {
+            return;
+        };
+
+        self.components = components
+            .into_iter()
+            .map(|(component, setting)| {
+                let allowed = setting
+                    .allowed
+                    .into_iter()
+                    .filter_map(|(prop, pattern)| {
+                        match Regex::new(&format!("^(?:{pattern})$")) {
+                            Ok(regex) => Some(AllowedValues {
+                                prop,
+                                pattern,
+                                regex,
+                            }),
+                            Err(err) => {
+                                warn!("Invalid regex for prop {prop} on {component} in Rule008ComponentProps: {err}");
+                                None
+                            }
+                        }
+                    })
+                    .collect();
+                (
+                    component,
+                    ComponentSchema {
+                        required: setting.required,
+                        allowed,
+                    },
+                )
+            })
+            .collect();
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "components": {
+                "type": "object",
+                "description": "Prop schemas, keyed by component name.",
+                "additionalProperties": {
+                    "type": "object",
+                    "properties": {
+                        "required": string_array_schema(
+                            "Props that must be present on the component."
+                        ),
+                        "allowed": {
+                            "type": "object",
+                            "description": "Regexes that string values of each prop must fully match.",
+                            "additionalProperties": { "type": "string" },
+                        },
+                    },
+                },
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let (name, attributes) = match ast {
+            Node::MdxJsxFlowElement(element) => (element.name.as_ref(), &element.attributes),
+            Node::MdxJsxTextElement(element) => (element.name.as_ref(), &element.attributes),
+            _ => return None,
+        };
+        let name = name?;
+        let schema = self.components.get(name)?;
+
+        let properties = attributes
+            .iter()
+            .filter_map(|attr| match attr {
+                AttributeContent::Property(property) => Some(property),
+                AttributeContent::Expression(_) => None,
+            })
+            .collect::<Vec<_>>();
+        // Spread props could contain any prop, so required props can't be
+        // checked reliably.
+        let has_spread = attributes
+            .iter()
+            .any(|attr| matches!(attr, AttributeContent::Expression(_)));
+
+        let mut errors = Vec::new();
+
+        if !has_spread {
+            for required in &schema.required {
+                if !properties.iter().any(|property| &property.name == required) {
+                    if let Some(error) = LintError::from_node()
+                        .node(ast)
+                        .context(context)
+                        .rule(self.name())
+                        .level(level)
+                        .message(&format!("Missing required prop \"{required}\" on <{name}>"))
+                        .call()
+                    {
+                        errors.push(error);
+                    }
+                }
+            }
+        }
+
+        for AllowedValues {
+            prop,
+            pattern,
+            regex,
+        } in &schema.allowed
+        {
+            let Some(property) = properties.iter().find(|property| &property.name == prop) else {
+                continue;
+            };
+            let Some(AttributeValue::Literal(value)) = &property.value else {
+                continue;
+            };
+            if regex.is_match(value) {
+                continue;
+            }
+
+            let message = format!(
+                "Invalid value \"{value}\" for prop \"{prop}\" on <{name}>. Allowed values must match: {pattern}"
+            );
+            let location = find_jsx_attribute_value_range(ast, &property.name, value, context)
+                .map(|range| DenormalizedLocation::from_offset_range(range, context));
+            let error = match location {
+                Some(location) => Some(
+                    LintError::from_raw_location()
+                        .rule(self.name())
+                        .level(level)
+                        .message(message)
+                        .location(location)
+                        .call(),
+                ),
+                None => LintError::from_node()
+                    .node(ast)
+                    .context(context)
+                    .rule(self.name())
+                    .level(level)
+                    .message(&message)
+                    .call(),
+            };
+            errors.extend(error);
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::testing::assert_lint_snapshot;
+
+    fn settings() -> Option<Value> {
+        Some(json!({
+            "components": {
+                "Admonition": {
+                    "required": ["type"],
+                    "allowed": { "type": "note|tip|caution|danger" },
+                },
+                "Tabs": { "required": ["queryGroup"] },
+            }
+        }))
+    }
+
+    #[test]
+    fn test_rule008_valid_props() {
+        assert_lint_snapshot!(
+            "Rule008ComponentProps",
+            "<Admonition type=\"note\">\n\nText\n\n</Admonition>\n",
+            settings()
+        );
+    }
+
+    #[test]
+    fn test_rule008_missing_required_prop() {
+        assert_lint_snapshot!(
+            "Rule008ComponentProps",
+            "<Tabs>\n\nText\n\n</Tabs>\n",
+            settings()
+        );
+    }
+
+    #[test]
+    fn test_rule008_invalid_value() {
+        assert_lint_snapshot!(
+            "Rule008ComponentProps",
+            "<Admonition type=\"notice\">\n\nText\n\n</Admonition>\n",
+            settings()
+        );
+    }
+
+    #[test]
+    fn test_rule008_value_must_fully_match() {
+        assert_lint_snapshot!(
+            "Rule008ComponentProps",
+            "<Admonition type=\"notes\">\n\nText\n\n</Admonition>\n",
+            settings()
+        );
+    }
+
+    #[test]
+    fn test_rule008_expression_values_are_not_checked() {
+        assert_lint_snapshot!(
+            "Rule008ComponentProps",
+            "<Admonition type={kind}>\n\nText\n\n</Admonition>\n",
+            settings()
+        );
+    }
+
+    #[test]
+    fn test_rule008_spread_props_skip_required_check() {
+        assert_lint_snapshot!(
+            "Rule008ComponentProps",
+            "<Tabs {...props}>\n\nText\n\n</Tabs>\n",
+            settings()
+        );
+    }
+
+    #[test]
+    fn test_rule008_unknown_component() {
+        assert_lint_snapshot!(
+            "Rule008ComponentProps",
+            "<Other>\n\nText\n\n</Other>\n",
+            settings()
+        );
+    }
+
+    #[test]
+    fn test_rule008_inline_component() {
+        assert_lint_snapshot!(
+            "Rule008ComponentProps",
+            "Some <Tabs>inline</Tabs> text\n",
+            settings()
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use glob::Pattern;
+use log::warn;
+use markdown::mdast::Node;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::path::matches_any_glob,
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_DATE_FORMAT: &str = r"\d{4}-\d{2}-\d{2}";
+const DEFAULT_ENTRY_HEADING_DEPTH: u8 = 2;
+
+/// Changelog entries must follow a consistent structure.
+///
+/// Each entry starts with a heading containing its date, optionally followed
+/// by category headings one level below it. Entries are separated by a single
+/// blank line.
+///
+/// The rule only applies to files matching the configured `files` globs.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// ## 2024-12-01
+///
+/// ### Fixed
+///
+/// - Fixed a bug.
+///
+/// ## 2024-11-15
+///
+/// ### Added
+///
+/// - Added a feature.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// ## December 1, 2024
+///
+/// ### Bugfixes
+///
+/// - Fixed a bug.
+///
+///
+/// ## 2024-11-15
+/// ```
+///
+/// ## Configuration
+///
+/// - `files`: Globs for the changelog files to check. The rule is inactive if
+///   this is not set.
+/// - `date_format`: Regex that entry headings must fully match. Defaults to
+///   `\d{4}-\d{2}-\d{2}`.
+/// - `categories`: Allowed category headings. Any category is allowed if this
+///   is not set.
+/// - `entry_heading_depth`: Depth of entry headings. Defaults to 2.
+///
+/// ```toml
+/// [Rule009ChangelogFormat]
+/// files = ["**/changelog/*.mdx"]
+/// categories = ["Added", "Changed", "Fixed", "Removed"]
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Correctness)]
+pub struct Rule009ChangelogFormat {
+    files: Vec<Pattern>,
+    date_format: String,
+    date_regex: Regex,
+    categories: Vec<String>,
+    entry_heading_depth: u8,
+}
+
+impl Default for Rule009ChangelogFormat {
+    fn default() -> Self {
+        Self {
+            files: Vec::new(),
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            date_regex: Self::full_match_regex(DEFAULT_DATE_FORMAT).unwrap(),
+            categories: Vec::new(),
+            entry_heading_depth: DEFAULT_ENTRY_HEADING_DEPTH,
+        }
+    }
+}
+
+impl Rule for Rule009ChangelogFormat {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(files) = settings.get_array_of_globs("files") {
+            self.files = files;
+        }
+        if let Some(date_format) = settings.get_deserializable::<String>("date_format") {
+            match Self::full_match_regex(&date_format) {
+                Ok(regex) => {
+                    self.date_format = date_format;
+                    self.date_regex = regex;
+                }
+                Err(err) => warn!("Invalid date_format for {}: {err}", self.name()),
+            }
+        }
+        if let Some(categories) = settings.get_deserializable::<Vec<String>>("categories") {
+            self.categories = categories;
+        }
+        if let Some(depth) = settings.get_deserializable::<u8>("entry_heading_depth") {
+            self.entry_heading_depth = depth;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "files": string_array_schema("Globs for the changelog files to check."),
+            "date_format": {
+                "type": "string",
+                "default": DEFAULT_DATE_FORMAT,
+                "description": "Regex that entry headings must fully match.",
+            },
+            "categories": string_array_schema("Allowed category headings."),
+            "entry_heading_depth": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": 6,
+                "default": DEFAULT_ENTRY_HEADING_DEPTH,
+                "description": "Depth of entry headings.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+        if self.files.is_empty() || !matches_any_glob(context.file_path()?, &self.files) {
+            return None;
+        }
+
+        let mut errors = Vec::new();
+        let mut in_entry = false;
+
+        for child in &root.children {
+            if let Node::Heading(heading) = child {
+                if heading.depth == self.entry_heading_depth {
+                    errors.extend(self.check_date(child, context, level));
+                    if in_entry {
+                        errors.extend(self.check_spacing(child, context, level));
+                    }
+                    in_entry = true;
+                } else if in_entry && heading.depth == self.entry_heading_depth + 1 {
+                    errors.extend(self.check_category(child, context, level));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule009ChangelogFormat {
+    fn full_match_regex(pattern: &str) -> Result<Regex, regex::Error> {
+        Regex::new(&format!("^(?:{pattern})$"))
+    }
+
+    fn check_date(&self, heading: &Node, context: &Context, level: LintLevel) -> Option<LintError> {
+        let text = heading.to_string();
+        let text = text.trim();
+        if self.date_regex.is_match(text) {
+            return None;
+        }
+
+        LintError::from_node()
+            .node(heading)
+            .context(context)
+            .rule(self.name())
+            .level(level)
+            .message(&format!(
+                "Changelog entry heading should be a date matching {}. Got: \"{text}\".",
+                self.date_format
+            ))
+            .call()
+    }
+
+    fn check_category(
+        &self,
+        heading: &Node,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        if self.categories.is_empty() {
+            return None;
+        }
+
+        let text = heading.to_string();
+        let text = text.trim();
+        if self.categories.iter().any(|category| category == text) {
+            return None;
+        }
+
+        LintError::from_node()
+            .node(heading)
+            .context(context)
+            .rule(self.name())
+            .level(level)
+            .message(&format!(
+                "Allowed changelog categories are: {}. Got: \"{text}\".",
+                self.categories.join(", ")
+            ))
+            .call()
+    }
+
+    fn check_spacing(
+        &self,
+        heading: &Node,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        let end: usize =
+            AdjustedOffset::from_unist(&heading.position()?.start, context.content_start_offset())
+                .into();
+        let content_start: usize = context.content_start_offset().into();
+
+        // Measure the whitespace between the heading and the end of the
+        // previous entry's content.
+        let between_len = context
+            .rope()
+            .byte_slice(content_start..end)
+            .chars()
+            .rev()
+            .take_while(|c| c.is_whitespace())
+            .map(char::len_utf8)
+            .sum::<usize>();
+        let start = end - between_len;
+        if start == content_start {
+            return None;
+        }
+
+        let range = AdjustedRange::new(start.into(), end.into());
+        let between = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+        if between.chars().filter(|c| *c == '\n').count() == 2 {
+            return None;
+        }
+
+        let line_ending = if between.contains('\r') { "\r\n" } else { "\n" };
+        let location = DenormalizedLocation::from_offset_range(range, context);
+        let fix = LintCorrection::Replace(LintCorrectionReplace {
+            location: location.clone(),
+            text: line_ending.repeat(2),
+        });
+
+        Some(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message("Changelog entries should be separated by a single blank line")
+                .location(location)
+                .fix(vec![fix])
+                .call(),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::testing::assert_lint_snapshot;
+
+    const CHANGELOG: &str = "docs/changelog/index.mdx";
+
+    fn settings() -> Option<Value> {
+        Some(json!({
+            "files": ["**/changelog/*.mdx"],
+            "categories": ["Added", "Fixed"],
+        }))
+    }
+
+    #[test]
+    fn test_rule009_valid_changelog() {
+        assert_lint_snapshot!(
+            "Rule009ChangelogFormat",
+            "# Changelog\n\n## 2024-12-01\n\n### Fixed\n\n- A fix.\n\n## 2024-11-15\n\n### Added\n\n- A feature.\n",
+            settings(),
+            file = CHANGELOG
+        );
+    }
+
+    #[test]
+    fn test_rule009_invalid_date() {
+        assert_lint_snapshot!(
+            "Rule009ChangelogFormat",
+            "## December 1, 2024\n\n- A fix.\n",
+            settings(),
+            file = CHANGELOG
+        );
+    }
+
+    #[test]
+    fn test_rule009_invalid_category() {
+        assert_lint_snapshot!(
+            "Rule009ChangelogFormat",
+            "## 2024-12-01\n\n### Bugfixes\n\n- A fix.\n",
+            settings(),
+            file = CHANGELOG
+        );
+    }
+
+    #[test]
+    fn test_rule009_extra_blank_lines_between_entries() {
+        assert_lint_snapshot!(
+            "Rule009ChangelogFormat",
+            "## 2024-12-01\n\n- A fix.\n\n\n\n## 2024-11-15\n\n- A feature.\n",
+            settings(),
+            file = CHANGELOG
+        );
+    }
+
+    #[test]
+    fn test_rule009_missing_blank_line_between_entries() {
+        assert_lint_snapshot!(
+            "Rule009ChangelogFormat",
+            "## 2024-12-01\n\n- A fix.\n## 2024-11-15\n\n- A feature.\n",
+            settings(),
+            file = CHANGELOG
+        );
+    }
+
+    #[test]
+    fn test_rule009_ignores_other_files() {
+        assert_lint_snapshot!(
+            "Rule009ChangelogFormat",
+            "## December 1, 2024\n\n- A fix.\n",
+            settings(),
+            file = "docs/guides/index.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule009_inactive_without_files() {
+        assert_lint_snapshot!(
+            "Rule009ChangelogFormat",
+            "## December 1, 2024\n\n- A fix.\n",
+            None,
+            file = CHANGELOG
+        );
+    }
+}
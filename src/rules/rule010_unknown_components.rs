@@ -0,0 +1,330 @@
+use std::{collections::HashSet, sync::LazyLock};
+
+use markdown::mdast::Node;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::AdjustedRange,
+    utils::edit_distance,
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+/// Maximum edit distance for a known component to be suggested as a
+/// replacement for an unknown one.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+static DEFAULT_IMPORT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\bimport\s+(?:type\s+)?([A-Za-z_$][\w$]*)\s*(?:,|\s+from\b)").unwrap()
+});
+static NAMED_IMPORTS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\bimport\s+(?:type\s+)?(?:[A-Za-z_$][\w$]*\s*,\s*)?\{([^}]*)\}").unwrap()
+});
+static NAMESPACE_IMPORT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\*\s*as\s+([A-Za-z_$][\w$]*)").unwrap());
+static EXPORT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\bexport\s+(?:default\s+)?(?:const|let|var|function|class)\s+([A-Za-z_$][\w$]*)")
+        .unwrap()
+});
+
+/// JSX components must be either imported in the file or in the configured
+/// list of allowed components.
+///
+/// Components that are neither might be typos, which render nothing instead
+/// of failing loudly. Intrinsic elements, such as `<div>`, aren't checked.
+///
+/// ## Examples
+///
+/// ### Valid (assuming `Admonition` is allowed)
+///
+/// ```markdown
+/// import { Chart } from './chart'
+///
+/// <Admonition type="note">
+///
+/// <Chart />
+///
+/// </Admonition>
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// <Admontion type="note">
+///
+/// Some text.
+///
+/// </Admontion>
+/// ```
+///
+/// ## Configuration
+///
+/// Configure the components provided globally by your docs pipeline via the
+/// `allowed_components` array. The rule is inactive unless configured.
+///
+/// ```toml
+/// [Rule010UnknownComponents]
+/// allowed_components = ["Admonition", "Tabs", "TabPanel"]
+/// ```
+#[derive(Debug, Default, RuleName)]
+#[rule_category(Correctness)]
+pub struct Rule010UnknownComponents {
+    allowed_components: Option<HashSet<String>>,
+}
+
+impl Rule for Rule010UnknownComponents {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        if let Some(settings) = settings {
+            self.allowed_components = Some(
+                settings
+                    .get_deserializable::<Vec<String>>("allowed_components")
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect(),
+            );
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "allowed_components": string_array_schema(
+                "Components that may be used without being imported in the file."
+            ),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+        let allowed_components = self.allowed_components.as_ref()?;
+
+        let mut known_components = allowed_components.clone();
+        for child in &root.children {
+            if let Some(source) = Self::esm_source(child, context) {
+                known_components.extend(Self::declared_identifiers(&source));
+            }
+        }
+
+        let mut errors = Vec::new();
+        for child in &root.children {
+            if Self::esm_source(child, context).is_none() {
+                self.check_components(child, &known_components, context, level, &mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule010UnknownComponents {
+    fn message(&self, name: &str, known_components: &HashSet<String>) -> String {
+        let suggestion = known_components
+            .iter()
+            .map(|known| (edit_distance(name, known), known))
+            .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+            .min();
+        match suggestion {
+            Some((_, known)) => format!(
+                "Unknown component <{name}>. Did you mean <{known}>? Otherwise, import it or add it to the allowed components."
+            ),
+            None => format!(
+                "Unknown component <{name}>. Import it or add it to the allowed components."
+            ),
+        }
+    }
+
+    /// ESM statements aren't parsed into ESM nodes, so they show up as
+    /// top-level paragraphs starting with `import` or `export`. Returns the
+    /// source of the node if it is one of these.
+    fn esm_source(node: &Node, context: &Context) -> Option<String> {
+        let Node::Paragraph(paragraph) = node else {
+            return None;
+        };
+        let position = paragraph.position.as_ref()?;
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let source = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+        if source.starts_with("import ") || source.starts_with("export ") {
+            Some(source)
+        } else {
+            None
+        }
+    }
+
+    fn declared_identifiers(source: &str) -> Vec<String> {
+        let mut identifiers = Vec::new();
+
+        for regex in [
+            &*DEFAULT_IMPORT_REGEX,
+            &*NAMESPACE_IMPORT_REGEX,
+            &*EXPORT_REGEX,
+        ] {
+            identifiers.extend(
+                regex
+                    .captures_iter(source)
+                    .filter_map(|captures| captures.get(1))
+                    .map(|identifier| identifier.as_str().to_string()),
+            );
+        }
+
+        for captures in NAMED_IMPORTS_REGEX.captures_iter(source) {
+            let Some(names) = captures.get(1) else {
+                continue;
+            };
+            identifiers.extend(names.as_str().split(',').filter_map(|name| {
+                let name = name.trim();
+                let name = name.strip_prefix("type ").unwrap_or(name);
+                let local_name = match name.split_once(" as ") {
+                    Some((_, alias)) => alias.trim(),
+                    None => name,
+                };
+                (!local_name.is_empty()).then(|| local_name.to_string())
+            }));
+        }
+
+        identifiers
+    }
+
+    fn check_components(
+        &self,
+        node: &Node,
+        known_components: &HashSet<String>,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let name = match node {
+            Node::MdxJsxFlowElement(element) => element.name.as_deref(),
+            Node::MdxJsxTextElement(element) => element.name.as_deref(),
+            _ => None,
+        };
+
+        if let Some(name) = name {
+            // Member expressions like `<Tabs.Panel>` are checked by their
+            // root object. Lowercase names are intrinsic elements, and names
+            // with colons are namespaced elements.
+            let root_name = name.split('.').next().unwrap_or(name);
+            let is_component =
+                root_name.starts_with(|c: char| c.is_uppercase()) && !root_name.contains(':');
+            if is_component && !known_components.contains(root_name) {
+                if let Some(error) = LintError::from_node()
+                    .node(node)
+                    .context(context)
+                    .rule(self.name())
+                    .level(level)
+                    .message(&self.message(root_name, known_components))
+                    .call()
+                {
+                    errors.push(error);
+                }
+            }
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                self.check_components(child, known_components, context, level, errors);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule010_allowed_component() {
+        assert_lint_snapshot!(
+            "Rule010UnknownComponents",
+            "<Admonition type=\"note\">\n\nText\n\n</Admonition>\n",
+            Some(json!({ "allowed_components": ["Admonition"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule010_unknown_component_with_suggestion() {
+        assert_lint_snapshot!(
+            "Rule010UnknownComponents",
+            "<Admontion type=\"note\">\n\nText\n\n</Admontion>\n",
+            Some(json!({ "allowed_components": ["Admonition"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule010_unknown_component_without_suggestion() {
+        assert_lint_snapshot!(
+            "Rule010UnknownComponents",
+            "Some <Chart /> here.\n",
+            Some(json!({ "allowed_components": ["Admonition"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule010_imported_components() {
+        let mdx = r#"import Chart from './chart'
+import { Table, Row as TableRow } from './table'
+import * as Icons from './icons'
+
+export const Note = ({ children }) => <Chart>{children}</Chart>
+
+<Chart />
+
+<Table>
+  <TableRow />
+</Table>
+
+<Icons.Check />
+
+<Note>Text</Note>
+"#;
+        assert_lint_snapshot!(
+            "Rule010UnknownComponents",
+            mdx,
+            Some(json!({ "allowed_components": [] }))
+        );
+    }
+
+    #[test]
+    fn test_rule010_nested_unknown_component() {
+        assert_lint_snapshot!(
+            "Rule010UnknownComponents",
+            "<Admonition type=\"note\">\n\n<Unknown />\n\n</Admonition>\n",
+            Some(json!({ "allowed_components": ["Admonition"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule010_intrinsic_elements_ignored() {
+        assert_lint_snapshot!(
+            "Rule010UnknownComponents",
+            "<div>\n\nText\n\n</div>\n",
+            Some(json!({ "allowed_components": [] }))
+        );
+    }
+
+    #[test]
+    fn test_rule010_inactive_without_configuration() {
+        assert_lint_snapshot!("Rule010UnknownComponents", "<Unknown />\n");
+    }
+}
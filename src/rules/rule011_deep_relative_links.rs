@@ -0,0 +1,411 @@
+use std::path::{Component, Path, PathBuf};
+
+use markdown::mdast::{Image, Link, Node};
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedRange, DenormalizedLocation},
+    utils::{
+        mdast::{jsx_url_attributes, DEFAULT_JSX_URL_ATTRIBUTES},
+        path::normalize_glob_path,
+    },
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_MAX_DEPTH: usize = 2;
+
+/// Relative links shouldn't traverse more than a configured number of parent
+/// directories.
+///
+/// Deeply nested relative paths break whenever either file moves. Link to the
+/// site-absolute path instead.
+///
+/// URL attributes of JSX components, such as `<Button href="../../../auth">`,
+/// are checked along with Markdown links and images.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// [Auth](../auth)
+/// [Auth](/guides/auth)
+/// ```
+///
+/// ### Invalid (assuming `max_depth` is 2)
+///
+/// ```markdown
+/// [Auth](../../../guides/auth)
+/// ```
+///
+/// ## Configuration
+///
+/// - `max_depth`: Maximum number of parent directories a relative link may
+///   traverse. Defaults to 2.
+/// - `content_root`: Directory, relative to the current directory, that is
+///   served at the site root. Required for autofixes.
+/// - `base_path`: Path prefix the content root is served under. Defaults to
+///   the site root.
+/// - `jsx_url_attributes`: Names of the JSX attributes that hold URLs.
+///   Defaults to `href` and `src`.
+///
+/// ```toml
+/// [Rule011DeepRelativeLinks]
+/// max_depth = 2
+/// content_root = "apps/docs/content"
+/// base_path = "/docs"
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Links)]
+pub struct Rule011DeepRelativeLinks {
+    enabled: bool,
+    max_depth: usize,
+    content_root: Option<PathBuf>,
+    base_path: String,
+    jsx_url_attributes: Vec<String>,
+}
+
+impl Default for Rule011DeepRelativeLinks {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_depth: DEFAULT_MAX_DEPTH,
+            content_root: None,
+            base_path: String::new(),
+            jsx_url_attributes: DEFAULT_JSX_URL_ATTRIBUTES
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+}
+
+impl Rule for Rule011DeepRelativeLinks {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[
+            NodeKind::Link,
+            NodeKind::Image,
+            NodeKind::MdxJsxFlowElement,
+            NodeKind::MdxJsxTextElement,
+        ])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        self.enabled = true;
+        if let Some(max_depth) = settings.get_deserializable::<usize>("max_depth") {
+            self.max_depth = max_depth;
+        }
+        if let Some(content_root) = settings.get_deserializable::<String>("content_root") {
+            self.content_root = Some(PathBuf::from(content_root));
+        }
+        if let Some(base_path) = settings.get_deserializable::<String>("base_path") {
+            self.base_path = base_path.trim_matches('/').to_string();
+        }
+        if let Some(names) = settings.get_array_of_strings("jsx_url_attributes") {
+            self.jsx_url_attributes = names;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "max_depth": {
+                "type": "integer",
+                "minimum": 0,
+                "default": DEFAULT_MAX_DEPTH,
+                "description": "Maximum number of parent directories a relative link may traverse.",
+            },
+            "content_root": {
+                "type": "string",
+                "description": "Directory, relative to the current directory, that is served at the site root.",
+            },
+            "base_path": {
+                "type": "string",
+                "description": "Path prefix the content root is served under.",
+            },
+            "jsx_url_attributes": string_array_schema(
+                "Names of the JSX attributes that hold URLs. Defaults to `href` and `src`."
+            ),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !self.enabled {
+            return None;
+        }
+        let errors: Vec<_> = match ast {
+            Node::Link(Link { url, .. }) | Node::Image(Image { url, .. }) => {
+                let (message, site_path) = self.check_url(url, context)?;
+                let fix = site_path.and_then(|site_path| {
+                    let location = self.find_url_location(ast, url, context)?;
+                    Some(vec![LintCorrection::Replace(LintCorrectionReplace {
+                        location,
+                        text: site_path,
+                    })])
+                });
+                LintError::from_node()
+                    .node(ast)
+                    .context(context)
+                    .rule(self.name())
+                    .level(level)
+                    .message(&message)
+                    .maybe_fix(fix)
+                    .call()
+                    .into_iter()
+                    .collect()
+            }
+            Node::MdxJsxFlowElement(_) | Node::MdxJsxTextElement(_) => {
+                jsx_url_attributes(ast, &self.jsx_url_attributes, context)
+                    .into_iter()
+                    .filter_map(|attribute| {
+                        let (message, site_path) = self.check_url(attribute.url, context)?;
+                        let location =
+                            DenormalizedLocation::from_offset_range(attribute.range, context);
+                        Some(
+                            LintError::from_raw_location()
+                                .rule(self.name())
+                                .level(level)
+                                .message(message)
+                                .location(location.clone())
+                                .maybe_fix(site_path.map(|site_path| {
+                                    vec![LintCorrection::Replace(LintCorrectionReplace {
+                                        location,
+                                        text: site_path,
+                                    })]
+                                }))
+                                .call(),
+                        )
+                    })
+                    .collect()
+            }
+            _ => return None,
+        };
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule011DeepRelativeLinks {
+    /// If a URL traverses too many parent directories, the message to report,
+    /// and the site-absolute path to use instead, if it can be worked out.
+    fn check_url(&self, url: &str, context: &Context) -> Option<(String, Option<String>)> {
+        if Self::is_absolute_or_external(url) {
+            return None;
+        }
+
+        let (path, suffix) = url.split_at(url.find(['#', '?']).unwrap_or(url.len()));
+        let depth = Self::parent_depth(path);
+        if depth <= self.max_depth {
+            return None;
+        }
+
+        let site_path = self
+            .site_absolute_path(path, context)
+            .map(|site_path| format!("{site_path}{suffix}"));
+        let message = match &site_path {
+            Some(site_path) => format!(
+                "Relative link traverses {depth} parent directories (maximum is {}). Use \"{site_path}\" instead.",
+                self.max_depth
+            ),
+            None => format!(
+                "Relative link traverses {depth} parent directories (maximum is {}). Use a site-absolute path instead.",
+                self.max_depth
+            ),
+        };
+        Some((message, site_path))
+    }
+
+    fn is_absolute_or_external(url: &str) -> bool {
+        if url.is_empty() || url.starts_with('/') || url.starts_with('#') {
+            return true;
+        }
+        // URL schemes such as `https:` or `mailto:`
+        url.split_once(':').is_some_and(|(scheme, _)| {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        })
+    }
+
+    /// The number of directories above the linking file's directory that the
+    /// path reaches at its highest point.
+    fn parent_depth(path: &str) -> usize {
+        let mut depth: isize = 0;
+        let mut min_depth: isize = 0;
+        for segment in path.split('/') {
+            match segment {
+                ".." => {
+                    depth -= 1;
+                    min_depth = min_depth.min(depth);
+                }
+                "" | "." => {}
+                _ => depth += 1,
+            }
+        }
+        min_depth.unsigned_abs()
+    }
+
+    fn site_absolute_path(&self, path: &str, context: &Context) -> Option<String> {
+        let content_root = self.content_root.as_ref()?;
+        let file_path = context.file_path()?;
+
+        let current_dir = std::env::current_dir().ok()?;
+        let absolute = |path: &Path| {
+            if path.is_absolute() {
+                normalize_glob_path(path)
+            } else {
+                normalize_glob_path(&current_dir.join(path))
+            }
+        };
+
+        let target = absolute(&file_path.parent()?.join(path));
+        let relative = target.strip_prefix(absolute(content_root)).ok()?;
+
+        let segments = std::iter::once(self.base_path.as_str())
+            .filter(|base_path| !base_path.is_empty())
+            .map(ToString::to_string)
+            .chain(
+                relative
+                    .components()
+                    .filter_map(|component| match component {
+                        Component::Normal(segment) => Some(segment.to_string_lossy().into_owned()),
+                        _ => None,
+                    }),
+            )
+            .collect::<Vec<_>>();
+        let mut site_path = format!("/{}", segments.join("/"));
+        if path.ends_with('/') && !site_path.ends_with('/') {
+            site_path.push('/');
+        }
+        Some(site_path)
+    }
+
+    /// Find the URL within the destination part of the link, so that a URL
+    /// repeated in the link text isn't replaced instead.
+    fn find_url_location(
+        &self,
+        node: &Node,
+        url: &str,
+        context: &Context,
+    ) -> Option<DenormalizedLocation> {
+        let node_range = AdjustedRange::from_unadjusted_position(node.position()?, context);
+        let node_start: usize = node_range.start.into();
+        let node_source = context
+            .rope()
+            .byte_slice(node_range.to_usize_range())
+            .to_string();
+
+        let paren_start = node_source.rfind("](")? + 2;
+        let url_start = paren_start + node_source[paren_start..].find(url)?;
+        let range = AdjustedRange::new(
+            (node_start + url_start).into(),
+            (node_start + url_start + url.len()).into(),
+        );
+        Some(DenormalizedLocation::from_offset_range(range, context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fix")]
+    use crate::testing::assert_lint_snapshot;
+
+    #[cfg(feature = "fix")]
+    const FILE: &str = "content/guides/auth/social/github.mdx";
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule011_shallow_link() {
+        assert_lint_snapshot!(
+            "Rule011DeepRelativeLinks",
+            "[Auth](../../auth)\n",
+            Some(json!({})),
+            file = FILE
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule011_absolute_and_external_links() {
+        assert_lint_snapshot!(
+            "Rule011DeepRelativeLinks",
+            "[Auth](/guides/auth)\n\n[Auth](https://example.com/../auth)\n\n[Auth](#auth)\n",
+            Some(json!({ "max_depth": 0 })),
+            file = FILE
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule011_deep_link_without_content_root() {
+        assert_lint_snapshot!(
+            "Rule011DeepRelativeLinks",
+            "[Database](../../../database)\n",
+            Some(json!({})),
+            file = FILE
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule011_deep_link_fix() {
+        assert_lint_snapshot!(
+            "Rule011DeepRelativeLinks",
+            "[Database](../../../database/tables#columns)\n",
+            Some(json!({ "content_root": "content", "base_path": "/docs/" })),
+            file = FILE
+        );
+    }
+
+    #[test]
+    fn test_rule011_parent_depth() {
+        assert_eq!(Rule011DeepRelativeLinks::parent_depth("../a"), 1);
+        assert_eq!(Rule011DeepRelativeLinks::parent_depth("./../../a/b"), 2);
+        assert_eq!(Rule011DeepRelativeLinks::parent_depth("a/../../../b"), 2);
+        assert_eq!(Rule011DeepRelativeLinks::parent_depth("a/b"), 0);
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule011_inactive_without_configuration() {
+        assert_lint_snapshot!(
+            "Rule011DeepRelativeLinks",
+            "[Database](../../../../database)\n",
+            None,
+            file = FILE
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule011_jsx_attributes() {
+        let mdx = r#"<Button href="../../../database">Database</Button>
+
+See <Link to="../../../storage#buckets">Storage</Link> and <Image src="../../../../a.png" />.
+"#;
+        assert_lint_snapshot!(
+            "Rule011DeepRelativeLinks",
+            mdx,
+            Some(json!({ "content_root": "content", "jsx_url_attributes": ["href", "to"] })),
+            file = FILE
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::HashSet;
+
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    utils::mdast::is_esm_paragraph,
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+/// Raw HTML elements shouldn't be used in MDX, since the renderer styles
+/// Markdown constructs but not arbitrary HTML.
+///
+/// Lowercase JSX elements, such as `<center>` or `<font>`, are treated as raw
+/// HTML. Components are checked by other rules.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// Some **bold** text.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// Some <b>bold</b> text.
+///
+/// <center>Centered text</center>
+/// ```
+///
+/// ## Configuration
+///
+/// Allow specific tags via the `allowed_tags` array. The rule is inactive
+/// unless configured.
+///
+/// ```toml
+/// [Rule012NoRawHtml]
+/// allowed_tags = ["br", "sup", "sub"]
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule012NoRawHtml {
+    allowed_tags: Option<HashSet<String>>,
+}
+
+impl Rule for Rule012NoRawHtml {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        if let Some(settings) = settings {
+            self.allowed_tags = Some(
+                settings
+                    .get_array_of_strings("allowed_tags")
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect(),
+            );
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "allowed_tags": string_array_schema("HTML tags that may be used."),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+        let allowed_tags = self.allowed_tags.as_ref()?;
+
+        let mut errors = Vec::new();
+        for child in &root.children {
+            // JSX inside exported components is part of the component
+            // definition rather than the page content.
+            if !is_esm_paragraph(child) {
+                self.check_elements(child, allowed_tags, context, level, &mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule012NoRawHtml {
+    fn message(&self, tag: &str) -> String {
+        match Self::markdown_alternative(tag) {
+            Some(alternative) => format!(
+                "Avoid raw HTML element <{tag}>. Use {alternative} instead."
+            ),
+            None => format!(
+                "Avoid raw HTML element <{tag}>. Use Markdown or a component instead, or add it to the allowed tags."
+            ),
+        }
+    }
+
+    fn markdown_alternative(tag: &str) -> Option<&'static str> {
+        match tag {
+            "b" | "strong" => Some("**bold**"),
+            "i" | "em" => Some("_emphasis_"),
+            "a" => Some("a Markdown link"),
+            "img" => Some("a Markdown image"),
+            "code" => Some("`inline code`"),
+            "pre" => Some("a fenced code block"),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => Some("a Markdown heading"),
+            "ul" | "ol" | "li" => Some("a Markdown list"),
+            "hr" => Some("a thematic break (---)"),
+            "blockquote" => Some("a Markdown blockquote"),
+            _ => None,
+        }
+    }
+
+    fn check_elements(
+        &self,
+        node: &Node,
+        allowed_tags: &HashSet<String>,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let name = match node {
+            Node::MdxJsxFlowElement(element) => element.name.as_deref(),
+            Node::MdxJsxTextElement(element) => element.name.as_deref(),
+            _ => None,
+        };
+
+        // Fragments have no name, and member expressions such as
+        // `<motion.div>` are components.
+        if let Some(name) = name {
+            let is_html = name.starts_with(|c: char| c.is_ascii_lowercase())
+                && !name.contains('.')
+                && !name.contains(':');
+            if is_html && !allowed_tags.contains(&name.to_lowercase()) {
+                if let Some(error) = LintError::from_node()
+                    .node(node)
+                    .context(context)
+                    .rule(self.name())
+                    .level(level)
+                    .message(&self.message(name))
+                    .call()
+                {
+                    errors.push(error);
+                }
+            }
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                self.check_elements(child, allowed_tags, context, level, errors);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule012_flow_element() {
+        assert_lint_snapshot!(
+            "Rule012NoRawHtml",
+            "<center>Centered text</center>\n",
+            Some(json!({ "allowed_tags": [] }))
+        );
+    }
+
+    #[test]
+    fn test_rule012_text_element_with_alternative() {
+        assert_lint_snapshot!(
+            "Rule012NoRawHtml",
+            "Some <b>bold</b> text.\n",
+            Some(json!({ "allowed_tags": [] }))
+        );
+    }
+
+    #[test]
+    fn test_rule012_allowed_tags() {
+        assert_lint_snapshot!(
+            "Rule012NoRawHtml",
+            "Line one<br />line two, and E = mc<sup>2</sup>.\n",
+            Some(json!({ "allowed_tags": ["BR", "sup"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule012_components_ignored() {
+        assert_lint_snapshot!(
+            "Rule012NoRawHtml",
+            "<Admonition type=\"note\">\n\n<motion.div />\n\n</Admonition>\n",
+            Some(json!({ "allowed_tags": [] }))
+        );
+    }
+
+    #[test]
+    fn test_rule012_exported_components_ignored() {
+        assert_lint_snapshot!(
+            "Rule012NoRawHtml",
+            "export const Note = () => <div>Note</div>\n\nText\n",
+            Some(json!({ "allowed_tags": [] }))
+        );
+    }
+
+    #[test]
+    fn test_rule012_inactive_without_configuration() {
+        assert_lint_snapshot!("Rule012NoRawHtml", "<center>Centered text</center>\n");
+    }
+}
@@ -0,0 +1,137 @@
+use markdown::mdast::Node;
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    utils::collapse_whitespace,
+};
+
+use super::{NodeKind, Rule, RuleName};
+
+/// H2 headings shouldn't repeat the page title.
+///
+/// The page title is taken from the `title` field of the frontmatter, or the
+/// H1 heading if there is no title in the frontmatter. Headings are compared
+/// case-insensitively.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// # Row Level Security
+///
+/// ## Enabling Row Level Security
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// # Row Level Security
+///
+/// ## Row level security
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule013DuplicateTitleHeading;
+
+impl Rule for Rule013DuplicateTitleHeading {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+
+        let title = context
+            .parse_result
+            .frontmatter_str("title")
+            .map(ToString::to_string)
+            .or_else(|| {
+                root.children.iter().find_map(|child| match child {
+                    Node::Heading(heading) if heading.depth == 1 => Some(child.to_string()),
+                    _ => None,
+                })
+            })?;
+        let title = collapse_whitespace(&title).to_lowercase();
+        if title.is_empty() {
+            return None;
+        }
+
+        let errors = root
+            .children
+            .iter()
+            .filter(|child| matches!(child, Node::Heading(heading) if heading.depth == 2))
+            .filter_map(|heading| {
+                let text = collapse_whitespace(&heading.to_string());
+                if text.to_lowercase() != title {
+                    return None;
+                }
+
+                LintError::from_node()
+                    .node(heading)
+                    .context(context)
+                    .rule(self.name())
+                    .level(level)
+                    .message(&format!(
+                        "Heading \"{text}\" repeats the page title. Use a more specific heading."
+                    ))
+                    .call()
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule013_h2_duplicates_h1() {
+        assert_lint_snapshot!(
+            "Rule013DuplicateTitleHeading",
+            "# Row Level Security\n\n## Row level  security\n\nText\n"
+        );
+    }
+
+    #[test]
+    fn test_rule013_h2_duplicates_frontmatter_title() {
+        assert_lint_snapshot!(
+            "Rule013DuplicateTitleHeading",
+            "---\ntitle: Storage\n---\n\n## Storage\n\n## Buckets\n"
+        );
+    }
+
+    #[test]
+    fn test_rule013_frontmatter_title_takes_precedence() {
+        assert_lint_snapshot!(
+            "Rule013DuplicateTitleHeading",
+            "---\ntitle: Storage\n---\n\n# Overview\n\n## Overview\n"
+        );
+    }
+
+    #[test]
+    fn test_rule013_distinct_headings() {
+        assert_lint_snapshot!(
+            "Rule013DuplicateTitleHeading",
+            "# Row Level Security\n\n## Enabling Row Level Security\n\n### Row Level Security\n"
+        );
+    }
+
+    #[test]
+    fn test_rule013_no_title() {
+        assert_lint_snapshot!("Rule013DuplicateTitleHeading", "## Storage\n\n## Storage\n");
+    }
+}
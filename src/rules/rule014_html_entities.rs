@@ -0,0 +1,413 @@
+use std::{collections::HashSet, sync::LazyLock};
+
+use log::warn;
+use markdown::mdast::Node;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+/// The part of an entity after the ampersand.
+const ENTITY_REFERENCE: &str = r"(?:#[0-9]+|#[xX][0-9a-fA-F]+|[A-Za-z][A-Za-z0-9]*);";
+
+static ENTITY_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("&{ENTITY_REFERENCE}")).unwrap());
+static LEADING_ENTITY_REFERENCE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^{ENTITY_REFERENCE}")).unwrap());
+
+/// Named entities recognized by the rule, and the characters they encode.
+const ENTITIES: &[(&str, char)] = &[
+    ("nbsp", '\u{a0}'),
+    ("ensp", '\u{2002}'),
+    ("emsp", '\u{2003}'),
+    ("thinsp", '\u{2009}'),
+    ("zwnj", '\u{200c}'),
+    ("zwj", '\u{200d}'),
+    ("shy", '\u{ad}'),
+    ("amp", '&'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("mdash", '—'),
+    ("ndash", '–'),
+    ("hellip", '…'),
+    ("lsquo", '‘'),
+    ("rsquo", '’'),
+    ("ldquo", '“'),
+    ("rdquo", '”'),
+    ("laquo", '«'),
+    ("raquo", '»'),
+    ("prime", '′'),
+    ("copy", '©'),
+    ("reg", '®'),
+    ("trade", '™'),
+    ("deg", '°'),
+    ("plusmn", '±'),
+    ("times", '×'),
+    ("divide", '÷'),
+    ("middot", '·'),
+    ("bull", '•'),
+    ("larr", '←'),
+    ("rarr", '→'),
+    ("uarr", '↑'),
+    ("darr", '↓'),
+    ("harr", '↔'),
+    ("rArr", '⇒'),
+    ("le", '≤'),
+    ("ge", '≥'),
+    ("ne", '≠'),
+    ("asymp", '≈'),
+    ("infin", '∞'),
+    ("micro", 'µ'),
+    ("euro", '€'),
+    ("pound", '£'),
+    ("yen", '¥'),
+    ("cent", '¢'),
+    ("sect", '§'),
+    ("para", '¶'),
+    ("dagger", '†'),
+    ("frac12", '½'),
+    ("frac14", '¼'),
+    ("frac34", '¾'),
+];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Preference {
+    /// Write characters literally instead of as entities.
+    #[default]
+    Characters,
+    /// Write characters as named entities.
+    Entities,
+}
+
+/// HTML entities and literal characters shouldn't be mixed in prose.
+///
+/// By default, entities such as `&mdash;` are flagged and autofixed to the
+/// characters they encode, which are easier to read in the source. Entities
+/// for invisible characters, such as `&nbsp;`, are always left alone, as are
+/// entities for characters with special meaning in MDX, such as `&lt;`.
+///
+/// The reverse policy flags literal characters that have a named entity, and
+/// autofixes them to the entity.
+///
+/// Only prose is checked. Code, JSX attributes, and ESM aren't affected.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// Row Level Security — the basics.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// Row Level Security &mdash; the basics.
+/// ```
+///
+/// ## Configuration
+///
+/// - `prefer`: Either `"characters"` (the default) or `"entities"`.
+/// - `ignore`: Names of entities to leave alone, such as `"rsquo"`.
+///
+/// The rule is inactive unless configured.
+///
+/// ```toml
+/// [Rule014HtmlEntities]
+/// prefer = "characters"
+/// ignore = ["trade"]
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule014HtmlEntities {
+    enabled: bool,
+    prefer: Preference,
+    ignore: HashSet<String>,
+}
+
+impl Rule for Rule014HtmlEntities {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Text])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        self.enabled = true;
+        if let Some(prefer) = settings.get_deserializable::<String>("prefer") {
+            match prefer.as_str() {
+                "characters" => self.prefer = Preference::Characters,
+                "entities" => self.prefer = Preference::Entities,
+                _ => warn!(
+                    "Invalid prefer \"{prefer}\" for {}. Expected \"characters\" or \"entities\".",
+                    self.name()
+                ),
+            }
+        }
+        if let Some(ignore) = settings.get_deserializable::<Vec<String>>("ignore") {
+            self.ignore = ignore
+                .into_iter()
+                .map(|name| name.trim_matches(['&', ';']).to_string())
+                .collect();
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "prefer": {
+                "type": "string",
+                "enum": ["characters", "entities"],
+                "default": "characters",
+                "description": "Whether to write characters literally or as HTML entities.",
+            },
+            "ignore": string_array_schema("Names of entities to leave alone."),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !self.enabled {
+            return None;
+        }
+        let Node::Text(text) = ast else {
+            return None;
+        };
+        let position = text.position.as_ref()?;
+
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let node_start: usize = range.start.into();
+        let source = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+
+        let replacements = match self.prefer {
+            Preference::Characters => self.entities_to_replace(&source),
+            Preference::Entities => self.characters_to_replace(&source),
+        };
+
+        let errors = replacements
+            .into_iter()
+            .map(|replacement| {
+                let location = DenormalizedLocation::from_offset_range(
+                    AdjustedRange::new(
+                        AdjustedOffset::from(node_start + replacement.range.start),
+                        AdjustedOffset::from(node_start + replacement.range.end),
+                    ),
+                    context,
+                );
+                let fix = LintCorrection::Replace(LintCorrectionReplace {
+                    location: location.clone(),
+                    text: replacement.text,
+                });
+                LintError::from_raw_location()
+                    .rule(self.name())
+                    .level(level)
+                    .message(replacement.message)
+                    .location(location)
+                    .fix(vec![fix])
+                    .call()
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+struct Replacement {
+    range: std::ops::Range<usize>,
+    text: String,
+    message: String,
+}
+
+impl Rule014HtmlEntities {
+    fn entities_to_replace(&self, source: &str) -> Vec<Replacement> {
+        ENTITY_REGEX
+            .find_iter(source)
+            .filter(|found| !source[..found.start()].ends_with('\\'))
+            .filter_map(|found| {
+                let entity = found.as_str();
+                let character = Self::decode(entity)?;
+                if Self::entity_name(character).is_some_and(|name| self.ignore.contains(name))
+                    || !Self::is_safe_literal(character, &source[found.end()..])
+                {
+                    return None;
+                }
+
+                Some(Replacement {
+                    range: found.range(),
+                    text: character.to_string(),
+                    message: format!(
+                        "Use the character \"{character}\" instead of the HTML entity \"{entity}\"."
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    fn characters_to_replace(&self, source: &str) -> Vec<Replacement> {
+        source
+            .char_indices()
+            .filter(|(_, character)| !character.is_ascii())
+            .filter_map(|(index, character)| {
+                let name = Self::entity_name(character)?;
+                if self.ignore.contains(name) {
+                    return None;
+                }
+
+                let entity = format!("&{name};");
+                Some(Replacement {
+                    range: index..index + character.len_utf8(),
+                    message: format!(
+                        "Use the HTML entity \"{entity}\" instead of the character {}.",
+                        Self::display_character(character)
+                    ),
+                    text: entity,
+                })
+            })
+            .collect()
+    }
+
+    fn decode(entity: &str) -> Option<char> {
+        let reference = entity.strip_prefix('&')?.strip_suffix(';')?;
+        match reference.strip_prefix('#') {
+            Some(numeric) => {
+                let code_point = match numeric.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => numeric.parse().ok()?,
+                };
+                char::from_u32(code_point)
+            }
+            None => ENTITIES
+                .iter()
+                .find(|(name, _)| *name == reference)
+                .map(|(_, character)| *character),
+        }
+    }
+
+    fn entity_name(character: char) -> Option<&'static str> {
+        ENTITIES
+            .iter()
+            .find(|(_, entity_character)| *entity_character == character)
+            .map(|(name, _)| *name)
+    }
+
+    fn is_invisible(character: char) -> bool {
+        character.is_whitespace()
+            || character.is_control()
+            || matches!(
+                character,
+                '\u{ad}' | '\u{200b}'..='\u{200f}' | '\u{2060}' | '\u{feff}'
+            )
+    }
+
+    /// Whether the character can be written literally without changing how
+    /// the surrounding MDX is parsed.
+    fn is_safe_literal(character: char, following: &str) -> bool {
+        if Self::is_invisible(character) {
+            return false;
+        }
+        if !character.is_ascii() {
+            return true;
+        }
+        match character {
+            // A literal ampersand could form a new entity with the text after
+            // it, as in `&amp;copy;`.
+            '&' => !LEADING_ENTITY_REFERENCE_REGEX.is_match(following),
+            '"' | '\'' | ',' | '.' | ':' | ';' | '?' | '/' | '(' | ')' | '%' | '$' | '@' => true,
+            _ => character.is_ascii_alphanumeric(),
+        }
+    }
+
+    fn display_character(character: char) -> String {
+        if Self::is_invisible(character) {
+            format!("U+{:04X}", character as u32)
+        } else {
+            format!("\"{character}\"")
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule014_named_entity() {
+        assert_lint_snapshot!(
+            "Rule014HtmlEntities",
+            "Row Level Security &mdash; the basics.\n",
+            Some(json!({}))
+        );
+    }
+
+    #[test]
+    fn test_rule014_numeric_entities() {
+        assert_lint_snapshot!(
+            "Rule014HtmlEntities",
+            "Copyright &#169; Supabase &#x2122;\n",
+            Some(json!({}))
+        );
+    }
+
+    #[test]
+    fn test_rule014_unsafe_entities_ignored() {
+        assert_lint_snapshot!(
+            "Rule014HtmlEntities",
+            "Use a&nbsp;b when x &lt; y, &#42;not emphasis&#42;, and &amp;copy; literally.\n",
+            Some(json!({}))
+        );
+    }
+
+    #[test]
+    fn test_rule014_ignore_setting() {
+        assert_lint_snapshot!(
+            "Rule014HtmlEntities",
+            "A &mdash; B &#8212; C\n",
+            Some(json!({ "ignore": ["&mdash;"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule014_prefer_entities() {
+        assert_lint_snapshot!(
+            "Rule014HtmlEntities",
+            "A — B\u{a0}C &ndash; D\n",
+            Some(json!({ "prefer": "entities" }))
+        );
+    }
+
+    #[test]
+    fn test_rule014_code_ignored() {
+        assert_lint_snapshot!(
+            "Rule014HtmlEntities",
+            "Use `&mdash;` here.\n",
+            Some(json!({}))
+        );
+    }
+
+    #[test]
+    fn test_rule014_inactive_without_configuration() {
+        assert_lint_snapshot!("Rule014HtmlEntities", "A &mdash; B\n");
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::LazyLock;
+
+use log::warn;
+use markdown::mdast::Node;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::mdast::is_esm_paragraph,
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const MODIFIERS: &str =
+    "Ctrl|CTRL|Control|Cmd|CMD|Command|Alt|ALT|Option|Opt|Shift|SHIFT|Meta|Win|Super|Fn";
+/// Keys that can follow the modifiers. Longer names come first, since the
+/// first matching alternative wins.
+const KEYS: &str = "F[1-9][0-9]?|Enter|Return|Tab|Escape|Esc|Space|Backspace|Delete|Del|Insert|Home|End|PageUp|PageDown|Up|Down|Left|Right|[A-Za-z0-9]|[,/`=\\[\\]]";
+
+static SHORTCUT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"\b(?:{MODIFIERS})(?:[ \t]*[+-][ \t]*(?:{MODIFIERS}))*[ \t]*[+-][ \t]*(?:{KEYS})"
+    ))
+    .unwrap()
+});
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ShortcutStyle {
+    #[default]
+    Kbd,
+    Code,
+}
+
+/// Keyboard shortcuts should be formatted consistently, instead of appearing
+/// as plain text.
+///
+/// Shortcuts are detected as one or more modifier keys, such as `Ctrl` or
+/// `Cmd`, followed by a key, separated by `+` or `-`. Shortcuts that are
+/// already in code or a `<kbd>` element aren't flagged.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// Press <kbd>Ctrl+C</kbd> to copy.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// Press Ctrl+C to copy.
+/// ```
+///
+/// ## Configuration
+///
+/// Set `style` to `"kbd"` (the default) to wrap shortcuts in `<kbd>`
+/// elements, or to `"code"` to wrap them in backticks.
+///
+/// ```toml
+/// [Rule015KeyboardShortcuts]
+/// style = "code"
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule015KeyboardShortcuts {
+    style: ShortcutStyle,
+}
+
+impl Rule for Rule015KeyboardShortcuts {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(style) = settings.get_deserializable::<String>("style") {
+            match style.as_str() {
+                "kbd" => self.style = ShortcutStyle::Kbd,
+                "code" => self.style = ShortcutStyle::Code,
+                _ => warn!(
+                    "Invalid style \"{style}\" for {}. Expected \"kbd\" or \"code\".",
+                    self.name()
+                ),
+            }
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "style": {
+                "type": "string",
+                "enum": ["kbd", "code"],
+                "default": "kbd",
+                "description": "How to format keyboard shortcuts.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+
+        let mut errors = Vec::new();
+        for child in &root.children {
+            if !is_esm_paragraph(child) {
+                self.check_text(child, context, level, &mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule015KeyboardShortcuts {
+    fn format(&self, shortcut: &str) -> String {
+        match self.style {
+            ShortcutStyle::Kbd => format!("<kbd>{shortcut}</kbd>"),
+            ShortcutStyle::Code => format!("`{shortcut}`"),
+        }
+    }
+
+    fn check_text(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let element_name = match node {
+            Node::MdxJsxFlowElement(element) => element.name.as_deref(),
+            Node::MdxJsxTextElement(element) => element.name.as_deref(),
+            Node::Text(text) => {
+                if let Some(position) = text.position.as_ref() {
+                    self.check_source(position, context, level, errors);
+                }
+                return;
+            }
+            _ => None,
+        };
+        if element_name.is_some_and(|name| name.eq_ignore_ascii_case("kbd")) {
+            return;
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                self.check_text(child, context, level, errors);
+            }
+        }
+    }
+
+    fn check_source(
+        &self,
+        position: &markdown::unist::Position,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let node_start: usize = range.start.into();
+        let source = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+
+        for found in SHORTCUT_REGEX.find_iter(&source) {
+            // Named keys and letters must end at a word boundary, so that
+            // words like "Shift-click" aren't matched.
+            let shortcut = found.as_str();
+            if shortcut.ends_with(|c: char| c.is_alphanumeric())
+                && source[found.end()..].starts_with(|c: char| c.is_alphanumeric())
+            {
+                continue;
+            }
+            let start = node_start + found.start();
+            let end = node_start + found.end();
+
+            let location = DenormalizedLocation::from_offset_range(
+                AdjustedRange::new(AdjustedOffset::from(start), AdjustedOffset::from(end)),
+                context,
+            );
+            let formatted = self.format(shortcut);
+            let fix = LintCorrection::Replace(LintCorrectionReplace {
+                location: location.clone(),
+                text: formatted.clone(),
+            });
+            errors.push(
+                LintError::from_raw_location()
+                    .rule(self.name())
+                    .level(level)
+                    .message(format!(
+                        "Format the keyboard shortcut \"{shortcut}\" as {formatted}."
+                    ))
+                    .location(location)
+                    .fix(vec![fix])
+                    .call(),
+            );
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule015_plain_shortcut() {
+        assert_lint_snapshot!("Rule015KeyboardShortcuts", "Press Ctrl+C to copy.\n");
+    }
+
+    #[test]
+    fn test_rule015_multiple_modifiers() {
+        assert_lint_snapshot!(
+            "Rule015KeyboardShortcuts",
+            "Open the palette with Cmd-Shift-P or Ctrl + Enter.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule015_code_style() {
+        assert_lint_snapshot!(
+            "Rule015KeyboardShortcuts",
+            "Press Alt+F4.\n",
+            Some(json!({ "style": "code" }))
+        );
+    }
+
+    #[test]
+    fn test_rule015_formatted_shortcuts_ignored() {
+        assert_lint_snapshot!(
+            "Rule015KeyboardShortcuts",
+            "Press <kbd>Ctrl+C</kbd> or `Cmd+C` to copy.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule015_words_ignored() {
+        assert_lint_snapshot!(
+            "Rule015KeyboardShortcuts",
+            "Shift-click to select, and add Alt-text to images. Use the Option type.\n"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_MAX_LINES: usize = 1000;
+const DEFAULT_MAX_KILOBYTES: usize = 100;
+
+/// Source files shouldn't grow too large. Long pages are hard to review and
+/// maintain, and are usually better split into partials.
+///
+/// The error is reported once per file, on the first line, and includes the
+/// file's line count and size.
+///
+/// ## Examples
+///
+/// ### Invalid (assuming `max_lines` is 3)
+///
+/// ```markdown
+/// # Heading
+///
+/// A paragraph.
+///
+/// Another paragraph.
+/// ```
+///
+/// ## Configuration
+///
+/// - `max_lines`: Maximum number of lines in a file. Defaults to 1000.
+/// - `max_kilobytes`: Maximum size of a file, in kilobytes. Defaults to 100.
+///
+/// ```toml
+/// [Rule016FileSize]
+/// max_lines = 500
+/// max_kilobytes = 50
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule016FileSize {
+    max_lines: usize,
+    max_kilobytes: usize,
+}
+
+impl Default for Rule016FileSize {
+    fn default() -> Self {
+        Self {
+            max_lines: DEFAULT_MAX_LINES,
+            max_kilobytes: DEFAULT_MAX_KILOBYTES,
+        }
+    }
+}
+
+impl Rule for Rule016FileSize {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(max_lines) = settings.get_deserializable::<usize>("max_lines") {
+            self.max_lines = max_lines;
+        }
+        if let Some(max_kilobytes) = settings.get_deserializable::<usize>("max_kilobytes") {
+            self.max_kilobytes = max_kilobytes;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "max_lines": {
+                "type": "integer",
+                "minimum": 1,
+                "default": DEFAULT_MAX_LINES,
+                "description": "Maximum number of lines in a file.",
+            },
+            "max_kilobytes": {
+                "type": "integer",
+                "minimum": 1,
+                "default": DEFAULT_MAX_KILOBYTES,
+                "description": "Maximum size of a file, in kilobytes.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !matches!(ast, Node::Root(_)) {
+            return None;
+        }
+
+        let rope = context.rope();
+        let lines = rope.line_len();
+        let bytes = rope.byte_len();
+
+        let mut exceeded = Vec::new();
+        if lines > self.max_lines {
+            let plural = if self.max_lines == 1 { "" } else { "s" };
+            exceeded.push(format!("{} line{plural}", self.max_lines));
+        }
+        if bytes > self.max_kilobytes * 1024 {
+            exceeded.push(format!("{} KB", self.max_kilobytes));
+        }
+        if exceeded.is_empty() {
+            return None;
+        }
+
+        let first_line_end = if lines > 1 {
+            rope.byte_of_line(1)
+        } else {
+            bytes
+        };
+        let location = DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(
+                AdjustedOffset::from(0),
+                AdjustedOffset::from(first_line_end),
+            ),
+            context,
+        );
+
+        let error = LintError::from_raw_location()
+            .rule(self.name())
+            .level(level)
+            .message(format!(
+                "File has {lines} lines and is {:.1} KB, which exceeds the limit of {}. Consider splitting it into partials.",
+                bytes as f64 / 1024.0,
+                exceeded.join(" and ")
+            ))
+            .location(location)
+            .call()
+            .with_details(json!({
+                "lines": lines,
+                "bytes": bytes,
+                "max_lines": self.max_lines,
+                "max_bytes": self.max_kilobytes * 1024,
+            }));
+        Some(vec![error])
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule016_within_limits() {
+        assert_lint_snapshot!("Rule016FileSize", "# Heading\n\nA paragraph.\n");
+    }
+
+    #[test]
+    fn test_rule016_too_many_lines() {
+        assert_lint_snapshot!(
+            "Rule016FileSize",
+            "# Heading\n\nA paragraph.\n\nAnother paragraph.\n",
+            Some(json!({ "max_lines": 3 }))
+        );
+    }
+
+    #[test]
+    fn test_rule016_too_large() {
+        assert_lint_snapshot!(
+            "Rule016FileSize",
+            &format!("{}\n\nEnd.\n", "a".repeat(1100)),
+            Some(json!({ "max_lines": 1, "max_kilobytes": 1 }))
+        );
+    }
+
+    #[test]
+    fn test_rule016_reported_once() {
+        assert_lint_snapshot!(
+            "Rule016FileSize",
+            "# Heading\n\nA paragraph.\n",
+            Some(json!({ "max_lines": 1 }))
+        );
+    }
+}
@@ -0,0 +1,535 @@
+use std::collections::{BTreeMap, HashMap};
+
+use glob::Pattern;
+use log::{debug, warn};
+use markdown::mdast::{Code, Node};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    config::config_schema,
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::{edit_distance, path::matches_any_glob},
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+/// Name of the built-in schema describing this linter's own config file.
+const BUILTIN_SCHEMA: &str = "supa-mdx-lint";
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Config samples in code blocks should only use known keys, since readers
+/// copy them as-is.
+///
+/// Keys in `toml` and `json` code blocks are checked against the configured
+/// schemas. Each schema lists the allowed keys as dotted paths, where `*`
+/// matches any single key. The keys nested under an allowed key are only
+/// checked if the schema lists keys beneath it. Code blocks that can't be
+/// parsed, for example because they are abbreviated, are skipped.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ````markdown
+/// ```toml
+/// [Rule001HeadingCase]
+/// may_uppercase = ["Supabase"]
+/// ```
+/// ````
+///
+/// ### Invalid
+///
+/// ````markdown
+/// ```toml
+/// [Rule001HeadingCase]
+/// may_upercase = ["Supabase"]
+/// ```
+/// ````
+///
+/// ## Configuration
+///
+/// Schemas are keyed by name. The rule is inactive if no schemas are
+/// configured. Each schema has the following settings:
+///
+/// - `keys`: Allowed keys, as dotted paths.
+/// - `schema`: A built-in list of keys to allow, in addition to `keys`. Set it
+///   to `"supa-mdx-lint"` for the keys of this linter's config file.
+/// - `languages`: Code block languages to check, out of `toml` and `json`.
+///   Defaults to both.
+/// - `files`: Globs for the files to check. Defaults to all files.
+///
+/// A key is allowed if any schema that applies to the code block allows it.
+///
+/// ```toml
+/// [Rule017ConfigKeys.schemas.linter]
+/// schema = "supa-mdx-lint"
+/// languages = ["toml"]
+/// files = ["docs/guides/linting/**"]
+///
+/// [Rule017ConfigKeys.schemas.functions]
+/// keys = ["functions.*.verify_jwt", "functions.*.import_map"]
+/// ```
+#[derive(Debug, Default, RuleName)]
+#[rule_category(Correctness)]
+pub struct Rule017ConfigKeys {
+    schemas: Vec<KeySchema>,
+}
+
+#[derive(Debug)]
+struct KeySchema {
+    keys: Vec<Vec<String>>,
+    languages: Vec<String>,
+    files: Vec<Pattern>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeySchemaSettings {
+    schema: Option<String>,
+    #[serde(default)]
+    keys: Vec<String>,
+    #[serde(default = "default_languages")]
+    languages: Vec<String>,
+    #[serde(default)]
+    files: Vec<String>,
+}
+
+fn default_languages() -> Vec<String> {
+    vec!["toml".to_string(), "json".to_string()]
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum KeyStatus {
+    /// The key is allowed, and the schema lists keys beneath it.
+    Parent,
+    /// The key is allowed, and anything beneath it is too.
+    Leaf,
+    Unknown,
+}
+
+impl Rule for Rule017ConfigKeys {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Code])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        let Some(schemas) =
+            settings.get_deserializable::<BTreeMap<String, KeySchemaSettings>>("schemas")
+        else {
+            return;
+        };
+        for (name, schema) in schemas {
+            let mut keys = schema
+                .keys
+                .iter()
+                .map(|key| key.split('.').map(str::to_string).collect())
+                .collect::<Vec<_>>();
+            match schema.schema.as_deref() {
+                Some(BUILTIN_SCHEMA) => {
+                    schema_key_paths(&config_schema(), &mut Vec::new(), &mut keys)
+                }
+                Some(other) => warn!(
+                    "Unknown built-in schema \"{other}\" for {} schema {name}. Expected \"{BUILTIN_SCHEMA}\".",
+                    self.name()
+                ),
+                None => {}
+            }
+
+            let files = schema
+                .files
+                .iter()
+                .filter_map(|glob| match Pattern::new(glob) {
+                    Ok(pattern) => Some(pattern),
+                    Err(err) => {
+                        warn!("Encountered invalid glob in rule settings: {glob}: {err}");
+                        None
+                    }
+                })
+                .collect();
+            self.schemas.push(KeySchema {
+                keys,
+                languages: schema
+                    .languages
+                    .iter()
+                    .map(|language| language.to_lowercase())
+                    .collect(),
+                files,
+            });
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "schemas": {
+                "type": "object",
+                "description": "Schemas for config samples, keyed by name.",
+                "additionalProperties": object_schema(json!({
+                    "keys": string_array_schema("Allowed keys, as dotted paths. Use * to match any key."),
+                    "schema": {
+                        "type": "string",
+                        "enum": [BUILTIN_SCHEMA],
+                        "description": "A built-in list of keys to allow.",
+                    },
+                    "languages": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "default": default_languages(),
+                        "description": "Code block languages to check.",
+                    },
+                    "files": string_array_schema("Globs for the files to check."),
+                })),
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Code(code) = ast else {
+            return None;
+        };
+        let language = code.lang.as_deref()?.to_lowercase();
+
+        let schemas = self
+            .schemas
+            .iter()
+            .filter(|schema| schema.languages.contains(&language))
+            .filter(|schema| {
+                schema.files.is_empty()
+                    || context
+                        .file_path()
+                        .is_some_and(|path| matches_any_glob(path, &schema.files))
+            })
+            .collect::<Vec<_>>();
+        if schemas.is_empty() {
+            return None;
+        }
+
+        let document = match language.as_str() {
+            "json" => serde_json::from_str::<Value>(&code.value).ok(),
+            "toml" => toml::from_str::<toml::Table>(&code.value)
+                .ok()
+                .map(|table| toml_to_json(toml::Value::Table(table))),
+            _ => None,
+        };
+        let Some(document) = document else {
+            debug!("Skipping {language} code block that could not be parsed");
+            return None;
+        };
+
+        let mut unknown_keys = Vec::new();
+        Self::find_unknown_keys(&document, &mut Vec::new(), &schemas, &mut unknown_keys);
+        if unknown_keys.is_empty() {
+            return None;
+        }
+
+        let errors = self.errors(code, unknown_keys, &schemas, context, level);
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule017ConfigKeys {
+    fn find_unknown_keys(
+        value: &Value,
+        path: &mut Vec<String>,
+        schemas: &[&KeySchema],
+        unknown_keys: &mut Vec<Vec<String>>,
+    ) {
+        let Value::Object(object) = value else {
+            return;
+        };
+        for (key, value) in object {
+            path.push(key.clone());
+            match Self::key_status(path, schemas) {
+                KeyStatus::Parent => Self::find_unknown_keys(value, path, schemas, unknown_keys),
+                KeyStatus::Leaf => {}
+                KeyStatus::Unknown => unknown_keys.push(path.clone()),
+            }
+            path.pop();
+        }
+    }
+
+    fn key_status(path: &[String], schemas: &[&KeySchema]) -> KeyStatus {
+        let mut status = KeyStatus::Unknown;
+        for key in schemas.iter().flat_map(|schema| &schema.keys) {
+            if key.len() < path.len() || !Self::matches_prefix(key, path) {
+                continue;
+            }
+            if key.len() > path.len() {
+                return KeyStatus::Parent;
+            }
+            status = KeyStatus::Leaf;
+        }
+        status
+    }
+
+    fn matches_prefix(key: &[String], path: &[String]) -> bool {
+        key.iter()
+            .zip(path)
+            .all(|(pattern, segment)| pattern == "*" || pattern == segment)
+    }
+
+    /// Find the closest allowed key at the same level as an unknown key.
+    fn suggestion<'schema>(
+        path: &[String],
+        schemas: &[&'schema KeySchema],
+    ) -> Option<&'schema str> {
+        let (name, parent) = path.split_last()?;
+        schemas
+            .iter()
+            .flat_map(|schema| &schema.keys)
+            .filter(|key| key.len() > parent.len() && Self::matches_prefix(key, parent))
+            .map(|key| key[parent.len()].as_str())
+            .filter(|candidate| *candidate != "*")
+            .map(|candidate| (edit_distance(name, candidate), candidate))
+            .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+            .min()
+            .map(|(_, candidate)| candidate)
+    }
+
+    fn errors(
+        &self,
+        code: &Code,
+        unknown_keys: Vec<Vec<String>>,
+        schemas: &[&KeySchema],
+        context: &Context,
+        level: LintLevel,
+    ) -> Vec<LintError> {
+        let Some(position) = code.position.as_ref() else {
+            return Vec::new();
+        };
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let node_start: usize = range.start.into();
+        let source = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+        // Skip the opening fence, so that the language isn't mistaken for a
+        // key.
+        let content_start = source.find('\n').map_or(source.len(), |idx| idx + 1);
+
+        let mut search_from = HashMap::<String, usize>::new();
+        let mut errors = Vec::new();
+        for path in unknown_keys {
+            let name = path.last().expect("Key paths are never empty");
+            let from = search_from.get(name).copied().unwrap_or(content_start);
+            let (start, end) = match Self::key_offset(&source, name, from) {
+                Some(offset) => {
+                    search_from.insert(name.clone(), offset + name.len());
+                    (node_start + offset, node_start + offset + name.len())
+                }
+                None => (node_start, range.end.into()),
+            };
+            let location = DenormalizedLocation::from_offset_range(
+                AdjustedRange::new(AdjustedOffset::from(start), AdjustedOffset::from(end)),
+                context,
+            );
+
+            let key = path.join(".");
+            let suggestion = Self::suggestion(&path, schemas);
+            let message = match suggestion {
+                Some(suggestion) => {
+                    format!("Unknown config key \"{key}\". Did you mean \"{suggestion}\"?")
+                }
+                None => format!("Unknown config key \"{key}\"."),
+            };
+            let suggestions = suggestion.map(|suggestion| {
+                vec![LintCorrection::Replace(LintCorrectionReplace {
+                    location: location.clone(),
+                    text: suggestion.to_string(),
+                })]
+            });
+
+            errors.push(
+                LintError::from_raw_location()
+                    .rule(self.name())
+                    .level(level)
+                    .message(message)
+                    .location(location)
+                    .maybe_suggestions(suggestions)
+                    .call(),
+            );
+        }
+        errors
+    }
+
+    /// Find where a key is written in the source, as a whole word that may be
+    /// quoted.
+    fn key_offset(source: &str, key: &str, from: usize) -> Option<usize> {
+        let is_key_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+        source
+            .get(from..)?
+            .match_indices(key)
+            .map(|(idx, _)| from + idx)
+            .find(|&idx| {
+                !source[..idx].ends_with(is_key_char)
+                    && !source[idx + key.len()..].starts_with(is_key_char)
+            })
+    }
+}
+
+/// Collect the key paths described by a JSON schema, using `*` for keys that
+/// can have any name.
+fn schema_key_paths(schema: &Value, prefix: &mut Vec<String>, paths: &mut Vec<Vec<String>>) {
+    if let Some(variants) = schema.get("anyOf").and_then(Value::as_array) {
+        for variant in variants {
+            schema_key_paths(variant, prefix, paths);
+        }
+        return;
+    }
+
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        for (key, property) in properties {
+            prefix.push(key.clone());
+            paths.push(prefix.clone());
+            schema_key_paths(property, prefix, paths);
+            prefix.pop();
+        }
+    }
+    if let Some(additional) = schema.get("additionalProperties").filter(|v| v.is_object()) {
+        prefix.push("*".to_string());
+        paths.push(prefix.clone());
+        schema_key_paths(additional, prefix, paths);
+        prefix.pop();
+    }
+}
+
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(string) => Value::String(string),
+        toml::Value::Integer(integer) => json!(integer),
+        toml::Value::Float(float) => json!(float),
+        toml::Value::Boolean(boolean) => Value::Bool(boolean),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(array) => Value::Array(array.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fix")]
+    use crate::testing::assert_lint_snapshot;
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule017_builtin_schema() {
+        let mdx = r#"```toml
+ignore_patterns = ["drafts/**"]
+
+[Rule001HeadingCase]
+may_uppercase = ["Supabase"]
+
+[Rule004ExcludeWords.rules.simple]
+words = ["simply"]
+description = "Avoid assumptions."
+
+[profile.ci]
+Rule003Spelling = false
+```
+"#;
+        assert_lint_snapshot!(
+            "Rule017ConfigKeys",
+            mdx,
+            Some(json!({ "schemas": { "linter": { "schema": "supa-mdx-lint" } } }))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule017_unknown_key_with_suggestion() {
+        assert_lint_snapshot!(
+            "Rule017ConfigKeys",
+            "```toml\n[Rule001HeadingCase]\nmay_upercase = [\"Supabase\"]\n```\n",
+            Some(json!({ "schemas": { "linter": { "schema": "supa-mdx-lint" } } }))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule017_custom_keys_json() {
+        let mdx = r#"```json
+{ "functions": { "hello": { "verify_jwt": false, "import_mapp": "./map.json" } } }
+```
+
+```json
+{ "other": true }
+```
+"#;
+        assert_lint_snapshot!(
+            "Rule017ConfigKeys",
+            mdx,
+            Some(json!({
+                "schemas": {
+                    "functions": { "keys": ["functions.*.verify_jwt", "functions.*.import_map"] }
+                }
+            }))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule017_languages_and_unparseable_blocks() {
+        assert_lint_snapshot!(
+            "Rule017ConfigKeys",
+            "```json\n{ \"unknown\": true }\n```\n\n```toml\nunknown = \n```\n\n```toml\nunknown = true\n```\n",
+            Some(json!({ "schemas": { "linter": { "keys": ["known"], "languages": ["toml"] } } }))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule017_inactive_without_schemas() {
+        assert_lint_snapshot!("Rule017ConfigKeys", "```toml\nunknown = true\n```\n");
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule017_files() {
+        let settings = json!({
+            "schemas": { "linter": { "keys": ["known"], "files": ["docs/**"] } }
+        });
+        assert_lint_snapshot!(
+            "Rule017ConfigKeys",
+            "```toml\nunknown = true\n```\n",
+            Some(settings.clone()),
+            file = "docs/config.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule017ConfigKeys",
+            "```toml\nunknown = true\n```\n",
+            Some(settings),
+            file = "guides/config.mdx"
+        );
+    }
+
+    #[test]
+    fn test_key_offset() {
+        let source = "```toml\nbase_url = 1\nurl = 2\n```";
+        assert_eq!(Rule017ConfigKeys::key_offset(source, "url", 8), Some(21));
+        assert_eq!(
+            Rule017ConfigKeys::key_offset("{ \"a\": { \"a\": 1 } }", "a", 5),
+            Some(10)
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::sync::LazyLock;
+
+use markdown::mdast::Node;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::{edit_distance, mdast::is_esm_paragraph},
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_SNIPPETS: &[&str] = &[
+    "lorem ipsum dolor sit amet",
+    "consectetur adipiscing elit",
+    "sed do eiusmod tempor",
+    "add your content here",
+    "replace this text",
+    "insert description here",
+];
+
+/// Snippets are allowed one edit for every this many characters, so that
+/// placeholders with typos or small changes are still caught.
+const CHARS_PER_EDIT: usize = 10;
+
+static WORD_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[\p{L}\p{N}']+").unwrap());
+
+/// Placeholder text from page templates, such as "Lorem ipsum", must be
+/// replaced before publishing.
+///
+/// Snippets are matched case-insensitively, ignoring punctuation, and allow
+/// small differences such as typos.
+///
+/// ## Examples
+///
+/// ### Invalid
+///
+/// ```markdown
+/// ## Overview
+///
+/// Lorem ipsum dolor sit amet, consectetur adipiscing elit.
+/// ```
+///
+/// ## Configuration
+///
+/// - `snippets`: Placeholder snippets to detect, in addition to the built-in
+///   list, such as the placeholders used in your page templates.
+///
+/// ```toml
+/// [Rule018PlaceholderText]
+/// snippets = ["Describe the feature here"]
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Correctness)]
+pub struct Rule018PlaceholderText {
+    snippets: Vec<Vec<String>>,
+}
+
+impl Default for Rule018PlaceholderText {
+    fn default() -> Self {
+        Self {
+            snippets: DEFAULT_SNIPPETS
+                .iter()
+                .map(|snippet| Self::words(snippet))
+                .collect(),
+        }
+    }
+}
+
+impl Rule for Rule018PlaceholderText {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(snippets) = settings.get_array_of_strings("snippets") {
+            self.snippets.extend(
+                snippets
+                    .iter()
+                    .map(|snippet| Self::words(snippet))
+                    .filter(|words| !words.is_empty()),
+            );
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "snippets": string_array_schema("Placeholder snippets to detect, in addition to the built-in list."),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+
+        let mut errors = Vec::new();
+        for child in &root.children {
+            if !is_esm_paragraph(child) {
+                self.check_text(child, context, level, &mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule018PlaceholderText {
+    fn words(text: &str) -> Vec<String> {
+        WORD_REGEX
+            .find_iter(text)
+            .map(|word| word.as_str().to_lowercase())
+            .collect()
+    }
+
+    fn check_text(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        if let Node::Text(text) = node {
+            if let Some(position) = text.position.as_ref() {
+                self.check_source(position, context, level, errors);
+            }
+            return;
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                self.check_text(child, context, level, errors);
+            }
+        }
+    }
+
+    fn check_source(
+        &self,
+        position: &markdown::unist::Position,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let node_start: usize = range.start.into();
+        let source = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+
+        let words = WORD_REGEX.find_iter(&source).collect::<Vec<_>>();
+        let lowercase_words = words
+            .iter()
+            .map(|word| word.as_str().to_lowercase())
+            .collect::<Vec<_>>();
+
+        let mut idx = 0;
+        while idx < words.len() {
+            let Some(length) = self.match_at(&lowercase_words[idx..]) else {
+                idx += 1;
+                continue;
+            };
+
+            let start = node_start + words[idx].start();
+            let end = node_start + words[idx + length - 1].end();
+            let placeholder = &source[words[idx].start()..words[idx + length - 1].end()];
+            let location = DenormalizedLocation::from_offset_range(
+                AdjustedRange::new(AdjustedOffset::from(start), AdjustedOffset::from(end)),
+                context,
+            );
+            errors.push(
+                LintError::from_raw_location()
+                    .rule(self.name())
+                    .level(level)
+                    .message(format!(
+                        "Replace the placeholder text \"{placeholder}\" left over from a template."
+                    ))
+                    .location(location)
+                    .call(),
+            );
+            idx += length;
+        }
+    }
+
+    /// Check whether any snippet matches the words at the start of `words`,
+    /// returning the number of words matched.
+    fn match_at(&self, words: &[String]) -> Option<usize> {
+        self.snippets
+            .iter()
+            .filter(|snippet| snippet.len() <= words.len())
+            .find(|snippet| {
+                let expected = snippet.join(" ");
+                let actual = words[..snippet.len()].join(" ");
+                edit_distance(&actual, &expected) <= expected.len() / CHARS_PER_EDIT
+            })
+            .map(Vec::len)
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule018_lorem_ipsum() {
+        assert_lint_snapshot!(
+            "Rule018PlaceholderText",
+            "## Overview\n\nLorem ipsum dolor sit amet, consectetur adipiscing elit.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule018_fuzzy_match() {
+        assert_lint_snapshot!(
+            "Rule018PlaceholderText",
+            "Lorem ipsom dolor sit amet.\n\nADD YOUR CONTENT HERE\n"
+        );
+    }
+
+    #[test]
+    fn test_rule018_no_false_positives() {
+        assert_lint_snapshot!(
+            "Rule018PlaceholderText",
+            "Lorem is a name, and so is Ipsum.\n\nReplace this setting with your own.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule018_custom_snippets() {
+        assert_lint_snapshot!(
+            "Rule018PlaceholderText",
+            "## Feature\n\nDescribe the feature here.\n",
+            Some(json!({ "snippets": ["Describe the feature here."] }))
+        );
+    }
+}
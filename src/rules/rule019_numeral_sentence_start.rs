@@ -0,0 +1,289 @@
+use markdown::mdast::Node;
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::mdast::is_esm_paragraph,
+};
+
+use super::{NodeKind, Rule, RuleName};
+
+const NUMBER_WORDS: &[&str] = &[
+    "Zero",
+    "One",
+    "Two",
+    "Three",
+    "Four",
+    "Five",
+    "Six",
+    "Seven",
+    "Eight",
+    "Nine",
+    "Ten",
+    "Eleven",
+    "Twelve",
+    "Thirteen",
+    "Fourteen",
+    "Fifteen",
+    "Sixteen",
+    "Seventeen",
+    "Eighteen",
+    "Nineteen",
+    "Twenty",
+];
+
+/// Abbreviations that end in a period without ending the sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "e.g.", "i.e.", "etc.", "vs.", "approx.", "no.", "fig.", "p.", "pp.", "v.", "ch.", "sec.",
+];
+
+const CLOSING_PUNCTUATION: &[char] = &['"', '\'', ')', ']', '”', '’'];
+
+/// Sentences shouldn't start with a numeral. Spell the number out, or rewrite
+/// the sentence so the number comes later.
+///
+/// Headings, list items, and tables are exempt, since numerals are often
+/// clearer there. Numbers up to twenty can be fixed automatically.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// Three ways to connect to your database.
+///
+/// There are 3 ways to connect to your database.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// 3 ways to connect to your database.
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule019NumeralSentenceStart;
+
+impl Rule for Rule019NumeralSentenceStart {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+
+        let mut errors = Vec::new();
+        for child in &root.children {
+            if !is_esm_paragraph(child) {
+                self.check_node(child, context, level, &mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule019NumeralSentenceStart {
+    fn check_node(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        match node {
+            Node::Heading(_)
+            | Node::ListItem(_)
+            | Node::Table(_)
+            | Node::Code(_)
+            | Node::InlineCode(_) => {}
+            Node::Paragraph(paragraph) => {
+                for (idx, child) in paragraph.children.iter().enumerate() {
+                    match child {
+                        Node::Text(_) => self.check_text(child, idx == 0, context, level, errors),
+                        _ => self.check_node(child, context, level, errors),
+                    }
+                }
+            }
+            Node::Text(_) => self.check_text(node, false, context, level, errors),
+            _ => {
+                if let Some(children) = node.children() {
+                    for child in children {
+                        self.check_node(child, context, level, errors);
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_text(
+        &self,
+        node: &Node,
+        at_paragraph_start: bool,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let Some(position) = node.position() else {
+            return;
+        };
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let node_start: usize = range.start.into();
+        let source = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+
+        for (start, number) in numerals(&source) {
+            let end = start + number.len();
+            if !is_sentence_start(&source[..start], at_paragraph_start) {
+                continue;
+            }
+            // Part of a larger token, such as "3.5", "10,000", or "3px".
+            if source[end..]
+                .chars()
+                .next()
+                .is_some_and(|c| !c.is_whitespace())
+            {
+                continue;
+            }
+
+            let location = DenormalizedLocation::from_offset_range(
+                AdjustedRange::new(
+                    AdjustedOffset::from(node_start + start),
+                    AdjustedOffset::from(node_start + end),
+                ),
+                context,
+            );
+            let fix = number
+                .parse::<usize>()
+                .ok()
+                .and_then(|number| NUMBER_WORDS.get(number))
+                .map(|word| {
+                    vec![LintCorrection::Replace(LintCorrectionReplace {
+                        location: location.clone(),
+                        text: word.to_string(),
+                    })]
+                });
+
+            errors.push(
+                LintError::from_raw_location()
+                    .rule(self.name())
+                    .level(level)
+                    .message(format!(
+                        "Don't start a sentence with a numeral. Spell out \"{number}\" or rewrite the sentence."
+                    ))
+                    .location(location)
+                    .maybe_fix(fix)
+                    .call(),
+            );
+        }
+    }
+}
+
+/// Find the runs of ASCII digits in the text that aren't preceded by another
+/// word character, returning their byte offsets.
+fn numerals(text: &str) -> Vec<(usize, &str)> {
+    let mut numerals = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    let mut previous = None::<char>;
+    while let Some((start, c)) = chars.next() {
+        if c.is_ascii_digit() && !previous.is_some_and(|p| p.is_alphanumeric()) {
+            let mut end = start + 1;
+            while let Some((idx, next)) = chars.peek().copied() {
+                if !next.is_ascii_digit() {
+                    break;
+                }
+                end = idx + 1;
+                chars.next();
+            }
+            numerals.push((start, &text[start..end]));
+            previous = text[..end].chars().next_back();
+        } else {
+            previous = Some(c);
+        }
+    }
+    numerals
+}
+
+/// Whether a word following the given text would start a sentence.
+fn is_sentence_start(before: &str, at_paragraph_start: bool) -> bool {
+    if before.is_empty() {
+        return at_paragraph_start;
+    }
+    if !before.ends_with(char::is_whitespace) {
+        return false;
+    }
+
+    let before = before.trim_end();
+    let punctuated = before.trim_end_matches(CLOSING_PUNCTUATION);
+    if !punctuated.ends_with(['.', '!', '?']) {
+        return false;
+    }
+
+    let last_word = punctuated
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches(['"', '\'', '(', '[', '“', '‘'])
+        .to_lowercase();
+    !ABBREVIATIONS.contains(&last_word.as_str())
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule019_paragraph_start() {
+        assert_lint_snapshot!(
+            "Rule019NumeralSentenceStart",
+            "3 ways to connect to your database.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule019_mid_paragraph() {
+        assert_lint_snapshot!(
+            "Rule019NumeralSentenceStart",
+            "Connect to your database. 12 options are available.\n\nIt's fast! (\"Really.\") 2024 was a big year.\n\nRun `init`. 2 tables are created.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule019_valid() {
+        assert_lint_snapshot!(
+            "Rule019NumeralSentenceStart",
+            "There are 3 ways to connect.\n\nUse a tool, e.g. 3 different ones.\n\n3.5 is the version.\n\n10,000 users signed up.\n\n3D rendering is supported.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule019_exempt_nodes() {
+        assert_lint_snapshot!(
+            "Rule019NumeralSentenceStart",
+            "## 3 ways to connect\n\n- 3 ways to connect\n- 4 more ways\n\n| Col |\n| --- |\n| 3 ways |\n\n```\n3 ways\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_rule019_inline_start() {
+        // Text that follows inline content doesn't start the paragraph.
+        assert_lint_snapshot!(
+            "Rule019NumeralSentenceStart",
+            "**Note:** 3 ways to connect.\n\n> 3 ways to connect.\n"
+        );
+    }
+}
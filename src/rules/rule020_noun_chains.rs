@@ -0,0 +1,339 @@
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::{
+        mdast::is_esm_paragraph,
+        words::{Capitalize, WordIterator, WordIteratorOptions},
+    },
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_MIN_LENGTH: usize = 4;
+
+/// Words that join or introduce nouns rather than stacking them, and so break
+/// up a chain.
+const FUNCTION_WORDS: &[&str] = &[
+    "a", "about", "above", "after", "all", "also", "am", "an", "and", "any", "are", "as", "at",
+    "be", "been", "before", "being", "below", "between", "both", "but", "by", "can", "could",
+    "did", "do", "does", "each", "either", "every", "for", "from", "had", "has", "have", "he",
+    "her", "his", "how", "i", "if", "in", "into", "is", "it", "its", "may", "might", "more",
+    "most", "must", "my", "neither", "no", "nor", "not", "of", "off", "on", "once", "only", "or",
+    "our", "out", "over", "shall", "she", "should", "so", "some", "than", "that", "the", "their",
+    "them", "then", "there", "these", "they", "this", "those", "through", "to", "under", "until",
+    "up", "very", "was", "we", "were", "what", "when", "where", "which", "while", "who", "why",
+    "will", "with", "within", "without", "would", "you", "your",
+];
+
+/// Long chains of stacked nouns and modifiers, such as "Postgres row level
+/// security policy configuration guide", are hard to parse. Consider
+/// rewriting them with prepositions or verbs: "a guide to configuring row
+/// level security policies in Postgres".
+///
+/// This is a heuristic, so it's reported as a hint. A chain is a run of words
+/// without articles, prepositions, conjunctions, or punctuation between them,
+/// where at least one word is capitalized mid-sentence or technical, such as
+/// an acronym or an identifier. Headings, code, and tables are skipped.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// This guide explains how to configure row level security policies in Postgres.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// Read the Postgres row level security policy configuration guide.
+/// ```
+///
+/// ## Configuration
+///
+/// - `min_length`: The number of words in a row that counts as a long chain.
+///   Defaults to 4.
+///
+/// ```toml
+/// [Rule020NounChains]
+/// min_length = 5
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule020NounChains {
+    min_length: usize,
+}
+
+impl Default for Rule020NounChains {
+    fn default() -> Self {
+        Self {
+            min_length: DEFAULT_MIN_LENGTH,
+        }
+    }
+}
+
+impl Rule for Rule020NounChains {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(min_length) = settings.get_deserializable::<usize>("min_length") {
+            self.min_length = min_length.max(2);
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "min_length": {
+                "type": "integer",
+                "minimum": 2,
+                "description": "The number of words in a row that counts as a long chain.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+
+        let mut errors = Vec::new();
+        for child in &root.children {
+            if !is_esm_paragraph(child) {
+                self.check_node(child, context, level, &mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule020NounChains {
+    fn check_node(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        match node {
+            Node::Heading(_) | Node::Table(_) | Node::Code(_) | Node::InlineCode(_) => {}
+            Node::Paragraph(paragraph) => {
+                for (idx, child) in paragraph.children.iter().enumerate() {
+                    match child {
+                        Node::Text(_) => self.check_text(child, idx == 0, context, level, errors),
+                        _ => self.check_node(child, context, level, errors),
+                    }
+                }
+            }
+            Node::Text(_) => self.check_text(node, false, context, level, errors),
+            _ => {
+                if let Some(children) = node.children() {
+                    for child in children {
+                        self.check_node(child, context, level, errors);
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_text(
+        &self,
+        node: &Node,
+        at_paragraph_start: bool,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let Some(position) = node.position() else {
+            return;
+        };
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let node_start: usize = range.start.into();
+        let text = context.rope().byte_slice(range.to_usize_range());
+
+        let options = WordIteratorOptions {
+            initial_capitalize: if at_paragraph_start {
+                Capitalize::True
+            } else {
+                Capitalize::False
+            },
+            ..Default::default()
+        };
+
+        let mut chain = Vec::<ChainWord>::new();
+        let mut previous_end = None::<usize>;
+        for (offset, word, capitalize) in WordIterator::new(text, node_start, options) {
+            let word = word.to_string();
+            let start = offset;
+            let end = offset + word.len();
+            let breaks_chain = previous_end.is_some_and(|previous_end| {
+                !text
+                    .byte_slice(previous_end - node_start..start - node_start)
+                    .chars()
+                    .all(char::is_whitespace)
+            });
+            let leading_punctuation = word.starts_with(|c: char| !c.is_alphanumeric());
+            if breaks_chain || leading_punctuation {
+                self.flush(&mut chain, context, level, errors);
+            }
+
+            let word = word.trim_start_matches(|c: char| !c.is_alphanumeric());
+            let start = end - word.len();
+            let trimmed = word.trim_end_matches(|c: char| !c.is_alphanumeric());
+            if trimmed.len() < word.len() {
+                // Trailing punctuation, such as a comma or period, ends the
+                // chain after this word.
+                previous_end = None;
+            } else {
+                previous_end = Some(end);
+            }
+
+            if is_function_word(trimmed) || !trimmed.chars().any(char::is_alphabetic) {
+                self.flush(&mut chain, context, level, errors);
+                continue;
+            }
+
+            chain.push(ChainWord {
+                start,
+                end: start + trimmed.len(),
+                notable: is_technical(trimmed)
+                    || (capitalize == Capitalize::False && is_capitalized(trimmed)),
+            });
+            if previous_end.is_none() {
+                self.flush(&mut chain, context, level, errors);
+            }
+        }
+        self.flush(&mut chain, context, level, errors);
+    }
+
+    /// Report the current chain if it's long enough, and start a new one.
+    fn flush(
+        &self,
+        chain: &mut Vec<ChainWord>,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let words = std::mem::take(chain);
+        if words.len() < self.min_length || !words.iter().any(|word| word.notable) {
+            return;
+        }
+
+        let (first, last) = (&words[0], &words[words.len() - 1]);
+        let location = DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(
+                AdjustedOffset::from(first.start),
+                AdjustedOffset::from(last.end),
+            ),
+            context,
+        );
+        let phrase = context.rope().byte_slice(first.start..last.end).to_string();
+        errors.push(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(format!(
+                    "The {}-word noun chain \"{phrase}\" may be hard to read. Consider rewriting it with prepositions or verbs.",
+                    words.len()
+                ))
+                .location(location)
+                .call(),
+        );
+    }
+}
+
+#[derive(Debug)]
+struct ChainWord {
+    start: usize,
+    end: usize,
+    notable: bool,
+}
+
+fn is_function_word(word: &str) -> bool {
+    let lowercase = word.to_lowercase();
+    let lowercase = lowercase
+        .strip_suffix("'s")
+        .or_else(|| lowercase.strip_suffix("’s"))
+        .unwrap_or(&lowercase);
+    FUNCTION_WORDS.contains(&lowercase)
+}
+
+fn is_capitalized(word: &str) -> bool {
+    word.chars().next().is_some_and(char::is_uppercase)
+}
+
+/// Whether the word looks like an acronym, an identifier, or a version, such
+/// as "API", "PostgREST", or "pg15".
+fn is_technical(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next();
+    chars.any(char::is_uppercase) || word.chars().any(|c| c.is_ascii_digit())
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule020_noun_chain() {
+        assert_lint_snapshot!(
+            "Rule020NounChains",
+            "Read the Postgres row level security policy configuration guide.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule020_technical_words() {
+        assert_lint_snapshot!(
+            "Rule020NounChains",
+            "it uses the JWT secret rotation schedule.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule020_no_notable_words() {
+        // Long runs of plain lowercase words are often ordinary prose, and
+        // capitalization at the start of a sentence doesn't count.
+        assert_lint_snapshot!(
+            "Rule020NounChains",
+            "Pick the database backup retention period.\n\nDatabase backup retention period options.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule020_broken_chains() {
+        assert_lint_snapshot!(
+            "Rule020NounChains",
+            "Read the guide to configuring row level security policies in Postgres.\n\nUse Postgres, Supabase Auth, and Storage buckets.\n\n## Postgres row level security policy guide\n\nRun `Postgres row level security policy guide`.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule020_min_length() {
+        assert_lint_snapshot!(
+            "Rule020NounChains",
+            "Read the Postgres row level security policy configuration guide.\n",
+            Some(json!({ "min_length": 8 }))
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use glob::Pattern;
+use log::warn;
+use markdown::mdast::Node;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::path::matches_any_glob,
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_MIN_LENGTH: usize = 50;
+const DEFAULT_MAX_LENGTH: usize = 160;
+
+/// Pages need a `description` in their frontmatter, of a length that search
+/// engines display in full. Descriptions that are too short don't say much,
+/// and ones that are too long are cut off.
+///
+/// Lengths are counted in characters. The rule only applies to files matching
+/// the configured `files` globs, or the globs of one of the `overrides`.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// ---
+/// title: Row Level Security
+/// description: Secure your data using Postgres Row Level Security policies, and control access per user.
+/// ---
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// ---
+/// title: Row Level Security
+/// description: Secure your data.
+/// ---
+/// ```
+///
+/// ## Configuration
+///
+/// - `files`: Globs for the files to check.
+/// - `min_length`: Minimum length of a description. Defaults to 50.
+/// - `max_length`: Maximum length of a description. Defaults to 160.
+/// - `overrides`: Different bounds for some files. The last override whose
+///   `files` globs match a file applies, and any bound it doesn't set falls
+///   back to the top-level setting.
+///
+/// ```toml
+/// [Rule021DescriptionLength]
+/// files = ["content/guides/**"]
+/// min_length = 70
+///
+/// [[Rule021DescriptionLength.overrides]]
+/// files = ["content/reference/**"]
+/// min_length = 20
+/// max_length = 120
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule021DescriptionLength {
+    files: Vec<Pattern>,
+    bounds: Bounds,
+    overrides: Vec<Override>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds {
+    min: usize,
+    max: usize,
+}
+
+#[derive(Debug)]
+struct Override {
+    files: Vec<Pattern>,
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OverrideIntermediate {
+    files: Vec<String>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+}
+
+impl Default for Rule021DescriptionLength {
+    fn default() -> Self {
+        Self {
+            files: Vec::new(),
+            bounds: Bounds {
+                min: DEFAULT_MIN_LENGTH,
+                max: DEFAULT_MAX_LENGTH,
+            },
+            overrides: Vec::new(),
+        }
+    }
+}
+
+impl Rule for Rule021DescriptionLength {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(files) = settings.get_array_of_globs("files") {
+            self.files = files;
+        }
+        if let Some(min) = settings.get_deserializable::<usize>("min_length") {
+            self.bounds.min = min;
+        }
+        if let Some(max) = settings.get_deserializable::<usize>("max_length") {
+            self.bounds.max = max;
+        }
+        if let Some(overrides) =
+            settings.get_deserializable::<Vec<OverrideIntermediate>>("overrides")
+        {
+            self.overrides = overrides
+                .into_iter()
+                .map(|item| Override {
+                    files: item
+                        .files
+                        .iter()
+                        .filter_map(|glob| match Pattern::new(glob) {
+                            Ok(pattern) => Some(pattern),
+                            Err(err) => {
+                                warn!("Encountered invalid glob in rule settings: {glob}: {err}");
+                                None
+                            }
+                        })
+                        .collect(),
+                    min: item.min_length,
+                    max: item.max_length,
+                })
+                .collect();
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "files": string_array_schema("Globs for the files to check."),
+            "min_length": {
+                "type": "integer",
+                "minimum": 0,
+                "default": DEFAULT_MIN_LENGTH,
+                "description": "Minimum length of a description, in characters.",
+            },
+            "max_length": {
+                "type": "integer",
+                "minimum": 1,
+                "default": DEFAULT_MAX_LENGTH,
+                "description": "Maximum length of a description, in characters.",
+            },
+            "overrides": {
+                "type": "array",
+                "description": "Different bounds for the files matching some globs. The last matching override applies.",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "files": string_array_schema("Globs for the files the override applies to."),
+                        "min_length": { "type": "integer", "minimum": 0 },
+                        "max_length": { "type": "integer", "minimum": 1 },
+                    },
+                    "required": ["files"],
+                    "additionalProperties": false,
+                },
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !matches!(ast, Node::Root(_)) {
+            return None;
+        }
+        let bounds = self.bounds_for(context)?;
+
+        let Some(description) = context.parse_result.frontmatter_str("description") else {
+            let error = LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message("Add a \"description\" field to the frontmatter. Search engines show it in results.")
+                .location(Self::first_line(context))
+                .call();
+            return Some(vec![error]);
+        };
+
+        let length = description.trim().chars().count();
+        let message = if length < bounds.min {
+            format!(
+                "Description is {length} characters long. Use at least {} characters.",
+                bounds.min
+            )
+        } else if length > bounds.max {
+            format!(
+                "Description is {length} characters long. Use at most {} characters so it isn't cut off in search results.",
+                bounds.max
+            )
+        } else {
+            return None;
+        };
+
+        let error = LintError::from_raw_location()
+            .rule(self.name())
+            .level(level)
+            .message(message)
+            .location(Self::description_line(context).unwrap_or_else(|| Self::first_line(context)))
+            .call()
+            .with_details(json!({
+                "length": length,
+                "min_length": bounds.min,
+                "max_length": bounds.max,
+            }));
+        Some(vec![error])
+    }
+}
+
+impl Rule021DescriptionLength {
+    /// The bounds that apply to the file being linted, or `None` if the rule
+    /// doesn't apply to it.
+    fn bounds_for(&self, context: &Context) -> Option<Bounds> {
+        let path = context.file_path()?;
+        if let Some(item) = self
+            .overrides
+            .iter()
+            .rev()
+            .find(|item| matches_any_glob(path, &item.files))
+        {
+            return Some(Bounds {
+                min: item.min.unwrap_or(self.bounds.min),
+                max: item.max.unwrap_or(self.bounds.max),
+            });
+        }
+
+        matches_any_glob(path, &self.files).then_some(self.bounds)
+    }
+
+    /// The line of the frontmatter that sets the description.
+    fn description_line(context: &Context) -> Option<DenormalizedLocation> {
+        let rope = context.rope();
+        let frontmatter_end: usize = context.content_start_offset().into();
+        let row = (0..rope.line_len())
+            .take_while(|row| rope.byte_of_line(*row) < frontmatter_end)
+            .find(|row| {
+                let line = rope.line(*row).to_string();
+                let line = line.trim_start();
+                line.strip_prefix("description")
+                    .is_some_and(|rest| rest.trim_start().starts_with([':', '=']))
+            })?;
+
+        let start = rope.byte_of_line(row);
+        let end = start + rope.line(row).byte_len();
+        Some(DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(AdjustedOffset::from(start), AdjustedOffset::from(end)),
+            context,
+        ))
+    }
+
+    fn first_line(context: &Context) -> DenormalizedLocation {
+        let rope = context.rope();
+        let end = if rope.line_len() > 0 {
+            rope.line(0).byte_len()
+        } else {
+            0
+        };
+        DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(AdjustedOffset::from(0), AdjustedOffset::from(end)),
+            context,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    fn page(description: &str) -> String {
+        format!("---\ntitle: Page\ndescription: {description}\n---\n\n# Page\n")
+    }
+
+    #[test]
+    fn test_rule021_only_checks_configured_files() {
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            &page("Short."),
+            None,
+            file = "docs/guide.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            &page("Short."),
+            Some(json!({ "files": ["docs/**"] })),
+            file = "docs/guide.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            &page("Short."),
+            Some(json!({ "files": ["docs/**"] })),
+            file = "blog/post.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule021_missing_description() {
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            "---\ntitle: Page\n---\n\n# Page\n",
+            Some(json!({ "files": ["**"] })),
+            file = "guide.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            "# Page\n",
+            Some(json!({ "files": ["**"] })),
+            file = "guide.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule021_too_short() {
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            &page("Secure your data."),
+            Some(json!({ "files": ["**"] })),
+            file = "guide.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule021_too_long() {
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            &page(&"é".repeat(61)),
+            Some(json!({ "files": ["**"], "max_length": 60 })),
+            file = "guide.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule021_within_bounds() {
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            &page(
+                "Secure your data using Postgres Row Level Security policies, and control access."
+            ),
+            Some(json!({ "files": ["**"] })),
+            file = "guide.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule021_overrides() {
+        let settings = json!({
+            "files": ["docs/**"],
+            "overrides": [
+                { "files": ["docs/reference/**"], "min_length": 10 },
+                { "files": ["api/**"], "min_length": 5, "max_length": 20 },
+            ],
+        });
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            &page("Secure your data."),
+            Some(settings.clone()),
+            file = "docs/reference/auth.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            &page("Secure your data."),
+            Some(settings.clone()),
+            file = "docs/guides/auth.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            &page("The auth API reference."),
+            Some(settings),
+            file = "api/auth.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule021_toml_frontmatter() {
+        assert_lint_snapshot!(
+            "Rule021DescriptionLength",
+            "---\ntitle = \"Page\"\ndescription = \"Short.\"\n---\n\n# Page\n",
+            Some(json!({ "files": ["**"] })),
+            file = "guide.mdx"
+        );
+    }
+}
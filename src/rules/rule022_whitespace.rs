@@ -0,0 +1,352 @@
+use std::ops::Range;
+
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionDelete, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_MAX_BLANK_LINES: usize = 1;
+const DEFAULT_TAB_WIDTH: usize = 4;
+
+/// Lines shouldn't end in whitespace, prose shouldn't contain hard tabs, and
+/// there shouldn't be more than a set number of blank lines in a row.
+///
+/// The rule works on the lines of the source rather than the parsed document.
+/// Frontmatter and code blocks, including their fences, are skipped unless
+/// configured otherwise. All three problems can be fixed automatically:
+/// trailing whitespace and extra blank lines are removed, tabs used for
+/// indentation are replaced with spaces, and other tabs with a single space.
+///
+/// ## Examples
+///
+/// ### Invalid
+///
+/// ```markdown
+/// A line with trailing spaces.···
+///
+///
+/// A paragraph after two blank lines.
+/// ```
+///
+/// ## Configuration
+///
+/// - `max_blank_lines`: Maximum number of consecutive blank lines. Defaults
+///   to 1.
+/// - `tab_width`: Number of spaces that replace each tab used for indentation.
+///   Defaults to 4.
+/// - `include_frontmatter`: Also check the frontmatter. Defaults to false.
+/// - `include_code`: Also check code blocks. Defaults to false.
+///
+/// ```toml
+/// [Rule022Whitespace]
+/// max_blank_lines = 2
+/// include_frontmatter = true
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule022Whitespace {
+    max_blank_lines: usize,
+    tab_width: usize,
+    include_frontmatter: bool,
+    include_code: bool,
+}
+
+impl Default for Rule022Whitespace {
+    fn default() -> Self {
+        Self {
+            max_blank_lines: DEFAULT_MAX_BLANK_LINES,
+            tab_width: DEFAULT_TAB_WIDTH,
+            include_frontmatter: false,
+            include_code: false,
+        }
+    }
+}
+
+impl Rule for Rule022Whitespace {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(max_blank_lines) = settings.get_deserializable::<usize>("max_blank_lines") {
+            self.max_blank_lines = max_blank_lines;
+        }
+        if let Some(tab_width) = settings.get_deserializable::<usize>("tab_width") {
+            self.tab_width = tab_width;
+        }
+        if let Some(include_frontmatter) =
+            settings.get_deserializable::<bool>("include_frontmatter")
+        {
+            self.include_frontmatter = include_frontmatter;
+        }
+        if let Some(include_code) = settings.get_deserializable::<bool>("include_code") {
+            self.include_code = include_code;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "max_blank_lines": {
+                "type": "integer",
+                "minimum": 0,
+                "default": DEFAULT_MAX_BLANK_LINES,
+                "description": "Maximum number of consecutive blank lines.",
+            },
+            "tab_width": {
+                "type": "integer",
+                "minimum": 1,
+                "default": DEFAULT_TAB_WIDTH,
+                "description": "Number of spaces that replace each tab used for indentation.",
+            },
+            "include_frontmatter": {
+                "type": "boolean",
+                "default": false,
+                "description": "Also check the frontmatter.",
+            },
+            "include_code": {
+                "type": "boolean",
+                "default": false,
+                "description": "Also check code blocks.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+
+        let skipped = self.skipped_lines(root, context);
+        let rope = context.rope();
+        let mut errors = Vec::new();
+        let mut blank_run = None::<Range<usize>>;
+
+        for row in 0..rope.line_len() {
+            if skipped.iter().any(|range| range.contains(&row)) {
+                self.check_blank_run(blank_run.take(), context, level, &mut errors);
+                continue;
+            }
+
+            let line = rope.line(row).to_string();
+            let line_start = rope.byte_of_line(row);
+            if line.trim().is_empty() {
+                blank_run = Some(blank_run.map_or(row..row + 1, |run| run.start..row + 1));
+            } else {
+                self.check_blank_run(blank_run.take(), context, level, &mut errors);
+            }
+
+            self.check_trailing_whitespace(&line, line_start, context, level, &mut errors);
+            self.check_tabs(&line, line_start, context, level, &mut errors);
+        }
+        self.check_blank_run(blank_run, context, level, &mut errors);
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule022Whitespace {
+    /// The rows of the frontmatter and code blocks, unless they're included.
+    fn skipped_lines(&self, root: &markdown::mdast::Root, context: &Context) -> Vec<Range<usize>> {
+        let rope = context.rope();
+        let mut skipped = Vec::new();
+
+        let content_start: usize = context.content_start_offset().into();
+        if !self.include_frontmatter && content_start > 0 {
+            skipped.push(0..rope.line_of_byte(content_start));
+        }
+
+        if !self.include_code {
+            let mut stack = root.children.iter().collect::<Vec<_>>();
+            while let Some(node) = stack.pop() {
+                if let Node::Code(code) = node {
+                    if let Some(position) = code.position.as_ref() {
+                        let range = AdjustedRange::from_unadjusted_position(position, context);
+                        let start = rope.line_of_byte(range.start.into());
+                        let end = rope.line_of_byte(range.end.into());
+                        skipped.push(start..end + 1);
+                    }
+                } else if let Some(children) = node.children() {
+                    stack.extend(children);
+                }
+            }
+        }
+
+        skipped
+    }
+
+    fn check_trailing_whitespace(
+        &self,
+        line: &str,
+        line_start: usize,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let trimmed = line.trim_end();
+        if trimmed.len() == line.len() {
+            return;
+        }
+
+        let location = Self::location(line_start + trimmed.len()..line_start + line.len(), context);
+        errors.push(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message("Remove trailing whitespace.")
+                .location(location.clone())
+                .fix(vec![LintCorrection::Delete(LintCorrectionDelete {
+                    location,
+                })])
+                .call(),
+        );
+    }
+
+    fn check_tabs(
+        &self,
+        line: &str,
+        line_start: usize,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        // Trailing tabs are reported as trailing whitespace.
+        let content = line.trim_end();
+        let indent = content.len() - content.trim_start().len();
+
+        for (offset, _) in content.match_indices('\t') {
+            let replacement = if offset < indent {
+                " ".repeat(self.tab_width)
+            } else {
+                " ".to_string()
+            };
+            let location = Self::location(line_start + offset..line_start + offset + 1, context);
+            errors.push(
+                LintError::from_raw_location()
+                    .rule(self.name())
+                    .level(level)
+                    .message("Replace the tab with spaces.")
+                    .location(location.clone())
+                    .fix(vec![LintCorrection::Replace(LintCorrectionReplace {
+                        location,
+                        text: replacement,
+                    })])
+                    .call(),
+            );
+        }
+    }
+
+    fn check_blank_run(
+        &self,
+        run: Option<Range<usize>>,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let Some(run) = run else {
+            return;
+        };
+        if run.len() <= self.max_blank_lines {
+            return;
+        }
+
+        let rope = context.rope();
+        let start = rope.byte_of_line(run.start + self.max_blank_lines);
+        let end = if run.end < rope.line_len() {
+            rope.byte_of_line(run.end)
+        } else {
+            rope.byte_len()
+        };
+        let location = Self::location(start..end, context);
+        let plural = if self.max_blank_lines == 1 { "" } else { "s" };
+        errors.push(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(format!(
+                    "Found {} blank lines in a row. Use at most {} blank line{plural}.",
+                    run.len(),
+                    self.max_blank_lines
+                ))
+                .location(location.clone())
+                .fix(vec![LintCorrection::Delete(LintCorrectionDelete {
+                    location,
+                })])
+                .call(),
+        );
+    }
+
+    fn location(range: Range<usize>, context: &Context) -> DenormalizedLocation {
+        DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(
+                AdjustedOffset::from(range.start),
+                AdjustedOffset::from(range.end),
+            ),
+            context,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule022_clean() {
+        assert_lint_snapshot!(
+            "Rule022Whitespace",
+            "# Heading\n\nA paragraph.\n\n- A list\n"
+        );
+    }
+
+    #[test]
+    fn test_rule022_trailing_whitespace() {
+        assert_lint_snapshot!("Rule022Whitespace", "# Heading \n\nA paragraph.\t \n");
+    }
+
+    #[test]
+    fn test_rule022_tabs() {
+        assert_lint_snapshot!("Rule022Whitespace", "- A list\n\n\tIndented\tprose.\n");
+    }
+
+    #[test]
+    fn test_rule022_blank_lines() {
+        assert_lint_snapshot!("Rule022Whitespace", "# Heading\n\n\n\nA paragraph.\n");
+        assert_lint_snapshot!(
+            "Rule022Whitespace",
+            "# Heading\n\n\n\nA paragraph.\n",
+            Some(json!({ "max_blank_lines": 3 }))
+        );
+    }
+
+    #[test]
+    fn test_rule022_skips_frontmatter_and_code() {
+        let mdx = "---\ntitle: Page \n---\n\n```go\nfunc main() {\n\tfmt.Println() \n\n\n}\n```\n";
+        assert_lint_snapshot!("Rule022Whitespace", mdx);
+        assert_lint_snapshot!(
+            "Rule022Whitespace",
+            mdx,
+            Some(json!({ "include_frontmatter": true, "include_code": true }))
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use markdown::mdast::{AttributeContent, AttributeValue, MdxJsxAttribute, Node};
+use serde_json::Value;
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::DenormalizedLocation,
+    utils::mdast::find_jsx_attribute_value_range,
+};
+
+use super::{string_array_schema, NodeKind, Rule, Rule001HeadingCase, RuleName, RuleSettings};
+
+const DEFAULT_COMPONENTS: &[&str] = &["Admonition"];
+const DEFAULT_ATTRIBUTES: &[&str] = &["label", "title"];
+
+/// Admonition titles should be in sentence case, like headings.
+///
+/// Titles are set through JSX attributes rather than headings, so
+/// `Rule001HeadingCase` doesn't see them. This rule checks them the same way,
+/// including its exceptions and presets.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// <Admonition type="note" label="Before you begin">
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// <Admonition type="note" label="Before You Begin">
+/// ```
+///
+/// ## Configuration
+///
+/// - `components`: The components whose titles are checked. Defaults to
+///   `["Admonition"]`.
+/// - `attributes`: The attributes that hold a title. Defaults to
+///   `["label", "title"]`.
+/// - `presets`, `may_uppercase`, `may_lowercase`: Exceptions, as for
+///   `Rule001HeadingCase`. To share them, include the same file in both rules.
+///
+/// ```toml
+/// Rule001HeadingCase = "include('Rule001HeadingCase.toml')"
+/// Rule023AdmonitionTitleCase = "include('Rule001HeadingCase.toml')"
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule023AdmonitionTitleCase {
+    heading_case: Rule001HeadingCase,
+    components: Vec<String>,
+    attributes: Vec<String>,
+}
+
+impl Default for Rule023AdmonitionTitleCase {
+    fn default() -> Self {
+        Self {
+            heading_case: Rule001HeadingCase::default(),
+            components: DEFAULT_COMPONENTS.iter().map(ToString::to_string).collect(),
+            attributes: DEFAULT_ATTRIBUTES.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl Rule for Rule023AdmonitionTitleCase {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::MdxJsxFlowElement, NodeKind::MdxJsxTextElement])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(components) = settings.get_deserializable::<Vec<String>>("components") {
+            self.components = components;
+        }
+        if let Some(attributes) = settings.get_deserializable::<Vec<String>>("attributes") {
+            self.attributes = attributes;
+        }
+        self.heading_case.setup(Some(settings));
+    }
+
+    fn settings_schema(&self) -> Value {
+        // The exceptions are the same as for headings.
+        let mut schema = self.heading_case.settings_schema();
+        schema["properties"]["components"] =
+            string_array_schema("Components whose titles are checked.");
+        schema["properties"]["attributes"] = string_array_schema("Attributes that hold a title.");
+        schema
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let (name, attributes) = match ast {
+            Node::MdxJsxFlowElement(element) => (element.name.as_ref()?, &element.attributes),
+            Node::MdxJsxTextElement(element) => (element.name.as_ref()?, &element.attributes),
+            _ => return None,
+        };
+        if !self.components.contains(name) {
+            return None;
+        }
+
+        let errors = attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                AttributeContent::Property(property)
+                    if self.attributes.contains(&property.name) =>
+                {
+                    self.check_attribute(ast, name, property, context, level)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule023AdmonitionTitleCase {
+    fn check_attribute(
+        &self,
+        node: &Node,
+        component: &str,
+        property: &MdxJsxAttribute,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        let Some(AttributeValue::Literal(value)) = &property.value else {
+            return None;
+        };
+
+        let range = find_jsx_attribute_value_range(node, &property.name, value, context)?;
+        // Values with escapes or entities don't map back onto the source, so
+        // fixes can't be placed reliably.
+        if context.rope().byte_slice(range.to_usize_range()) != value.as_str() {
+            return None;
+        }
+
+        let fixes = self
+            .heading_case
+            .check_range_sentence_case(range.clone(), context)?;
+        Some(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(format!(
+                    "The \"{}\" of <{component}> should be sentence case",
+                    property.name
+                ))
+                .location(DenormalizedLocation::from_offset_range(range, context))
+                .fix(fixes)
+                .call(),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule023_sentence_case() {
+        assert_lint_snapshot!(
+            "Rule023AdmonitionTitleCase",
+            "<Admonition type=\"note\" label=\"Before you begin\">\n\nText.\n\n</Admonition>\n"
+        );
+    }
+
+    #[test]
+    fn test_rule023_title_case() {
+        assert_lint_snapshot!(
+            "Rule023AdmonitionTitleCase",
+            "<Admonition type=\"note\" label=\"Before You Begin\">\n\nText.\n\n</Admonition>\n"
+        );
+    }
+
+    #[test]
+    fn test_rule023_lowercase_first_word() {
+        assert_lint_snapshot!(
+            "Rule023AdmonitionTitleCase",
+            "<Admonition type=\"tip\" title='before you begin' />\n"
+        );
+    }
+
+    #[test]
+    fn test_rule023_exceptions() {
+        assert_lint_snapshot!(
+            "Rule023AdmonitionTitleCase",
+            "<Admonition type=\"note\" label=\"Enable Row Level Security for the API\" />\n",
+            Some(json!({
+                "presets": ["tech-acronyms"],
+                "may_uppercase": ["Row Level Security"],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_rule023_components_and_attributes() {
+        let mdx = "<Callout label=\"Before You Begin\" />\n\n<Admonition type=\"Note\" />\n\n<Callout heading=\"Before You Begin\" />\n";
+        assert_lint_snapshot!("Rule023AdmonitionTitleCase", mdx);
+        assert_lint_snapshot!(
+            "Rule023AdmonitionTitleCase",
+            mdx,
+            Some(json!({ "components": ["Callout"], "attributes": ["heading"] }))
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use log::warn;
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const MARKERS: &[char] = &['-', '*', '+'];
+const CONSISTENT: &str = "consistent";
+
+/// The marker to use for unordered list items.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum MarkerStyle {
+    /// Whichever marker the first list item in the document has.
+    #[default]
+    Consistent,
+    Marker(char),
+}
+
+impl MarkerStyle {
+    fn parse(setting: &str) -> Option<Self> {
+        if setting == CONSISTENT {
+            return Some(Self::Consistent);
+        }
+        MARKERS
+            .iter()
+            .find(|marker| *setting == marker.to_string())
+            .copied()
+            .map(Self::Marker)
+    }
+}
+
+/// Unordered lists should use the same bullet marker throughout.
+///
+/// By default, the marker of the first list item in each document is
+/// expected for the rest of it. Markers that don't match are fixed
+/// automatically.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// - First item
+/// - Second item
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// - First item
+/// * Second item
+/// ```
+///
+/// ## Configuration
+///
+/// - `marker`: The marker to use, one of `-`, `*`, `+`, or `consistent`.
+///   Defaults to `consistent`.
+///
+/// ```toml
+/// [Rule024ListMarker]
+/// marker = "-"
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule024ListMarker {
+    marker: MarkerStyle,
+}
+
+impl Rule for Rule024ListMarker {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(marker) = settings.get_deserializable::<String>("marker") {
+            match MarkerStyle::parse(&marker) {
+                Some(style) => self.marker = style,
+                None => warn!(
+                    "Invalid list marker for {}: {marker:?}. Expected one of -, *, +, or {CONSISTENT:?}.",
+                    self.name()
+                ),
+            }
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "marker": {
+                "type": "string",
+                "enum": ["-", "*", "+", CONSISTENT],
+                "default": CONSISTENT,
+                "description": "The marker to use for unordered list items, or `consistent` to match the first list item in each document.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(_) = ast else {
+            return None;
+        };
+
+        let mut expected = match self.marker {
+            MarkerStyle::Marker(marker) => Some(marker),
+            MarkerStyle::Consistent => None,
+        };
+        let mut errors = Vec::new();
+        self.check_node(ast, context, level, &mut expected, &mut errors);
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule024ListMarker {
+    fn check_node(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+        expected: &mut Option<char>,
+        errors: &mut Vec<LintError>,
+    ) {
+        if let Node::List(list) = node {
+            if !list.ordered {
+                for item in &list.children {
+                    errors.extend(self.check_item(item, context, level, expected));
+                    self.check_node(item, context, level, expected, errors);
+                }
+                return;
+            }
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                self.check_node(child, context, level, expected, errors);
+            }
+        }
+    }
+
+    fn check_item(
+        &self,
+        item: &Node,
+        context: &Context,
+        level: LintLevel,
+        expected: &mut Option<char>,
+    ) -> Option<LintError> {
+        let start: usize = AdjustedRange::from_unadjusted_position(item.position()?, context)
+            .start
+            .into();
+        let marker = context.rope().byte_slice(start..).chars().next()?;
+        if !MARKERS.contains(&marker) {
+            return None;
+        }
+        let expected = *expected.get_or_insert(marker);
+        if marker == expected {
+            return None;
+        }
+
+        let location = DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(
+                AdjustedOffset::from(start),
+                AdjustedOffset::from(start + marker.len_utf8()),
+            ),
+            context,
+        );
+        Some(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(format!(
+                    "Use \"{expected}\" for list items instead of \"{marker}\"."
+                ))
+                .location(location.clone())
+                .fix(vec![LintCorrection::Replace(LintCorrectionReplace {
+                    location,
+                    text: expected.to_string(),
+                })])
+                .call(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fix")]
+    use crate::testing::assert_lint_snapshot;
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule024_consistent_markers() {
+        assert_lint_snapshot!(
+            "Rule024ListMarker",
+            "- One\n- Two\n  - Nested\n\n1. One\n2. Two\n"
+        );
+        assert_lint_snapshot!("Rule024ListMarker", "* One\n* Two\n  * Nested\n");
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule024_other_markers() {
+        assert_lint_snapshot!("Rule024ListMarker", "* One\n* Two\n\n+ Three\n");
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule024_nested_and_blockquoted() {
+        assert_lint_snapshot!("Rule024ListMarker", "- One\n  * Nested\n\n> * Quoted\n");
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule024_configured_marker() {
+        assert_lint_snapshot!(
+            "Rule024ListMarker",
+            "* One\n- Two\n",
+            Some(json!({ "marker": "*" }))
+        );
+        assert_lint_snapshot!(
+            "Rule024ListMarker",
+            "* One\n* Two\n\n+ Three\n",
+            Some(json!({ "marker": "-" }))
+        );
+    }
+
+    #[test]
+    fn test_rule024_invalid_marker() {
+        let mut rule = Rule024ListMarker::default();
+        rule.setup(Some(&mut RuleSettings::from_key_value(
+            "marker",
+            toml::Value::String("x".to_string()),
+        )));
+        assert_eq!(rule.marker, MarkerStyle::Consistent);
+    }
+}
@@ -0,0 +1,350 @@
+use markdown::mdast::{Node, Paragraph, Table};
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionInsert},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+/// Tables should have the same number of cells in every row, and a delimiter
+/// row under the header.
+///
+/// Rows with too few cells are rendered with empty cells, and cells past the
+/// number in the header are dropped. Without a delimiter row, such as
+/// `| --- | --- |`, the rows aren't parsed as a table at all and render as a
+/// paragraph of text.
+///
+/// Short rows can be fixed automatically by padding them with empty cells.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// | Plan | Price |
+/// | ---- | ----- |
+/// | Free | $0    |
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// | Plan | Price |
+/// | ---- | ----- |
+/// | Free |
+///
+/// | Plan | Price |
+/// | Free | $0    |
+/// ```
+///
+/// ## Configuration
+///
+/// - `pad_short_rows`: Offer a fix that pads short rows with empty cells.
+///   Defaults to true.
+///
+/// ```toml
+/// [Rule025TableFormat]
+/// pad_short_rows = false
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Correctness)]
+pub struct Rule025TableFormat {
+    pad_short_rows: bool,
+}
+
+impl Default for Rule025TableFormat {
+    fn default() -> Self {
+        Self {
+            pad_short_rows: true,
+        }
+    }
+}
+
+impl Rule for Rule025TableFormat {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Table, NodeKind::Paragraph])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(pad_short_rows) = settings.get_deserializable::<bool>("pad_short_rows") {
+            self.pad_short_rows = pad_short_rows;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "pad_short_rows": {
+                "type": "boolean",
+                "default": true,
+                "description": "Offer a fix that pads short rows with empty cells.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let errors = match ast {
+            Node::Table(table) => self.check_table(table, context, level),
+            Node::Paragraph(paragraph) => self
+                .check_paragraph(paragraph, context, level)
+                .into_iter()
+                .collect(),
+            _ => return None,
+        };
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule025TableFormat {
+    fn check_table(&self, table: &Table, context: &Context, level: LintLevel) -> Vec<LintError> {
+        let rows = table
+            .children
+            .iter()
+            .filter_map(|row| {
+                let range = AdjustedRange::from_unadjusted_position(row.position()?, context);
+                let source = context
+                    .rope()
+                    .byte_slice(range.to_usize_range())
+                    .to_string();
+                Some((range, source))
+            })
+            .collect::<Vec<_>>();
+        let Some(((_, header), body)) = rows.split_first() else {
+            return Vec::new();
+        };
+        let columns = count_cells(header);
+
+        let mut errors = Vec::new();
+        for (range, source) in body {
+            let cells = count_cells(source);
+            if cells == columns {
+                continue;
+            }
+
+            let message = if cells < columns {
+                format!(
+                    "Row has {cells} cells, but the header has {columns}. Add the missing cells."
+                )
+            } else {
+                format!(
+                    "Row has {cells} cells, but the header has {columns}. Cells past the header's are dropped."
+                )
+            };
+            let location = DenormalizedLocation::from_offset_range(range.clone(), context);
+            let fix = (self.pad_short_rows && cells < columns)
+                .then(|| Self::pad_row(range, source, columns - cells, context));
+            errors.push(
+                LintError::from_raw_location()
+                    .rule(self.name())
+                    .level(level)
+                    .message(message)
+                    .location(location)
+                    .maybe_fix(fix)
+                    .call()
+                    .with_details(json!({ "cells": cells, "columns": columns })),
+            );
+        }
+        errors
+    }
+
+    /// Append empty cells to the end of a row.
+    fn pad_row(
+        range: &AdjustedRange,
+        source: &str,
+        missing: usize,
+        context: &Context,
+    ) -> Vec<LintCorrection> {
+        let trimmed = source.trim_end();
+        let end = usize::from(range.start) + trimmed.len();
+        // Without a closing pipe, the last pipe added closes the row rather
+        // than starting a cell.
+        let pipes = if has_closing_pipe(trimmed) {
+            missing
+        } else {
+            missing + 1
+        };
+        let location = DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(AdjustedOffset::from(end), AdjustedOffset::from(end)),
+            context,
+        );
+        vec![LintCorrection::Insert(LintCorrectionInsert {
+            location,
+            text: " |".repeat(pipes),
+        })]
+    }
+
+    /// Report paragraphs made of pipe-delimited lines, which are probably
+    /// tables that are missing their delimiter row.
+    fn check_paragraph(
+        &self,
+        paragraph: &Paragraph,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        let position = paragraph.position.as_ref()?;
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let source = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+
+        let lines = source
+            .lines()
+            .map(|line| line.trim_start_matches(|c: char| c == '>' || c.is_whitespace()))
+            .collect::<Vec<_>>();
+        let looks_like_table = lines.len() >= 2
+            && lines.iter().all(|line| {
+                let line = line.trim_end();
+                line.len() > 1 && line.starts_with('|') && has_closing_pipe(line)
+            });
+        if !looks_like_table {
+            return None;
+        }
+
+        let header_start: usize = range.start.into();
+        let header_end = header_start + source.lines().next().unwrap_or_default().len();
+        let columns = count_cells(lines[0]);
+        Some(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(format!(
+                    "This looks like a table, but it has no delimiter row, so it isn't rendered as one. Add a row like \"|{}\" after the header.",
+                    " --- |".repeat(columns)
+                ))
+                .location(DenormalizedLocation::from_offset_range(
+                    AdjustedRange::new(
+                        AdjustedOffset::from(header_start),
+                        AdjustedOffset::from(header_end),
+                    ),
+                    context,
+                ))
+                .call(),
+        )
+    }
+}
+
+/// The positions of the pipes in a row that separate cells, skipping escaped
+/// pipes.
+fn pipe_positions(row: &str) -> Vec<usize> {
+    let mut positions = Vec::new();
+    let mut escaped = false;
+    for (idx, c) in row.char_indices() {
+        match c {
+            '\\' => escaped = !escaped,
+            '|' if !escaped => positions.push(idx),
+            _ => escaped = false,
+        }
+        if c != '\\' {
+            escaped = false;
+        }
+    }
+    positions
+}
+
+fn has_closing_pipe(row: &str) -> bool {
+    let row = row.trim_end();
+    pipe_positions(row)
+        .last()
+        .is_some_and(|idx| idx + 1 == row.len())
+}
+
+fn count_cells(row: &str) -> usize {
+    let row = row.trim();
+    let mut pipes = pipe_positions(row).len();
+    if row.starts_with('|') {
+        pipes -= 1;
+    }
+    if has_closing_pipe(row) && row.len() > 1 {
+        pipes -= 1;
+    }
+    pipes + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fix")]
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule025_count_cells() {
+        assert_eq!(count_cells("| a | b |"), 2);
+        assert_eq!(count_cells("a | b"), 2);
+        assert_eq!(count_cells("| a | b"), 2);
+        assert_eq!(count_cells("| a \\| b |"), 1);
+        assert_eq!(count_cells("| a | |"), 2);
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule025_valid_table() {
+        assert_lint_snapshot!(
+            "Rule025TableFormat",
+            "| Plan | Price |\n| ---- | ----- |\n| Free | $0 |\n| Pro | \\| |\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule025_short_row() {
+        assert_lint_snapshot!(
+            "Rule025TableFormat",
+            "| Plan | Price | Limit |\n| --- | --- | --- |\n| Free |\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule025_short_row_without_closing_pipe() {
+        assert_lint_snapshot!(
+            "Rule025TableFormat",
+            "Plan | Price | Limit\n--- | --- | ---\nFree | $0\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule025_long_row() {
+        assert_lint_snapshot!(
+            "Rule025TableFormat",
+            "| Plan | Price |\n| --- | --- |\n| Free | $0 | Extra |\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule025_pad_short_rows_setting() {
+        assert_lint_snapshot!(
+            "Rule025TableFormat",
+            "| Plan | Price |\n| --- | --- |\n| Free |\n",
+            Some(json!({ "pad_short_rows": false }))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule025_missing_delimiter_row() {
+        assert_lint_snapshot!(
+            "Rule025TableFormat",
+            "Intro.\n\n| Plan | Price |\n| Free | $0 |\n\nSome text.\n\n| Just one line |\n\nUse `a | b` for alternatives.\nOr not.\n"
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    utils::collapse_whitespace,
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+/// Sibling headings, which have the same level and the same parent section,
+/// shouldn't have the same text. Repeated headings, such as several "Example"
+/// H3s under one H2, are hard to tell apart in the table of contents and
+/// produce confusing anchor links.
+///
+/// Headings are compared case-insensitively. Only headings at the top level
+/// of the document are checked, not those nested in components.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// ## Queries
+///
+/// ### Example
+///
+/// ## Mutations
+///
+/// ### Example
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// ## Queries
+///
+/// ### Example
+///
+/// ### Example
+/// ```
+///
+/// ## Configuration
+///
+/// - `allowed`: Heading texts that may repeat among siblings.
+///
+/// ```toml
+/// [Rule026DuplicateSiblingHeadings]
+/// allowed = ["Parameters", "Returns"]
+/// ```
+#[derive(Debug, Default, RuleName)]
+#[rule_category(Accessibility)]
+pub struct Rule026DuplicateSiblingHeadings {
+    allowed: Vec<String>,
+}
+
+/// A heading in the document outline.
+#[derive(Debug)]
+struct OutlineEntry<'node> {
+    node: &'node Node,
+    depth: u8,
+    text: String,
+    /// The index of the closest preceding heading with a lower depth.
+    parent: Option<usize>,
+}
+
+impl Rule for Rule026DuplicateSiblingHeadings {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(allowed) = settings.get_array_of_strings("allowed") {
+            self.allowed = allowed;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "allowed": string_array_schema("Heading texts that may repeat among siblings."),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+
+        let outline = Self::outline(&root.children);
+        let mut errors = Vec::new();
+        for (idx, entry) in outline.iter().enumerate() {
+            if entry.text.is_empty() || self.allowed.contains(&entry.text) {
+                continue;
+            }
+
+            let Some(original) = outline[..idx].iter().find(|earlier| {
+                earlier.depth == entry.depth
+                    && earlier.parent == entry.parent
+                    && earlier.text == entry.text
+            }) else {
+                continue;
+            };
+
+            let text = collapse_whitespace(&entry.node.to_string());
+            let line = original
+                .node
+                .position()
+                .map(|position| position.start.line)
+                .unwrap_or_default();
+            errors.extend(
+                LintError::from_node()
+                    .node(entry.node)
+                    .context(context)
+                    .rule(self.name())
+                    .level(level)
+                    .message(&format!(
+                        "Heading \"{text}\" repeats a sibling heading on line {line}. Give each section a distinct title."
+                    ))
+                    .call(),
+            );
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule026DuplicateSiblingHeadings {
+    /// Index the headings among the nodes, tracking each heading's parent
+    /// section.
+    fn outline(nodes: &[Node]) -> Vec<OutlineEntry<'_>> {
+        let mut outline = Vec::<OutlineEntry>::new();
+        // Indices into the outline of the currently open sections, from the
+        // outermost in.
+        let mut open = Vec::<usize>::new();
+
+        for node in nodes {
+            let Node::Heading(heading) = node else {
+                continue;
+            };
+
+            while open
+                .last()
+                .is_some_and(|idx| outline[*idx].depth >= heading.depth)
+            {
+                open.pop();
+            }
+            outline.push(OutlineEntry {
+                node,
+                depth: heading.depth,
+                text: collapse_whitespace(&node.to_string()).to_lowercase(),
+                parent: open.last().copied(),
+            });
+            open.push(outline.len() - 1);
+        }
+
+        outline
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule026_duplicate_siblings() {
+        assert_lint_snapshot!(
+            "Rule026DuplicateSiblingHeadings",
+            "## Queries\n\n### Example\n\nText.\n\n### example\n\n### Example\n"
+        );
+    }
+
+    #[test]
+    fn test_rule026_different_parents() {
+        assert_lint_snapshot!(
+            "Rule026DuplicateSiblingHeadings",
+            "## Queries\n\n### Example\n\n## Mutations\n\n### Example\n"
+        );
+    }
+
+    #[test]
+    fn test_rule026_different_levels() {
+        assert_lint_snapshot!(
+            "Rule026DuplicateSiblingHeadings",
+            "## Example\n\n### Example\n\n#### Example\n"
+        );
+    }
+
+    #[test]
+    fn test_rule026_parent_skips_levels() {
+        // The H4 closes the H3's section, so the second H3 has the same
+        // parent H2 as the first.
+        assert_lint_snapshot!(
+            "Rule026DuplicateSiblingHeadings",
+            "## Queries\n\n### Example\n\n#### Details\n\n### Example\n"
+        );
+    }
+
+    #[test]
+    fn test_rule026_allowed() {
+        assert_lint_snapshot!(
+            "Rule026DuplicateSiblingHeadings",
+            "## Queries\n\n### Example\n\n### Example\n",
+            Some(json!({ "allowed": ["Example"] }))
+        );
+    }
+}
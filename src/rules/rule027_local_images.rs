@@ -0,0 +1,336 @@
+use std::path::{Path, PathBuf};
+
+use markdown::mdast::{AttributeContent, AttributeValue, Node};
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::{AdjustedRange, DenormalizedLocation},
+    utils::mdast::find_jsx_attribute_value_range,
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_COMPONENTS: &[&str] = &["img", "Image"];
+
+/// Local images should exist.
+///
+/// Relative image paths, such as `./assets/diagram.png`, are resolved against
+/// the directory of the file being linted. Site-absolute paths, such as
+/// `/images/diagram.png`, are resolved against the configured `static_root`,
+/// and aren't checked if it isn't set. External URLs are never checked.
+///
+/// Both Markdown images and the `src` of image components are checked.
+///
+/// The rule is off unless configured.
+///
+/// ## Examples
+///
+/// ### Invalid (assuming the file doesn't exist)
+///
+/// ```markdown
+/// ![Dashboard](/images/dashboard.png)
+/// ```
+///
+/// ## Configuration
+///
+/// - `static_root`: Directory, relative to the current directory, that
+///   static assets are served from.
+/// - `base_path`: Path prefix the static assets are served under. Defaults to
+///   the site root.
+/// - `components`: Components whose `src` attribute is an image path.
+///   Defaults to `["img", "Image"]`.
+///
+/// ```toml
+/// [Rule027LocalImages]
+/// static_root = "apps/docs/public"
+/// base_path = "/docs"
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Links)]
+pub struct Rule027LocalImages {
+    enabled: bool,
+    static_root: Option<PathBuf>,
+    base_path: String,
+    components: Vec<String>,
+}
+
+impl Default for Rule027LocalImages {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            static_root: None,
+            base_path: String::new(),
+            components: DEFAULT_COMPONENTS.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl Rule for Rule027LocalImages {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[
+            NodeKind::Image,
+            NodeKind::MdxJsxFlowElement,
+            NodeKind::MdxJsxTextElement,
+        ])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        self.enabled = true;
+        if let Some(static_root) = settings.get_deserializable::<String>("static_root") {
+            self.static_root = Some(PathBuf::from(static_root));
+        }
+        if let Some(base_path) = settings.get_deserializable::<String>("base_path") {
+            self.base_path = base_path.trim_matches('/').to_string();
+        }
+        if let Some(components) = settings.get_deserializable::<Vec<String>>("components") {
+            self.components = components;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "static_root": {
+                "type": "string",
+                "description": "Directory, relative to the current directory, that static assets are served from.",
+            },
+            "base_path": {
+                "type": "string",
+                "description": "Path prefix the static assets are served under.",
+            },
+            "components": string_array_schema("Components whose `src` attribute is an image path."),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !self.enabled {
+            return None;
+        }
+
+        let (src, location) = match ast {
+            Node::Image(image) => {
+                let range =
+                    AdjustedRange::from_unadjusted_position(image.position.as_ref()?, context);
+                (
+                    image.url.as_str(),
+                    DenormalizedLocation::from_offset_range(range, context),
+                )
+            }
+            Node::MdxJsxFlowElement(element) => {
+                self.component_src(ast, element.name.as_ref()?, &element.attributes, context)?
+            }
+            Node::MdxJsxTextElement(element) => {
+                self.component_src(ast, element.name.as_ref()?, &element.attributes, context)?
+            }
+            _ => return None,
+        };
+
+        let path = self.resolve(src, context)?;
+        if path.exists() {
+            return None;
+        }
+
+        Some(vec![LintError::from_raw_location()
+            .rule(self.name())
+            .level(level)
+            .message(format!(
+                "Image not found: \"{src}\" should be at {}.",
+                path.display()
+            ))
+            .location(location)
+            .call()])
+    }
+}
+
+impl Rule027LocalImages {
+    /// The literal `src` of an image component, and its location.
+    fn component_src<'node>(
+        &self,
+        node: &Node,
+        name: &str,
+        attributes: &'node [AttributeContent],
+        context: &Context,
+    ) -> Option<(&'node str, DenormalizedLocation)> {
+        if !self.components.iter().any(|component| component == name) {
+            return None;
+        }
+
+        let src = attributes.iter().find_map(|attribute| match attribute {
+            AttributeContent::Property(property) if property.name == "src" => {
+                match &property.value {
+                    Some(AttributeValue::Literal(src)) => Some(src.as_str()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })?;
+        let range = find_jsx_attribute_value_range(node, "src", src, context)?;
+        Some((src, DenormalizedLocation::from_offset_range(range, context)))
+    }
+
+    /// The file an image source refers to, or `None` if it isn't a local
+    /// path that can be resolved.
+    fn resolve(&self, src: &str, context: &Context) -> Option<PathBuf> {
+        let path = &src[..src.find(['?', '#']).unwrap_or(src.len())];
+        if path.is_empty() || is_external(path) {
+            return None;
+        }
+
+        if let Some(site_path) = path.strip_prefix('/') {
+            let static_root = self.static_root.as_ref()?;
+            let site_path = if self.base_path.is_empty() {
+                site_path
+            } else {
+                site_path
+                    .strip_prefix(self.base_path.as_str())?
+                    .trim_start_matches('/')
+            };
+            return Some(static_root.join(site_path));
+        }
+
+        let directory = context.file_path()?.parent().unwrap_or(Path::new(""));
+        Some(directory.join(path))
+    }
+}
+
+/// Whether a source is a URL with a scheme, such as `https:` or `data:`, or a
+/// protocol-relative URL.
+pub(super) fn is_external(src: &str) -> bool {
+    if src.starts_with("//") {
+        return true;
+    }
+    src.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::parser::parse;
+
+    struct Site {
+        dir: TempDir,
+    }
+
+    impl Site {
+        fn new() -> Self {
+            let dir = TempDir::new().unwrap();
+            fs::create_dir_all(dir.path().join("public/images")).unwrap();
+            fs::create_dir_all(dir.path().join("content/assets")).unwrap();
+            fs::write(dir.path().join("public/images/dashboard.png"), "").unwrap();
+            fs::write(dir.path().join("content/assets/diagram.png"), "").unwrap();
+            Self { dir }
+        }
+
+        fn rule(&self, base_path: Option<&str>) -> Rule027LocalImages {
+            let mut table = toml::Table::new();
+            table.insert(
+                "static_root".to_string(),
+                toml::Value::String(self.dir.path().join("public").display().to_string()),
+            );
+            if let Some(base_path) = base_path {
+                table.insert(
+                    "base_path".to_string(),
+                    toml::Value::String(base_path.to_string()),
+                );
+            }
+            let mut rule = Rule027LocalImages::default();
+            rule.setup(Some(&mut RuleSettings::new(table)));
+            rule
+        }
+
+        fn check(&self, rule: &Rule027LocalImages, mdx: &str) -> Vec<LintError> {
+            let file_path = self.dir.path().join("content/page.mdx");
+            let parse_result = parse(mdx).unwrap();
+            let context = Context::builder()
+                .parse_result(&parse_result)
+                .file_path(&file_path)
+                .build()
+                .unwrap();
+
+            let mut errors = Vec::new();
+            let mut stack = vec![parse_result.ast()];
+            while let Some(node) = stack.pop() {
+                errors.extend(
+                    rule.check(node, &context, LintLevel::Error)
+                        .unwrap_or_default(),
+                );
+                if let Some(children) = node.children() {
+                    stack.extend(children.iter().rev());
+                }
+            }
+            errors
+        }
+    }
+
+    #[test]
+    fn test_rule027_existing_images() {
+        let site = Site::new();
+        let rule = site.rule(None);
+        let mdx = "![Dashboard](/images/dashboard.png)\n\n![Diagram](./assets/diagram.png?v=2)\n\n<img src=\"assets/diagram.png\" />\n";
+        assert!(site.check(&rule, mdx).is_empty());
+    }
+
+    #[test]
+    fn test_rule027_missing_images() {
+        let site = Site::new();
+        let rule = site.rule(None);
+        let mdx = "![Dashboard](/images/missing.png)\n\n<Image src=\"./assets/missing.png\" alt=\"\" />\n";
+        let errors = site.check(&rule, mdx);
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0]
+            .message
+            .starts_with("Image not found: \"/images/missing.png\" should be at "));
+        assert_eq!(errors[0].offset_range(), 0..33);
+        assert_eq!(errors[1].offset_range(), 47..67);
+    }
+
+    #[test]
+    fn test_rule027_base_path() {
+        let site = Site::new();
+        let rule = site.rule(Some("/docs"));
+        assert!(site
+            .check(&rule, "![Dashboard](/docs/images/dashboard.png)")
+            .is_empty());
+        assert_eq!(
+            site.check(&rule, "![Dashboard](/docs/images/missing.png)")
+                .len(),
+            1
+        );
+        // Paths outside the base path aren't served from the static root.
+        assert!(site
+            .check(&rule, "![Dashboard](/images/missing.png)")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_rule027_skips_external_and_unconfigured() {
+        let site = Site::new();
+        let rule = site.rule(None);
+        let mdx = "![A](https://example.com/a.png)\n\n![B](//cdn.example.com/b.png)\n\n![C](data:image/png;base64,AAAA)\n";
+        assert!(site.check(&rule, mdx).is_empty());
+
+        let rule = Rule027LocalImages::default();
+        assert!(site
+            .check(&rule, "![Dashboard](/images/missing.png)")
+            .is_empty());
+    }
+}
@@ -0,0 +1,336 @@
+use glob::Pattern;
+use log::warn;
+use markdown::mdast::{AttributeContent, AttributeValue, Node};
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::path::matches_any_glob,
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_PATTERNS: &[&str] =
+    &[r"(?i)\b(is|are|was|were|has been|have been|will be|now|being) deprecated\b"];
+const DEFAULT_COMPONENT: &str = "Admonition";
+const DEFAULT_TYPE: &str = "deprecation";
+
+/// Deprecations should be announced with the standard deprecation notice, so
+/// readers can spot them.
+///
+/// Phrases such as "this feature is deprecated" are flagged unless the same
+/// section, which runs from one heading to the next, also contains an
+/// `<Admonition type="deprecation">`. Text inside the notice itself is never
+/// flagged.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// ## Legacy API keys
+///
+/// <Admonition type="deprecation">
+///
+/// Legacy API keys are deprecated. Use publishable keys instead.
+///
+/// </Admonition>
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// ## Legacy API keys
+///
+/// Legacy API keys are deprecated. Use publishable keys instead.
+/// ```
+///
+/// ## Configuration
+///
+/// - `files`: Globs for the files to check. Defaults to all files.
+/// - `patterns`: Regex patterns for phrases that announce a deprecation.
+/// - `component`: The notice component. Defaults to `Admonition`.
+/// - `type`: The `type` of the notice component. Defaults to `deprecation`.
+///
+/// ```toml
+/// [Rule028DeprecationNotice]
+/// files = ["content/guides/**"]
+/// patterns = ["(?i)\\bno longer supported\\b"]
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Correctness)]
+pub struct Rule028DeprecationNotice {
+    files: Vec<Pattern>,
+    patterns: Vec<Regex>,
+    component: String,
+    notice_type: String,
+}
+
+impl Default for Rule028DeprecationNotice {
+    fn default() -> Self {
+        Self {
+            files: Vec::new(),
+            patterns: DEFAULT_PATTERNS
+                .iter()
+                .map(|pattern| Regex::new(pattern).expect("Default patterns should be valid"))
+                .collect(),
+            component: DEFAULT_COMPONENT.to_string(),
+            notice_type: DEFAULT_TYPE.to_string(),
+        }
+    }
+}
+
+impl Rule for Rule028DeprecationNotice {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(files) = settings.get_array_of_globs("files") {
+            self.files = files;
+        }
+        if let Some(patterns) = settings.get_deserializable::<Vec<String>>("patterns") {
+            self.patterns = patterns
+                .iter()
+                .filter_map(|pattern| match Regex::new(pattern) {
+                    Ok(regex) => Some(regex),
+                    Err(err) => {
+                        warn!("Encountered invalid regex in rule settings: {pattern}: {err}");
+                        None
+                    }
+                })
+                .collect();
+        }
+        if let Some(component) = settings.get_deserializable::<String>("component") {
+            self.component = component;
+        }
+        if let Some(notice_type) = settings.get_deserializable::<String>("type") {
+            self.notice_type = notice_type;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "files": string_array_schema("Globs for the files to check. Defaults to all files."),
+            "patterns": string_array_schema("Regex patterns for phrases that announce a deprecation."),
+            "component": {
+                "type": "string",
+                "default": DEFAULT_COMPONENT,
+                "description": "The notice component.",
+            },
+            "type": {
+                "type": "string",
+                "default": DEFAULT_TYPE,
+                "description": "The `type` of the notice component.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+        if !self.files.is_empty() && !matches_any_glob(context.file_path()?, &self.files) {
+            return None;
+        }
+
+        // Each section starts at a heading, apart from any content before
+        // the first heading.
+        let mut sections = vec![Vec::new()];
+        for node in &root.children {
+            if matches!(node, Node::Heading(_)) {
+                sections.push(Vec::new());
+            }
+            sections.last_mut().unwrap().push(node);
+        }
+
+        let mut errors = Vec::new();
+        for section in sections {
+            if section.iter().any(|node| self.contains_notice(node)) {
+                continue;
+            }
+            for node in section {
+                self.check_node(node, context, level, &mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule028DeprecationNotice {
+    fn is_notice(&self, node: &Node) -> bool {
+        let (name, attributes) = match node {
+            Node::MdxJsxFlowElement(element) => (&element.name, &element.attributes),
+            Node::MdxJsxTextElement(element) => (&element.name, &element.attributes),
+            _ => return false,
+        };
+        name.as_deref() == Some(self.component.as_str())
+            && attributes.iter().any(|attribute| {
+                matches!(
+                    attribute,
+                    AttributeContent::Property(property)
+                        if property.name == "type"
+                            && matches!(
+                                &property.value,
+                                Some(AttributeValue::Literal(value)) if *value == self.notice_type
+                            )
+                )
+            })
+    }
+
+    fn contains_notice(&self, node: &Node) -> bool {
+        self.is_notice(node)
+            || node
+                .children()
+                .is_some_and(|children| children.iter().any(|child| self.contains_notice(child)))
+    }
+
+    fn check_node(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        match node {
+            Node::Code(_) | Node::InlineCode(_) => {}
+            Node::Text(text) => {
+                let Some(position) = text.position.as_ref() else {
+                    return;
+                };
+                let range = AdjustedRange::from_unadjusted_position(position, context);
+                if context.is_skipped(&range) {
+                    return;
+                }
+                let start: usize = range.start.into();
+                let source = context
+                    .rope()
+                    .byte_slice(range.to_usize_range())
+                    .to_string();
+
+                for pattern in &self.patterns {
+                    for found in pattern.find_iter(&source) {
+                        let location = DenormalizedLocation::from_offset_range(
+                            AdjustedRange::new(
+                                AdjustedOffset::from(start + found.start()),
+                                AdjustedOffset::from(start + found.end()),
+                            ),
+                            context,
+                        );
+                        errors.push(
+                            LintError::from_raw_location()
+                                .rule(self.name())
+                                .level(level)
+                                .message(format!(
+                                    "This section mentions a deprecation without a deprecation notice. Add a <{} type=\"{}\"> to the section.",
+                                    self.component, self.notice_type
+                                ))
+                                .location(location)
+                                .call(),
+                        );
+                    }
+                }
+            }
+            _ => {
+                if let Some(children) = node.children() {
+                    for child in children {
+                        self.check_node(child, context, level, errors);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule028_missing_notice() {
+        assert_lint_snapshot!(
+            "Rule028DeprecationNotice",
+            "## Legacy keys\n\nLegacy API keys are deprecated. Use publishable keys.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule028_notice_in_section() {
+        assert_lint_snapshot!(
+            "Rule028DeprecationNotice",
+            "## Legacy keys\n\nLegacy API keys are deprecated.\n\n<Admonition type=\"deprecation\">\n\nThis feature is deprecated.\n\n</Admonition>\n"
+        );
+    }
+
+    #[test]
+    fn test_rule028_notice_in_other_section() {
+        assert_lint_snapshot!(
+            "Rule028DeprecationNotice",
+            "## Old\n\n<Admonition type=\"deprecation\">\n\nOld is going away.\n\n</Admonition>\n\n## Legacy keys\n\nLegacy API keys have been deprecated.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule028_wrong_notice_type() {
+        assert_lint_snapshot!(
+            "Rule028DeprecationNotice",
+            "<Admonition type=\"note\">\n\nThis feature is deprecated.\n\n</Admonition>\n"
+        );
+    }
+
+    #[test]
+    fn test_rule028_ignores_code_and_other_phrases() {
+        assert_lint_snapshot!(
+            "Rule028DeprecationNotice",
+            "Run `this is deprecated`.\n\nSee the deprecated features list.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule028_settings() {
+        let settings = json!({
+            "files": ["docs/**"],
+            "patterns": ["(?i)\\bno longer supported\\b"],
+            "component": "Notice",
+            "type": "sunset",
+        });
+        let mdx = "Version 1 is no longer supported.\n";
+        assert_lint_snapshot!(
+            "Rule028DeprecationNotice",
+            mdx,
+            Some(settings.clone()),
+            file = "docs/v1.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule028DeprecationNotice",
+            mdx,
+            Some(settings.clone()),
+            file = "blog/v1.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule028DeprecationNotice",
+            "<Notice type=\"sunset\">\n\nVersion 1 is no longer supported.\n\n</Notice>\n",
+            Some(settings),
+            file = "docs/v1.mdx"
+        );
+    }
+}
@@ -0,0 +1,391 @@
+use log::warn;
+use markdown::mdast::Node;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{FixSafety, LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{
+    object_schema, string_array_schema, NodeKind, RegexBeginning, RegexEnding, RegexSettings, Rule,
+    RuleName, RuleSettings,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ColonStyle {
+    /// Capitalize the first word after a colon, as headings do.
+    #[default]
+    Capitalize,
+    /// Lowercase the first word after a colon.
+    Lowercase,
+}
+
+/// Where the scan is relative to the last colon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColonState {
+    None,
+    /// Just after a colon, which counts only if whitespace follows.
+    Colon,
+    /// After a colon and whitespace, waiting for the next word.
+    Pending,
+}
+
+/// The first word after a colon in body text should follow a consistent
+/// capitalization style.
+///
+/// Headings always capitalize the word after a colon (see
+/// `Rule001HeadingCase`). This rule applies a configurable style to
+/// paragraphs, including those in lists and admonitions. Only colons followed
+/// by whitespace count, so URLs and times are skipped. Acronyms, words with
+/// internal capitals, and words after inline code are left alone.
+///
+/// Fixes are only applied with `--fix-unsafe`, since the word might be a
+/// proper noun.
+///
+/// The rule is off unless configured.
+///
+/// ## Examples
+///
+/// ### Valid (with `style = "lowercase"`)
+///
+/// ```markdown
+/// There are two options: enable the extension or use a trigger.
+/// ```
+///
+/// ### Invalid (with `style = "lowercase"`)
+///
+/// ```markdown
+/// There are two options: Enable the extension or use a trigger.
+/// ```
+///
+/// ## Configuration
+///
+/// - `style`: Either `capitalize` or `lowercase`. Defaults to `capitalize`,
+///   to match headings.
+/// - `may_uppercase`: Regex patterns for words that may stay capitalized
+///   after a colon, such as product names.
+/// - `may_lowercase`: Regex patterns for words that may stay lowercase after
+///   a colon.
+///
+/// ```toml
+/// [Rule029ColonCapitalization]
+/// style = "lowercase"
+/// may_uppercase = ["Supabase", "Postgres"]
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule029ColonCapitalization {
+    enabled: bool,
+    style: ColonStyle,
+    may_uppercase: Vec<Regex>,
+    may_lowercase: Vec<Regex>,
+}
+
+impl Rule for Rule029ColonCapitalization {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Paragraph])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        self.enabled = true;
+        if let Some(style) = settings.get_deserializable::<String>("style") {
+            match style.as_str() {
+                "capitalize" => self.style = ColonStyle::Capitalize,
+                "lowercase" => self.style = ColonStyle::Lowercase,
+                _ => warn!(
+                    "Invalid style for {}: {style:?}. Expected \"capitalize\" or \"lowercase\".",
+                    self.name()
+                ),
+            }
+        }
+
+        let regex_settings = RegexSettings {
+            beginning: Some(RegexBeginning::VeryBeginning),
+            ending: Some(RegexEnding::WordBoundary),
+        };
+        if let Some(may_uppercase) =
+            settings.get_array_of_regexes("may_uppercase", Some(&regex_settings))
+        {
+            self.may_uppercase = may_uppercase;
+        }
+        if let Some(may_lowercase) =
+            settings.get_array_of_regexes("may_lowercase", Some(&regex_settings))
+        {
+            self.may_lowercase = may_lowercase;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "style": {
+                "type": "string",
+                "enum": ["capitalize", "lowercase"],
+                "default": "capitalize",
+                "description": "How to capitalize the first word after a colon.",
+            },
+            "may_uppercase": string_array_schema(
+                "Regex patterns for words that may stay capitalized after a colon."
+            ),
+            "may_lowercase": string_array_schema(
+                "Regex patterns for words that may stay lowercase after a colon."
+            ),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !self.enabled || !matches!(ast, Node::Paragraph(_)) {
+            return None;
+        }
+        let range = AdjustedRange::from_unadjusted_position(ast.position()?, context);
+        if context.is_skipped(&range) {
+            return None;
+        }
+
+        let mut errors = Vec::new();
+        let mut state = ColonState::None;
+        self.check_inline(ast, context, level, &mut state, &mut errors);
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule029ColonCapitalization {
+    /// Scan the text of a paragraph in order, carrying the colon state across
+    /// inline nodes so that `**Note:** text` is handled.
+    fn check_inline(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+        state: &mut ColonState,
+        errors: &mut Vec<LintError>,
+    ) {
+        match node {
+            Node::Text(text) => {
+                let Some(position) = text.position.as_ref() else {
+                    *state = ColonState::None;
+                    return;
+                };
+                let range = AdjustedRange::from_unadjusted_position(position, context);
+                let start: usize = range.start.into();
+                let source = context
+                    .rope()
+                    .byte_slice(range.to_usize_range())
+                    .to_string();
+                self.check_text(&source, start, context, level, state, errors);
+            }
+            Node::Paragraph(_)
+            | Node::Emphasis(_)
+            | Node::Strong(_)
+            | Node::Delete(_)
+            | Node::Link(_)
+            | Node::LinkReference(_) => {
+                if let Some(children) = node.children() {
+                    for child in children {
+                        self.check_inline(child, context, level, state, errors);
+                    }
+                }
+            }
+            _ => *state = ColonState::None,
+        }
+    }
+
+    fn check_text(
+        &self,
+        source: &str,
+        start: usize,
+        context: &Context,
+        level: LintLevel,
+        state: &mut ColonState,
+        errors: &mut Vec<LintError>,
+    ) {
+        for (idx, c) in source.char_indices() {
+            *state = match (*state, c) {
+                (_, ':') => ColonState::Colon,
+                (ColonState::Colon | ColonState::Pending, c) if c.is_whitespace() => {
+                    ColonState::Pending
+                }
+                (ColonState::Pending, c) if c.is_alphabetic() => {
+                    let word = first_word(&source[idx..]);
+                    errors.extend(self.check_word(word, start + idx, context, level));
+                    ColonState::None
+                }
+                _ => ColonState::None,
+            };
+        }
+    }
+
+    fn check_word(
+        &self,
+        word: &str,
+        offset: usize,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        let mut chars = word.chars();
+        let first = chars.next()?;
+        let rest = chars.as_str();
+
+        let (replacement, message) = match self.style {
+            ColonStyle::Capitalize => {
+                if !first.is_lowercase()
+                    || self.may_lowercase.iter().any(|regex| regex.is_match(word))
+                {
+                    return None;
+                }
+                (
+                    first.to_uppercase().collect::<String>() + rest,
+                    "Capitalize the first word after a colon.",
+                )
+            }
+            ColonStyle::Lowercase => {
+                // "I", acronyms, and names like "PostgREST" keep their
+                // capitals.
+                if !first.is_uppercase()
+                    || word == "I"
+                    || rest.chars().any(char::is_uppercase)
+                    || self.may_uppercase.iter().any(|regex| regex.is_match(word))
+                {
+                    return None;
+                }
+                (
+                    first.to_lowercase().collect::<String>() + rest,
+                    "Lowercase the first word after a colon.",
+                )
+            }
+        };
+
+        let location = DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(
+                AdjustedOffset::from(offset),
+                AdjustedOffset::from(offset + word.len()),
+            ),
+            context,
+        );
+        Some(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(message)
+                .location(location.clone())
+                .fix(vec![LintCorrection::Replace(LintCorrectionReplace {
+                    location,
+                    text: replacement,
+                })])
+                .fix_safety(FixSafety::Unsafe)
+                .call(),
+        )
+    }
+}
+
+/// The word at the start of the text, including apostrophes and hyphens
+/// within it.
+fn first_word(text: &str) -> &str {
+    let end = text
+        .char_indices()
+        .find(|(idx, c)| {
+            !(c.is_alphanumeric()
+                || matches!(c, '\'' | '’' | '-')
+                    && text[idx + c.len_utf8()..]
+                        .chars()
+                        .next()
+                        .is_some_and(char::is_alphanumeric))
+        })
+        .map_or(text.len(), |(idx, _)| idx);
+    &text[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fix")]
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule029_first_word() {
+        assert_eq!(first_word("don't stop"), "don't");
+        assert_eq!(first_word("row-level security"), "row-level");
+        assert_eq!(first_word("end-"), "end");
+        assert_eq!(first_word("word."), "word");
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule029_capitalize() {
+        assert_lint_snapshot!(
+            "Rule029ColonCapitalization",
+            "There are two options: enable it or not.\n\nThere are two options: Enable it or not.\n",
+            Some(json!({}))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule029_lowercase() {
+        assert_lint_snapshot!(
+            "Rule029ColonCapitalization",
+            "There are two options: Enable it or not.\n\nSupported formats: JSON and CSV.\n\nUse the REST API: PostgREST handles it.\n\nAsk yourself: I wonder why?\n",
+            Some(json!({ "style": "lowercase" }))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule029_skips_non_prose_colons() {
+        assert_lint_snapshot!(
+            "Rule029ColonCapitalization",
+            "Visit https://example.com for details.\n\nThe job runs at 10:30 daily.\n\nRun this: `npm install` first.\n\n# Heading: lowercase\n",
+            Some(json!({}))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule029_across_inline_nodes() {
+        assert_lint_snapshot!(
+            "Rule029ColonCapitalization",
+            "**Note:** this is important.\n\n- Item: *first* one\n",
+            Some(json!({}))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule029_exceptions() {
+        assert_lint_snapshot!(
+            "Rule029ColonCapitalization",
+            "Built on one platform: Supabase.\n",
+            Some(json!({ "style": "lowercase", "may_uppercase": ["Supabase"] }))
+        );
+        assert_lint_snapshot!(
+            "Rule029ColonCapitalization",
+            "Install it with: npm install.\n",
+            Some(json!({ "may_lowercase": ["npm"] }))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule029_off_by_default() {
+        assert_lint_snapshot!(
+            "Rule029ColonCapitalization",
+            "There are two options: enable it or not.\n"
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use log::warn;
+use markdown::mdast::Node;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_PATTERNS: &[&str] = &[
+    r#"(?P<path>[A-Za-z]:[\\/]+Users[\\/]+(?P<user>[^\\/\s"'`<>]+))"#,
+    r#"(?:^|[\s"'`(=:,\[])(?P<path>/(?:Users|home)/(?P<user>[^/\s"'`<>]+))"#,
+];
+const DEFAULT_ALLOWED: &[&str] = &[
+    "me",
+    "name",
+    "user",
+    "username",
+    "your-username",
+    "your_username",
+    "yourname",
+    "you",
+];
+const PLACEHOLDER: &str = "~";
+
+/// Paths shouldn't contain a contributor's home directory, such as
+/// `C:\Users\alice` or `/Users/alice`. These leak from contributors' machines
+/// into examples and don't match the reader's machine.
+///
+/// Both prose and code are checked. Each path comes with a suggestion to
+/// replace the home directory with `~`. Placeholder user names, such as
+/// `username`, are allowed.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// Save the file to `~/project/supabase/config.toml`.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// Save the file to `/Users/alice/project/supabase/config.toml`.
+/// ```
+///
+/// ## Configuration
+///
+/// - `patterns`: Regex patterns for user-specific paths, replacing the
+///   defaults. A `path` capture group narrows the reported range, and a
+///   `user` capture group is checked against `allowed`.
+/// - `allowed`: User names that are placeholders rather than real users.
+///
+/// ```toml
+/// [Rule030UserPaths]
+/// allowed = ["runner"]
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Correctness)]
+pub struct Rule030UserPaths {
+    patterns: Vec<Regex>,
+    allowed: Vec<String>,
+}
+
+impl Default for Rule030UserPaths {
+    fn default() -> Self {
+        Self {
+            patterns: DEFAULT_PATTERNS
+                .iter()
+                .map(|pattern| Regex::new(pattern).expect("Default patterns should be valid"))
+                .collect(),
+            allowed: DEFAULT_ALLOWED.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl Rule for Rule030UserPaths {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Text, NodeKind::InlineCode, NodeKind::Code])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(patterns) = settings.get_deserializable::<Vec<String>>("patterns") {
+            self.patterns = patterns
+                .iter()
+                .filter_map(|pattern| match Regex::new(pattern) {
+                    Ok(regex) => Some(regex),
+                    Err(err) => {
+                        warn!("Encountered invalid regex in rule settings: {pattern}: {err}");
+                        None
+                    }
+                })
+                .collect();
+        }
+        if let Some(allowed) = settings.get_array_of_strings("allowed") {
+            self.allowed.extend(allowed);
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "patterns": string_array_schema("Regex patterns for user-specific paths, replacing the defaults."),
+            "allowed": string_array_schema("User names that are placeholders rather than real users."),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !matches!(ast, Node::Text(_) | Node::InlineCode(_) | Node::Code(_)) {
+            return None;
+        }
+
+        let range = AdjustedRange::from_unadjusted_position(ast.position()?, context);
+        let start: usize = range.start.into();
+        let source = context
+            .rope()
+            .byte_slice(range.to_usize_range())
+            .to_string();
+
+        let mut errors = Vec::new();
+        let mut reported_until = 0;
+        for pattern in &self.patterns {
+            for captures in pattern.captures_iter(&source) {
+                let found = captures
+                    .name("path")
+                    .unwrap_or_else(|| captures.get(0).expect("Group 0 is always present"));
+                if found.start() < reported_until {
+                    continue;
+                }
+                if captures
+                    .name("user")
+                    .is_some_and(|user| self.is_placeholder(user.as_str()))
+                {
+                    continue;
+                }
+                reported_until = found.end();
+
+                let location = DenormalizedLocation::from_offset_range(
+                    AdjustedRange::new(
+                        AdjustedOffset::from(start + found.start()),
+                        AdjustedOffset::from(start + found.end()),
+                    ),
+                    context,
+                );
+                errors.push(
+                    LintError::from_raw_location()
+                        .rule(self.name())
+                        .level(level)
+                        .message(format!(
+                            "Avoid user-specific paths like \"{}\". Use a placeholder such as \"{PLACEHOLDER}\" instead.",
+                            found.as_str()
+                        ))
+                        .location(location.clone())
+                        .suggestions(vec![LintCorrection::Replace(LintCorrectionReplace {
+                            location,
+                            text: PLACEHOLDER.to_string(),
+                        })])
+                        .call(),
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule030UserPaths {
+    fn is_placeholder(&self, user: &str) -> bool {
+        let user = user.trim_matches(|c| matches!(c, '<' | '>' | '{' | '}' | '$'));
+        self.allowed
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(user))
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule030_user_paths_in_prose_and_code() {
+        assert_lint_snapshot!(
+            "Rule030UserPaths",
+            "Open /Users/alice/project.\n\nRun `cd C:\\Users\\bob\\app`.\n\n```bash\ncd /home/carol/app\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_rule030_skips_placeholders_and_urls() {
+        assert_lint_snapshot!(
+            "Rule030UserPaths",
+            "Open `/Users/<username>/project` or `C:\\Users\\YourName`.\n\nSee https://example.com/home/alice and ~/project.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule030_settings() {
+        assert_lint_snapshot!(
+            "Rule030UserPaths",
+            "`/home/runner/work`\n\n`/home/alice/work`\n",
+            Some(json!({ "allowed": ["runner"] }))
+        );
+        assert_lint_snapshot!(
+            "Rule030UserPaths",
+            "`/var/lib/alice` and `/Users/alice`\n",
+            Some(json!({ "patterns": [r"/var/lib/\w+"] }))
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use markdown::mdast::Node;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::mdast::is_esm_paragraph,
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_MAX: usize = 2;
+
+/// Sentences and list items shouldn't trail off with "etc." or an ellipsis.
+/// They leave readers guessing at what's missing. Name the items that matter,
+/// or introduce the list with "such as" or "for example".
+///
+/// Only endings are flagged, so "etc." mid-sentence and ellipses inside code
+/// or placeholders, such as `foo(...)`, are fine. Headings and code are
+/// skipped. Since an open-ended list is sometimes the right call, each
+/// document may use a few, and the rest are reported as hints.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// Store files such as images and videos.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// Store images, videos, etc.
+/// ```
+///
+/// ## Configuration
+///
+/// - `max_per_document`: The number of trailing "etc."s and ellipses allowed
+///   in each document before the rest are reported. Defaults to 2.
+///
+/// ```toml
+/// [Rule031TrailingEtc]
+/// max_per_document = 0
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule031TrailingEtc {
+    max_per_document: usize,
+    pattern: Regex,
+}
+
+impl Default for Rule031TrailingEtc {
+    fn default() -> Self {
+        Self {
+            max_per_document: DEFAULT_MAX,
+            pattern: Regex::new(r"\betc\.|\.\.\.|…").expect("Pattern should be valid"),
+        }
+    }
+}
+
+impl Rule for Rule031TrailingEtc {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Hint
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(max) = settings.get_deserializable::<usize>("max_per_document") {
+            self.max_per_document = max;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "max_per_document": {
+                "type": "integer",
+                "minimum": 0,
+                "default": DEFAULT_MAX,
+                "description": "The number of trailing \"etc.\"s and ellipses allowed in each document.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(root) = ast else {
+            return None;
+        };
+
+        let mut found = Vec::new();
+        for child in &root.children {
+            if !is_esm_paragraph(child) {
+                self.collect(child, context, &mut found);
+            }
+        }
+
+        let errors = found
+            .into_iter()
+            .skip(self.max_per_document)
+            .map(|(range, text)| {
+                LintError::from_raw_location()
+                    .rule(self.name())
+                    .level(level)
+                    .message(format!(
+                        "Avoid ending with \"{text}\". Name the items that matter, or introduce them with \"such as\"."
+                    ))
+                    .location(DenormalizedLocation::from_offset_range(range, context))
+                    .call()
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule031TrailingEtc {
+    /// Collect the trailing "etc."s and ellipses in a node, in document order.
+    fn collect(&self, node: &Node, context: &Context, found: &mut Vec<(AdjustedRange, String)>) {
+        match node {
+            Node::Heading(_) | Node::Code(_) | Node::InlineCode(_) => {}
+            Node::Text(text) => {
+                let Some(position) = text.position.as_ref() else {
+                    return;
+                };
+                let range = AdjustedRange::from_unadjusted_position(position, context);
+                if context.is_skipped(&range) {
+                    return;
+                }
+                let start: usize = range.start.into();
+                let source = context
+                    .rope()
+                    .byte_slice(range.to_usize_range())
+                    .to_string();
+
+                for matched in self.pattern.find_iter(&source) {
+                    if !ends_sentence(&source[matched.end()..]) {
+                        continue;
+                    }
+                    found.push((
+                        AdjustedRange::new(
+                            AdjustedOffset::from(start + matched.start()),
+                            AdjustedOffset::from(start + matched.end()),
+                        ),
+                        matched.as_str().to_string(),
+                    ));
+                }
+            }
+            _ => {
+                if let Some(children) = node.children() {
+                    for child in children {
+                        self.collect(child, context, found);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Whether the text following a match shows that the match ends a sentence:
+/// nothing follows, or only closing punctuation and then a new sentence.
+fn ends_sentence(rest: &str) -> bool {
+    let rest = rest.trim_start_matches(['.', ')', '"', '\'', '”', '’']);
+    if rest.trim().is_empty() {
+        return true;
+    }
+    let trimmed = rest.trim_start();
+    trimmed.len() < rest.len() && trimmed.starts_with(|c: char| c.is_uppercase())
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule031_trailing_etc() {
+        assert_lint_snapshot!(
+            "Rule031TrailingEtc",
+            "Store images, videos, etc.\n\n- Tables\n- Views...\n\nWait… Then continue.\n",
+            Some(json!({ "max_per_document": 0 }))
+        );
+    }
+
+    #[test]
+    fn test_rule031_mid_sentence() {
+        assert_lint_snapshot!(
+            "Rule031TrailingEtc",
+            "Images, videos, etc. are stored in buckets.\n\nCall `foo(...)` or foo(...) to start.\n\n## Images, videos, etc.\n",
+            Some(json!({ "max_per_document": 0 }))
+        );
+    }
+
+    #[test]
+    fn test_rule031_default_max() {
+        assert_lint_snapshot!(
+            "Rule031TrailingEtc",
+            "Store images, videos, etc.\n\n- Tables\n- Views...\n\nWait… Then continue.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule031_max_per_document() {
+        assert_lint_snapshot!(
+            "Rule031TrailingEtc",
+            "Store images, videos, etc.\n\nAnd more...\n",
+            Some(json!({ "max_per_document": 1 }))
+        );
+    }
+}
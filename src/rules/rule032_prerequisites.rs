@@ -0,0 +1,303 @@
+use glob::Pattern;
+use markdown::mdast::{List, Node};
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    utils::{collapse_whitespace, path::matches_any_glob},
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_HEADING: &str = "Prerequisites";
+
+/// The prerequisites section of a page should be a bulleted list, where each
+/// item starts with a link or a product name, so readers can see at a glance
+/// what they need and where to get it.
+///
+/// The section runs from the prerequisites heading to the next heading of the
+/// same or a higher level. It may include an introduction before the list.
+/// Pages without a prerequisites section aren't checked.
+///
+/// The rule is off unless configured.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// ## Prerequisites
+///
+/// - [A Supabase project](https://supabase.com/dashboard)
+/// - Node.js 20 or later
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// ## Prerequisites
+///
+/// - You need a Supabase project
+/// ```
+///
+/// ## Configuration
+///
+/// - `files`: Globs for the files to check. Defaults to all files.
+/// - `heading`: The text of the prerequisites heading. Defaults to
+///   `Prerequisites`.
+/// - `products`: Product names that an item may start with instead of a
+///   link. Matched case-sensitively.
+///
+/// ```toml
+/// [Rule032Prerequisites]
+/// files = ["content/guides/**"]
+/// products = ["Node.js", "Supabase CLI"]
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule032Prerequisites {
+    enabled: bool,
+    files: Vec<Pattern>,
+    heading: String,
+    products: Vec<String>,
+}
+
+impl Default for Rule032Prerequisites {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            files: Vec::new(),
+            heading: DEFAULT_HEADING.to_string(),
+            products: Vec::new(),
+        }
+    }
+}
+
+impl Rule for Rule032Prerequisites {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        self.enabled = true;
+        if let Some(files) = settings.get_array_of_globs("files") {
+            self.files = files;
+        }
+        if let Some(heading) = settings.get_deserializable::<String>("heading") {
+            self.heading = heading;
+        }
+        if let Some(products) = settings.get_deserializable::<Vec<String>>("products") {
+            self.products = products;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "files": string_array_schema("Globs for the files to check. Defaults to all files."),
+            "heading": {
+                "type": "string",
+                "default": DEFAULT_HEADING,
+                "description": "The text of the prerequisites heading.",
+            },
+            "products": string_array_schema("Product names that an item may start with instead of a link."),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !self.enabled {
+            return None;
+        }
+        let Node::Root(root) = ast else {
+            return None;
+        };
+        if !self.files.is_empty() && !matches_any_glob(context.file_path()?, &self.files) {
+            return None;
+        }
+
+        let mut errors = Vec::new();
+        for (idx, node) in root.children.iter().enumerate() {
+            let Node::Heading(heading) = node else {
+                continue;
+            };
+            if !collapse_whitespace(&node.to_string()).eq_ignore_ascii_case(&self.heading) {
+                continue;
+            }
+
+            let section = root.children[idx + 1..].iter().take_while(
+                |sibling| !matches!(sibling, Node::Heading(next) if next.depth <= heading.depth),
+            );
+            let lists = section
+                .filter_map(|sibling| match sibling {
+                    Node::List(list) => Some((sibling, list)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            if lists.is_empty() {
+                errors.extend(self.error(
+                    node,
+                    context,
+                    level,
+                    &format!(
+                        "List the prerequisites in the \"{}\" section as bullet points.",
+                        self.heading
+                    ),
+                ));
+                continue;
+            }
+            for (list_node, list) in lists {
+                self.check_list(list_node, list, context, level, &mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule032Prerequisites {
+    fn check_list(
+        &self,
+        node: &Node,
+        list: &List,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        if list.ordered {
+            errors.extend(self.error(
+                node,
+                context,
+                level,
+                "Use a bulleted list for prerequisites, not a numbered list.",
+            ));
+            return;
+        }
+
+        for item in &list.children {
+            if !self.is_valid_item(item) {
+                errors.extend(self.error(
+                    item,
+                    context,
+                    level,
+                    "Start each prerequisite with a link or a product name.",
+                ));
+            }
+        }
+    }
+
+    /// Whether a list item starts with a link or a configured product name.
+    fn is_valid_item(&self, item: &Node) -> bool {
+        let Some(Node::Paragraph(paragraph)) =
+            item.children().and_then(|children| children.first())
+        else {
+            return false;
+        };
+
+        match paragraph.children.first() {
+            Some(Node::Link(_) | Node::LinkReference(_)) => true,
+            Some(Node::Text(text)) => self.products.iter().any(|product| {
+                text.value
+                    .strip_prefix(product.as_str())
+                    .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric()))
+            }),
+            _ => false,
+        }
+    }
+
+    fn error(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+        message: &str,
+    ) -> Option<LintError> {
+        LintError::from_node()
+            .node(node)
+            .context(context)
+            .rule(self.name())
+            .level(level)
+            .message(message)
+            .call()
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule032_valid_items() {
+        assert_lint_snapshot!(
+            "Rule032Prerequisites",
+            "## Prerequisites\n\nBefore you begin, you need:\n\n- [A Supabase project](https://supabase.com/dashboard)\n- Node.js 20 or later\n- Supabase CLI\n\n## Steps\n\n- Anything goes here\n",
+            Some(json!({ "products": ["Node.js", "Supabase CLI"] })),
+            file = "guide.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule032_invalid_items() {
+        assert_lint_snapshot!(
+            "Rule032Prerequisites",
+            "## Prerequisites\n\n- You need a Supabase project\n- Node.js 20\n- Nodejs\n\n### Optional\n\n- Docker\n",
+            Some(json!({ "products": ["Node"] })),
+            file = "guide.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule032_not_a_bulleted_list() {
+        assert_lint_snapshot!(
+            "Rule032Prerequisites",
+            "## Prerequisites\n\nA Supabase project.\n",
+            Some(json!({})),
+            file = "guide.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule032Prerequisites",
+            "## Prerequisites\n\n1. [A project](https://supabase.com/dashboard)\n",
+            Some(json!({})),
+            file = "guide.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule032_settings() {
+        let settings = json!({ "files": ["docs/**"], "heading": "Before you begin" });
+        let mdx = "## Before you begin\n\n- A project\n";
+        assert_lint_snapshot!(
+            "Rule032Prerequisites",
+            mdx,
+            Some(settings.clone()),
+            file = "docs/guide.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule032Prerequisites",
+            mdx,
+            Some(settings.clone()),
+            file = "blog/post.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule032Prerequisites",
+            "## Prerequisites\n\n- A project\n",
+            Some(settings),
+            file = "docs/guide.mdx"
+        );
+        assert_lint_snapshot!("Rule032Prerequisites", mdx, None, file = "docs/guide.mdx");
+    }
+}
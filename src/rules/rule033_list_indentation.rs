@@ -0,0 +1,343 @@
+use log::warn;
+use markdown::{
+    mdast::{ListItem, Node},
+    unist::Position,
+};
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{FixSafety, LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const INDENTS: &[usize] = &[2, 4];
+const DEFAULT_INDENT: usize = 2;
+
+/// Nested lists should be indented by a consistent number of spaces, and
+/// paragraphs that continue a list item should line up with the item's text.
+///
+/// A nested list is indented by the configured step from its parent item's
+/// marker, or up to the parent item's text if that is further in, since
+/// Markdown doesn't nest the list otherwise. Misaligned items and paragraphs
+/// can be re-indented automatically, along with everything nested in them.
+/// Fixes are only applied with `--fix-unsafe`, since re-indenting can change
+/// which item a block belongs to.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// - First item
+///   - Nested item
+///
+///   More about the first item.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// - First item
+///    - Nested item
+///
+///     More about the first item.
+/// ```
+///
+/// ## Configuration
+///
+/// - `indent`: The number of spaces to indent nested lists by, either 2 or
+///   4. Defaults to 2.
+///
+/// ```toml
+/// [Rule033ListIndentation]
+/// indent = 4
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule033ListIndentation {
+    indent: usize,
+}
+
+impl Default for Rule033ListIndentation {
+    fn default() -> Self {
+        Self {
+            indent: DEFAULT_INDENT,
+        }
+    }
+}
+
+impl Rule for Rule033ListIndentation {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::List])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(indent) = settings.get_deserializable::<usize>("indent") {
+            if INDENTS.contains(&indent) {
+                self.indent = indent;
+            } else {
+                warn!(
+                    "Invalid indent for {}: {indent}. Expected 2 or 4.",
+                    self.name()
+                );
+            }
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "indent": {
+                "type": "integer",
+                "enum": INDENTS,
+                "default": DEFAULT_INDENT,
+                "description": "The number of spaces to indent nested lists by.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::List(list) = ast else {
+            return None;
+        };
+
+        let mut errors = Vec::new();
+        for item in &list.children {
+            let Node::ListItem(item) = item else {
+                continue;
+            };
+            self.check_item(item, context, level, &mut errors);
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule033ListIndentation {
+    fn check_item(
+        &self,
+        item: &ListItem,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let (Some(item_position), Some(text_position)) = (
+            item.position.as_ref(),
+            item.children.first().and_then(Node::position),
+        ) else {
+            return;
+        };
+        if text_position.start.line != item_position.start.line {
+            return;
+        }
+        let (Some((item_indent, _)), Some((text_indent, _))) = (
+            indentation(item_position, context),
+            indentation(text_position, context),
+        ) else {
+            return;
+        };
+        let nested_indent = (item_indent + self.indent).max(text_indent);
+
+        for child in item.children.iter().skip(1) {
+            match child {
+                Node::List(nested) => {
+                    for nested_item in &nested.children {
+                        errors.extend(self.check_indent(
+                            nested_item,
+                            nested_indent,
+                            "nested list items",
+                            "",
+                            context,
+                            level,
+                        ));
+                    }
+                }
+                _ => errors.extend(self.check_indent(
+                    child,
+                    text_indent,
+                    "continuation lines",
+                    ", to line up with the text of the list item",
+                    context,
+                    level,
+                )),
+            }
+        }
+    }
+
+    fn check_indent(
+        &self,
+        node: &Node,
+        expected: usize,
+        subject: &str,
+        reason: &str,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        let position = node.position()?;
+        let (actual, line_start) = indentation(position, context)?;
+        if actual == expected {
+            return None;
+        }
+
+        let end: usize = AdjustedRange::from_unadjusted_position(position, context)
+            .end
+            .into();
+        let start = line_start + actual;
+
+        let location = DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(
+                AdjustedOffset::from(line_start),
+                AdjustedOffset::from(start),
+            ),
+            context,
+        );
+        let fix = reindent(
+            &context.rope().byte_slice(line_start..end).to_string(),
+            line_start,
+            actual,
+        )
+        .map(|lines| {
+            lines
+                .into_iter()
+                .map(|line| {
+                    LintCorrection::Replace(LintCorrectionReplace {
+                        location: DenormalizedLocation::from_offset_range(
+                            AdjustedRange::new(
+                                AdjustedOffset::from(line),
+                                AdjustedOffset::from(line + actual),
+                            ),
+                            context,
+                        ),
+                        text: " ".repeat(expected),
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Some(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(format!(
+                    "Indent {subject} by {expected} spaces, not {actual}{reason}."
+                ))
+                .location(location)
+                .maybe_fix(fix)
+                .fix_safety(FixSafety::Unsafe)
+                .call(),
+        )
+    }
+}
+
+/// The indentation of a block, counted from the start of its line, and the
+/// offset of the line start. Container nodes in the AST start at their
+/// parent's content column, so any further spaces are counted from the
+/// source.
+fn indentation(position: &Position, context: &Context) -> Option<(usize, usize)> {
+    let start: usize =
+        AdjustedOffset::from_unist(&position.start, context.content_start_offset()).into();
+    let column = position.start.column - 1;
+    let spaces = context
+        .rope()
+        .byte_slice(start..)
+        .chars()
+        .take_while(|c| *c == ' ')
+        .count();
+    Some((column + spaces, start.checked_sub(column)?))
+}
+
+/// The offsets of the lines to shift from one indent to another when
+/// re-indenting a block starting at `offset`. Only the leading spaces of each
+/// line are replaced, so the fix doesn't clash with fixes to the text inside
+/// the block. Lines indented less than the block, such as lazy continuation
+/// lines, are left alone. Returns `None` if the block isn't indented with
+/// spaces alone, such as in a block quote.
+fn reindent(block: &str, offset: usize, actual: usize) -> Option<Vec<usize>> {
+    if !block.get(..actual)?.chars().all(|c| c == ' ') {
+        return None;
+    }
+
+    let mut lines = Vec::new();
+    let mut line_start = offset;
+    for line in block.split('\n') {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if !line.trim().is_empty() && indent >= actual {
+            lines.push(line_start);
+        }
+        line_start += line.len() + 1;
+    }
+    Some(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fix")]
+    use crate::testing::assert_lint_snapshot;
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule033_consistent_indentation() {
+        assert_lint_snapshot!(
+            "Rule033ListIndentation",
+            "- One\n  - Nested\n    - Deeper\n\n  More about one.\n\n1. First\n   - Nested\n\n   More about first.\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule033_nested_list() {
+        assert_lint_snapshot!(
+            "Rule033ListIndentation",
+            "- One\n   - Nested\n     continued\n     - Deeper\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule033_continuation_paragraph() {
+        assert_lint_snapshot!(
+            "Rule033ListIndentation",
+            "1. First\n\n    More about first.\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule033_block_quote_has_no_fix() {
+        assert_lint_snapshot!("Rule033ListIndentation", "> - One\n>    - Nested\n");
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule033_indent_setting() {
+        assert_lint_snapshot!(
+            "Rule033ListIndentation",
+            "- One\n    - Nested\n\n* Two\n  * Nested\n",
+            Some(json!({ "indent": 4 }))
+        );
+    }
+
+    #[test]
+    fn test_rule033_invalid_indent() {
+        let mut rule = Rule033ListIndentation::default();
+        let table: toml::Table = toml::from_str("indent = 3").unwrap();
+        rule.setup(Some(&mut RuleSettings::new(table)));
+        assert_eq!(rule.indent, DEFAULT_INDENT);
+    }
+}
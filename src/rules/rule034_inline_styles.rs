@@ -0,0 +1,250 @@
+use markdown::mdast::{AttributeContent, Node};
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::{AdjustedRange, DenormalizedLocation},
+    utils::mdast::find_jsx_attribute_name_range,
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const STYLE_ATTRIBUTE: &str = "style";
+const CLASS_ATTRIBUTES: &[&str] = &["className", "class"];
+const DEFAULT_MAX_CLASS_NAMES: usize = 3;
+
+/// JSX in content files shouldn't carry presentation. Inline `style` props
+/// and class names tie the content to one look, and bypass the design
+/// system's components.
+///
+/// Every `style` prop is reported. A few `className` props are allowed in
+/// each document, and the rest are reported.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// <Admonition type="note">
+///
+/// Projects are paused after a week of inactivity.
+///
+/// </Admonition>
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// <div style={{ color: 'red' }}>
+///
+/// Projects are paused after a week of inactivity.
+///
+/// </div>
+/// ```
+///
+/// ## Configuration
+///
+/// - `max_class_names`: The number of `className` props allowed in each
+///   document. Defaults to 3.
+/// - `allow_components`: Components that may use `style` and `className`
+///   freely, such as layout wrappers.
+///
+/// ```toml
+/// [Rule034InlineStyles]
+/// max_class_names = 0
+/// allow_components = ["Grid"]
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule034InlineStyles {
+    max_class_names: usize,
+    allow_components: Vec<String>,
+}
+
+impl Default for Rule034InlineStyles {
+    fn default() -> Self {
+        Self {
+            max_class_names: DEFAULT_MAX_CLASS_NAMES,
+            allow_components: Vec::new(),
+        }
+    }
+}
+
+impl Rule for Rule034InlineStyles {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(max) = settings.get_deserializable::<usize>("max_class_names") {
+            self.max_class_names = max;
+        }
+        if let Some(components) = settings.get_deserializable::<Vec<String>>("allow_components") {
+            self.allow_components = components;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "max_class_names": {
+                "type": "integer",
+                "minimum": 0,
+                "default": DEFAULT_MAX_CLASS_NAMES,
+                "description": "The number of className props allowed in each document.",
+            },
+            "allow_components": string_array_schema("Components that may use style and className freely."),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(_) = ast else {
+            return None;
+        };
+
+        let mut styles = Vec::new();
+        let mut class_names = Vec::new();
+        self.collect(ast, context, &mut styles, &mut class_names);
+
+        let errors = styles
+            .into_iter()
+            .map(|range| {
+                self.error(
+                    range,
+                    "Avoid inline styles in content. Use a design system component instead.",
+                    context,
+                    level,
+                )
+            })
+            .chain(
+                class_names
+                    .into_iter()
+                    .skip(self.max_class_names)
+                    .map(|range| {
+                        self.error(
+                            range,
+                            &format!(
+                                "Avoid using className more than {} time{} in a document. Move the styling into a component.",
+                                self.max_class_names,
+                                if self.max_class_names == 1 { "" } else { "s" }
+                            ),
+                            context,
+                            level,
+                        )
+                    }),
+            )
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule034InlineStyles {
+    /// Collect the ranges of `style` and `className` props, in document
+    /// order.
+    fn collect(
+        &self,
+        node: &Node,
+        context: &Context,
+        styles: &mut Vec<AdjustedRange>,
+        class_names: &mut Vec<AdjustedRange>,
+    ) {
+        let element = match node {
+            Node::MdxJsxFlowElement(element) => Some((&element.name, &element.attributes)),
+            Node::MdxJsxTextElement(element) => Some((&element.name, &element.attributes)),
+            _ => None,
+        };
+        if let Some((name, attributes)) = element {
+            let allowed = name
+                .as_ref()
+                .is_some_and(|name| self.allow_components.contains(name));
+            for attribute in attributes {
+                let AttributeContent::Property(property) = attribute else {
+                    continue;
+                };
+                let found = if property.name == STYLE_ATTRIBUTE {
+                    &mut *styles
+                } else if CLASS_ATTRIBUTES.contains(&property.name.as_str()) {
+                    &mut *class_names
+                } else {
+                    continue;
+                };
+                let Some(range) = find_jsx_attribute_name_range(node, &property.name, context)
+                else {
+                    continue;
+                };
+                if !allowed && !context.is_skipped(&range) {
+                    found.push(range);
+                }
+            }
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                self.collect(child, context, styles, class_names);
+            }
+        }
+    }
+
+    fn error(
+        &self,
+        range: AdjustedRange,
+        message: &str,
+        context: &Context,
+        level: LintLevel,
+    ) -> LintError {
+        LintError::from_raw_location()
+            .rule(self.name())
+            .level(level)
+            .message(message)
+            .location(DenormalizedLocation::from_offset_range(range, context))
+            .call()
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule034_style_props() {
+        assert_lint_snapshot!(
+            "Rule034InlineStyles",
+            "<div style={{ color: 'red' }}>\n\nSet the style = bold.\n\n</div>\n\nSome <span style={{ margin: 0 }}>text</span>.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule034_class_names() {
+        let mdx = "<div className=\"grid\">\n\n<span className=\"bold\">One</span>\n\n</div>\n\n<p class=\"small\">Two</p>\n";
+        assert_lint_snapshot!(
+            "Rule034InlineStyles",
+            mdx,
+            Some(json!({ "max_class_names": 1 }))
+        );
+        assert_lint_snapshot!("Rule034InlineStyles", mdx);
+    }
+
+    #[test]
+    fn test_rule034_allow_components() {
+        assert_lint_snapshot!(
+            "Rule034InlineStyles",
+            "<Grid style={{ gap: 4 }} className=\"cols-2\">\n\nText.\n\n</Grid>\n",
+            Some(json!({ "allow_components": ["Grid"] }))
+        );
+    }
+}
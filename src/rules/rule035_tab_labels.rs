@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+
+use markdown::mdast::{AttributeContent, AttributeValue, Node};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    location::{AdjustedRange, DenormalizedLocation},
+    utils::mdast::{find_jsx_attribute_name_range, find_jsx_attribute_value_range},
+};
+
+use super::{NodeKind, Rule, RuleName, RuleSettings};
+
+const TABS_COMPONENT: &str = "Tabs";
+const PANEL_COMPONENT: &str = "TabPanel";
+const LABEL_ATTRIBUTE: &str = "label";
+
+/// The tabs in a `<Tabs>` block should each have a label, and no two tabs
+/// should share one. Tabs are switched by label, so a duplicate or empty
+/// label silently breaks switching to that tab.
+///
+/// Labels set with JSX expressions, such as `label={name}`, aren't checked.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// <Tabs>
+///   <TabPanel id="npm" label="npm">...</TabPanel>
+///   <TabPanel id="yarn" label="Yarn">...</TabPanel>
+/// </Tabs>
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// <Tabs>
+///   <TabPanel id="npm" label="npm">...</TabPanel>
+///   <TabPanel id="npx" label="npm">...</TabPanel>
+///   <TabPanel id="yarn" label="">...</TabPanel>
+/// </Tabs>
+/// ```
+#[derive(Debug, Default, RuleName)]
+#[rule_category(Correctness)]
+pub struct Rule035TabLabels;
+
+impl Rule for Rule035TabLabels {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::MdxJsxFlowElement])
+    }
+
+    fn setup(&mut self, _settings: Option<&mut RuleSettings>) {
+        // No configuration options for this rule
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::MdxJsxFlowElement(tabs) = ast else {
+            return None;
+        };
+        if tabs.name.as_deref() != Some(TABS_COMPONENT) {
+            return None;
+        }
+
+        let mut seen = HashSet::new();
+        let mut errors = Vec::new();
+        for panel in &tabs.children {
+            let Node::MdxJsxFlowElement(element) = panel else {
+                continue;
+            };
+            if element.name.as_deref() != Some(PANEL_COMPONENT) {
+                continue;
+            }
+            let Some(label) = element
+                .attributes
+                .iter()
+                .find_map(|attribute| match attribute {
+                    AttributeContent::Property(property) if property.name == LABEL_ATTRIBUTE => {
+                        Some(&property.value)
+                    }
+                    _ => None,
+                })
+            else {
+                continue;
+            };
+
+            match label {
+                Some(AttributeValue::Literal(label)) if !label.trim().is_empty() => {
+                    if seen.insert(label.trim()) {
+                        continue;
+                    }
+                    errors.extend(
+                        find_jsx_attribute_value_range(panel, LABEL_ATTRIBUTE, label, context)
+                            .map(|range| {
+                                self.error(
+                                    range,
+                                    &format!(
+                                        "The tab label \"{}\" is used more than once in this <{TABS_COMPONENT}> block. Give each tab a unique label.",
+                                        label.trim()
+                                    ),
+                                    context,
+                                    level,
+                                )
+                            }),
+                    );
+                }
+                Some(AttributeValue::Expression(_)) => {}
+                _ => errors.extend(
+                    find_jsx_attribute_name_range(panel, LABEL_ATTRIBUTE, context).map(|range| {
+                        self.error(
+                            range,
+                            &format!("Give each <{PANEL_COMPONENT}> a non-empty label."),
+                            context,
+                            level,
+                        )
+                    }),
+                ),
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule035TabLabels {
+    fn error(
+        &self,
+        range: AdjustedRange,
+        message: &str,
+        context: &Context,
+        level: LintLevel,
+    ) -> LintError {
+        LintError::from_raw_location()
+            .rule(self.name())
+            .level(level)
+            .message(message)
+            .location(DenormalizedLocation::from_offset_range(range, context))
+            .call()
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule035_unique_labels() {
+        assert_lint_snapshot!(
+            "Rule035TabLabels",
+            "<Tabs>\n<TabPanel id=\"npm\" label=\"npm\">\n\nnpm install\n\n</TabPanel>\n<TabPanel id=\"yarn\" label=\"Yarn\">\n\nyarn add\n\n</TabPanel>\n</Tabs>\n"
+        );
+    }
+
+    #[test]
+    fn test_rule035_duplicate_labels() {
+        assert_lint_snapshot!(
+            "Rule035TabLabels",
+            "<Tabs>\n<TabPanel id=\"npm\" label=\"npm\">\n\nnpm install\n\n</TabPanel>\n<TabPanel id=\"npx\" label=\"npm\">\n\nnpx\n\n</TabPanel>\n</Tabs>\n"
+        );
+    }
+
+    #[test]
+    fn test_rule035_empty_labels() {
+        assert_lint_snapshot!(
+            "Rule035TabLabels",
+            "<Tabs>\n<TabPanel id=\"npm\" label=\"\">\n\nnpm install\n\n</TabPanel>\n<TabPanel id=\"yarn\" label=\" \">\n\nyarn add\n\n</TabPanel>\n<TabPanel id=\"pnpm\" label={name}>\n\npnpm add\n\n</TabPanel>\n</Tabs>\n"
+        );
+    }
+
+    #[test]
+    fn test_rule035_other_components() {
+        assert_lint_snapshot!(
+            "Rule035TabLabels",
+            "<Steps>\n<TabPanel label=\"One\">\n\nA\n\n</TabPanel>\n<TabPanel label=\"One\">\n\nB\n\n</TabPanel>\n</Steps>\n"
+        );
+    }
+}
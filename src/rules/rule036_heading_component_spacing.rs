@@ -0,0 +1,275 @@
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionInsert},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::mdast::{blank_lines_between, flow_component_name},
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+/// Components and headings should be laid out so that each section reads
+/// cleanly: a heading shouldn't be squashed against the component before it,
+/// and a component shouldn't come between a heading and its first
+/// paragraph.
+///
+/// Missing blank lines before headings can be fixed automatically.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// </Admonition>
+///
+/// ## Set up the client
+///
+/// Install the client library.
+///
+/// <Admonition type="note">
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// </Admonition>
+/// ## Set up the client
+///
+/// <Admonition type="note">
+///
+/// ...
+///
+/// </Admonition>
+///
+/// Install the client library.
+/// ```
+///
+/// ## Configuration
+///
+/// - `blank_line_after_component`: Require a blank line between a component
+///   and a heading that follows it. Defaults to true.
+/// - `component_after_heading`: Report components between a heading and its
+///   first paragraph. Defaults to true.
+/// - `allow_components`: Components that may come straight after a heading,
+///   such as tabs whose panels hold the section's text.
+///
+/// ```toml
+/// [Rule036HeadingComponentSpacing]
+/// allow_components = ["Tabs"]
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule036HeadingComponentSpacing {
+    blank_line_after_component: bool,
+    component_after_heading: bool,
+    allow_components: Vec<String>,
+}
+
+impl Default for Rule036HeadingComponentSpacing {
+    fn default() -> Self {
+        Self {
+            blank_line_after_component: true,
+            component_after_heading: true,
+            allow_components: Vec::new(),
+        }
+    }
+}
+
+impl Rule for Rule036HeadingComponentSpacing {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root, NodeKind::MdxJsxFlowElement])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(enabled) = settings.get_deserializable::<bool>("blank_line_after_component") {
+            self.blank_line_after_component = enabled;
+        }
+        if let Some(enabled) = settings.get_deserializable::<bool>("component_after_heading") {
+            self.component_after_heading = enabled;
+        }
+        if let Some(components) = settings.get_deserializable::<Vec<String>>("allow_components") {
+            self.allow_components = components;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "blank_line_after_component": {
+                "type": "boolean",
+                "default": true,
+                "description": "Require a blank line between a component and a heading that follows it.",
+            },
+            "component_after_heading": {
+                "type": "boolean",
+                "default": true,
+                "description": "Report components between a heading and its first paragraph.",
+            },
+            "allow_components": string_array_schema("Components that may come straight after a heading."),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let children = match ast {
+            Node::Root(root) => &root.children,
+            Node::MdxJsxFlowElement(element) => &element.children,
+            _ => return None,
+        };
+
+        let mut errors = Vec::new();
+        for (idx, pair) in children.windows(2).enumerate() {
+            let [prev, node] = pair else {
+                continue;
+            };
+            if self.blank_line_after_component {
+                errors.extend(self.check_blank_line(prev, node, context, level));
+            }
+            if self.component_after_heading {
+                errors.extend(self.check_component_after_heading(
+                    prev,
+                    node,
+                    children.get(idx + 2),
+                    context,
+                    level,
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule036HeadingComponentSpacing {
+    fn check_blank_line(
+        &self,
+        component: &Node,
+        heading: &Node,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        let name = flow_component_name(component)?;
+        if !matches!(heading, Node::Heading(_)) || blank_lines_between(component, heading)? > 0 {
+            return None;
+        }
+
+        let start =
+            AdjustedOffset::from_unist(&heading.position()?.start, context.content_start_offset());
+        let component_end: usize =
+            AdjustedRange::from_unadjusted_position(component.position()?, context)
+                .end
+                .into();
+        let between = context
+            .rope()
+            .byte_slice(component_end..start.into())
+            .to_string();
+        let line_ending = if between.contains('\r') { "\r\n" } else { "\n" };
+        let fix = LintCorrection::Insert(LintCorrectionInsert {
+            location: DenormalizedLocation::from_offset_range(
+                AdjustedRange::new(start, start),
+                context,
+            ),
+            text: line_ending.to_string(),
+        });
+
+        LintError::from_node()
+            .node(heading)
+            .context(context)
+            .rule(self.name())
+            .level(level)
+            .message(&format!(
+                "Add a blank line between the <{name}> component and the heading after it."
+            ))
+            .fix(vec![fix])
+            .call()
+    }
+
+    fn check_component_after_heading(
+        &self,
+        heading: &Node,
+        component: &Node,
+        next: Option<&Node>,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        let name = flow_component_name(component)?;
+        if !matches!(heading, Node::Heading(_))
+            || !matches!(next, Some(Node::Paragraph(_)))
+            || self.allow_components.iter().any(|allowed| allowed == name)
+        {
+            return None;
+        }
+
+        LintError::from_node()
+            .node(component)
+            .context(context)
+            .rule(self.name())
+            .level(level)
+            .message(&format!(
+                "Move the <{name}> component after the first paragraph of the section, so the heading is followed by text."
+            ))
+            .call()
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule036_valid_layout() {
+        assert_lint_snapshot!(
+            "Rule036HeadingComponentSpacing",
+            "<Admonition type=\"note\">\n\nNote.\n\n</Admonition>\n\n## Set up\n\nInstall it.\n\n<Admonition type=\"note\">\n\nNote.\n\n</Admonition>\n\n## Next\n\n<Admonition type=\"note\">\n\nNothing else in this section.\n\n</Admonition>\n"
+        );
+    }
+
+    #[test]
+    fn test_rule036_heading_after_component() {
+        assert_lint_snapshot!(
+            "Rule036HeadingComponentSpacing",
+            "<Admonition type=\"note\">\n\nNote.\n\n</Admonition>\n## Set up\n\nInstall it.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule036_component_after_heading() {
+        let mdx =
+            "## Set up\n\n<Admonition type=\"note\">\n\nNote.\n\n</Admonition>\n\nInstall it.\n";
+        assert_lint_snapshot!("Rule036HeadingComponentSpacing", mdx);
+        assert_lint_snapshot!(
+            "Rule036HeadingComponentSpacing",
+            mdx,
+            Some(json!({ "allow_components": ["Admonition"] }))
+        );
+        assert_lint_snapshot!(
+            "Rule036HeadingComponentSpacing",
+            mdx,
+            Some(json!({ "component_after_heading": false }))
+        );
+    }
+
+    #[test]
+    fn test_rule036_nested_in_component() {
+        assert_lint_snapshot!(
+            "Rule036HeadingComponentSpacing",
+            "<Tabs>\n<TabPanel id=\"a\" label=\"A\">\n\nText.\n\n</TabPanel>\n## Heading\n\nText.\n\n</Tabs>\n",
+            Some(json!({ "component_after_heading": false }))
+        );
+    }
+}
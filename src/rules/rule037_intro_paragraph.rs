@@ -0,0 +1,235 @@
+use glob::Pattern;
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    utils::{mdast::is_esm_paragraph, path::matches_any_glob},
+};
+
+use super::{object_schema, string_array_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const DEFAULT_MIN_WORDS: usize = 10;
+
+/// A page should open with a paragraph that introduces it, before its first
+/// section heading. Stub pages that jump straight into a table or a code
+/// sample leave readers guessing whether they're in the right place.
+///
+/// Only top-level paragraphs count towards the introduction, so text inside
+/// components such as admonitions doesn't. Pages without section headings are
+/// checked as a whole.
+///
+/// The rule is off unless configured.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// # Database functions
+///
+/// Database functions let you run logic inside Postgres, close to your data.
+///
+/// ## Create a function
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// # Database functions
+///
+/// ## Create a function
+/// ```
+///
+/// ## Configuration
+///
+/// - `files`: Globs for the files to check. Defaults to all files.
+/// - `min_words`: The number of words the introduction needs. Defaults to
+///   10.
+///
+/// ```toml
+/// [Rule037IntroParagraph]
+/// files = ["content/guides/**"]
+/// min_words = 20
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule037IntroParagraph {
+    enabled: bool,
+    files: Vec<Pattern>,
+    min_words: usize,
+}
+
+impl Default for Rule037IntroParagraph {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            files: Vec::new(),
+            min_words: DEFAULT_MIN_WORDS,
+        }
+    }
+}
+
+impl Rule for Rule037IntroParagraph {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        self.enabled = true;
+        if let Some(files) = settings.get_array_of_globs("files") {
+            self.files = files;
+        }
+        if let Some(min_words) = settings.get_deserializable::<usize>("min_words") {
+            self.min_words = min_words;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "files": string_array_schema("Globs for the files to check. Defaults to all files."),
+            "min_words": {
+                "type": "integer",
+                "minimum": 1,
+                "default": DEFAULT_MIN_WORDS,
+                "description": "The number of words the introduction needs.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !self.enabled {
+            return None;
+        }
+        let Node::Root(root) = ast else {
+            return None;
+        };
+        if !self.files.is_empty() && !matches_any_glob(context.file_path()?, &self.files) {
+            return None;
+        }
+
+        let first_section = root
+            .children
+            .iter()
+            .position(|node| matches!(node, Node::Heading(heading) if heading.depth > 1));
+        let intro = &root.children[..first_section.unwrap_or(root.children.len())];
+        let words = intro
+            .iter()
+            .filter(|node| matches!(node, Node::Paragraph(_)) && !is_esm_paragraph(node))
+            .map(|node| node.to_string().split_whitespace().count())
+            .sum::<usize>();
+        if words >= self.min_words {
+            return None;
+        }
+
+        // Without a section heading, there's no clear place to report a short
+        // introduction, other than the page title.
+        let reported = first_section.map(|idx| &root.children[idx]).or_else(|| {
+            intro
+                .iter()
+                .find(|node| matches!(node, Node::Heading(heading) if heading.depth == 1))
+        })?;
+
+        LintError::from_node()
+            .node(reported)
+            .context(context)
+            .rule(self.name())
+            .level(level)
+            .message(&format!(
+                "Introduce the page with a paragraph of at least {} word{} before the first section.",
+                self.min_words,
+                if self.min_words == 1 { "" } else { "s" }
+            ))
+            .call()
+            .map(|error| vec![error])
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule037_off_by_default() {
+        assert_lint_snapshot!(
+            "Rule037IntroParagraph",
+            "# Title\n\n## Section\n",
+            None,
+            file = "page.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule037_intro_paragraph() {
+        assert_lint_snapshot!(
+            "Rule037IntroParagraph",
+            "# Functions\n\nDatabase functions run logic inside Postgres.\n\n## Create a function\n",
+            Some(json!({ "min_words": 5 })),
+            file = "page.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule037_missing_intro() {
+        assert_lint_snapshot!(
+            "Rule037IntroParagraph",
+            "# Functions\n\n| Name | Type |\n| - | - |\n| a | int |\n\n## Create a function\n\nText.\n",
+            Some(json!({ "min_words": 5 })),
+            file = "page.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule037_short_intro() {
+        assert_lint_snapshot!(
+            "Rule037IntroParagraph",
+            "import Foo from './foo'\n\n# Functions\n\nToo short.\n\n<Admonition type=\"note\">\n\nAdmonitions don't count as the intro.\n\n</Admonition>\n\n### Details\n",
+            Some(json!({ "min_words": 5 })),
+            file = "page.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule037_no_sections() {
+        assert_lint_snapshot!(
+            "Rule037IntroParagraph",
+            "# Functions\n\n```sql\nselect 1;\n```\n",
+            Some(json!({ "min_words": 5 })),
+            file = "page.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule037IntroParagraph",
+            "Some text.\n",
+            Some(json!({ "min_words": 5 })),
+            file = "page.mdx"
+        );
+    }
+
+    #[test]
+    fn test_rule037_files() {
+        let mdx = "# Functions\n\n## Create a function\n";
+        assert_lint_snapshot!(
+            "Rule037IntroParagraph",
+            mdx,
+            Some(json!({ "files": ["guides/**"] })),
+            file = "guides/functions.mdx"
+        );
+        assert_lint_snapshot!(
+            "Rule037IntroParagraph",
+            mdx,
+            Some(json!({ "files": ["guides/**"] })),
+            file = "reference/functions.mdx"
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use log::warn;
+use markdown::mdast::Node;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{FixSafety, LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::collapse_whitespace,
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const STYLES: &[(&str, char)] = &[("kebab-case", '-'), ("snake_case", '_')];
+const DEFAULT_STYLE: &str = "kebab-case";
+
+/// An explicit heading ID, written `[#id]` at the end of a heading.
+pub(super) fn heading_id_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"\[#([^\]\n]*)\][ \t]*#*[ \t]*$").unwrap())
+}
+
+/// Explicit heading IDs, written `## Title [#custom-id]`, should be unique
+/// within a page and follow the slug style, so that anchor links are
+/// predictable.
+///
+/// IDs that don't follow the style can be normalized automatically. Fixes are
+/// only applied with `--fix-unsafe`, since changing an ID breaks links to the
+/// old anchor.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// ## Row level security [#row-level-security]
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// ## Row level security [#Row_Level_Security]
+///
+/// ## Policies [#row-level-security]
+/// ```
+///
+/// ## Configuration
+///
+/// - `style`: The slug style for IDs, either `kebab-case` or `snake_case`.
+///   Defaults to `kebab-case`.
+/// - `match_heading`: Require each ID to be the slug of its heading's text.
+///   Defaults to false.
+///
+/// ```toml
+/// [Rule038HeadingIds]
+/// match_heading = true
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Links)]
+pub struct Rule038HeadingIds {
+    separator: char,
+    match_heading: bool,
+}
+
+impl Default for Rule038HeadingIds {
+    fn default() -> Self {
+        Self {
+            separator: '-',
+            match_heading: false,
+        }
+    }
+}
+
+/// An explicit ID found at the end of a heading.
+#[derive(Debug)]
+struct HeadingId {
+    id: String,
+    range: AdjustedRange,
+    /// The text of the heading, without the ID.
+    text: String,
+    line: usize,
+}
+
+impl Rule for Rule038HeadingIds {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(style) = settings.get_deserializable::<String>("style") {
+            match STYLES.iter().find(|(name, _)| *name == style) {
+                Some((_, separator)) => self.separator = *separator,
+                None => warn!(
+                    "Invalid style for {}: {style:?}. Expected kebab-case or snake_case.",
+                    self.name()
+                ),
+            }
+        }
+        if let Some(match_heading) = settings.get_deserializable::<bool>("match_heading") {
+            self.match_heading = match_heading;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "style": {
+                "type": "string",
+                "enum": STYLES.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+                "default": DEFAULT_STYLE,
+                "description": "The slug style for heading IDs.",
+            },
+            "match_heading": {
+                "type": "boolean",
+                "default": false,
+                "description": "Require each heading ID to be the slug of its heading's text.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(_) = ast else {
+            return None;
+        };
+
+        let mut ids = Vec::new();
+        collect_heading_ids(ast, context, &mut ids);
+
+        let mut seen = HashMap::new();
+        let mut errors = Vec::new();
+        for heading_id in &ids {
+            if let Some(line) = seen.get(heading_id.id.as_str()) {
+                errors.push(self.error(
+                    heading_id,
+                    &format!(
+                        "The heading ID \"{}\" is already used on line {line}. Give each heading a unique ID.",
+                        heading_id.id
+                    ),
+                    None,
+                    context,
+                    level,
+                ));
+                continue;
+            }
+            seen.insert(heading_id.id.as_str(), heading_id.line);
+
+            let normalized = self.slugify(&heading_id.id);
+            if normalized.is_empty() {
+                errors.push(self.error(
+                    heading_id,
+                    "Add an ID between the brackets, or remove them.",
+                    None,
+                    context,
+                    level,
+                ));
+            } else if normalized != heading_id.id {
+                errors.push(self.error(
+                    heading_id,
+                    &format!(
+                        "Use lowercase letters, numbers, and \"{}\" in heading IDs: \"{normalized}\".",
+                        self.separator
+                    ),
+                    Some(normalized),
+                    context,
+                    level,
+                ));
+            } else if self.match_heading {
+                let expected = self.slugify(&heading_id.text);
+                if !expected.is_empty() && expected != heading_id.id {
+                    errors.push(self.error(
+                        heading_id,
+                        &format!(
+                            "The heading ID \"{}\" doesn't match the heading. Use \"{expected}\".",
+                            heading_id.id
+                        ),
+                        Some(expected),
+                        context,
+                        level,
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule038HeadingIds {
+    /// Lowercase the text, and join its words with the separator.
+    fn slugify(&self, text: &str) -> String {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(&self.separator.to_string())
+    }
+
+    fn error(
+        &self,
+        heading_id: &HeadingId,
+        message: &str,
+        replacement: Option<String>,
+        context: &Context,
+        level: LintLevel,
+    ) -> LintError {
+        let location = DenormalizedLocation::from_offset_range(heading_id.range.clone(), context);
+        LintError::from_raw_location()
+            .rule(self.name())
+            .level(level)
+            .message(message)
+            .location(location.clone())
+            .maybe_fix(replacement.map(|text| {
+                vec![LintCorrection::Replace(LintCorrectionReplace {
+                    location,
+                    text,
+                })]
+            }))
+            .fix_safety(FixSafety::Unsafe)
+            .call()
+    }
+}
+
+/// Collect the explicit IDs of the headings in the document, including those
+/// nested in components, in document order.
+fn collect_heading_ids(node: &Node, context: &Context, ids: &mut Vec<HeadingId>) {
+    if let Node::Heading(_) = node {
+        ids.extend(heading_id(node, context));
+        return;
+    }
+    if let Some(children) = node.children() {
+        for child in children {
+            collect_heading_ids(child, context, ids);
+        }
+    }
+}
+
+fn heading_id(node: &Node, context: &Context) -> Option<HeadingId> {
+    let position = node.position()?;
+    let range = AdjustedRange::from_unadjusted_position(position, context);
+    let start: usize = range.start.into();
+    let source = context
+        .rope()
+        .byte_slice(Into::<std::ops::Range<usize>>::into(range))
+        .to_string();
+    // Only the first line, so the underline of a setext heading isn't read as
+    // part of it.
+    let first_line = source.lines().next()?;
+    let id = heading_id_regex().captures(first_line)?.get(1)?;
+
+    let text = node.to_string();
+    let text = text.rfind("[#").map_or(text.as_str(), |idx| &text[..idx]);
+    Some(HeadingId {
+        id: id.as_str().to_string(),
+        range: AdjustedRange::new(
+            AdjustedOffset::from(start + id.start()),
+            AdjustedOffset::from(start + id.end()),
+        ),
+        text: collapse_whitespace(text),
+        line: position.start.line,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fix")]
+    use crate::testing::assert_lint_snapshot;
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule038_valid_ids() {
+        assert_lint_snapshot!(
+            "Rule038HeadingIds",
+            "# Title\n\n## Row level security [#row-level-security]\n\n## Policies [#policies-2]\n\n## No ID\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule038_duplicate_ids() {
+        assert_lint_snapshot!(
+            "Rule038HeadingIds",
+            "## Policies [#policies]\n\nText.\n\n<Tabs>\n\n### More policies [#policies]\n\n</Tabs>\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule038_invalid_characters() {
+        assert_lint_snapshot!(
+            "Rule038HeadingIds",
+            "## Row level security [#Row_Level Security!]\n\n## Empty [#]\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule038_style() {
+        assert_lint_snapshot!(
+            "Rule038HeadingIds",
+            "## Row level security [#row-level-security]\n",
+            Some(json!({ "style": "snake_case" }))
+        );
+    }
+
+    #[test]
+    fn test_rule038_invalid_style() {
+        let mut rule = Rule038HeadingIds::default();
+        let table: toml::Table = toml::from_str(r#"style = "camelCase""#).unwrap();
+        rule.setup(Some(&mut RuleSettings::new(table)));
+        assert_eq!(rule.separator, '-');
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule038_match_heading() {
+        let mdx = "## Row level `security` [#rls]\n";
+        assert_lint_snapshot!("Rule038HeadingIds", mdx);
+        assert_lint_snapshot!(
+            "Rule038HeadingIds",
+            mdx,
+            Some(json!({ "match_heading": true }))
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use crate::LintLevel;
+
+word_list_rule! {
+    /// Drafting markers, such as `TODO` and `FIXME`, shouldn't be left in
+    /// published docs. Markers are checked in prose and headings, but not in
+    /// code. Filler text such as "Lorem ipsum" is reported by
+    /// `Rule018PlaceholderText`, and `Rule003Spelling` leaves the built-in
+    /// markers to this rule.
+    ///
+    /// Markers are matched case-sensitively, so "a todo app" isn't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Valid
+    ///
+    /// ```markdown
+    /// Build a todo app with Supabase.
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```markdown
+    /// ## Rate limits
+    ///
+    /// TODO: document the rate limits.
+    /// ```
+    ///
+    /// ## Configuration
+    ///
+    /// - `words`: Markers to report, in addition to `TODO`, `FIXME`, `XXX`,
+    ///   and `TK`.
+    /// - `ignore`: Built-in markers that shouldn't be reported.
+    /// - `files`: Globs for the files to check. Defaults to all files.
+    ///
+    /// ```toml
+    /// [Rule039DraftMarkers]
+    /// words = ["WIP"]
+    /// files = ["content/guides/**"]
+    /// ```
+    #[rule_category(Correctness)]
+    pub struct Rule039DraftMarkers {
+        level: LintLevel::Warning,
+        message: "Remove the draft marker \"%s\" before publishing.",
+        case_sensitive: true,
+        words: ["TODO", "FIXME", "XXX", "TK"],
+    }
+}
+
+/// The built-in markers, which `Rule003Spelling` doesn't report as
+/// misspellings.
+#[cfg_attr(not(feature = "spelling"), allow(dead_code))]
+pub(crate) const DRAFT_MARKERS: &[&str] = &["TODO", "FIXME", "XXX", "TK"];
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use super::*;
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule039_markers() {
+        assert_lint_snapshot!(
+            "Rule039DraftMarkers",
+            "## Rate limits TODO\n\nFIXME: document the limits.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule039_reports_built_in_markers() {
+        let mdx = DRAFT_MARKERS
+            .iter()
+            .map(|marker| format!("{marker} here.\n"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_lint_snapshot!("Rule039DraftMarkers", &mdx);
+    }
+
+    #[test]
+    fn test_rule039_multibyte_next_word() {
+        assert_lint_snapshot!("Rule039DraftMarkers", "TODO über alles.\n");
+    }
+
+    #[test]
+    fn test_rule039_ignores_placeholder_text() {
+        assert_lint_snapshot!("Rule039DraftMarkers", "Lorem ipsum dolor sit amet.\n");
+    }
+
+    #[test]
+    fn test_rule039_ignores_code_and_lowercase() {
+        assert_lint_snapshot!(
+            "Rule039DraftMarkers",
+            "Build a todo app.\n\nRun `grep TODO`.\n\n```js\n// TODO\n```\n"
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use log::{debug, warn};
+use markdown::mdast;
+use regex::Regex;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{context::Context, errors::LintError, LintLevel};
+
+use super::{
+    object_schema,
+    word_list::{exclusion_rules_schema, WordExclusionIndex, WordExclusionIndexIntermediate},
+    NodeKind, Rule, RuleName, RuleSettings,
+};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The day of a month, without a leading zero.
+const DAY: &str = r"(3[01]|[12]\d|[1-9])";
+
+/// A group of built-in patterns that can be turned on with the `presets`
+/// setting.
+struct Preset {
+    name: &'static str,
+    description: &'static str,
+    patterns: fn() -> Vec<(String, String)>,
+}
+
+const PRESETS: &[Preset] = &[
+    Preset {
+        name: "thousands",
+        description: "Use commas to separate thousands: \"%r\".",
+        patterns: thousands_patterns,
+    },
+    Preset {
+        name: "dates",
+        description: "Write dates as Month D, YYYY: \"%r\".",
+        patterns: date_patterns,
+    },
+];
+
+/// Dates are left out, since they clash with date formats other rules expect,
+/// such as the ISO dates in changelog headings for `Rule009ChangelogFormat`.
+const DEFAULT_PRESETS: &[&str] = &["thousands"];
+
+/// Numbers of five or more digits, which need thousands separators. Four-digit
+/// numbers are left alone, since they're often years.
+fn thousands_patterns() -> Vec<(String, String)> {
+    (1..=4)
+        .map(|groups| {
+            let head = if groups == 1 { r"\d{1,2}" } else { r"\d{0,2}" };
+            let pattern = format!(r"\b([1-9]{head}){}\b", r"(\d{3})".repeat(groups));
+            let replacement = (1..=groups + 1)
+                .map(|idx| format!("${{{idx}}}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            (pattern, replacement)
+        })
+        .collect()
+}
+
+/// Dates that aren't written as Month D, YYYY, such as `2024-01-15`,
+/// `15 January 2024`, and `January 15th 2024`.
+fn date_patterns() -> Vec<(String, String)> {
+    let months = MONTHS.join("|");
+    let mut patterns = MONTHS
+        .iter()
+        .enumerate()
+        .map(|(idx, month)| {
+            (
+                format!(r"\b(\d{{4}})-{:02}-0?{DAY}\b", idx + 1),
+                format!("{month} ${{2}}, ${{1}}"),
+            )
+        })
+        .collect::<Vec<_>>();
+    patterns.push((
+        format!(r"\b{DAY} ({months}),? (\d{{4}})\b"),
+        "${2} ${1}, ${3}".to_string(),
+    ));
+    patterns.push((
+        format!(r"\b({months}) {DAY}(?:st|nd|rd|th),? (\d{{4}})\b"),
+        "${1} ${2}, ${3}".to_string(),
+    ));
+    patterns.push((
+        format!(r"\b({months}) {DAY} (\d{{4}})\b"),
+        "${1} ${2}, ${3}".to_string(),
+    ));
+    patterns
+}
+
+/// Numbers and dates should be written consistently. By default, numbers of
+/// five or more digits need thousands separators. With the `dates` preset,
+/// dates are written as Month D, YYYY.
+///
+/// Built-in checks are grouped into presets. Custom checks can be added with
+/// regex patterns, which can refer to capture groups in their replacements,
+/// in the same format as `Rule004ExcludeWords`. Custom checks set their own
+/// levels, while presets use the level of this rule.
+///
+/// Numbers and dates in code aren't checked.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// The free plan includes 1,000,000 requests.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// The free plan includes 1000000 requests.
+/// ```
+///
+/// ## Configuration
+///
+/// - `presets`: The built-in checks to use: `thousands` and `dates`. Defaults
+///   to `thousands`. `Rule009ChangelogFormat` expects ISO dates in changelog
+///   headings, so with `dates`, turn this rule off for changelogs, for example
+///   in a `.supa-mdx-lint` file in their directory.
+/// - `rules`: Custom checks, keyed by name, each with a `description`, a
+///   `level`, and a list of `patterns` with optional replacements. Add
+///   `files` to limit a check to some files.
+///
+/// ```toml
+/// [Rule040NumberFormat]
+/// presets = ["thousands", "dates"]
+///
+/// [Rule040NumberFormat.rules.percent]
+/// description = "Write percentages with the % sign: \"%r\"."
+/// level = "WARNING"
+/// patterns = [['(\d+) percent', '${1}%']]
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule040NumberFormat {
+    presets: WordExclusionIndex,
+    rules: WordExclusionIndex,
+}
+
+impl Default for Rule040NumberFormat {
+    fn default() -> Self {
+        Self {
+            presets: presets_index(DEFAULT_PRESETS),
+            rules: WordExclusionIndex::default(),
+        }
+    }
+}
+
+/// Build an index of the patterns in the named presets.
+fn presets_index(names: &[impl AsRef<str>]) -> WordExclusionIndex {
+    let mut index = WordExclusionIndex::default();
+    for preset in PRESETS {
+        if !names.iter().any(|name| name.as_ref() == preset.name) {
+            continue;
+        }
+        let patterns = (preset.patterns)()
+            .into_iter()
+            .map(|(pattern, replacement)| {
+                let regex = Regex::new(&pattern).expect("Preset patterns are valid regexes");
+                (regex, Some(replacement))
+            })
+            .collect();
+        index
+            .add_group()
+            .description(preset.description)
+            // Presets are checked at the rule's level, so this is ignored.
+            .level(LintLevel::Warning)
+            .case_sensitive(true)
+            .words(Vec::new())
+            .patterns(patterns)
+            .call();
+    }
+    index
+}
+
+impl Rule for Rule040NumberFormat {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Text])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(presets) = settings.get_deserializable::<Vec<String>>("presets") {
+            for name in &presets {
+                if !PRESETS.iter().any(|preset| preset.name == name) {
+                    warn!(
+                        "Unknown preset for {}: {name:?}. Expected one of: {}.",
+                        self.name(),
+                        PRESETS
+                            .iter()
+                            .map(|preset| preset.name)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    );
+                }
+            }
+            self.presets = presets_index(&presets);
+        }
+        if let Some(rules) = settings.get_deserializable::<WordExclusionIndexIntermediate>("rules")
+        {
+            self.rules = rules.into();
+        }
+
+        debug!("Rule 040 is set up: {:#?}", self)
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "presets": {
+                "type": "array",
+                "items": {
+                    "type": "string",
+                    "enum": PRESETS.iter().map(|preset| preset.name).collect::<Vec<_>>(),
+                },
+                "default": DEFAULT_PRESETS,
+                "description": "The built-in number and date checks to use.",
+            },
+            "rules": exclusion_rules_schema(),
+        }))
+    }
+
+    fn check(
+        &self,
+        ast: &mdast::Node,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<Vec<LintError>> {
+        // Custom rules set their own levels, and take precedence over presets
+        // that match the same text.
+        let mut errors = self
+            .rules
+            .check(self.name(), ast, context, None)
+            .unwrap_or_default();
+        let preset_errors = self
+            .presets
+            .check(self.name(), ast, context, Some(level))
+            .unwrap_or_default();
+        for error in preset_errors {
+            let range = error.offset_range();
+            if !errors.iter().any(|other| {
+                let other = other.offset_range();
+                other.start < range.end && range.start < other.end
+            }) {
+                errors.push(error);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule040_thousands() {
+        assert_lint_snapshot!(
+            "Rule040NumberFormat",
+            "Up to 12345 rows, 1000000 requests, and 9876543210 bytes.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule040_thousands_valid() {
+        assert_lint_snapshot!(
+            "Rule040NumberFormat",
+            "Since 2024, 1,000,000 rows at 3.14159 each, in v12345abc and `10000`.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule040_dates() {
+        assert_lint_snapshot!(
+            "Rule040NumberFormat",
+            "Released 2024-01-05, updated 15 March 2024, and retired December 1st 2025.\n\nReleased January 5, 2024.\n",
+            Some(json!({ "presets": ["dates"] }))
+        );
+    }
+
+    #[test]
+    fn test_rule040_dates_off_by_default() {
+        assert_lint_snapshot!(
+            "Rule040NumberFormat",
+            "## 2024-01-05\n\nReleased 15 March 2024.\n",
+            None,
+            file = "CHANGELOG.md"
+        );
+    }
+
+    #[test]
+    fn test_rule040_presets() {
+        assert_lint_snapshot!(
+            "Rule040NumberFormat",
+            "Up to 12345 rows.\n\nReleased 2024-01-05.\n",
+            Some(json!({ "presets": ["dates"] }))
+        );
+        assert_lint_snapshot!(
+            "Rule040NumberFormat",
+            "Up to 12345 rows, released 2024-01-05.\n",
+            Some(json!({ "presets": [] }))
+        );
+    }
+
+    #[test]
+    fn test_rule040_custom_rules() {
+        assert_lint_snapshot!(
+            "Rule040NumberFormat",
+            "Save 20 percent on ID 12345.\n",
+            Some(json!({
+                "rules": {
+                    "percent": {
+                        "description": "Write percentages with the % sign: \"%r\".",
+                        "level": "ERROR",
+                        "patterns": [[r"(\d+) percent", "${1}%"]],
+                    },
+                    "ids": {
+                        "description": "Don't write IDs as numbers.",
+                        "patterns": ["ID 12345"],
+                    },
+                },
+            }))
+        );
+    }
+}
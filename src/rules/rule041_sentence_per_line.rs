@@ -0,0 +1,239 @@
+use markdown::mdast::Node;
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::{
+        mdast::is_esm_paragraph,
+        words::{is_sentence_start, WordIterator},
+    },
+};
+
+use super::{NodeKind, Rule, RuleName, RuleSettings};
+
+/// Each sentence should start on a new line, so that diffs show which
+/// sentences changed. Also known as semantic line breaks. Single line breaks
+/// don't show up in the rendered page.
+///
+/// Only sentence breaks within a run of text are checked, so a sentence that
+/// starts right after inline code, a link, or emphasis isn't flagged.
+///
+/// The rule is off unless configured.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// Create a table.
+/// Then enable Row Level Security on it.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// Create a table. Then enable Row Level Security on it.
+/// ```
+///
+/// ## Configuration
+///
+/// Turn the rule on by adding its table to the config file:
+///
+/// ```toml
+/// [Rule041SentencePerLine]
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule041SentencePerLine {
+    enabled: bool,
+}
+
+impl Rule for Rule041SentencePerLine {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Paragraph])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        self.enabled = settings.is_some();
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        if !self.enabled || !matches!(ast, Node::Paragraph(_)) || is_esm_paragraph(ast) {
+            return None;
+        }
+
+        let indent = continuation_indent(ast, context)?;
+        let mut errors = Vec::new();
+        self.check_node(ast, &indent, context, level, &mut errors);
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule041SentencePerLine {
+    fn check_node(
+        &self,
+        node: &Node,
+        indent: &str,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        match node {
+            Node::Text(_) => self.check_text(node, indent, context, level, errors),
+            _ => {
+                if let Some(children) = node.children() {
+                    for child in children {
+                        self.check_node(child, indent, context, level, errors);
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_text(
+        &self,
+        node: &Node,
+        indent: &str,
+        context: &Context,
+        level: LintLevel,
+        errors: &mut Vec<LintError>,
+    ) {
+        let Some(position) = node.position() else {
+            return;
+        };
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        let node_start: usize = range.start.into();
+        let slice = context.rope().byte_slice(range.to_usize_range());
+
+        let mut previous_end = None::<usize>;
+        for (offset, word, _) in WordIterator::new(slice, 0, Default::default()) {
+            let Some(previous) = previous_end.replace(offset + word.byte_len()) else {
+                continue;
+            };
+
+            let between = slice.byte_slice(previous..offset).to_string();
+            if between.contains('\n')
+                || !word.chars().next().is_some_and(char::is_uppercase)
+                || !is_sentence_start()
+                    .slice(slice)
+                    .query_offset(offset)
+                    .count_beginning_as_sentence_start(false)
+                    .call()
+            {
+                continue;
+            }
+
+            // The whitespace between the sentences is replaced, leaving any
+            // punctuation on either side in place.
+            let Some(space_start) = between.find(char::is_whitespace) else {
+                continue;
+            };
+            let space_end = between
+                .rfind(char::is_whitespace)
+                .map(|idx| idx + between[idx..].chars().next().map_or(1, char::len_utf8))
+                .unwrap_or(space_start);
+
+            let fix = LintCorrection::Replace(LintCorrectionReplace {
+                location: DenormalizedLocation::from_offset_range(
+                    AdjustedRange::new(
+                        AdjustedOffset::from(node_start + previous + space_start),
+                        AdjustedOffset::from(node_start + previous + space_end),
+                    ),
+                    context,
+                ),
+                text: format!("\n{indent}"),
+            });
+            let location = DenormalizedLocation::from_offset_range(
+                AdjustedRange::new(
+                    AdjustedOffset::from(node_start + offset),
+                    AdjustedOffset::from(node_start + offset + word.byte_len()),
+                ),
+                context,
+            );
+            errors.push(
+                LintError::from_raw_location()
+                    .rule(self.name())
+                    .level(level)
+                    .message("Start each sentence on a new line.")
+                    .location(location)
+                    .fix(vec![fix])
+                    .call(),
+            );
+        }
+    }
+}
+
+/// The text to start a continuation line of the paragraph with, so that it
+/// stays inside the same list item or blockquote.
+fn continuation_indent(paragraph: &Node, context: &Context) -> Option<String> {
+    let position = paragraph.position()?;
+    let range = AdjustedRange::from_unadjusted_position(position, context);
+    let rope = context.rope();
+    let start: usize = range.start.into();
+    let line_start = rope.byte_of_line(rope.line_of_byte(start));
+    Some(
+        rope.byte_slice(line_start..start)
+            .chars()
+            .map(|c| {
+                if c == '>' || c.is_whitespace() {
+                    c
+                } else {
+                    ' '
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule041_multiple_sentences() {
+        assert_lint_snapshot!(
+            "Rule041SentencePerLine",
+            "Create a table. Then enable RLS! Is it on? Yes.\n",
+            Some(json!({}))
+        );
+    }
+
+    #[test]
+    fn test_rule041_valid() {
+        assert_lint_snapshot!(
+            "Rule041SentencePerLine",
+            "Create a table.\nThen enable RLS.\n\nUse a tool, e.g. psql, to connect.\n\nRun `init`. Then connect.\n",
+            Some(json!({}))
+        );
+    }
+
+    #[test]
+    fn test_rule041_indent() {
+        assert_lint_snapshot!(
+            "Rule041SentencePerLine",
+            "- Create a table. Then enable RLS.\n\n> Create a table. Then enable RLS.\n",
+            Some(json!({}))
+        );
+    }
+
+    #[test]
+    fn test_rule041_off_by_default() {
+        assert_lint_snapshot!(
+            "Rule041SentencePerLine",
+            "Create a table. Then enable RLS.\n"
+        );
+    }
+}
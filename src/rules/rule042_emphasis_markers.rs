@@ -0,0 +1,325 @@
+use log::warn;
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+};
+
+use super::{object_schema, NodeKind, Rule, RuleName, RuleSettings};
+
+const CONSISTENT: &str = "consistent";
+
+/// The marker to use for emphasis or strong emphasis.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum MarkerStyle {
+    /// Whichever marker the first use in the document has.
+    #[default]
+    Consistent,
+    Marker(char),
+}
+
+impl MarkerStyle {
+    /// Parse a setting such as `"_"` or `"**"`, where the marker is repeated
+    /// `width` times.
+    fn parse(setting: &str, width: usize) -> Option<Self> {
+        if setting == CONSISTENT {
+            return Some(Self::Consistent);
+        }
+        ['*', '_']
+            .into_iter()
+            .find(|marker| *setting == marker.to_string().repeat(width))
+            .map(Self::Marker)
+    }
+}
+
+/// Emphasis and strong emphasis should use the same markers throughout:
+/// either asterisks or underscores.
+///
+/// By default, the markers used first in each document are expected for the
+/// rest of it. Markers that don't match are fixed automatically. Emphasis
+/// inside a word, such as `un*frigging*believable`, only works with
+/// asterisks, so it's never changed to underscores.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// Use *emphasis* and **strong emphasis** like *this*.
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// Use *emphasis* and **strong emphasis** like _this_.
+/// ```
+///
+/// ## Configuration
+///
+/// - `emphasis`: The marker for emphasis, `*`, `_`, or `consistent`. Defaults
+///   to `consistent`.
+/// - `strong`: The marker for strong emphasis, `**`, `__`, or `consistent`.
+///   Defaults to `consistent`.
+///
+/// ```toml
+/// [Rule042EmphasisMarkers]
+/// emphasis = "_"
+/// strong = "**"
+/// ```
+#[derive(Debug, Default, RuleName)]
+pub struct Rule042EmphasisMarkers {
+    emphasis: MarkerStyle,
+    strong: MarkerStyle,
+}
+
+impl Rule for Rule042EmphasisMarkers {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Root])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        for (key, width) in [("emphasis", 1), ("strong", 2)] {
+            let Some(setting) = settings.get_deserializable::<String>(key) else {
+                continue;
+            };
+            match MarkerStyle::parse(&setting, width) {
+                Some(style) if key == "emphasis" => self.emphasis = style,
+                Some(style) => self.strong = style,
+                None => warn!(
+                    "Invalid {key} marker for {}: {setting:?}. Expected {:?}, {:?}, or {CONSISTENT:?}.",
+                    self.name(),
+                    "*".repeat(width),
+                    "_".repeat(width),
+                ),
+            }
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "emphasis": {
+                "type": "string",
+                "enum": ["*", "_", CONSISTENT],
+                "default": CONSISTENT,
+                "description": "The marker for emphasis, or `consistent` to match the first emphasis in each document.",
+            },
+            "strong": {
+                "type": "string",
+                "enum": ["**", "__", CONSISTENT],
+                "default": CONSISTENT,
+                "description": "The marker for strong emphasis, or `consistent` to match the first strong emphasis in each document.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Root(_) = ast else {
+            return None;
+        };
+
+        let mut expected = Expected {
+            emphasis: match self.emphasis {
+                MarkerStyle::Marker(marker) => Some(marker),
+                MarkerStyle::Consistent => None,
+            },
+            strong: match self.strong {
+                MarkerStyle::Marker(marker) => Some(marker),
+                MarkerStyle::Consistent => None,
+            },
+        };
+        let mut errors = Vec::new();
+        self.check_node(ast, context, level, &mut expected, &mut errors);
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+/// The markers expected for the rest of the document, once known.
+struct Expected {
+    emphasis: Option<char>,
+    strong: Option<char>,
+}
+
+impl Rule042EmphasisMarkers {
+    fn check_node(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+        expected: &mut Expected,
+        errors: &mut Vec<LintError>,
+    ) {
+        let checked = match node {
+            Node::Emphasis(_) => Some((1, &mut expected.emphasis, "emphasis")),
+            Node::Strong(_) => Some((2, &mut expected.strong, "strong emphasis")),
+            _ => None,
+        };
+        if let Some((width, expected, kind)) = checked {
+            errors.extend(self.check_markers(node, width, expected, kind, context, level));
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                self.check_node(child, context, level, expected, errors);
+            }
+        }
+    }
+
+    fn check_markers(
+        &self,
+        node: &Node,
+        width: usize,
+        expected: &mut Option<char>,
+        kind: &str,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        let range = AdjustedRange::from_unadjusted_position(node.position()?, context);
+        let (start, end): (usize, usize) = (range.start.into(), range.end.into());
+        if end < start + 2 * width {
+            return None;
+        }
+
+        let rope = context.rope();
+        let marker = rope.byte_slice(start..).chars().next()?;
+        let closing = rope.byte_slice(end - 1..).chars().next()?;
+        if !matches!(marker, '*' | '_') || closing != marker {
+            return None;
+        }
+
+        let expected = *expected.get_or_insert(marker);
+        if marker == expected {
+            return None;
+        }
+        if expected == '_' {
+            // Underscores don't open or close emphasis inside a word.
+            let before = rope.byte_slice(..start).chars().next_back();
+            let after = rope.byte_slice(end..).chars().next();
+            if before.is_some_and(char::is_alphanumeric) || after.is_some_and(char::is_alphanumeric)
+            {
+                return None;
+            }
+        }
+
+        let replacement = expected.to_string().repeat(width);
+        let marker_location = |from: usize| {
+            DenormalizedLocation::from_offset_range(
+                AdjustedRange::new(
+                    AdjustedOffset::from(from),
+                    AdjustedOffset::from(from + width),
+                ),
+                context,
+            )
+        };
+        let opening = marker_location(start);
+        let fix = [opening.clone(), marker_location(end - width)]
+            .into_iter()
+            .map(|location| {
+                LintCorrection::Replace(LintCorrectionReplace {
+                    location,
+                    text: replacement.clone(),
+                })
+            })
+            .collect();
+
+        Some(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(format!(
+                    "Use \"{replacement}\" for {kind} instead of \"{}\".",
+                    marker.to_string().repeat(width)
+                ))
+                .location(opening)
+                .fix(fix)
+                .call(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fix")]
+    use crate::testing::assert_lint_snapshot;
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule042_consistent_markers() {
+        assert_lint_snapshot!(
+            "Rule042EmphasisMarkers",
+            "Some *text* and *more*, **bold** and **more**.\n"
+        );
+        assert_lint_snapshot!("Rule042EmphasisMarkers", "Some _text_ and __bold__.\n");
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule042_mixed_markers() {
+        assert_lint_snapshot!(
+            "Rule042EmphasisMarkers",
+            "Some _text_ and *more*, __bold__ and **more**.\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule042_configured() {
+        assert_lint_snapshot!(
+            "Rule042EmphasisMarkers",
+            "# A _heading_\n\nSome **bold** and *text*.\n",
+            Some(json!({ "emphasis": "*", "strong": "__" }))
+        );
+    }
+
+    #[test]
+    fn test_rule042_invalid_settings() {
+        let mut rule = Rule042EmphasisMarkers::default();
+        let mut table = toml::Table::new();
+        table.insert(
+            "emphasis".to_string(),
+            toml::Value::String("**".to_string()),
+        );
+        table.insert("strong".to_string(), toml::Value::String("_".to_string()));
+        rule.setup(Some(&mut RuleSettings::new(table)));
+        assert_eq!(rule.emphasis, MarkerStyle::Consistent);
+        assert_eq!(rule.strong, MarkerStyle::Consistent);
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule042_nested() {
+        assert_lint_snapshot!(
+            "Rule042EmphasisMarkers",
+            "Some ***bold italic*** text.\n",
+            Some(json!({ "emphasis": "_", "strong": "**" }))
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule042_intraword() {
+        assert_lint_snapshot!(
+            "Rule042EmphasisMarkers",
+            "Un*frigging*believable.\n\nSome *text*.\n",
+            Some(json!({ "emphasis": "_" }))
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionReplace},
+    location::{AdjustedRange, DenormalizedLocation},
+    parser::parse_lossy,
+    utils::collapse_whitespace,
+};
+
+use super::{
+    object_schema, rule027_local_images::is_external, string_array_schema, NodeKind, Rule,
+    RuleName, RuleSettings,
+};
+
+const DEFAULT_PHRASES: &[&str] = &[
+    "click here",
+    "here",
+    "learn more",
+    "more",
+    "read more",
+    "this link",
+    "this page",
+];
+
+/// Extensions tried, in order, for links to other pages written without one.
+const PAGE_EXTENSIONS: &[&str] = &["mdx", "md"];
+
+/// Link text should describe where the link goes, so that it makes sense out
+/// of context, such as in a screen reader's list of links.
+///
+/// Links whose whole text is a vague phrase, such as "click here", are
+/// flagged. Where the destination is known, its title is suggested as the
+/// link text: the heading for a link to a section of the same page, or the
+/// frontmatter title or first heading for a link to another local page.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// Read the [Row Level Security guide](./row-level-security).
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// To learn about Row Level Security, [click here](./row-level-security).
+/// ```
+///
+/// ## Configuration
+///
+/// - `phrases`: The link text to flag, compared ignoring case and trailing
+///   punctuation. Replaces the default list, which includes "here", "click
+///   here", "this link", and "read more".
+///
+/// ```toml
+/// [Rule043VagueLinkText]
+/// phrases = ["here", "click here", "this article"]
+/// ```
+#[derive(Debug, RuleName)]
+#[rule_category(Accessibility)]
+pub struct Rule043VagueLinkText {
+    phrases: Vec<String>,
+}
+
+impl Default for Rule043VagueLinkText {
+    fn default() -> Self {
+        Self {
+            phrases: DEFAULT_PHRASES.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl Rule for Rule043VagueLinkText {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Link])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(phrases) = settings.get_array_of_strings("phrases") {
+            self.phrases = phrases.iter().map(|phrase| normalize(phrase)).collect();
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "phrases": string_array_schema(
+                "Link text to flag as vague, compared ignoring case and trailing punctuation."
+            ),
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Link(link) = ast else {
+            return None;
+        };
+
+        let text = collapse_whitespace(&ast.to_string());
+        if !self.phrases.contains(&normalize(&text)) {
+            return None;
+        }
+
+        let first = link.children.first()?.position()?;
+        let last = link.children.last()?.position()?;
+        let text_range = AdjustedRange::new(
+            AdjustedRange::from_unadjusted_position(first, context).start,
+            AdjustedRange::from_unadjusted_position(last, context).end,
+        );
+        let location = DenormalizedLocation::from_offset_range(text_range, context);
+
+        let title = destination_title(&link.url, context);
+        let message = match &title {
+            Some(title) => format!(
+                "Link text \"{text}\" doesn't say where the link goes. Describe the destination instead, such as \"{title}\"."
+            ),
+            None => format!(
+                "Link text \"{text}\" doesn't say where the link goes. Describe the destination instead."
+            ),
+        };
+
+        Some(vec![LintError::from_raw_location()
+            .rule(self.name())
+            .level(level)
+            .message(message)
+            .location(location.clone())
+            .maybe_suggestions(title.map(|title| {
+                vec![LintCorrection::Replace(LintCorrectionReplace {
+                    location,
+                    text: title,
+                })]
+            }))
+            .call()])
+    }
+}
+
+/// Lowercase link text, and drop surrounding punctuation, so that "Here."
+/// matches "here".
+fn normalize(text: &str) -> String {
+    text.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// The title of a link's destination, if it's a section of the same page or
+/// another local page.
+fn destination_title(url: &str, context: &Context) -> Option<String> {
+    if let Some(anchor) = url.strip_prefix('#') {
+        return section_title(context.parse_result.ast(), anchor);
+    }
+
+    let path = &url[..url.find(['?', '#']).unwrap_or(url.len())];
+    if path.is_empty() || path.starts_with('/') || is_external(path) {
+        return None;
+    }
+    let directory = context.file_path()?.parent().unwrap_or(Path::new(""));
+    let content = fs::read_to_string(page_file(&directory.join(path))?).ok()?;
+    page_title(&content)
+}
+
+/// The text of the heading an anchor links to, using the slugs most docs
+/// sites generate: lowercase words joined with hyphens.
+fn section_title(node: &Node, anchor: &str) -> Option<String> {
+    if let Node::Heading(_) = node {
+        let text = collapse_whitespace(&node.to_string());
+        let slug = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join("-");
+        return (slug == anchor.to_lowercase()).then_some(text);
+    }
+    node.children()?
+        .iter()
+        .find_map(|child| section_title(child, anchor))
+}
+
+/// The file for a link to a local page, which can leave out the extension,
+/// or link to a directory's index page.
+fn page_file(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+    PAGE_EXTENSIONS
+        .iter()
+        .map(|extension| path.with_extension(extension))
+        .chain(
+            PAGE_EXTENSIONS
+                .iter()
+                .map(|extension| path.join("index").with_extension(extension)),
+        )
+        .find(|candidate| candidate.is_file())
+}
+
+/// A page's frontmatter title, or its first top-level heading.
+fn page_title(content: &str) -> Option<String> {
+    let page = parse_lossy(content);
+    if let Some(title) = page.frontmatter_str("title") {
+        return Some(title.to_string());
+    }
+    page.ast().children()?.iter().find_map(|child| match child {
+        Node::Heading(heading) if heading.depth == 1 => {
+            Some(collapse_whitespace(&child.to_string()))
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::parser::parse;
+    #[cfg(feature = "fix")]
+    use crate::testing::assert_lint_snapshot;
+
+    fn check_with_path(
+        rule: &Rule043VagueLinkText,
+        mdx: &str,
+        file_path: Option<&Path>,
+    ) -> Vec<LintError> {
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .maybe_file_path(file_path)
+            .build()
+            .unwrap();
+
+        let mut errors = Vec::new();
+        let mut stack = vec![parse_result.ast()];
+        while let Some(node) = stack.pop() {
+            errors.extend(
+                rule.check(node, &context, LintLevel::Warning)
+                    .unwrap_or_default(),
+            );
+            if let Some(children) = node.children() {
+                stack.extend(children.iter().rev());
+            }
+        }
+        errors
+    }
+
+    fn suggestion(error: &LintError) -> Option<&str> {
+        match error.suggestions.as_deref()? {
+            [LintCorrection::Replace(replace)] => Some(&replace.text),
+            _ => panic!("Expected a single replace suggestion"),
+        }
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule043_vague_text() {
+        assert_lint_snapshot!(
+            "Rule043VagueLinkText",
+            "To set it up, [click here](https://example.com). Or [Read more.](./other).\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule043_descriptive_text() {
+        assert_lint_snapshot!(
+            "Rule043VagueLinkText",
+            "Read the [Row Level Security guide](./rls).\n\nSee [here and there](./places).\n"
+        );
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule043_suggests_section_heading() {
+        assert_lint_snapshot!(
+            "Rule043VagueLinkText",
+            "# Auth\n\nConfigure it [here](#setting-up-providers).\n\n## Setting up providers\n"
+        );
+    }
+
+    #[test]
+    fn test_rule043_suggests_page_title() {
+        let dir = TempDir::new().unwrap();
+        let guides = dir.path().join("guides");
+        fs::create_dir_all(guides.join("auth")).unwrap();
+        fs::write(
+            guides.join("rls.mdx"),
+            "---\ntitle: Row Level Security\n---\n\n# RLS\n",
+        )
+        .unwrap();
+        fs::write(guides.join("auth/index.mdx"), "# Auth overview\n").unwrap();
+        let file_path = guides.join("page.mdx");
+
+        let rule = Rule043VagueLinkText::default();
+        let errors = check_with_path(
+            &rule,
+            "Read [this page](./rls). Then [read more](./auth#setup). And [here](./missing).",
+            Some(&file_path),
+        );
+        assert_eq!(errors.len(), 3);
+        assert_eq!(suggestion(&errors[0]), Some("Row Level Security"));
+        assert_eq!(suggestion(&errors[1]), Some("Auth overview"));
+        assert_eq!(suggestion(&errors[2]), None);
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn test_rule043_configured_phrases() {
+        assert_lint_snapshot!(
+            "Rule043VagueLinkText",
+            "See [here](./other).\n\nSee [this article](./other).\n",
+            Some(json!({ "phrases": ["This Article"] }))
+        );
+    }
+}
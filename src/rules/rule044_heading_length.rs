@@ -0,0 +1,266 @@
+use markdown::mdast::Node;
+use serde_json::{json, Value};
+use supa_mdx_macros::RuleName;
+
+use crate::{
+    context::Context,
+    errors::{LintError, LintLevel},
+    fix::{LintCorrection, LintCorrectionDelete},
+    location::{AdjustedOffset, AdjustedRange, DenormalizedLocation},
+    utils::collapse_whitespace,
+};
+
+use super::{
+    object_schema, rule038_heading_ids::heading_id_regex, NodeKind, Rule, RuleName, RuleSettings,
+};
+
+const DEFAULT_MAX_CHARACTERS: usize = 70;
+const DEFAULT_MAX_WORDS: usize = 12;
+
+/// Punctuation that headings shouldn't end with. Question and exclamation
+/// marks are allowed, since they change the meaning of a heading.
+const TRAILING_PUNCTUATION: &[char] = &['.', ':', ';', ','];
+
+/// Headings should be short enough to scan, and shouldn't end with
+/// punctuation such as periods or colons.
+///
+/// Lengths are counted without any explicit heading ID, such as
+/// `[#custom-id]`. Trailing punctuation is removed automatically.
+///
+/// ## Examples
+///
+/// ### Valid
+///
+/// ```markdown
+/// ## Enable Row Level Security
+/// ```
+///
+/// ### Invalid
+///
+/// ```markdown
+/// ## Before you begin:
+///
+/// ## How to enable Row Level Security on every table in your project and check that it works
+/// ```
+///
+/// ## Configuration
+///
+/// - `max_characters`: Maximum length of a heading, in characters. Defaults
+///   to 70.
+/// - `max_words`: Maximum length of a heading, in words. Defaults to 12.
+///
+/// ```toml
+/// [Rule044HeadingLength]
+/// max_characters = 50
+/// max_words = 8
+/// ```
+#[derive(Debug, RuleName)]
+pub struct Rule044HeadingLength {
+    max_characters: usize,
+    max_words: usize,
+}
+
+impl Default for Rule044HeadingLength {
+    fn default() -> Self {
+        Self {
+            max_characters: DEFAULT_MAX_CHARACTERS,
+            max_words: DEFAULT_MAX_WORDS,
+        }
+    }
+}
+
+impl Rule for Rule044HeadingLength {
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn interests(&self) -> Option<&'static [NodeKind]> {
+        Some(&[NodeKind::Heading])
+    }
+
+    fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(max) = settings.get_deserializable::<usize>("max_characters") {
+            self.max_characters = max;
+        }
+        if let Some(max) = settings.get_deserializable::<usize>("max_words") {
+            self.max_words = max;
+        }
+    }
+
+    fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "max_characters": {
+                "type": "integer",
+                "minimum": 1,
+                "default": DEFAULT_MAX_CHARACTERS,
+                "description": "Maximum length of a heading, in characters.",
+            },
+            "max_words": {
+                "type": "integer",
+                "minimum": 1,
+                "default": DEFAULT_MAX_WORDS,
+                "description": "Maximum length of a heading, in words.",
+            },
+        }))
+    }
+
+    fn check(&self, ast: &Node, context: &Context, level: LintLevel) -> Option<Vec<LintError>> {
+        let Node::Heading(heading) = ast else {
+            return None;
+        };
+
+        let mut errors = Vec::new();
+        errors.extend(self.check_length(ast, context, level));
+        if let Some(Node::Text(_)) = heading.children.last() {
+            errors.extend(self.check_punctuation(ast, context, level));
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+}
+
+impl Rule044HeadingLength {
+    fn check_length(&self, node: &Node, context: &Context, level: LintLevel) -> Option<LintError> {
+        let text = node.to_string();
+        let text = match heading_id_regex().find(&text) {
+            Some(id) => &text[..id.start()],
+            None => &text,
+        };
+        let text = collapse_whitespace(text);
+
+        let characters = text.chars().count();
+        let words = text.split_whitespace().count();
+        let message = if characters > self.max_characters {
+            format!(
+                "Shorten this heading to {} characters or fewer. It has {characters}.",
+                self.max_characters
+            )
+        } else if words > self.max_words {
+            format!(
+                "Shorten this heading to {} words or fewer. It has {words}.",
+                self.max_words
+            )
+        } else {
+            return None;
+        };
+
+        Some(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(message)
+                .location(DenormalizedLocation::from_offset_range(
+                    AdjustedRange::from_unadjusted_position(node.position()?, context),
+                    context,
+                ))
+                .call(),
+        )
+    }
+
+    fn check_punctuation(
+        &self,
+        node: &Node,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<LintError> {
+        let last = node.children()?.last()?;
+        let range = AdjustedRange::from_unadjusted_position(last.position()?, context);
+        let start: usize = range.start.into();
+        // Read the source, rather than the text node's value, so that offsets
+        // stay right around escapes.
+        let source = context
+            .rope()
+            .byte_slice(Into::<std::ops::Range<usize>>::into(range))
+            .to_string();
+        let source = match heading_id_regex().find(&source) {
+            Some(id) => &source[..id.start()],
+            None => &source,
+        };
+
+        let trimmed = source.trim_end();
+        let without_punctuation = trimmed.trim_end_matches(TRAILING_PUNCTUATION);
+        if without_punctuation.len() == trimmed.len()
+            // Don't leave the heading empty.
+            || (without_punctuation.trim().is_empty() && node.children()?.len() == 1)
+            // An escaped character is meant literally.
+            || without_punctuation.ends_with('\\')
+        {
+            return None;
+        }
+
+        let punctuation = &trimmed[without_punctuation.len()..];
+        let location = DenormalizedLocation::from_offset_range(
+            AdjustedRange::new(
+                AdjustedOffset::from(start + without_punctuation.len()),
+                AdjustedOffset::from(start + trimmed.len()),
+            ),
+            context,
+        );
+        Some(
+            LintError::from_raw_location()
+                .rule(self.name())
+                .level(level)
+                .message(format!(
+                    "Remove the \"{punctuation}\" at the end of the heading."
+                ))
+                .location(location.clone())
+                .fix(vec![LintCorrection::Delete(LintCorrectionDelete {
+                    location,
+                })])
+                .call(),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "fix"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::assert_lint_snapshot;
+
+    #[test]
+    fn test_rule044_short_headings() {
+        assert_lint_snapshot!(
+            "Rule044HeadingLength",
+            "# Row Level Security\n\n## What is RLS?\n\n## Use `supabase.auth`\n"
+        );
+    }
+
+    #[test]
+    fn test_rule044_trailing_punctuation() {
+        assert_lint_snapshot!(
+            "Rule044HeadingLength",
+            "## Before you begin:\n\n## Next steps... [#next]\n\n## Use `select`.\n"
+        );
+    }
+
+    #[test]
+    fn test_rule044_literal_punctuation() {
+        assert_lint_snapshot!("Rule044HeadingLength", "## ...\n\n## Version 2\\.\n");
+    }
+
+    #[test]
+    fn test_rule044_length() {
+        assert_lint_snapshot!(
+            "Rule044HeadingLength",
+            "## How to enable Row Level Security on every table in your project and check that it works\n"
+        );
+    }
+
+    #[test]
+    fn test_rule044_max_words() {
+        assert_lint_snapshot!(
+            "Rule044HeadingLength",
+            "## Set up the client [#a-very-long-custom-id]\n\n## Set up auth [#a-very-long-custom-id]\n",
+            Some(json!({ "max_words": 3 }))
+        );
+    }
+}
@@ -0,0 +1,1355 @@
+//! A matching engine for rules that look for a list of words or phrases, and
+//! optionally suggest a replacement for each.
+//!
+//! Rules built on it share the same word splitting, multi-word matching, and
+//! case handling. Use [`word_list_rule`] to declare a rule that reports a
+//! fixed list of phrases with a single message.
+
+use std::{borrow::Cow, collections::HashMap, iter::Peekable, sync::LazyLock};
+
+use bon::bon;
+use crop::RopeSlice;
+use glob::Pattern;
+use indexmap::IndexSet;
+use log::{debug, trace, warn};
+use markdown::mdast;
+use regex::Regex;
+use serde::{
+    de::{MapAccess, SeqAccess},
+    ser::{SerializeMap, SerializeTuple},
+    Deserialize, Serialize, Serializer,
+};
+use serde_json::{json, Value};
+
+use crate::{
+    context::Context,
+    errors::LintError,
+    fix::LintCorrection,
+    location::{AdjustedRange, DenormalizedLocation},
+    rope::Rope,
+    utils::{
+        path::matches_any_glob,
+        words::{
+            extras::{WordIteratorExtension, WordIteratorPrefix},
+            WordIterator, WordIteratorItem,
+        },
+    },
+    LintLevel,
+};
+
+use super::{object_schema, string_array_schema, RuleSettings};
+
+/// Provides an index of exclusions to allow for easy lookup and matching based
+/// on the first word of the exclusion.
+#[derive(Debug, Default)]
+pub(crate) struct WordExclusionIndex {
+    index: WordExclusionIndexInner,
+    rules: Vec<RuleMeta>,
+    patterns: Vec<PatternExclusion>,
+}
+
+/// An exclusion defined as a regex rather than a literal phrase. Patterns are
+/// matched against the whole text node, after the phrases.
+#[derive(Debug)]
+struct PatternExclusion {
+    regex: Regex,
+    /// A replacement template, which can refer to capture groups in the
+    /// regex, such as `$1`.
+    replacement: Option<String>,
+    rule_index: usize,
+}
+
+#[derive(Debug, Default)]
+struct WordExclusionIndexInner(HashMap<Prefix<'static>, WordExclusionMeta>);
+
+#[derive(Debug, Default, PartialEq, Eq, Hash)]
+struct Prefix<'a>(Cow<'a, str>, CaseSensitivity);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+enum CaseSensitivity {
+    Sensitive,
+    #[default]
+    Insensitive,
+}
+
+#[derive(Debug, Default)]
+struct WordExclusionMeta {
+    /// The trailing part of an exclusion, after the first word is stripped.
+    remainders: IndexSet<String>,
+    /// The rule indexes and replacements associated with these exclusions, if
+    /// any. Rule indexes correspond to the position within the rules of the
+    /// WordExclusionIndex.
+    ///
+    /// Invariant: Ordering must correspond to the ordering of `remainders`.
+    details: Vec<(usize, Option<String>)>,
+}
+
+/// The definition of a user-defined rule.
+///
+/// ## Fields
+/// * `String` - A human-readable description of the rule
+/// * `LintLevel` - The level at which the rule should be linted
+/// * `Vec<Pattern>` - Globs for the files the rule applies to, or empty to
+///   apply it to all files
+#[derive(Debug, Default, Clone)]
+struct RuleMeta(String, LintLevel, Vec<Pattern>);
+
+/// A structure to allow for deserialization from an easy-to-write rule config
+/// format.
+#[derive(Debug, Default)]
+pub(crate) struct WordExclusionIndexIntermediate {
+    pub(crate) rule: HashMap<String, WordExclusionMetaIntermediate>,
+}
+
+impl Serialize for WordExclusionIndexIntermediate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.rule.len()))?;
+        for (key, value) in &self.rule {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for WordExclusionIndexIntermediate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = WordExclusionIndexIntermediate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("A map of rule names to their exclusion details")
+            }
+
+            fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut rule = HashMap::new();
+
+                while let Some((key, value)) =
+                    map.next_entry::<String, WordExclusionMetaIntermediate>()?
+                {
+                    rule.insert(key, value);
+                }
+
+                Ok(WordExclusionIndexIntermediate { rule })
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(crate) struct WordExclusionMetaIntermediate {
+    #[serde(default)]
+    pub(crate) level: LintLevel,
+    #[serde(default)]
+    pub(crate) case_sensitive: bool,
+    #[serde(default)]
+    pub(crate) words: Vec<ExclusionDefinition>,
+    pub(crate) description: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) files: Vec<String>,
+    /// Regex patterns to exclude, with optional replacement templates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) patterns: Vec<ExclusionDefinition>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum ExclusionDefinition {
+    ExcludeOnly(String),
+    WithReplace(String, String),
+}
+
+impl Serialize for ExclusionDefinition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            ExclusionDefinition::ExcludeOnly(s) => serializer.serialize_str(s),
+            ExclusionDefinition::WithReplace(a, b) => {
+                let mut seq = serializer.serialize_tuple(2)?;
+                seq.serialize_element(a)?;
+                seq.serialize_element(b)?;
+                seq.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ExclusionDefinition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = ExclusionDefinition;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("A string (representing an exclusion) or a tuple of two strings (representing an exclusion and its replacement")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(ExclusionDefinition::ExcludeOnly(value.to_string()))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let first: String = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                let second: String = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                Ok(ExclusionDefinition::WithReplace(first, second))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[derive(Debug)]
+struct IndexLookupResult<'a> {
+    case_sensitive_details: Option<&'a WordExclusionMeta>,
+    case_insensitive_details: Option<&'a WordExclusionMeta>,
+}
+
+impl From<bool> for CaseSensitivity {
+    fn from(case_sensitive: bool) -> CaseSensitivity {
+        if case_sensitive {
+            CaseSensitivity::Sensitive
+        } else {
+            CaseSensitivity::Insensitive
+        }
+    }
+}
+
+impl<'a> From<(Cow<'a, str>, CaseSensitivity)> for Prefix<'a> {
+    fn from((s, case_sensitivity): (Cow<'a, str>, CaseSensitivity)) -> Self {
+        let prefix = match case_sensitivity {
+            CaseSensitivity::Sensitive => s,
+            CaseSensitivity::Insensitive => s.to_lowercase().into(),
+        };
+        Prefix(prefix, case_sensitivity)
+    }
+}
+
+impl RuleMeta {
+    fn description(&self) -> &str {
+        &self.0
+    }
+
+    fn level(&self) -> LintLevel {
+        self.1
+    }
+
+    /// Whether the rule applies to the file being linted. Rules scoped to
+    /// files never apply to sources without a path.
+    fn applies_to(&self, context: &Context) -> bool {
+        self.2.is_empty()
+            || context
+                .file_path()
+                .is_some_and(|path| matches_any_glob(path, &self.2))
+    }
+}
+
+impl From<&str> for ExclusionDefinition {
+    fn from(word: &str) -> Self {
+        ExclusionDefinition::ExcludeOnly(word.to_string())
+    }
+}
+
+impl From<(&str, &str)> for ExclusionDefinition {
+    fn from((word, replacement): (&str, &str)) -> Self {
+        ExclusionDefinition::WithReplace(word.to_string(), replacement.to_string())
+    }
+}
+
+impl ExclusionDefinition {
+    /// The phrase to match, without its replacement.
+    #[allow(dead_code)]
+    fn word(&self) -> &str {
+        match self {
+            ExclusionDefinition::ExcludeOnly(w) | ExclusionDefinition::WithReplace(w, _) => w,
+        }
+    }
+
+    fn into_parts(self) -> (String, Option<String>) {
+        match self {
+            ExclusionDefinition::ExcludeOnly(w) => (w, None),
+            ExclusionDefinition::WithReplace(w, r) => (w, Some(r)),
+        }
+    }
+}
+
+#[bon]
+impl WordExclusionIndex {
+    /// Add a group of phrases that share a message and level.
+    #[builder]
+    pub(crate) fn add_group(
+        &mut self,
+        /// The message to report. `%s` is replaced with the matched text, and
+        /// `%r` with the suggested replacement.
+        #[builder(into)]
+        description: String,
+        level: LintLevel,
+        #[builder(default)] case_sensitive: bool,
+        words: impl IntoIterator<Item = ExclusionDefinition>,
+        /// Globs for the files the group applies to. Applies to all files if
+        /// empty.
+        #[builder(default)]
+        files: Vec<Pattern>,
+        /// Regexes to match, with optional replacement templates.
+        #[builder(default)]
+        patterns: Vec<(Regex, Option<String>)>,
+    ) {
+        let rule_index = self.rules.len();
+        self.rules.push(RuleMeta(description, level, files));
+
+        for (regex, replacement) in patterns {
+            self.patterns.push(PatternExclusion {
+                regex,
+                replacement,
+                rule_index,
+            });
+        }
+
+        for word in words {
+            self.insert_exclusion()
+                .exclusion(word)
+                .case_sensitivity(case_sensitive.into())
+                .rule_index(rule_index)
+                .call();
+        }
+    }
+
+    #[builder]
+    fn insert_exclusion(
+        &mut self,
+        exclusion: ExclusionDefinition,
+        case_sensitivity: CaseSensitivity,
+        rule_index: usize,
+    ) {
+        let (word, replacement) = exclusion.into_parts();
+
+        let rope = Rope::from(word.as_ref());
+        let mut iter = WordIterator::new(rope.byte_slice(..), 0, Default::default());
+
+        let prefix = iter.next();
+        let remainder = iter.collect_remainder();
+
+        if let Some(prefix) = prefix {
+            self.handle_insert_prefix()
+                .prefix(prefix.1.to_string())
+                .maybe_remainder(remainder)
+                .maybe_replacement(replacement)
+                .case_sensitivity(case_sensitivity)
+                .rule_index(rule_index)
+                .call();
+        }
+    }
+
+    #[builder]
+    fn handle_insert_prefix(
+        &mut self,
+        prefix: String,
+        remainder: Option<String>,
+        replacement: Option<String>,
+        case_sensitivity: CaseSensitivity,
+        rule_index: usize,
+    ) {
+        let prefix = Prefix::from((Cow::from(prefix), case_sensitivity));
+        let remainder = remainder.unwrap_or_default();
+
+        let existing = self.index.0.get_mut(&prefix);
+        match existing {
+            Some(existing) => {
+                let (inserted_idx, is_new) = existing.remainders.insert_full(remainder);
+
+                if is_new {
+                    existing.details.push((rule_index, replacement))
+                } else {
+                    let rule_meta = self
+                        .rules
+                        .get(rule_index)
+                        .expect("Rule meta previously inserted into global rule map");
+                    let new_rule_level = rule_meta.level();
+                    match self.rules.get_mut(inserted_idx) {
+                        Some(existing_rule) if existing_rule.level() < new_rule_level => {
+                            if let Some(idx) = existing.details.get_mut(inserted_idx) {
+                                *idx = (rule_index, replacement)
+                            }
+                        }
+                        _ => {
+                            // The new rule doesn't outrank the existing one,
+                            // leave it.
+                        }
+                    }
+                }
+            }
+            None => {
+                let mut remainders = IndexSet::new();
+                remainders.insert(remainder);
+
+                self.index.0.insert(
+                    prefix,
+                    WordExclusionMeta {
+                        remainders,
+                        details: vec![(rule_index, replacement)],
+                    },
+                );
+            }
+        }
+    }
+
+    fn get<'a, 'b: 'a>(&'a self, prefix: &'b str) -> IndexLookupResult<'a> {
+        let case_sensitive_key = Prefix::from((Cow::from(prefix), CaseSensitivity::Sensitive));
+        let case_insensitive_key = Prefix::from((Cow::from(prefix), CaseSensitivity::Insensitive));
+
+        let case_sensitive = self.index.0.get(&case_sensitive_key);
+        let case_insensitive = self.index.0.get(&case_insensitive_key);
+
+        IndexLookupResult {
+            case_sensitive_details: case_sensitive,
+            case_insensitive_details: case_insensitive,
+        }
+    }
+}
+
+impl From<WordExclusionIndexIntermediate> for WordExclusionIndex {
+    fn from(exclude_words: WordExclusionIndexIntermediate) -> Self {
+        let mut this = Self {
+            index: WordExclusionIndexInner::default(),
+            rules: Vec::with_capacity(exclude_words.rule.len()),
+            patterns: Vec::new(),
+        };
+
+        for (name, rule_details) in exclude_words.rule {
+            let files = rule_details
+                .files
+                .iter()
+                .filter_map(|glob| match Pattern::new(glob) {
+                    Ok(pattern) => Some(pattern),
+                    Err(err) => {
+                        warn!("Encountered invalid glob in rule {name}: {glob}: {err}");
+                        None
+                    }
+                })
+                .collect();
+            let patterns = rule_details
+                .patterns
+                .into_iter()
+                .filter_map(|pattern| {
+                    let (pattern, replacement) = pattern.into_parts();
+                    let source = if rule_details.case_sensitive {
+                        pattern.clone()
+                    } else {
+                        format!("(?i){pattern}")
+                    };
+                    match Regex::new(&source) {
+                        Ok(regex) => Some((regex, replacement)),
+                        Err(err) => {
+                            warn!("Encountered invalid regex in rule {name}: {pattern}: {err}");
+                            None
+                        }
+                    }
+                })
+                .collect();
+            this.add_group()
+                .description(rule_details.description)
+                .level(rule_details.level)
+                .case_sensitive(rule_details.case_sensitive)
+                .words(rule_details.words)
+                .files(files)
+                .patterns(patterns)
+                .call();
+        }
+
+        this
+    }
+}
+enum Suffix<'a> {
+    Finish,
+    Remaining(&'a str),
+}
+
+impl<'a> From<&'a str> for Suffix<'a> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "" => Suffix::Finish,
+            _ => Suffix::Remaining(s),
+        }
+    }
+}
+
+struct ExclusionMatch<'a> {
+    new_iterator: WordIteratorExtension<'a, WordIteratorPrefix<'a>>,
+    match_: Option<MatchDetails>,
+}
+
+#[derive(Debug)]
+struct MatchDetails {
+    last_word: Option<LastWordMatched>,
+    replacement: Option<String>,
+    rule: RuleMeta,
+}
+
+#[derive(Debug)]
+struct MatchDetailsIntermediate<'a> {
+    match_: MatchDetailsIntermediateInner,
+    rule: RuleMeta,
+    replacement: &'a Option<String>,
+}
+
+#[derive(Debug)]
+enum MatchDetailsIntermediateInner {
+    OneWord,
+    /// The match is multiple words long. The position of the last matching
+    /// word is tracked to calculate the full match range later. This is the
+    /// offset not in the text, but in the vector of matches so far.
+    MultipleWords(usize),
+}
+
+#[derive(Debug)]
+struct LastWordMatched(usize, String);
+
+#[bon]
+impl WordExclusionIndex {
+    /// Find the phrases in a text node, reporting each under the given rule
+    /// name. If `level` is set, it overrides the level of the phrase's group.
+    pub(crate) fn check(
+        &self,
+        rule_name: &str,
+        ast: &mdast::Node,
+        context: &Context,
+        level: Option<LintLevel>,
+    ) -> Option<Vec<LintError>> {
+        let mdast::Node::Text(text_node) = ast else {
+            return None;
+        };
+        let Some(position) = &text_node.position else {
+            return None;
+        };
+        debug!("Checking word list for node {:#?}", ast);
+
+        let mut errors = None::<Vec<LintError>>;
+
+        let range = AdjustedRange::from_unadjusted_position(position, context);
+        if context.is_skipped(&range) {
+            return None;
+        }
+        let text = context
+            .rope()
+            .byte_slice(Into::<std::ops::Range<usize>>::into(range.clone()));
+        let mut word_iterator: WordIteratorExtension<'_, WordIteratorPrefix> =
+            WordIterator::new(text, range.start.into(), Default::default()).into();
+
+        while let Some((offset, word, _)) = word_iterator.next() {
+            let word = word.to_string();
+
+            let ExclusionMatch {
+                new_iterator,
+                match_: r#match,
+            } = self.match_exclusions(self.get(&word), word_iterator, context);
+            word_iterator = new_iterator;
+
+            if let Some(MatchDetails {
+                last_word,
+                rule,
+                replacement,
+            }) = r#match
+            {
+                let end_offset = match last_word {
+                    Some(last_word) => last_word.0 + last_word.1.len(),
+                    None => offset + word.len(),
+                };
+
+                let error = self
+                    .create_lint_error()
+                    .beginning_offset(offset)
+                    .end_offset(end_offset)
+                    .maybe_replacement(replacement)
+                    .rule(rule)
+                    .rule_name(rule_name)
+                    .maybe_level(level)
+                    .range(range.clone())
+                    .context(context)
+                    .call();
+                errors.get_or_insert_with(Vec::new).push(error);
+            }
+        }
+
+        self.check_patterns(rule_name, text, range, context, level, &mut errors);
+
+        errors
+    }
+
+    /// Find the regex exclusions in a text node. Matches must start and end
+    /// at word boundaries, and are skipped if they overlap a phrase that was
+    /// already reported.
+    fn check_patterns(
+        &self,
+        rule_name: &str,
+        text: RopeSlice<'_>,
+        range: AdjustedRange,
+        context: &Context,
+        level: Option<LintLevel>,
+        errors: &mut Option<Vec<LintError>>,
+    ) {
+        if self.patterns.is_empty() {
+            return;
+        }
+
+        let text = text.to_string();
+        let start: usize = range.start.into();
+        let mut reported = errors
+            .iter()
+            .flatten()
+            .map(|error| error.offset_range())
+            .collect::<Vec<_>>();
+
+        for pattern in &self.patterns {
+            let rule = &self.rules[pattern.rule_index];
+            if !rule.applies_to(context) {
+                continue;
+            }
+
+            for captures in pattern.regex.captures_iter(&text) {
+                let found = captures.get(0).expect("Group 0 is always present");
+                if found.is_empty() || !is_word_bounded(&text, found.range()) {
+                    continue;
+                }
+                let found_range = start + found.start()..start + found.end();
+                if reported
+                    .iter()
+                    .any(|other| other.start < found_range.end && found_range.start < other.end)
+                {
+                    continue;
+                }
+
+                let replacement = pattern.replacement.as_ref().map(|template| {
+                    let mut replacement = String::new();
+                    captures.expand(template, &mut replacement);
+                    replacement
+                });
+                let error = self
+                    .create_lint_error()
+                    .beginning_offset(found_range.start)
+                    .end_offset(found_range.end)
+                    .maybe_replacement(replacement)
+                    .rule(rule.clone())
+                    .rule_name(rule_name)
+                    .maybe_level(level)
+                    .range(range.clone())
+                    .context(context)
+                    .call();
+                reported.push(found_range);
+                errors.get_or_insert_with(Vec::new).push(error);
+            }
+        }
+
+        if let Some(errors) = errors {
+            errors.sort_by_key(|error| error.offset_range().start);
+        }
+    }
+
+    #[builder]
+    fn create_lint_error(
+        &self,
+        beginning_offset: usize,
+        end_offset: usize,
+        range: AdjustedRange,
+        replacement: Option<String>,
+        context: &Context<'_>,
+        rule: RuleMeta,
+        rule_name: &str,
+        level: Option<LintLevel>,
+    ) -> LintError {
+        trace!("Creating lint error for word list. Range: {range:#?}; Beginning offset: {beginning_offset}; End offset: {end_offset}");
+        let narrowed_range = AdjustedRange::new(beginning_offset.into(), end_offset.into());
+        let word = context.rope().byte_slice(narrowed_range.to_usize_range());
+
+        let suggestion = vec![LintCorrection::create_word_splice_correction()
+            .context(context)
+            .outer_range(&range)
+            .splice_range(&narrowed_range)
+            .maybe_replace(replacement.clone().map(Cow::from))
+            .call()];
+        let location = DenormalizedLocation::from_offset_range(narrowed_range, context);
+        let message = substitute_format_string(rule.description().to_string(), word, replacement);
+
+        LintError::from_raw_location()
+            .rule(rule_name)
+            .message(message)
+            .level(level.unwrap_or(rule.level()))
+            .location(location)
+            .suggestions(suggestion)
+            .call()
+    }
+
+    fn match_exclusions<'a>(
+        &self,
+        IndexLookupResult {
+            case_sensitive_details,
+            case_insensitive_details,
+        }: IndexLookupResult,
+        words: WordIteratorExtension<'a, WordIteratorPrefix<'a>>,
+        context: &Context,
+    ) -> ExclusionMatch<'a> {
+        trace!("Checking for need to match exclusions in word list");
+        if case_sensitive_details.is_none() && case_insensitive_details.is_none() {
+            return ExclusionMatch {
+                new_iterator: words,
+                match_: None,
+            };
+        }
+        debug!("Matching exclusions in word list");
+
+        let mut result_so_far = None::<MatchDetailsIntermediate>;
+        let all = combine_exclusions(case_sensitive_details, case_insensitive_details)
+            .filter(|(rule_index, ..)| self.rules[*rule_index].applies_to(context))
+            .peekable();
+
+        let mut consumed = vec![];
+        let words = self
+            .match_exclusions_rec()
+            .remaining(all)
+            .consumed(&mut consumed)
+            .words(words)
+            .result(&mut result_so_far)
+            .call();
+
+        let new_iterator = {
+            match result_so_far {
+                Some(MatchDetailsIntermediate {
+                    match_: MatchDetailsIntermediateInner::MultipleWords(end_pos_incl),
+                    ..
+                }) => reattach_unused_words(words, consumed.clone().into_iter(), end_pos_incl + 1),
+                _ => reattach_unused_words(words, consumed.clone().into_iter(), 0),
+            }
+        };
+        ExclusionMatch {
+            new_iterator,
+            match_: result_so_far.map(|res| MatchDetails {
+                last_word: match res.match_ {
+                    MatchDetailsIntermediateInner::OneWord => None,
+                    MatchDetailsIntermediateInner::MultipleWords(end_pos_incl) => {
+                        let last_word = consumed.into_iter().nth(end_pos_incl).expect(
+                            "Saved result only points to actual positions in the list of matches",
+                        );
+                        Some(LastWordMatched(last_word.0, last_word.1.to_string()))
+                    }
+                },
+                rule: res.rule,
+                replacement: res.replacement.clone(),
+            }),
+        }
+    }
+
+    #[builder]
+    fn match_exclusions_rec<'a, 'b>(
+        &self,
+        /// Words that have been consumed so far.
+        consumed: &mut Vec<WordIteratorItem<'b>>,
+        /// The remaining candidates that may still be viable matches. Stored
+        /// alongside their rule index.
+        mut remaining: Peekable<
+            impl Iterator<Item = (usize, Suffix<'a>, CaseSensitivity, &'a Option<String>)>,
+        >,
+        /// The remaining words to match.
+        mut words: WordIteratorExtension<'b, WordIteratorPrefix<'b>>,
+        result: &mut Option<MatchDetailsIntermediate<'a>>,
+    ) -> WordIteratorExtension<'b, WordIteratorPrefix<'b>> {
+        #[cfg(debug_assertions)]
+        trace!("Recursing through the match in word list. Consumed: \"{consumed:#?}\"; Current result: {result:#?}");
+
+        match words.next() {
+            None => {
+                // There are no words left in the string to match. If any of
+                // the prior matches were complete matches, then they are the
+                // longest matches. Pick an arbitary one.
+                if let Some((rule_index, _, _, repl)) =
+                    remaining.find(|(_, rem, _, _)| matches!(rem, Suffix::Finish))
+                {
+                    self.save_result()
+                        .matched(consumed)
+                        .rule_index(rule_index)
+                        .replacement(repl)
+                        .result(result)
+                        .call()
+                }
+                words
+            }
+            Some(word_item) => {
+                let mut next_iteration = None;
+                for (rule_index, suffix, case_sensitivity, repl) in remaining {
+                    match suffix {
+                        Suffix::Finish => self
+                            .save_result()
+                            .matched(consumed)
+                            .rule_index(rule_index)
+                            .result(result)
+                            .replacement(repl)
+                            .call(),
+                        Suffix::Remaining(s) => {
+                            if let Some(remainder) =
+                                trim_start((s, case_sensitivity), word_item.1.to_string())
+                            {
+                                // The match could potentially continue. Store the
+                                // candidate to run another iteration.
+                                next_iteration.get_or_insert_with(Vec::new).push((
+                                    rule_index,
+                                    Suffix::from(remainder),
+                                    case_sensitivity,
+                                    repl,
+                                ));
+                            }
+                        }
+                    }
+                }
+
+                consumed.push(word_item);
+                if let Some(next_iteration) = next_iteration {
+                    self.match_exclusions_rec()
+                        .remaining(next_iteration.into_iter().peekable())
+                        .words(words)
+                        .consumed(consumed)
+                        .result(result)
+                        .call()
+                } else {
+                    words
+                }
+            }
+        }
+    }
+
+    #[builder]
+    fn save_result<'a>(
+        &self,
+        matched: &[WordIteratorItem<'_>],
+        rule_index: usize,
+        replacement: &'a Option<String>,
+        result: &mut Option<MatchDetailsIntermediate<'a>>,
+    ) {
+        let match_ = if matched.is_empty() {
+            MatchDetailsIntermediateInner::OneWord
+        } else {
+            MatchDetailsIntermediateInner::MultipleWords(matched.len() - 1)
+        };
+
+        result.replace(MatchDetailsIntermediate {
+            match_,
+            rule: self
+                .rules
+                .get(rule_index)
+                .expect("Rule meta added when this linter rule was set up")
+                .clone(),
+            replacement,
+        });
+    }
+}
+fn combine_exclusions<'a>(
+    case_sensitive: Option<&'a WordExclusionMeta>,
+    case_insensitive: Option<&'a WordExclusionMeta>,
+) -> Peekable<impl Iterator<Item = (usize, Suffix<'a>, CaseSensitivity, &'a Option<String>)>> {
+    fn remainders_iter(
+        details: &WordExclusionMeta,
+    ) -> impl Iterator<Item = (usize, Suffix<'_>, &Option<String>)> {
+        details.remainders.iter().enumerate().map(|(i, rem)| {
+            let (rule_index, replacement) = details
+                .details
+                .get(i)
+                .expect("Details added when setting up rule");
+            (*rule_index, Suffix::from(rem.as_str()), replacement)
+        })
+    }
+
+    let case_sensitive = case_sensitive
+        .map(remainders_iter)
+        .into_iter()
+        .flatten()
+        .map(|(i, rem, repl)| (i, rem, CaseSensitivity::Sensitive, repl));
+    let case_insensitive = case_insensitive
+        .map(remainders_iter)
+        .into_iter()
+        .flatten()
+        .map(|(i, rem, repl)| (i, rem, CaseSensitivity::Insensitive, repl));
+
+    case_sensitive.chain(case_insensitive).peekable()
+}
+
+/// The schema for exclusion rules in the `rules` setting, keyed by rule name.
+pub(super) fn exclusion_rules_schema() -> Value {
+    json!({
+        "type": "object",
+        "description": "Exclusion rules, keyed by rule name.",
+        "additionalProperties": {
+            "type": "object",
+            "properties": {
+                "level": {
+                    "type": "string",
+                    "enum": ["ERROR", "WARNING", "HINT"],
+                },
+                "case_sensitive": { "type": "boolean" },
+                "words": {
+                    "type": "array",
+                    "description": "Words to exclude, optionally paired with a replacement.",
+                    "items": {
+                        "anyOf": [
+                            { "type": "string" },
+                            {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "minItems": 2,
+                                "maxItems": 2,
+                            },
+                        ],
+                    },
+                },
+                "patterns": {
+                    "type": "array",
+                    "description": "Regex patterns to exclude, optionally paired with a replacement template that can refer to capture groups, such as `$1`.",
+                    "items": {
+                        "anyOf": [
+                            { "type": "string" },
+                            {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "minItems": 2,
+                                "maxItems": 2,
+                            },
+                        ],
+                    },
+                },
+                "description": { "type": "string" },
+                "files": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Globs for the files the exclusions apply to. Defaults to all files.",
+                },
+            },
+            "required": ["description"],
+        },
+    })
+}
+
+/// Whether a match neither starts nor ends in the middle of a word, so that
+/// `simpl(y|e)` doesn't match the start of "simplest". Digits joined by a
+/// decimal or thousands separator count as one word, so that `\d{5}` doesn't
+/// match the end of "3.14159".
+fn is_word_bounded(text: &str, range: std::ops::Range<usize>) -> bool {
+    let found = &text[range.clone()];
+    let starts_word = !found.starts_with(char::is_alphanumeric)
+        || !continues_word(text[..range.start].chars().rev(), found.chars().next());
+    let ends_word = !found.ends_with(char::is_alphanumeric)
+        || !continues_word(text[range.end..].chars(), found.chars().next_back());
+    starts_word && ends_word
+}
+
+/// Whether the text next to the edge of a match continues the word at that
+/// edge. `outwards` iterates away from the match.
+fn continues_word(mut outwards: impl Iterator<Item = char>, edge: Option<char>) -> bool {
+    match outwards.next() {
+        Some(c) if c.is_alphanumeric() => true,
+        Some('.' | ',') => {
+            edge.is_some_and(|c| c.is_ascii_digit())
+                && outwards.next().is_some_and(|c| c.is_ascii_digit())
+        }
+        _ => false,
+    }
+}
+
+/// Strip a matched word from the start of the remaining text of an exclusion,
+/// returning the text left to match. The word must match whole, so that
+/// "Foo barbie" doesn't match "Foo bar bie".
+fn trim_start(hay: (&str, CaseSensitivity), prefix: impl AsRef<str>) -> Option<&str> {
+    let prefix = prefix.as_ref();
+    let matches = match hay.1 {
+        CaseSensitivity::Sensitive => hay.0.starts_with(prefix),
+        CaseSensitivity::Insensitive => hay.0.to_lowercase().starts_with(&prefix.to_lowercase()),
+    };
+    if !matches {
+        return None;
+    }
+
+    let rest = hay.0.get(prefix.len()..)?;
+    match rest.chars().next() {
+        Some(c) if c.is_alphanumeric() => None,
+        // Skip the space between words, so the next word can be matched.
+        _ => Some(rest.trim_start()),
+    }
+}
+
+fn reattach_unused_words<'words>(
+    words: WordIteratorExtension<'words, WordIteratorPrefix<'words>>,
+    consumed: impl Iterator<Item = WordIteratorItem<'words>>,
+    num_used: usize,
+) -> WordIteratorExtension<'words, WordIteratorPrefix<'words>> {
+    #[cfg(debug_assertions)]
+    trace!("Reattaching unused words after matching");
+    words.extend_on_prefix(WordIteratorPrefix::new(consumed.skip(num_used)))
+}
+
+static FORMAT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[^%](?<placeholder>%s|%r)").expect("Hardcoded regex should not fail to compile")
+});
+
+fn substitute_format_string(s: String, word: RopeSlice<'_>, replacement: Option<String>) -> String {
+    if FORMAT_REGEX.captures(&s).is_none() {
+        return s;
+    }
+
+    let mut result = String::with_capacity(s.len());
+    let mut last_index = 0;
+    for capture in FORMAT_REGEX.captures_iter(&s) {
+        let placeholder = capture.name("placeholder").unwrap();
+        let range = placeholder.range();
+
+        let substitution = if placeholder.as_str().ends_with('s') {
+            word.to_string()
+        } else {
+            replacement
+                .clone()
+                .unwrap_or("<REPLACEMENT_WORD>".to_string())
+        };
+
+        result.push_str(&s[last_index..range.start]);
+        result.push_str(&substitution);
+        last_index = range.end;
+    }
+    result.push_str(&s[last_index..]);
+    result
+}
+
+/// The phrases checked by a rule declared with [`word_list_rule`]: its
+/// built-in list, adjusted by the `words` and `ignore` settings, in the files
+/// matched by the `files` setting.
+#[derive(Debug)]
+pub(crate) struct WordList {
+    message: &'static str,
+    level: LintLevel,
+    case_sensitive: bool,
+    defaults: Vec<ExclusionDefinition>,
+    files: Vec<Pattern>,
+    index: WordExclusionIndex,
+}
+
+#[bon]
+impl WordList {
+    #[builder]
+    pub(crate) fn new(
+        /// The message to report. `%s` is replaced with the matched text, and
+        /// `%r` with the suggested replacement.
+        message: &'static str,
+        level: LintLevel,
+        #[builder(default)] case_sensitive: bool,
+        defaults: Vec<ExclusionDefinition>,
+    ) -> Self {
+        let mut this = Self {
+            message,
+            level,
+            case_sensitive,
+            defaults,
+            files: Vec::new(),
+            index: WordExclusionIndex::default(),
+        };
+        this.rebuild(Vec::new(), &[]);
+        this
+    }
+}
+
+impl WordList {
+    pub(crate) fn setup(&mut self, settings: Option<&mut RuleSettings>) {
+        let Some(settings) = settings else {
+            return;
+        };
+
+        if let Some(files) = settings.get_array_of_globs("files") {
+            self.files = files;
+        }
+
+        let words = settings
+            .get_deserializable::<Vec<ExclusionDefinition>>("words")
+            .unwrap_or_default();
+        let ignore = settings.get_array_of_strings("ignore").unwrap_or_default();
+        self.rebuild(words, &ignore);
+    }
+
+    pub(crate) fn settings_schema(&self) -> Value {
+        object_schema(json!({
+            "words": {
+                "type": "array",
+                "description": "Phrases to report in addition to the built-in list. Each item is a phrase, or a [phrase, replacement] pair.",
+                "items": {
+                    "anyOf": [
+                        { "type": "string" },
+                        {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "minItems": 2,
+                            "maxItems": 2,
+                        },
+                    ],
+                },
+            },
+            "ignore": string_array_schema("Phrases from the built-in list that shouldn't be reported."),
+            "files": string_array_schema("Globs for the files to check. Defaults to all files."),
+        }))
+    }
+
+    pub(crate) fn check(
+        &self,
+        rule_name: &str,
+        ast: &mdast::Node,
+        context: &Context,
+        level: LintLevel,
+    ) -> Option<Vec<LintError>> {
+        self.index.check(rule_name, ast, context, Some(level))
+    }
+
+    fn rebuild(&mut self, words: Vec<ExclusionDefinition>, ignore: &[String]) {
+        // Ignored phrases are compared as lowercase, since that's how
+        // `get_array_of_strings` returns them.
+        let defaults = self
+            .defaults
+            .iter()
+            .filter(|word| !ignore.contains(&word.word().to_lowercase()))
+            .cloned();
+
+        let mut index = WordExclusionIndex::default();
+        index
+            .add_group()
+            .description(self.message)
+            .level(self.level)
+            .case_sensitive(self.case_sensitive)
+            .words(defaults.chain(words))
+            .files(self.files.clone())
+            .call();
+        self.index = index;
+    }
+}
+
+/// Declares a rule that reports each occurrence of a fixed list of phrases
+/// with the same message, suggesting a replacement where one is given.
+///
+/// Users can add phrases with the `words` setting, turn off built-in ones
+/// with the `ignore` setting, and limit the rule to some files with the
+/// `files` setting.
+///
+/// ```ignore
+/// word_list_rule! {
+///     /// Doc comment for the rule.
+///     pub struct Rule999Example {
+///         level: LintLevel::Warning,
+///         message: "Avoid \"%s\", use \"%r\" instead.",
+///         case_sensitive: false,
+///         words: ["utilize", ("in order to", "to")],
+///     }
+/// }
+/// ```
+macro_rules! word_list_rule {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            level: $level:expr,
+            message: $message:expr,
+            $(case_sensitive: $case_sensitive:expr,)?
+            words: [$($word:expr),* $(,)?] $(,)?
+        }
+    ) => {
+        #[derive(Debug, supa_mdx_macros::RuleName)]
+        $(#[$meta])*
+        $vis struct $name($crate::rules::word_list::WordList);
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self(
+                    $crate::rules::word_list::WordList::builder()
+                        .message($message)
+                        .level($level)
+                        $(.case_sensitive($case_sensitive))?
+                        .defaults(vec![$($word.into()),*])
+                        .build(),
+                )
+            }
+        }
+
+        impl $crate::rules::Rule for $name {
+            fn default_level(&self) -> $crate::LintLevel {
+                $level
+            }
+
+            fn interests(&self) -> Option<&'static [$crate::utils::mdast::NodeKind]> {
+                Some(&[$crate::utils::mdast::NodeKind::Text])
+            }
+
+            fn setup(&mut self, settings: Option<&mut $crate::rules::RuleSettings>) {
+                self.0.setup(settings);
+            }
+
+            fn settings_schema(&self) -> serde_json::Value {
+                self.0.settings_schema()
+            }
+
+            fn check(
+                &self,
+                ast: &markdown::mdast::Node,
+                context: &$crate::context::Context,
+                level: $crate::LintLevel,
+            ) -> Option<Vec<$crate::errors::LintError>> {
+                self.0.check(
+                    $crate::rules::RuleName::name(self),
+                    ast,
+                    context,
+                    level,
+                )
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fix::LintCorrectionReplace,
+        parser::parse,
+        rules::{Rule, RuleSettings},
+    };
+
+    use super::*;
+
+    word_list_rule! {
+        /// A rule for testing the macro.
+        struct TestWordList {
+            level: LintLevel::Warning,
+            message: "Avoid \"%s\".",
+            words: ["utilize", ("in order to", "to")],
+        }
+    }
+
+    word_list_rule! {
+        struct TestCaseSensitiveWordList {
+            level: LintLevel::Error,
+            message: "Don't use \"%s\".",
+            case_sensitive: true,
+            words: ["Foo"],
+        }
+    }
+
+    fn check(rule: &impl Rule, mdx: &str) -> Option<Vec<LintError>> {
+        let parse_result = parse(mdx).unwrap();
+        let context = Context::builder()
+            .parse_result(&parse_result)
+            .build()
+            .unwrap();
+        let paragraph = &parse_result.ast().children().unwrap()[0];
+        let text = &paragraph.children().unwrap()[0];
+        rule.check(text, &context, rule.default_level())
+    }
+
+    #[test]
+    fn test_word_list_matches_defaults() {
+        let rule = TestWordList::default();
+        let errors = check(&rule, "Utilize the API in order to query data.").unwrap();
+        assert_eq!(errors.len(), 2);
+
+        assert_eq!(errors[0].rule, "TestWordList");
+        assert_eq!(errors[0].level, LintLevel::Warning);
+        assert_eq!(errors[0].message, "Avoid \"Utilize\".");
+        assert_eq!(errors[0].offset_range(), 0..7);
+
+        assert_eq!(errors[1].message, "Avoid \"in order to\".");
+        assert_eq!(errors[1].offset_range(), 16..27);
+        assert_eq!(
+            errors[1].suggestions,
+            Some(vec![LintCorrection::Replace(LintCorrectionReplace {
+                text: "to".to_string(),
+                location: errors[1].location.clone(),
+            })])
+        );
+    }
+
+    #[test]
+    fn test_word_list_matches_whole_words() {
+        let rule = TestWordList::default();
+        assert!(check(&rule, "Go in order today.").is_none());
+        assert!(check(&rule, "Go in orderto do it.").is_none());
+    }
+
+    #[test]
+    fn test_word_list_case_sensitive() {
+        let rule = TestCaseSensitiveWordList::default();
+        assert!(check(&rule, "This is a foo test.").is_none());
+        let errors = check(&rule, "This is a Foo test.").unwrap();
+        assert_eq!(errors[0].message, "Don't use \"Foo\".");
+        assert_eq!(errors[0].level, LintLevel::Error);
+    }
+
+    #[test]
+    fn test_word_list_settings() {
+        let mut rule = TestWordList::default();
+        let table: toml::Table = toml::from_str(
+            r#"
+            words = ["leverage", ["prior to", "before"]]
+            ignore = ["Utilize"]
+            "#,
+        )
+        .unwrap();
+        rule.setup(Some(&mut RuleSettings::new(table)));
+
+        let errors = check(&rule, "Utilize and leverage the API prior to launch.").unwrap();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].message, "Avoid \"leverage\".");
+        assert_eq!(errors[1].message, "Avoid \"prior to\".");
+    }
+
+    #[test]
+    fn test_word_list_files() {
+        let mut rule = TestWordList::default();
+        let table: toml::Table = toml::from_str(r#"files = ["guides/**"]"#).unwrap();
+        rule.setup(Some(&mut RuleSettings::new(table)));
+
+        let parse_result = parse("Utilize the API.").unwrap();
+        let check_path = |path: &str| {
+            let context = Context::builder()
+                .parse_result(&parse_result)
+                .file_path(std::path::Path::new(path))
+                .build()
+                .unwrap();
+            let text = &parse_result.ast().children().unwrap()[0]
+                .children()
+                .unwrap()[0];
+            rule.check(text, &context, LintLevel::Warning)
+        };
+        assert!(check_path("guides/api.mdx").is_some());
+        assert!(check_path("reference/api.mdx").is_none());
+    }
+
+    #[test]
+    fn test_is_word_bounded() {
+        assert!(is_word_bounded("a simple test", 2..8));
+        assert!(!is_word_bounded("simplest", 0..6));
+        assert!(is_word_bounded("Up to 12345.", 6..11));
+        assert!(!is_word_bounded("Pi is 3.14159", 8..13));
+        assert!(!is_word_bounded("1,000,000", 0..5));
+        assert!(is_word_bounded("v1.0, then 2.0", 11..14));
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt::Write;
+
+use markdown::mdast::Node;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parser::ParseResult,
+    utils::words::{WordIterator, WordIteratorOptions},
+};
+
+/// The reading speed used to estimate reading time, in words per minute.
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Counts of the content in a document, for docs dashboards and reports.
+///
+/// Words are counted in prose only, not in code blocks, inline code, or
+/// frontmatter.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentStats {
+    file_path: String,
+    words: usize,
+    headings: usize,
+    code_blocks: usize,
+    reading_time_minutes: usize,
+}
+
+impl DocumentStats {
+    pub(crate) fn new(file_path: String, parse_result: &ParseResult) -> Self {
+        let mut stats = Self {
+            file_path,
+            ..Default::default()
+        };
+        stats.count(parse_result.ast(), parse_result);
+        stats.reading_time_minutes = stats.words.div_ceil(WORDS_PER_MINUTE);
+        stats
+    }
+
+    fn count(&mut self, node: &Node, parse_result: &ParseResult) {
+        match node {
+            Node::Code(_) => self.code_blocks += 1,
+            Node::InlineCode(_) => {}
+            Node::Text(text) => {
+                let Some(position) = text.position.as_ref() else {
+                    return;
+                };
+                let range = parse_result.source_range(position);
+                let start = range.start;
+                self.words += WordIterator::new(
+                    parse_result.rope().byte_slice(range),
+                    start,
+                    WordIteratorOptions::default(),
+                )
+                .count();
+            }
+            _ => {
+                if matches!(node, Node::Heading(_)) {
+                    self.headings += 1;
+                }
+                if let Some(children) = node.children() {
+                    for child in children {
+                        self.count(child, parse_result);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    pub fn headings(&self) -> usize {
+        self.headings
+    }
+
+    pub fn code_blocks(&self) -> usize {
+        self.code_blocks
+    }
+
+    /// Estimated reading time, rounded up to the nearest minute.
+    pub fn reading_time_minutes(&self) -> usize {
+        self.reading_time_minutes
+    }
+}
+
+/// Format statistics as a table, with a row per file followed by the totals.
+pub fn stats_table(stats: &[DocumentStats]) -> String {
+    let totals = DocumentStats {
+        file_path: "Total".to_string(),
+        words: stats.iter().map(DocumentStats::words).sum(),
+        headings: stats.iter().map(DocumentStats::headings).sum(),
+        code_blocks: stats.iter().map(DocumentStats::code_blocks).sum(),
+        reading_time_minutes: stats.iter().map(DocumentStats::reading_time_minutes).sum(),
+    };
+
+    let width = stats
+        .iter()
+        .map(|stats| stats.file_path.len())
+        .max()
+        .unwrap_or_default()
+        .max("File".len());
+    let mut table = String::new();
+    writeln!(
+        table,
+        "{:<width$} | Words | Headings | Code blocks | Reading time (min)",
+        "File"
+    )
+    .unwrap();
+    writeln!(
+        table,
+        ":{:-<width$}|------:|---------:|------------:|-------------------:",
+        ""
+    )
+    .unwrap();
+    for row in stats.iter().chain([&totals]) {
+        writeln!(
+            table,
+            "{:<width$} | {:>5} | {:>8} | {:>11} | {:>18}",
+            row.file_path, row.words, row.headings, row.code_blocks, row.reading_time_minutes
+        )
+        .unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    #[test]
+    fn test_document_stats() {
+        let mdx = "---\ntitle: Getting started with Supabase\n---\n\n# Getting started\n\nCreate a project, then run `supabase init`.\n\n## Install\n\n```bash\nnpm install supabase\n```\n\n<Admonition>\n\nYou need Node.js.\n\n</Admonition>\n";
+        let stats = DocumentStats::new("guide.mdx".to_string(), &parse(mdx).unwrap());
+        assert_eq!(stats.file_path(), "guide.mdx");
+        assert_eq!(stats.words(), 11);
+        assert_eq!(stats.headings(), 2);
+        assert_eq!(stats.code_blocks(), 1);
+        assert_eq!(stats.reading_time_minutes(), 1);
+    }
+
+    #[test]
+    fn test_reading_time_rounds_up() {
+        let mdx = "word ".repeat(401);
+        let stats = DocumentStats::new("long.mdx".to_string(), &parse(&mdx).unwrap());
+        assert_eq!(stats.words(), 401);
+        assert_eq!(stats.reading_time_minutes(), 3);
+
+        let stats = DocumentStats::new("empty.mdx".to_string(), &parse("").unwrap());
+        assert_eq!(stats.reading_time_minutes(), 0);
+    }
+
+    #[test]
+    fn test_stats_table() {
+        let stats = vec![
+            DocumentStats::new("a.mdx".to_string(), &parse("# A\n\nOne two.\n").unwrap()),
+            DocumentStats::new("b.mdx".to_string(), &parse("Three.\n").unwrap()),
+        ];
+        assert_eq!(
+            stats_table(&stats),
+            "\
+File  | Words | Headings | Code blocks | Reading time (min)
+:-----|------:|---------:|------------:|-------------------:
+a.mdx |     3 |        1 |           0 |                  1
+b.mdx |     1 |        0 |           0 |                  1
+Total |     4 |        1 |           0 |                  2
+"
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Helpers for snapshot testing rules against fixture MDX, available with the
+//! `testing` feature.
+//!
+//! [`render_lint_snapshot`] lints a document with a single rule and renders
+//! its diagnostics, their fixes, and the fixed document as plain text, and
+//! [`assert_lint_snapshot!`] compares the result to a stored
+//! [insta](https://insta.rs) snapshot. Use them to check how rules behave
+//! with a project's settings:
+//!
+//! ```ignore
+//! #[test]
+//! fn test_heading_case() {
+//!     supa_mdx_lint::assert_lint_snapshot!(
+//!         "Rule001HeadingCase",
+//!         "# A Heading In Title Case\n",
+//!         Some(json!({ "may_uppercase": ["Title Case"] }))
+//!     );
+//! }
+//! ```
+
+use std::{fmt::Write, path::Path};
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use crate::{
+    config::ConfigDir,
+    fix::{FixSafety, LintCorrection},
+    location::DenormalizedLocation,
+    output::LintOutput,
+    rules::canonical_rule_name,
+    Config, LintSourceReference, Linter,
+};
+
+/// Lint MDX with a single rule, given by its name or alias, and render the
+/// result for a snapshot.
+///
+/// `settings` are the rule's settings, as they would appear in its table in
+/// the config file. Rules that are off unless configured, such as
+/// `Rule037IntroParagraph`, need settings, even if they're empty.
+///
+/// The snapshot lists each diagnostic with its line and column, counted from
+/// 1, followed by its details, fixes, and suggestions. If any diagnostic has a fix, the
+/// document is shown again with all the fixes applied, including unsafe ones.
+pub fn render_lint_snapshot(rule: &str, mdx: &str, settings: Option<Value>) -> Result<String> {
+    render(rule, None, mdx, settings)
+}
+
+/// Like [`render_lint_snapshot`], but lint the MDX as the content of the file
+/// at `path`, for rules that only check some files. The file doesn't need to
+/// exist.
+pub fn render_file_lint_snapshot(
+    rule: &str,
+    path: impl AsRef<Path>,
+    mdx: &str,
+    settings: Option<Value>,
+) -> Result<String> {
+    render(rule, Some(path.as_ref()), mdx, settings)
+}
+
+fn render(rule: &str, path: Option<&Path>, mdx: &str, settings: Option<Value>) -> Result<String> {
+    let rule = canonical_rule_name(rule).ok_or_else(|| anyhow!("Unknown rule: {rule}"))?;
+    let config = match settings {
+        Some(settings) => json!({ rule: settings }),
+        None => json!({}),
+    };
+    let config = Config::from_serializable()
+        .config(config)
+        .config_dir(&ConfigDir::none())
+        .call()?;
+    let linter = Linter::builder().config(config).fix_unsafe(true).build()?;
+
+    let errors = linter
+        .lint_string(mdx, LintSourceReference(path), Some(&[rule]), None)?
+        .into_iter()
+        .flat_map(|output| output.errors().to_vec())
+        .filter(|error| error.rule() == rule)
+        .collect::<Vec<_>>();
+
+    let mut snapshot = String::new();
+    writeln!(snapshot, "{rule}")?;
+    if let Some(path) = path {
+        writeln!(snapshot, "file: {}", path.display())?;
+    }
+    writeln!(snapshot, "--- input")?;
+    write_block(&mut snapshot, mdx)?;
+    writeln!(snapshot, "--- diagnostics")?;
+    if errors.is_empty() {
+        writeln!(snapshot, "(none)")?;
+    }
+    for error in &errors {
+        let start = &error.location().start;
+        writeln!(
+            snapshot,
+            "{}:{}: [{}] {}",
+            start.row + 1,
+            start.column + 1,
+            error.level(),
+            error.message()
+        )?;
+        if let Some(details) = error.details() {
+            writeln!(snapshot, "  details: {details}")?;
+        }
+        for fix in error.fix().unwrap_or_default() {
+            let safety = match error.fix_safety() {
+                FixSafety::Safe => "fix",
+                FixSafety::Unsafe => "unsafe fix",
+            };
+            writeln!(snapshot, "  {safety}: {}", describe_correction(fix))?;
+        }
+        for suggestion in error.suggestions().unwrap_or_default() {
+            writeln!(
+                snapshot,
+                "  suggestion: {}",
+                describe_correction(suggestion)
+            )?;
+        }
+    }
+
+    if errors.iter().any(|error| error.fix().is_some()) {
+        let output = LintOutput::new("", errors);
+        let (_, fixed) = linter.apply_fixes("", mdx, &output);
+        writeln!(snapshot, "--- fixed")?;
+        write_block(&mut snapshot, &fixed.to_string())?;
+    }
+    Ok(snapshot)
+}
+
+/// Lint MDX with a single rule, and compare the result to a stored snapshot
+/// with [`insta::assert_snapshot!`]. Takes the rule's name or alias, the MDX,
+/// and optionally the rule's settings, as for [`render_lint_snapshot`]. To
+/// lint the MDX as the content of a file, give its path after the settings
+/// with `file = "docs/guide.mdx"`, as for [`render_file_lint_snapshot`].
+///
+/// Snapshots are stored next to the calling test, and reviewed with
+/// `cargo insta review`, as for any insta snapshot.
+#[macro_export]
+macro_rules! assert_lint_snapshot {
+    ($rule:expr, $mdx:expr $(,)?) => {
+        $crate::assert_lint_snapshot!($rule, $mdx, None)
+    };
+    ($rule:expr, $mdx:expr, $settings:expr, file = $file:expr $(,)?) => {{
+        let lint_snapshot =
+            $crate::testing::render_file_lint_snapshot($rule, $file, $mdx, $settings)
+                .expect("Linting the snapshot input should succeed");
+        $crate::testing::insta::assert_snapshot!(lint_snapshot)
+    }};
+    ($rule:expr, $mdx:expr, $settings:expr $(,)?) => {{
+        let lint_snapshot = $crate::testing::render_lint_snapshot($rule, $mdx, $settings)
+            .expect("Linting the snapshot input should succeed");
+        $crate::testing::insta::assert_snapshot!(lint_snapshot)
+    }};
+}
+#[doc(inline)]
+pub use crate::assert_lint_snapshot;
+
+#[doc(hidden)]
+pub use insta;
+
+fn describe_correction(correction: &LintCorrection) -> String {
+    match correction {
+        LintCorrection::Insert(insert) => format!(
+            "insert {:?} at {}",
+            insert.text(),
+            describe_point(&insert.location)
+        ),
+        LintCorrection::Delete(delete) => format!("delete {}", describe_range(&delete.location)),
+        LintCorrection::Replace(replace) => format!(
+            "replace {} with {:?}",
+            describe_range(&replace.location),
+            replace.text()
+        ),
+    }
+}
+
+fn describe_point(location: &DenormalizedLocation) -> String {
+    format!("{}:{}", location.start.row + 1, location.start.column + 1)
+}
+
+fn describe_range(location: &DenormalizedLocation) -> String {
+    format!(
+        "{}-{}:{}",
+        describe_point(location),
+        location.end.row + 1,
+        location.end.column + 1
+    )
+}
+
+/// Write text on its own lines, noting a missing trailing newline, which
+/// would otherwise be invisible in the snapshot.
+fn write_block(snapshot: &mut String, text: &str) -> std::fmt::Result {
+    snapshot.push_str(text);
+    if !text.ends_with('\n') {
+        writeln!(snapshot, "\n(no trailing newline)")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_with_fixes() {
+        assert_lint_snapshot!(
+            "heading-case",
+            "# A Heading In Title Case\n\n## Using Supabase Auth\n",
+            Some(json!({ "may_uppercase": ["Supabase Auth"] }))
+        );
+    }
+
+    #[test]
+    fn test_snapshot_opt_in_rule() {
+        assert_lint_snapshot!(
+            "Rule041SentencePerLine",
+            "Create a table. Then enable RLS.\n\n- Use a client. Then query it.",
+            Some(json!({}))
+        );
+    }
+
+    #[test]
+    fn test_snapshot_without_errors() {
+        assert_lint_snapshot!("Rule001HeadingCase", "# A heading\n");
+    }
+
+    #[test]
+    fn test_snapshot_unknown_rule() {
+        let err = render_lint_snapshot("Rule999Nope", "# A heading\n", None).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown rule: Rule999Nope");
+    }
+}
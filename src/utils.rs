@@ -1,15 +1,18 @@
 mod char_tree;
+pub(crate) mod date;
+#[cfg(feature = "spelling")]
 pub(crate) mod lru;
 pub(crate) mod mdast;
 pub(crate) mod path;
 pub(crate) mod regex;
+pub(crate) mod walk;
 pub(crate) mod words;
 
-use std::{
-    borrow::Cow,
-    path::{Path, PathBuf},
-};
+#[cfg(feature = "cli-formats")]
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 
+#[cfg(feature = "cli-formats")]
 pub(crate) fn num_digits(n: usize) -> usize {
     if n == 0 {
         return 1;
@@ -26,6 +29,7 @@ pub(crate) fn num_digits(n: usize) -> usize {
     count
 }
 
+#[cfg(feature = "cli-formats")]
 pub(crate) fn pluralize(num: usize) -> &'static str {
     if num == 1 {
         ""
@@ -34,6 +38,7 @@ pub(crate) fn pluralize(num: usize) -> &'static str {
     }
 }
 
+#[cfg(feature = "cli-formats")]
 pub(crate) fn escape_backticks(s: &str) -> Cow<'_, str> {
     if s.contains('`') {
         Cow::Owned(s.replace('`', "\\`"))
@@ -42,6 +47,31 @@ pub(crate) fn escape_backticks(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// Replace each run of whitespace with a single space, and trim the ends.
+pub(crate) fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars = b.chars().collect::<Vec<_>>();
+    let mut previous_row = (0..=b_chars.len()).collect::<Vec<_>>();
+    let mut current_row = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current_row[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution_cost = if a_char == *b_char { 0 } else { 1 };
+            current_row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[b_chars.len()]
+}
+
 // https://stackoverflow.com/questions/39340924/given-two-absolute-paths-how-can-i-express-one-of-the-paths-relative-to-the-oth
 pub(crate) fn path_relative_from(path: &Path, base: &Path) -> Option<PathBuf> {
     use std::path::Component;
@@ -87,6 +117,7 @@ pub(crate) fn path_relative_from(path: &Path, base: &Path) -> Option<PathBuf> {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "cli-formats")]
     #[test]
     fn test_num_digits() {
         assert_eq!(num_digits(0), 1);
@@ -96,6 +127,16 @@ mod tests {
         assert_eq!(num_digits(8730240234), 10);
     }
 
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("note", "note"), 0);
+        assert_eq!(edit_distance("", "note"), 4);
+        assert_eq!(edit_distance("notee", "note"), 1);
+        assert_eq!(edit_distance("Admontion", "Admonition"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
     #[test]
     fn test_path_relative_from() {
         let path = Path::new("/foo/bar/baz");
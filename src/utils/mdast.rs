@@ -1,9 +1,14 @@
 use markdown::{
-    mdast::{MdxFlowExpression, Node},
+    mdast::{AttributeContent, AttributeValue, MdxFlowExpression, Node},
     unist::Position,
 };
 use regex::Regex;
 
+use crate::{
+    context::Context,
+    location::{AdjustedOffset, AdjustedRange},
+};
+
 pub trait HasChildren {
     fn get_children(&self) -> &Vec<Node>;
 }
@@ -40,6 +45,7 @@ impl HasChildren for markdown::mdast::Link {
 
 /// For some reason, `export const .* =` is parsed as a Text node. We need to
 /// this out to prevent running lints on it.
+#[cfg(feature = "spelling")]
 pub(crate) fn is_export_const(node: &markdown::mdast::Node) -> bool {
     match node {
         markdown::mdast::Node::Text(text) => {
@@ -50,6 +56,156 @@ pub(crate) fn is_export_const(node: &markdown::mdast::Node) -> bool {
     }
 }
 
+/// ESM statements aren't parsed into ESM nodes, so `import` and `export`
+/// statements show up as paragraphs instead.
+pub(crate) fn is_esm_paragraph(node: &Node) -> bool {
+    match node {
+        Node::Paragraph(paragraph) => match paragraph.children.first() {
+            Some(Node::Text(text)) => {
+                text.value.starts_with("import ") || text.value.starts_with("export ")
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// The name of a block-level JSX component, such as `Admonition`.
+pub(crate) fn flow_component_name(node: &Node) -> Option<&str> {
+    match node {
+        Node::MdxJsxFlowElement(element) => element.name.as_deref(),
+        _ => None,
+    }
+}
+
+/// The number of blank lines between two sibling nodes, where `first` comes
+/// before `second`.
+pub(crate) fn blank_lines_between(first: &Node, second: &Node) -> Option<usize> {
+    let (first, second) = (first.position()?, second.position()?);
+    Some(second.start.line.saturating_sub(first.end.line + 1))
+}
+
+/// JSX attributes don't have positions in the AST, so find the range of an
+/// attribute's literal value in the source of its element instead.
+pub(crate) fn find_jsx_attribute_value_range(
+    node: &Node,
+    name: &str,
+    value: &str,
+    context: &Context,
+) -> Option<AdjustedRange> {
+    let position = node.position()?;
+    let node_range = AdjustedRange::from_unadjusted_position(position, context);
+    let node_start: usize = node_range.start.into();
+    let node_source = context
+        .rope()
+        .byte_slice(node_range.to_usize_range())
+        .to_string();
+
+    let attribute_regex = Regex::new(&format!(r#"\s{}\s*=\s*["']"#, regex::escape(name))).ok()?;
+    let value_start = node_start + attribute_regex.find(&node_source)?.end();
+
+    Some(AdjustedRange::new(
+        AdjustedOffset::from(value_start),
+        AdjustedOffset::from(value_start + value.len()),
+    ))
+}
+
+/// Find the range of an attribute's name in the opening tag of a JSX element,
+/// for attributes whose values aren't literals, such as `style={{...}}`.
+pub(crate) fn find_jsx_attribute_name_range(
+    node: &Node,
+    name: &str,
+    context: &Context,
+) -> Option<AdjustedRange> {
+    let (tag_start, tag_source) = opening_tag_source(node, context)?;
+    let attribute_regex = Regex::new(&format!(r"\s({})\s*=", regex::escape(name))).ok()?;
+    let found = attribute_regex.captures(&tag_source)?.get(1)?;
+    Some(AdjustedRange::new(
+        AdjustedOffset::from(tag_start + found.start()),
+        AdjustedOffset::from(tag_start + found.end()),
+    ))
+}
+
+/// The names of the JSX attributes that hold URLs, for rules that don't
+/// configure their own.
+pub(crate) const DEFAULT_JSX_URL_ATTRIBUTES: &[&str] = &["href", "src"];
+
+/// A literal URL in an attribute of a JSX element, such as the `href` of
+/// `<Button href="/docs">`.
+#[derive(Debug)]
+pub(crate) struct JsxUrlAttribute<'node> {
+    pub(crate) url: &'node str,
+    /// The range of the URL, without its quotes.
+    pub(crate) range: AdjustedRange,
+}
+
+/// The URLs in a JSX element's attributes with the given names, so that rules
+/// about links can check components as well as Markdown links. Attributes set
+/// to expressions, such as `href={url}`, are skipped, since their values
+/// aren't known.
+pub(crate) fn jsx_url_attributes<'node>(
+    node: &'node Node,
+    names: &[impl AsRef<str>],
+    context: &Context,
+) -> Vec<JsxUrlAttribute<'node>> {
+    let attributes = match node {
+        Node::MdxJsxFlowElement(element) => &element.attributes,
+        Node::MdxJsxTextElement(element) => &element.attributes,
+        _ => return Vec::new(),
+    };
+    let Some((tag_start, tag_source)) = opening_tag_source(node, context) else {
+        return Vec::new();
+    };
+
+    attributes
+        .iter()
+        .filter_map(|attribute| {
+            let AttributeContent::Property(property) = attribute else {
+                return None;
+            };
+            if !names.iter().any(|name| name.as_ref() == property.name) {
+                return None;
+            }
+            let Some(AttributeValue::Literal(url)) = &property.value else {
+                return None;
+            };
+
+            let attribute_regex = Regex::new(&format!(
+                r#"\s{}\s*=\s*["']"#,
+                regex::escape(&property.name)
+            ))
+            .ok()?;
+            let url_start = tag_start + attribute_regex.find(&tag_source)?.end();
+            Some(JsxUrlAttribute {
+                url,
+                range: AdjustedRange::new(
+                    AdjustedOffset::from(url_start),
+                    AdjustedOffset::from(url_start + url.len()),
+                ),
+            })
+        })
+        .collect()
+}
+
+/// The start offset and source of a JSX element's opening tag, or as much of
+/// the element as comes before its first child.
+fn opening_tag_source(node: &Node, context: &Context) -> Option<(usize, String)> {
+    let position = node.position()?;
+    let node_range = AdjustedRange::from_unadjusted_position(position, context);
+    let node_start: usize = node_range.start.into();
+    // Stop at the first child, so that attributes written out in the
+    // element's content aren't matched.
+    let tag_end: usize = node
+        .children()
+        .and_then(|children| children.first())
+        .and_then(Node::position)
+        .map(|child| AdjustedRange::from_unadjusted_position(child, context).start)
+        .unwrap_or(node_range.end)
+        .into();
+    let tag_source = context.rope().byte_slice(node_start..tag_end).to_string();
+    Some((node_start, tag_source))
+}
+
 pub(crate) trait MaybePosition {
     fn position(&self) -> Option<&Position>;
 }
@@ -128,3 +284,86 @@ impl VariantName for MdxFlowExpression {
         "MdxFlowExpression".to_string()
     }
 }
+
+/// The kind of an AST node, without its contents.
+///
+/// Rules declare the kinds of node they check, so the registry only calls
+/// them for those nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum NodeKind {
+    Root,
+    Blockquote,
+    FootnoteDefinition,
+    MdxJsxFlowElement,
+    List,
+    MdxjsEsm,
+    Toml,
+    Yaml,
+    Break,
+    InlineCode,
+    InlineMath,
+    Delete,
+    Emphasis,
+    MdxTextExpression,
+    FootnoteReference,
+    Html,
+    Image,
+    ImageReference,
+    MdxJsxTextElement,
+    Link,
+    LinkReference,
+    Strong,
+    Text,
+    Code,
+    Math,
+    MdxFlowExpression,
+    Heading,
+    Table,
+    ThematicBreak,
+    TableRow,
+    TableCell,
+    ListItem,
+    Definition,
+    Paragraph,
+}
+
+impl From<&Node> for NodeKind {
+    fn from(node: &Node) -> Self {
+        match node {
+            Node::Root(_) => NodeKind::Root,
+            Node::Blockquote(_) => NodeKind::Blockquote,
+            Node::FootnoteDefinition(_) => NodeKind::FootnoteDefinition,
+            Node::MdxJsxFlowElement(_) => NodeKind::MdxJsxFlowElement,
+            Node::List(_) => NodeKind::List,
+            Node::MdxjsEsm(_) => NodeKind::MdxjsEsm,
+            Node::Toml(_) => NodeKind::Toml,
+            Node::Yaml(_) => NodeKind::Yaml,
+            Node::Break(_) => NodeKind::Break,
+            Node::InlineCode(_) => NodeKind::InlineCode,
+            Node::InlineMath(_) => NodeKind::InlineMath,
+            Node::Delete(_) => NodeKind::Delete,
+            Node::Emphasis(_) => NodeKind::Emphasis,
+            Node::MdxTextExpression(_) => NodeKind::MdxTextExpression,
+            Node::FootnoteReference(_) => NodeKind::FootnoteReference,
+            Node::Html(_) => NodeKind::Html,
+            Node::Image(_) => NodeKind::Image,
+            Node::ImageReference(_) => NodeKind::ImageReference,
+            Node::MdxJsxTextElement(_) => NodeKind::MdxJsxTextElement,
+            Node::Link(_) => NodeKind::Link,
+            Node::LinkReference(_) => NodeKind::LinkReference,
+            Node::Strong(_) => NodeKind::Strong,
+            Node::Text(_) => NodeKind::Text,
+            Node::Code(_) => NodeKind::Code,
+            Node::Math(_) => NodeKind::Math,
+            Node::MdxFlowExpression(_) => NodeKind::MdxFlowExpression,
+            Node::Heading(_) => NodeKind::Heading,
+            Node::Table(_) => NodeKind::Table,
+            Node::ThematicBreak(_) => NodeKind::ThematicBreak,
+            Node::TableRow(_) => NodeKind::TableRow,
+            Node::TableCell(_) => NodeKind::TableCell,
+            Node::ListItem(_) => NodeKind::ListItem,
+            Node::Definition(_) => NodeKind::Definition,
+            Node::Paragraph(_) => NodeKind::Paragraph,
+        }
+    }
+}
@@ -1,4 +1,7 @@
+use glob::{MatchOptions, Pattern};
+use serde::Deserialize;
 use std::{
+    cmp::Ordering,
     ops::Deref,
     path::{Component, Path, PathBuf},
 };
@@ -13,7 +16,8 @@ impl Deref for IsGlob {
     }
 }
 
-fn normalize_glob_path(path: &Path) -> PathBuf {
+/// Resolve `.` and `..` components without touching the filesystem.
+pub(crate) fn normalize_glob_path(path: &Path) -> PathBuf {
     let components = path.components();
     let mut result = PathBuf::new();
 
@@ -32,19 +36,339 @@ fn normalize_glob_path(path: &Path) -> PathBuf {
     result
 }
 
-/// Normalize a path for pattern comparison by canonicalizing it, and replacing
-/// Windows-specific features:
-/// - Replace ackslashes with slashes
-/// - Remove extended-length prefix
+/// Normalize a path for pattern comparison by canonicalizing it, and
+/// converting it to a platform-independent form with [`to_slash_path`].
+///
+/// Paths that can't be canonicalized, for example because they don't exist,
+/// have their `.` and `..` components resolved instead.
 pub(crate) fn normalize_path(path: &Path, is_glob: IsGlob) -> String {
     let path = if *is_glob {
         normalize_glob_path(path)
     } else {
-        path.canonicalize().unwrap_or(path.into())
+        path.canonicalize()
+            .unwrap_or_else(|_| normalize_glob_path(path))
     };
-    let mut path_str = path.to_string_lossy().replace("\\", "/");
-    if path_str.starts_with("//?/") {
-        path_str = path_str[4..].to_string();
+    to_slash_path(&path.to_string_lossy())
+}
+
+/// Convert a path string to a form that can be compared across platforms:
+/// - Replace backslashes with slashes
+/// - Remove the extended-length prefix (`\\?\`), keeping the leading
+///   slashes of UNC paths (`\\?\UNC\server\share` becomes
+///   `//server/share`)
+/// - Uppercase the drive letter
+pub(crate) fn to_slash_path(path: &str) -> String {
+    let mut path_str = path.replace("\\", "/");
+    if let Some(unc) = path_str.strip_prefix("//?/UNC/") {
+        path_str = format!("//{unc}");
+    } else if let Some(rest) = path_str
+        .strip_prefix("//?/")
+        .or_else(|| path_str.strip_prefix("//./"))
+    {
+        path_str = rest.to_string();
+    }
+
+    let bytes = path_str.as_bytes();
+    if bytes.len() >= 2 && bytes[0].is_ascii_lowercase() && bytes[1] == b':' {
+        path_str[..1].make_ascii_uppercase();
     }
     path_str
 }
+
+/// Options for matching normalized paths against globs. Paths are matched
+/// case-insensitively on Windows, where the file system is case-insensitive.
+pub(crate) fn glob_match_options() -> MatchOptions {
+    MatchOptions {
+        case_sensitive: !cfg!(windows),
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    }
+}
+
+/// Strip a directory prefix from a normalized path, only at a path segment
+/// boundary. The comparison is case-insensitive on Windows.
+pub(crate) fn strip_path_prefix<'path>(path: &'path str, prefix: &str) -> Option<&'path str> {
+    let prefix = prefix.trim_end_matches('/');
+    let head = path.get(..prefix.len())?;
+    let matches = if cfg!(windows) {
+        head.eq_ignore_ascii_case(prefix)
+    } else {
+        head == prefix
+    };
+    if !matches {
+        return None;
+    }
+
+    let rest = &path[prefix.len()..];
+    if rest.is_empty() || rest.starts_with('/') {
+        Some(rest.trim_start_matches('/'))
+    } else {
+        None
+    }
+}
+
+/// Build a glob pattern for a glob relative to a directory. Glob syntax in
+/// the directory itself, such as brackets in a folder name, is escaped so
+/// that it's matched literally.
+pub(crate) fn glob_in_dir(dir: &Path, glob: &str) -> String {
+    let dir_str = normalize_path(dir, IsGlob(true));
+    let glob_str = normalize_path(&dir.join(glob), IsGlob(true));
+    match strip_path_prefix(&glob_str, &dir_str) {
+        Some(relative) if !dir_str.is_empty() => {
+            let escaped_dir = Pattern::escape(dir_str.trim_end_matches('/'));
+            if relative.is_empty() {
+                escaped_dir
+            } else {
+                format!("{escaped_dir}/{relative}")
+            }
+        }
+        _ => glob_str,
+    }
+}
+
+/// Check whether a file path matches any of the given globs.
+///
+/// Paths under the current directory are matched relative to it, so globs
+/// such as `docs/**/*.mdx` work regardless of how the file was targeted.
+pub(crate) fn matches_any_glob(path: &Path, globs: &[Pattern]) -> bool {
+    let options = glob_match_options();
+
+    let path_str = normalize_path(path, IsGlob(false));
+    let relative_path_str = std::env::current_dir()
+        .ok()
+        .and_then(|current_dir| {
+            let current_dir = normalize_path(&current_dir, IsGlob(false));
+            strip_path_prefix(&path_str, &current_dir).map(str::to_string)
+        })
+        .unwrap_or_else(|| to_slash_path(&path.to_string_lossy()));
+
+    globs.iter().any(|glob| {
+        glob.matches_with(&relative_path_str, options) || glob.matches_with(&path_str, options)
+    })
+}
+
+/// How file paths are ordered in the output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum PathSortOrder {
+    /// Compare path segments character by character.
+    #[default]
+    Lexicographic,
+    /// Compare runs of digits in path segments by their numeric value, so
+    /// that `2.mdx` sorts before `10.mdx`.
+    Natural,
+}
+
+/// Compare two paths segment by segment, so that the order doesn't depend on
+/// the platform's path separator and files in a directory stay grouped
+/// together.
+pub(crate) fn compare_paths(a: &Path, b: &Path, order: PathSortOrder) -> Ordering {
+    let segments = |path: &Path| {
+        path.components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+    };
+    let (a, b) = (segments(a), segments(b));
+
+    for (a, b) in a.iter().zip(b.iter()) {
+        let ordering = match order {
+            PathSortOrder::Lexicographic => a.cmp(b),
+            PathSortOrder::Natural => natural_cmp(a, b),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+
+    loop {
+        match (a_chars.peek(), b_chars.peek()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_char), Some(b_char)) if a_char.is_ascii_digit() && b_char.is_ascii_digit() => {
+                let take_number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut number = String::new();
+                    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                        number.push(c);
+                    }
+                    number.trim_start_matches('0').to_string()
+                };
+                let a_number = take_number(&mut a_chars);
+                let b_number = take_number(&mut b_chars);
+                let ordering = a_number
+                    .len()
+                    .cmp(&b_number.len())
+                    .then_with(|| a_number.cmp(&b_number));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(a_char), Some(b_char)) => {
+                let ordering = a_char.cmp(b_char);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_any_glob_relative_path() {
+        let globs = vec![Pattern::new("docs/changelog/*.mdx").unwrap()];
+        assert!(matches_any_glob(
+            Path::new("docs/changelog/2024.mdx"),
+            &globs
+        ));
+        assert!(!matches_any_glob(Path::new("docs/guides/2024.mdx"), &globs));
+    }
+
+    #[test]
+    fn test_matches_any_glob_absolute_path() {
+        let globs = vec![Pattern::new("**/changelog/*.mdx").unwrap()];
+        assert!(matches_any_glob(
+            Path::new("/tmp/somewhere/changelog/2024.mdx"),
+            &globs
+        ));
+    }
+
+    #[test]
+    fn test_to_slash_path() {
+        assert_eq!(to_slash_path("/home/docs/a.mdx"), "/home/docs/a.mdx");
+        assert_eq!(to_slash_path(r"docs\guides\a.mdx"), "docs/guides/a.mdx");
+        assert_eq!(to_slash_path(r"c:\docs\a.mdx"), "C:/docs/a.mdx");
+        assert_eq!(to_slash_path(r"\\?\C:\docs\a.mdx"), "C:/docs/a.mdx");
+        assert_eq!(
+            to_slash_path(r"\\?\UNC\server\share\docs\a.mdx"),
+            "//server/share/docs/a.mdx"
+        );
+        assert_eq!(
+            to_slash_path(r"\\server\share\docs\a.mdx"),
+            "//server/share/docs/a.mdx"
+        );
+    }
+
+    #[test]
+    fn test_strip_path_prefix() {
+        assert_eq!(
+            strip_path_prefix("/repo/docs/a.mdx", "/repo"),
+            Some("docs/a.mdx")
+        );
+        assert_eq!(
+            strip_path_prefix("/repo/docs/a.mdx", "/repo/"),
+            Some("docs/a.mdx")
+        );
+        assert_eq!(strip_path_prefix("/repo", "/repo"), Some(""));
+        assert_eq!(strip_path_prefix("/repository/a.mdx", "/repo"), None);
+        assert_eq!(
+            strip_path_prefix("//server/share/docs/a.mdx", "//server/share"),
+            Some("docs/a.mdx")
+        );
+    }
+
+    #[test]
+    fn test_glob_in_dir() {
+        assert_eq!(
+            glob_in_dir(Path::new("/repo/[docs]"), "drafts/**"),
+            "/repo/[[]docs[]]/drafts/**"
+        );
+        assert_eq!(
+            glob_in_dir(Path::new("/repo/docs"), "../shared/*.mdx"),
+            "/repo/shared/*.mdx"
+        );
+        assert_eq!(
+            glob_in_dir(Path::new("/repo"), "/elsewhere/*.mdx"),
+            "/elsewhere/*.mdx"
+        );
+
+        let pattern = Pattern::new(&glob_in_dir(Path::new("/repo/[docs]"), "*.mdx")).unwrap();
+        assert!(pattern.matches_with("/repo/[docs]/a.mdx", glob_match_options()));
+        assert!(!pattern.matches_with("/repo/d/a.mdx", glob_match_options()));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_glob_in_dir_windows() {
+        assert_eq!(
+            glob_in_dir(Path::new(r"c:\repo"), "docs/*.mdx"),
+            "C:/repo/docs/*.mdx"
+        );
+        assert_eq!(
+            glob_in_dir(Path::new(r"\\server\share\repo"), "docs/*.mdx"),
+            "//server/share/repo/docs/*.mdx"
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_matches_any_glob_windows() {
+        let globs = vec![Pattern::new("docs/changelog/*.mdx").unwrap()];
+        assert!(matches_any_glob(
+            Path::new(r"docs\changelog\2024.mdx"),
+            &globs
+        ));
+        assert!(matches_any_glob(
+            Path::new(r"Docs\Changelog\2024.mdx"),
+            &globs
+        ));
+        assert_eq!(strip_path_prefix("C:/Repo/docs", "c:/repo"), Some("docs"));
+    }
+
+    #[test]
+    fn test_compare_paths_lexicographic() {
+        let mut paths = vec![
+            "docs/b.mdx",
+            "docs-old/a.mdx",
+            "docs/a/z.mdx",
+            "docs/10.mdx",
+            "docs/2.mdx",
+        ];
+        paths.sort_by(|a, b| {
+            compare_paths(Path::new(a), Path::new(b), PathSortOrder::Lexicographic)
+        });
+        assert_eq!(
+            paths,
+            vec![
+                "docs/10.mdx",
+                "docs/2.mdx",
+                "docs/a/z.mdx",
+                "docs/b.mdx",
+                "docs-old/a.mdx"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_paths_natural() {
+        let mut paths = vec![
+            "docs/10.mdx",
+            "docs/2.mdx",
+            "docs/02.mdx",
+            "docs/a10b.mdx",
+            "docs/a9b.mdx",
+        ];
+        paths.sort_by(|a, b| compare_paths(Path::new(a), Path::new(b), PathSortOrder::Natural));
+        assert_eq!(
+            paths,
+            vec![
+                "docs/02.mdx",
+                "docs/2.mdx",
+                "docs/10.mdx",
+                "docs/a9b.mdx",
+                "docs/a10b.mdx"
+            ]
+        );
+    }
+}
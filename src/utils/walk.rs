@@ -0,0 +1,194 @@
+use std::{
+    cmp::Ordering,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use bon::Builder;
+use log::{debug, warn};
+
+/// A directory that couldn't be read while walking.
+#[derive(Debug)]
+pub(crate) struct WalkError {
+    pub(crate) path: PathBuf,
+    pub(crate) error: anyhow::Error,
+}
+
+/// Walks a directory tree depth-first, listing the files in each directory
+/// in the given order.
+///
+/// Symlinked directories are only entered once along each path, so a link
+/// back to one of its own ancestors doesn't cause infinite recursion.
+#[derive(Builder)]
+pub(crate) struct DirectoryWalker<'a> {
+    /// Follow symlinks to files and directories. If false, symlinks are
+    /// skipped.
+    #[builder(default = true)]
+    follow_symlinks: bool,
+    /// How deep to descend below the root. Files directly in the root are at
+    /// depth 1.
+    max_depth: Option<usize>,
+    /// Whether to list a file, or descend into a directory.
+    include: &'a dyn Fn(&Path) -> bool,
+    /// The order of entries within a directory.
+    compare: &'a dyn Fn(&Path, &Path) -> Ordering,
+}
+
+impl DirectoryWalker<'_> {
+    /// The files under the root directory, and the errors for any
+    /// subdirectories that couldn't be read.
+    pub(crate) fn walk(&self, root: &Path) -> Result<Vec<Result<PathBuf, WalkError>>> {
+        let mut entries = Vec::new();
+        let mut ancestors = vec![fs::canonicalize(root)?];
+        self.walk_dir(root, 1, &mut ancestors, &mut entries)?;
+        Ok(entries)
+    }
+
+    fn walk_dir(
+        &self,
+        dir: &Path,
+        depth: usize,
+        ancestors: &mut Vec<PathBuf>,
+        entries: &mut Vec<Result<PathBuf, WalkError>>,
+    ) -> Result<()> {
+        if self.max_depth.is_some_and(|max_depth| depth > max_depth) {
+            return Ok(());
+        }
+
+        let mut children = fs::read_dir(dir)?
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                let is_symlink = entry.file_type().is_ok_and(|kind| kind.is_symlink());
+                if is_symlink && !self.follow_symlinks {
+                    debug!("Skipping symlink {:?}", entry.path());
+                    return false;
+                }
+                true
+            })
+            .map(|entry| entry.path())
+            .filter(|path| (self.include)(path))
+            .collect::<Vec<_>>();
+        children.sort_by(|a, b| (self.compare)(a, b));
+
+        for child in children {
+            if !child.is_dir() {
+                entries.push(Ok(child));
+                continue;
+            }
+
+            let canonical = match fs::canonicalize(&child) {
+                Ok(canonical) => canonical,
+                Err(err) => {
+                    entries.push(Err(WalkError {
+                        path: child,
+                        error: err.into(),
+                    }));
+                    continue;
+                }
+            };
+            if ancestors.contains(&canonical) {
+                warn!("Skipping {child:?}, which links back to one of its parent directories");
+                continue;
+            }
+
+            ancestors.push(canonical);
+            if let Err(error) = self.walk_dir(&child, depth + 1, ancestors, entries) {
+                entries.push(Err(WalkError { path: child, error }));
+            }
+            ancestors.pop();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walk(root: &Path, walker: DirectoryWalker) -> Vec<PathBuf> {
+        walker
+            .walk(root)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.unwrap().strip_prefix(root).unwrap().to_path_buf())
+            .collect()
+    }
+
+    #[test]
+    fn test_walk_depth_first_in_order() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("b/c")).unwrap();
+        fs::write(temp_dir.path().join("a.mdx"), "").unwrap();
+        fs::write(temp_dir.path().join("b/c/d.mdx"), "").unwrap();
+        fs::write(temp_dir.path().join("b/e.mdx"), "").unwrap();
+        fs::write(temp_dir.path().join("f.txt"), "").unwrap();
+
+        let include =
+            |path: &Path| path.is_dir() || path.extension().is_some_and(|ext| ext == "mdx");
+        let compare = |a: &Path, b: &Path| a.cmp(b);
+        assert_eq!(
+            walk(
+                temp_dir.path(),
+                DirectoryWalker::builder()
+                    .include(&include)
+                    .compare(&compare)
+                    .build()
+            ),
+            vec![
+                PathBuf::from("a.mdx"),
+                PathBuf::from("b/c/d.mdx"),
+                PathBuf::from("b/e.mdx"),
+            ]
+        );
+        assert_eq!(
+            walk(
+                temp_dir.path(),
+                DirectoryWalker::builder()
+                    .include(&include)
+                    .compare(&compare)
+                    .max_depth(2)
+                    .build()
+            ),
+            vec![PathBuf::from("a.mdx"), PathBuf::from("b/e.mdx")]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_symlinks() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("docs")).unwrap();
+        fs::write(temp_dir.path().join("docs/a.mdx"), "").unwrap();
+        std::os::unix::fs::symlink(temp_dir.path(), temp_dir.path().join("docs/loop")).unwrap();
+        std::os::unix::fs::symlink(
+            temp_dir.path().join("docs/a.mdx"),
+            temp_dir.path().join("link.mdx"),
+        )
+        .unwrap();
+
+        let include = |_: &Path| true;
+        let compare = |a: &Path, b: &Path| a.cmp(b);
+        assert_eq!(
+            walk(
+                temp_dir.path(),
+                DirectoryWalker::builder()
+                    .include(&include)
+                    .compare(&compare)
+                    .build()
+            ),
+            vec![PathBuf::from("docs/a.mdx"), PathBuf::from("link.mdx")]
+        );
+        assert_eq!(
+            walk(
+                temp_dir.path(),
+                DirectoryWalker::builder()
+                    .include(&include)
+                    .compare(&compare)
+                    .follow_symlinks(false)
+                    .build()
+            ),
+            vec![PathBuf::from("docs/a.mdx")]
+        );
+    }
+}
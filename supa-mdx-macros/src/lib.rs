@@ -1,8 +1,18 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
-/// Auto-generates the rule name for a rule.
-#[proc_macro_derive(RuleName)]
+/// Auto-generates the rule name, code, and category for a rule.
+///
+/// The code is derived from the rule number, so `Rule001HeadingCase` has the
+/// code `SMDX001`. The category defaults to `Style`, and can be set with the
+/// `rule_category` attribute:
+///
+/// ```ignore
+/// #[derive(RuleName)]
+/// #[rule_category(Links)]
+/// pub struct Rule006NoAbsoluteUrls;
+/// ```
+#[proc_macro_derive(RuleName, attributes(rule_category))]
 pub fn rule_name_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
     impl_rule_name_macro(&ast)
@@ -10,12 +20,46 @@ pub fn rule_name_derive(input: TokenStream) -> TokenStream {
 
 fn impl_rule_name_macro(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
+    let code = rule_code(&name.to_string());
+
+    let mut category = format_ident!("Style");
+    for attr in &ast.attrs {
+        if attr.path().is_ident("rule_category") {
+            match attr.parse_args::<syn::Ident>() {
+                Ok(ident) => category = ident,
+                Err(err) => return err.to_compile_error().into(),
+            }
+        }
+    }
+
     let gen = quote! {
-        impl RuleName for #name {
+        impl crate::rules::RuleName for #name {
             fn name(&self) -> &'static str {
                 stringify!(#name)
             }
+
+            fn code(&self) -> &'static str {
+                #code
+            }
+
+            fn category(&self) -> crate::rules::RuleCategory {
+                crate::rules::RuleCategory::#category
+            }
         }
     };
     gen.into()
 }
+
+/// The stable code for a rule, from the number after `Rule` in its name.
+fn rule_code(name: &str) -> String {
+    let number = name
+        .strip_prefix("Rule")
+        .map(|rest| {
+            rest.chars()
+                .take_while(char::is_ascii_digit)
+                .collect::<String>()
+        })
+        .filter(|number| !number.is_empty())
+        .unwrap_or_else(|| "000".to_string());
+    format!("SMDX{number}")
+}
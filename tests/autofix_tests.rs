@@ -219,3 +219,93 @@ This one is missing the opening newline.
 </Admonition>"#
     );
 }
+
+#[test]
+fn test_autofix_rule005_admonition_newlines_with_other_fixes() {
+    let tempdir = TempDir::new().unwrap();
+    let bad_file = "# Test admonition newlines
+
+- Indented in a list item.
+
+  <Admonition type=\"tip\">
+  This content is missing newlines around it.
+  </Admonition>
+
+<Admonition type=\"note\">
+  \nThis one has trailing whitespace on its empty line.
+</Admonition>
+";
+    fs::write(tempdir.path().join("bad.mdx"), bad_file).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(tempdir.path().join("bad.mdx"))
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert().success();
+
+    let result = fs::read_to_string(tempdir.path().join("bad.mdx")).unwrap();
+    assert_eq!(
+        result,
+        r#"# Test admonition newlines
+
+- Indented in a list item.
+
+  <Admonition type="tip">
+
+  This content is missing newlines around it.
+
+  </Admonition>
+
+<Admonition type="note">
+
+This one has trailing whitespace on its empty line.
+
+</Admonition>
+"#
+    );
+}
+
+#[test]
+fn test_autofix_rule002_admonition_types() {
+    let tempdir = TempDir::new().unwrap();
+    let bad_file = r#"# Test admonition types
+
+<Admonition type="cuation">
+
+Typo in the type.
+
+</Admonition>
+
+<Admonition type="notfound">
+
+Nothing close to this type.
+
+</Admonition>"#;
+    fs::write(tempdir.path().join("bad.mdx"), bad_file).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(tempdir.path().join("bad.mdx"))
+        .arg("--config")
+        .arg("tests/rule002/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert().failure();
+
+    let result = fs::read_to_string(tempdir.path().join("bad.mdx")).unwrap();
+    assert_eq!(
+        result,
+        r#"# Test admonition types
+
+<Admonition type="caution">
+
+Typo in the type.
+
+</Admonition>
+
+<Admonition type="notfound">
+
+Nothing close to this type.
+
+</Admonition>"#
+    );
+}
@@ -44,6 +44,20 @@ fn integration_test_directory_target() {
         .stdout(predicate::str::contains("Found 2 errors"));
 }
 
+#[test]
+fn integration_test_max_depth() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--max-depth")
+        .arg("1");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("3 sources linted"))
+        .stdout(predicate::str::contains("good003.mdx").not());
+}
+
 #[test]
 fn integration_test_silent() {
     let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
@@ -100,3 +114,362 @@ fn integration_test_rdf_no_extra_logs() {
         .arg("rdf");
     cmd.assert().success().stdout(predicate::str::is_empty());
 }
+
+#[test]
+fn integration_test_init() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("supa-mdx-lint.config.toml");
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("init")
+        .arg("--preset")
+        .arg("supabase")
+        .arg("--config")
+        .arg(&config_path);
+    cmd.assert().success();
+    let config = std::fs::read_to_string(&config_path).unwrap();
+    assert!(config.contains("[Rule002AdmonitionTypes]"));
+    assert!(config.contains("# [Rule010UnknownComponents]"));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("init").arg("--config").arg(&config_path);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Use --force to overwrite it"));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/good001.mdx")
+        .arg("--config")
+        .arg(&config_path);
+    cmd.assert()
+        .stdout(predicate::str::contains("1 source linted"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn integration_test_profile() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("supa-mdx-lint.config.toml");
+    std::fs::write(
+        &config_path,
+        r#"
+Rule003Spelling = false
+
+[Rule001HeadingCase]
+level = "warn"
+
+[profile.ci.Rule001HeadingCase]
+level = "error"
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg(&config_path);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[WARN]"));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--profile")
+        .arg("ci");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("[ERROR]"));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--profile")
+        .arg("local");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Profile local not found in config",
+    ));
+}
+
+#[test]
+fn integration_test_rule_level_overrides() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--rule")
+        .arg("Rule001HeadingCase:warn");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Found 2 warnings"));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .env("SUPA_MDX_LINT_DISABLE_RULES", "Rule001HeadingCase");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No errors or warnings found"));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--disable-rule")
+        .arg("Rule999Unknown");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown rule: Rule999Unknown"));
+}
+
+#[test]
+fn integration_test_quiet() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--rule")
+        .arg("Rule001HeadingCase:warn")
+        .arg("--quiet");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No errors or warnings found"))
+        .stdout(predicate::str::contains("WARN").not());
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--quiet");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("Found 2 errors"));
+}
+
+#[test]
+fn integration_test_category() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--category")
+        .arg("links,correctness");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No errors or warnings found"));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--category")
+        .arg("style");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "[ERROR] Heading should be sentence case (SMDX001, style)",
+        ))
+        .stdout(predicate::str::contains("Found 2 errors"));
+}
+
+#[test]
+fn integration_test_strict() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--rule")
+        .arg("Rule001HeadingCase:warn")
+        .arg("--strict");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("Found 2 warnings"));
+
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("supa-mdx-lint.config.toml");
+    let config = std::fs::read_to_string("tests/supa-mdx-lint.config.toml").unwrap();
+    std::fs::write(&config_path, format!("strict = true\n{config}")).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--rule")
+        .arg("Rule001HeadingCase:warn");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("Found 2 warnings"));
+}
+
+#[test]
+fn integration_test_unmatched_target() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/good001.mdx")
+        .arg("tests/missing*.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 source linted"))
+        .stdout(predicate::str::contains(
+            "Target \"tests/missing*.mdx\" did not match any lintable files",
+        ));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/good001.mdx")
+        .arg("tests/missing*.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--error-on-unmatched-target");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Target \"tests/missing*.mdx\" did not match any lintable files",
+    ));
+}
+
+#[test]
+fn integration_test_stats() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("stats")
+        .arg("tests/good001.mdx")
+        .arg("tests/good002.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "tests/good001.mdx |    13 |        2 |           0 |                  1",
+        ))
+        .stdout(predicate::str::contains("Total "));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("stats")
+        .arg("tests/good001.mdx")
+        .arg("--format")
+        .arg("json")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let stats: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(stats[0]["file_path"], "tests/good001.mdx");
+    assert_eq!(stats[0]["words"], 13);
+    assert_eq!(stats[0]["headings"], 2);
+}
+
+#[test]
+fn integration_test_output_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let output_path = dir.path().join("report.jsonl");
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--format")
+        .arg("rdf")
+        .arg("--output")
+        .arg(&output_path);
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("Wrote results to"))
+        .stdout(predicate::str::contains("Found 2 errors"))
+        .stdout(predicate::str::contains("Heading should be sentence case").not());
+
+    let report = std::fs::read_to_string(&output_path).unwrap();
+    assert_eq!(report.lines().count(), 2);
+    assert!(report.contains("Heading should be sentence case"));
+}
+
+#[test]
+fn integration_test_suggest_exceptions() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("supa-mdx-lint.config.toml"), "").unwrap();
+    std::fs::write(
+        dir.path().join("a.mdx"),
+        "# Deploy Edge Functions\n\n## Test Edge Functions locally\n\n## Other Heading\n",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("b.mdx"), "## Debug Edge Functions\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.current_dir(dir.path())
+        .arg(".")
+        .arg("--suggest-exceptions");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "  \"Edge Functions\", # 3 headings in 2 files\n",
+        ))
+        .stdout(predicate::str::contains("\"Heading\"").not());
+}
+
+#[test]
+fn integration_test_diff() {
+    let mut cmd = assert_cmd::Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--diff=-")
+        .write_stdin(
+            "--- a/tests/bad001.mdx\n+++ b/tests/bad001.mdx\n@@ -12 +12 @@\n-# But flag this\n+# But Flag This\n",
+        );
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("tests/bad001.mdx:12:1"))
+        .stdout(predicate::str::contains("Found 1 error"));
+
+    let mut cmd = assert_cmd::Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/bad001.mdx")
+        .arg("--config")
+        .arg("tests/supa-mdx-lint.config.toml")
+        .arg("--diff=-")
+        .write_stdin("--- a/tests/bad001.mdx\n+++ b/tests/bad001.mdx\n@@ -7 +7 @@\n-Old\n+New\n");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No errors or warnings found"));
+}
+
+#[test]
+fn integration_test_staged() {
+    let dir = tempfile::tempdir().unwrap();
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir.path())
+            .status()
+            .unwrap();
+        assert!(status.success());
+    };
+    git(&["init", "--quiet"]);
+    std::fs::write(dir.path().join("staged.mdx"), "# Bad Heading\n").unwrap();
+    std::fs::write(dir.path().join("unstaged.mdx"), "# Bad Heading\n").unwrap();
+    git(&["add", "staged.mdx"]);
+    // Only the staged content is linted, not the working tree.
+    std::fs::write(dir.path().join("staged.mdx"), "# Good heading\n").unwrap();
+
+    let config = std::env::current_dir()
+        .unwrap()
+        .join("tests/supa-mdx-lint.config.toml");
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.current_dir(dir.path())
+        .arg("--staged")
+        .arg("--config")
+        .arg(&config);
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("staged.mdx:1:1"))
+        .stdout(predicate::str::contains("unstaged.mdx").not())
+        .stdout(predicate::str::contains("Found 1 error"));
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.current_dir(dir.path())
+        .arg("--staged")
+        .arg("other/**")
+        .arg("--config")
+        .arg(&config);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No errors or warnings found"));
+}
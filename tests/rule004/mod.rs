@@ -12,7 +12,12 @@ fn integration_test_rule004() {
     cmd.assert()
         .failure()
         .stdout(predicate::str::contains("2 errors"))
+        .stdout(predicate::str::contains("2 warnings"))
         .stdout(predicate::str::contains(
             "Don't use the following filler words",
-        ));
+        ))
+        .stdout(predicate::str::contains(
+            "Don't say \"filler\" in these tests.",
+        ))
+        .stdout(predicate::str::contains("Don't say \"words\" in guides.").not());
 }
@@ -150,4 +150,40 @@ Rule005AdmonitionNewlines = false
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("No errors or warnings found"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn integration_test_rule006_jsx_attributes() {
+    let tempdir = TempDir::new().unwrap();
+
+    let config_content = format!(
+        "{}\ncheck_images = false\n",
+        fs::read_to_string("tests/rule006/supa-mdx-lint.config.toml").unwrap()
+    );
+    let test_content = r#"# Test
+
+<Button href="https://supabase.com/docs/auth">Auth</Button>
+
+![Logo](https://supabase.com/images/logo.png)
+"#;
+
+    fs::write(tempdir.path().join("config.toml"), config_content).unwrap();
+    fs::write(tempdir.path().join("test.mdx"), test_content).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(tempdir.path().join("test.mdx"))
+        .arg("--config")
+        .arg(tempdir.path().join("config.toml"))
+        .arg("--fix");
+    cmd.assert().success();
+
+    assert_eq!(
+        fs::read_to_string(tempdir.path().join("test.mdx")).unwrap(),
+        r#"# Test
+
+<Button href="/docs/auth">Auth</Button>
+
+![Logo](https://supabase.com/images/logo.png)
+"#
+    );
+}
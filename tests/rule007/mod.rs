@@ -0,0 +1,52 @@
+use std::{fs, process::Command};
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn integration_test_rule007() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule007/rule007.mdx")
+        .arg("--config")
+        .arg("tests/rule007/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("4 errors"))
+        .stdout(predicate::str::contains(
+            "Write \"Supabase CLI\" with the correct capitalization",
+        ))
+        .stdout(predicate::str::contains(
+            "Don't split \"Edge Functions\" across lines",
+        ))
+        .stdout(predicate::str::contains(
+            "Use single spaces between the words in \"Supabase CLI\"",
+        ))
+        .stdout(predicate::str::contains(
+            "Use non-breaking spaces between the words in \"Edge Functions\"",
+        ));
+}
+
+#[test]
+fn integration_test_rule007_fix() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.mdx");
+    fs::write(
+        &file_path,
+        "Use the supabase cli to deploy Edge\nFunctions.\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(&file_path)
+        .arg("--config")
+        .arg("tests/rule007/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert().success();
+
+    let result = fs::read_to_string(&file_path).unwrap();
+    assert_eq!(
+        result,
+        "Use the Supabase CLI to deploy Edge\u{a0}Functions.\n"
+    );
+}
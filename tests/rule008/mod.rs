@@ -0,0 +1,24 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule008() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule008/rule008.mdx")
+        .arg("--config")
+        .arg("tests/rule008/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("3 errors"))
+        .stdout(predicate::str::contains(
+            "Invalid value \"warning\" for prop \"type\" on <Admonition>",
+        ))
+        .stdout(predicate::str::contains(
+            "Missing required prop \"type\" on <Admonition>",
+        ))
+        .stdout(predicate::str::contains(
+            "Missing required prop \"queryGroup\" on <Tabs>",
+        ));
+}
@@ -0,0 +1,61 @@
+use std::{fs, process::Command};
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn integration_test_rule009() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule009/changelog/rule009.mdx")
+        .arg("--config")
+        .arg("tests/rule009/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("3 errors"))
+        .stdout(predicate::str::contains(
+            "Changelog entry heading should be a date",
+        ))
+        .stdout(predicate::str::contains(
+            "Allowed changelog categories are: Added, Changed, Fixed, Removed. Got: \"Bugfixes\".",
+        ))
+        .stdout(predicate::str::contains(
+            "Changelog entries should be separated by a single blank line",
+        ));
+}
+
+#[test]
+fn integration_test_rule009_other_files_ignored() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule009/not-a-changelog.mdx")
+        .arg("--config")
+        .arg("tests/rule009/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No errors or warnings found"));
+}
+
+#[test]
+fn integration_test_rule009_fix() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir(temp_dir.path().join("changelog")).unwrap();
+    let file_path = temp_dir.path().join("changelog").join("test.mdx");
+    fs::write(
+        &file_path,
+        "## 2024-12-01\n\n- Fixed a bug.\n\n\n\n## 2024-11-15\n\n- Added a feature.\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(&file_path)
+        .arg("--config")
+        .arg("tests/rule009/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert().success();
+
+    let result = fs::read_to_string(&file_path).unwrap();
+    assert_eq!(
+        result,
+        "## 2024-12-01\n\n- Fixed a bug.\n\n## 2024-11-15\n\n- Added a feature.\n"
+    );
+}
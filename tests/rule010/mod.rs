@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule010() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule010/rule010.mdx")
+        .arg("--config")
+        .arg("tests/rule010/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("2 errors"))
+        .stdout(predicate::str::contains(
+            "Unknown component <Admontion>. Did you mean <Admonition>?",
+        ))
+        .stdout(predicate::str::contains("Unknown component <Unknown>."));
+}
@@ -0,0 +1,24 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule011() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule011/content/guides/auth/social/rule011.mdx")
+        .arg("--config")
+        .arg("tests/rule011/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("3 errors"))
+        .stdout(predicate::str::contains(
+            "Relative link traverses 3 parent directories (maximum is 2). Use \"/docs/database/tables#users\" instead.",
+        ))
+        .stdout(predicate::str::contains(
+            "Relative link traverses 3 parent directories (maximum is 2). Use \"/docs/storage\" instead.",
+        ))
+        .stdout(predicate::str::contains(
+            "Relative link traverses 4 parent directories (maximum is 2). Use a site-absolute path instead.",
+        ));
+}
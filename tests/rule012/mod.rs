@@ -0,0 +1,18 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule012() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule012/rule012.mdx")
+        .arg("--config")
+        .arg("tests/rule012/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains("Avoid raw HTML element <center>."))
+        .stdout(predicate::str::contains("Avoid raw HTML element <font>."))
+        .stdout(predicate::str::contains("<br>").not());
+}
@@ -0,0 +1,18 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule013() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule013/rule013.mdx")
+        .arg("--config")
+        .arg("tests/rule013/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("1 error"))
+        .stdout(predicate::str::contains(
+            "Heading \"Row level security\" repeats the page title.",
+        ));
+}
@@ -0,0 +1,22 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule014() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule014/rule014.mdx")
+        .arg("--config")
+        .arg("tests/rule014/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains(
+            "Use the character \"—\" instead of the HTML entity \"&mdash;\".",
+        ))
+        .stdout(predicate::str::contains(
+            "Use the character \"©\" instead of the HTML entity \"&copy;\".",
+        ))
+        .stdout(predicate::str::contains("&trade;").not());
+}
@@ -0,0 +1,21 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule015() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule015/rule015.mdx")
+        .arg("--config")
+        .arg("tests/rule015/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains(
+            "Format the keyboard shortcut \"Ctrl+C\" as `Ctrl+C`.",
+        ))
+        .stdout(predicate::str::contains(
+            "Format the keyboard shortcut \"Cmd-Shift-V\" as `Cmd-Shift-V`.",
+        ));
+}
@@ -0,0 +1,18 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule016() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule016/rule016.mdx")
+        .arg("--config")
+        .arg("tests/rule016/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "File has 11 lines and is 0.1 KB, which exceeds the limit of 5 lines.",
+        ));
+}
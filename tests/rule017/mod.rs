@@ -0,0 +1,21 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule017() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule017/rule017.mdx")
+        .arg("--config")
+        .arg("tests/rule017/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains(
+            "Unknown config key \"Rule001HeadingCase.may_upercase\". Did you mean \"may_uppercase\"?",
+        ))
+        .stdout(predicate::str::contains(
+            "Unknown config key \"ignore_pattern\". Did you mean \"ignore_patterns\"?",
+        ));
+}
@@ -0,0 +1,21 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule018() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule018/rule018.mdx")
+        .arg("--config")
+        .arg("tests/rule018/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("3 errors"))
+        .stdout(predicate::str::contains(
+            "Replace the placeholder text \"Lorem ipsum dolor sit amet\" left over from a template.",
+        ))
+        .stdout(predicate::str::contains(
+            "Replace the placeholder text \"Describe the feature here\" left over from a template.",
+        ));
+}
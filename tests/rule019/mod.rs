@@ -0,0 +1,21 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule019() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule019/rule019.mdx")
+        .arg("--config")
+        .arg("tests/rule019/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains(
+            "Don't start a sentence with a numeral. Spell out \"3\" or rewrite the sentence.",
+        ))
+        .stdout(predicate::str::contains(
+            "Don't start a sentence with a numeral. Spell out \"2024\" or rewrite the sentence.",
+        ));
+}
@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule020() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule020/rule020.mdx")
+        .arg("--config")
+        .arg("tests/rule020/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[HINT]"))
+        .stdout(predicate::str::contains(
+            "The 7-word noun chain \"Postgres row level security policy configuration guide\" may be hard to read.",
+        ))
+        .stdout(predicate::str::contains("No errors or warnings found"));
+}
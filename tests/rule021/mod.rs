@@ -0,0 +1,18 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule021() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule021")
+        .arg("--config")
+        .arg("tests/rule021/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "rule021.mdx:3:1: [WARN] Description is 17 characters long. Use at least 50 characters.",
+        ));
+}
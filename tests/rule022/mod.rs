@@ -0,0 +1,42 @@
+use std::{fs, process::Command};
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn integration_test_rule022() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule022/rule022.mdx")
+        .arg("--config")
+        .arg("tests/rule022/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("3 warnings"))
+        .stdout(predicate::str::contains("Remove trailing whitespace."))
+        .stdout(predicate::str::contains(
+            "Found 3 blank lines in a row. Use at most 1 blank line.",
+        ))
+        .stdout(predicate::str::contains("Replace the tab with spaces."));
+}
+
+#[test]
+fn integration_test_rule022_fix() {
+    let tempdir = TempDir::new().unwrap();
+    let path = tempdir.path().join("test.mdx");
+    fs::copy("tests/rule022/rule022.mdx", &path).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(&path)
+        .arg("--config")
+        .arg("tests/rule022/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Fixed 3 errors in 1 file"));
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "# Whitespace\n\nA line with trailing spaces.\n\nAn inline tab.\n\n```go\nfunc main() {\n\tfmt.Println()\n}\n```\n"
+    );
+}
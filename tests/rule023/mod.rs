@@ -0,0 +1,37 @@
+use std::{fs, process::Command};
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn integration_test_rule023() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule023/rule023.mdx")
+        .arg("--config")
+        .arg("tests/rule023/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("1 error"))
+        .stdout(predicate::str::contains(
+            "The \"label\" of <Admonition> should be sentence case",
+        ));
+}
+
+#[test]
+fn integration_test_rule023_fix() {
+    let tempdir = TempDir::new().unwrap();
+    let path = tempdir.path().join("test.mdx");
+    fs::copy("tests/rule023/rule023.mdx", &path).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(&path)
+        .arg("--config")
+        .arg("tests/rule023/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert().success();
+
+    let fixed = fs::read_to_string(&path).unwrap();
+    assert!(fixed.contains("label=\"Before you begin\""));
+    assert!(fixed.contains("title=\"Tuning Postgres\""));
+}
@@ -0,0 +1,41 @@
+use std::{fs, process::Command};
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn integration_test_rule024() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule024/rule024.mdx")
+        .arg("--config")
+        .arg("tests/rule024/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains(
+            "Use \"-\" for list items instead of \"*\".",
+        ))
+        .stdout(predicate::str::contains(
+            "Use \"-\" for list items instead of \"+\".",
+        ));
+}
+
+#[test]
+fn integration_test_rule024_fix() {
+    let tempdir = TempDir::new().unwrap();
+    let path = tempdir.path().join("test.mdx");
+    fs::copy("tests/rule024/rule024.mdx", &path).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(&path)
+        .arg("--config")
+        .arg("tests/rule024/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert().success();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "# List markers\n\n- First item\n- Second item\n\n- Third item\n  - Nested item\n"
+    );
+}
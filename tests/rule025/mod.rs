@@ -0,0 +1,41 @@
+use std::{fs, process::Command};
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn integration_test_rule025() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule025/rule025.mdx")
+        .arg("--config")
+        .arg("tests/rule025/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("3 warnings"))
+        .stdout(predicate::str::contains(
+            "Row has 2 cells, but the header has 3. Add the missing cells.",
+        ))
+        .stdout(predicate::str::contains(
+            "Row has 4 cells, but the header has 3.",
+        ))
+        .stdout(predicate::str::contains("it has no delimiter row"));
+}
+
+#[test]
+fn integration_test_rule025_fix() {
+    let tempdir = TempDir::new().unwrap();
+    let path = tempdir.path().join("test.mdx");
+    fs::copy("tests/rule025/rule025.mdx", &path).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(&path)
+        .arg("--config")
+        .arg("tests/rule025/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert().success();
+
+    let fixed = fs::read_to_string(&path).unwrap();
+    assert!(fixed.contains("| Pro  | $25   | |\n"));
+    assert!(fixed.contains("| Team | $599  | 8 GB | Extra |\n"));
+}
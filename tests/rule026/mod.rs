@@ -0,0 +1,18 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule026() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule026/rule026.mdx")
+        .arg("--config")
+        .arg("tests/rule026/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "Heading \"Example\" repeats a sibling heading on line 5.",
+        ));
+}
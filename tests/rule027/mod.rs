@@ -0,0 +1,21 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule027() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule027/rule027.mdx")
+        .arg("--config")
+        .arg("tests/rule027/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("2 errors"))
+        .stdout(predicate::str::contains(
+            "Image not found: \"/images/settings.png\"",
+        ))
+        .stdout(predicate::str::contains(
+            "Image not found: \"./assets/flow.png\"",
+        ));
+}
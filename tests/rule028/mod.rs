@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule028() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule028/rule028.mdx")
+        .arg("--config")
+        .arg("tests/rule028/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "This section mentions a deprecation without a deprecation notice.",
+        ))
+        .stdout(predicate::str::contains("rule028.mdx:13:"));
+}
@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule029() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule029/rule029.mdx")
+        .arg("--config")
+        .arg("tests/rule029/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "Lowercase the first word after a colon.",
+        ))
+        .stdout(predicate::str::contains("rule029.mdx:9:"));
+}
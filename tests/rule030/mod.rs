@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule030() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule030/rule030.mdx")
+        .arg("--config")
+        .arg("tests/rule030/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "Avoid user-specific paths like \"/Users/alice\".",
+        ))
+        .stdout(predicate::str::contains("rule030.mdx:6:4"));
+}
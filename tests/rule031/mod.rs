@@ -0,0 +1,18 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule031() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule031/rule031.mdx")
+        .arg("--config")
+        .arg("tests/rule031/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[HINT]"))
+        .stdout(predicate::str::contains("Avoid ending with \"etc.\"."))
+        .stdout(predicate::str::contains("rule031.mdx:9:14"))
+        .stdout(predicate::str::contains("No errors or warnings found"));
+}
@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule032() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule032/rule032.mdx")
+        .arg("--config")
+        .arg("tests/rule032/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "Start each prerequisite with a link or a product name.",
+        ))
+        .stdout(predicate::str::contains("rule032.mdx:7:1"));
+}
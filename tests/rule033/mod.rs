@@ -0,0 +1,50 @@
+use std::{fs, process::Command};
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn integration_test_rule033() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule033/rule033.mdx")
+        .arg("--config")
+        .arg("tests/rule033/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "Indent nested list items by 2 spaces, not 4.",
+        ))
+        .stdout(predicate::str::contains("rule033.mdx:9:1"));
+}
+
+#[test]
+fn integration_test_rule033_fix_unsafe() {
+    let tempdir = TempDir::new().unwrap();
+    let path = tempdir.path().join("test.mdx");
+    fs::copy("tests/rule033/rule033.mdx", &path).unwrap();
+
+    let fix = |flag: &str| {
+        let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+        cmd.arg(&path)
+            .arg("--config")
+            .arg("tests/rule033/supa-mdx-lint.config.toml")
+            .arg(flag);
+        cmd.assert()
+    };
+
+    fix("--fix")
+        .success()
+        .stdout(predicate::str::contains("1 fixable with --fix-unsafe"));
+    assert!(fs::read_to_string(&path)
+        .unwrap()
+        .contains("\n    - Use the dashboard"));
+
+    fix("--fix-unsafe")
+        .success()
+        .stdout(predicate::str::contains("Fixed 1 error in 1 file"));
+    assert!(fs::read_to_string(&path)
+        .unwrap()
+        .contains("\n  - Use the dashboard"));
+}
@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule034() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule034/rule034.mdx")
+        .arg("--config")
+        .arg("tests/rule034/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "Avoid inline styles in content. Use a design system component instead.",
+        ))
+        .stdout(predicate::str::contains("rule034.mdx:9:25"));
+}
@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule035() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule035/rule035.mdx")
+        .arg("--config")
+        .arg("tests/rule035/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("1 error"))
+        .stdout(predicate::str::contains(
+            "The tab label \"npm\" is used more than once in this <Tabs> block.",
+        ))
+        .stdout(predicate::str::contains("rule035.mdx:11:28"));
+}
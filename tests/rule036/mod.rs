@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule036() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule036/rule036.mdx")
+        .arg("--config")
+        .arg("tests/rule036/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "Add a blank line between the <Admonition> component and the heading after it.",
+        ))
+        .stdout(predicate::str::contains("rule036.mdx:8:1"));
+}
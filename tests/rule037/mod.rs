@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule037() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule037/rule037.mdx")
+        .arg("--config")
+        .arg("tests/rule037/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 warning"))
+        .stdout(predicate::str::contains(
+            "Introduce the page with a paragraph of at least 5 words before the first section.",
+        ))
+        .stdout(predicate::str::contains("rule037.mdx:7:1"));
+}
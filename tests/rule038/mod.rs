@@ -0,0 +1,22 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule038() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule038/rule038.mdx")
+        .arg("--config")
+        .arg("tests/rule038/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("2 errors"))
+        .stdout(predicate::str::contains(
+            "Use lowercase letters, numbers, and \"-\" in heading IDs: \"enable-rls\".",
+        ))
+        .stdout(predicate::str::contains(
+            "The heading ID \"policies\" is already used on line 7. Give each heading a unique ID.",
+        ))
+        .stdout(predicate::str::contains("rule038.mdx:11:24"));
+}
@@ -0,0 +1,19 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule039() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule039/rule039.mdx")
+        .arg("--config")
+        .arg("tests/rule039/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains(
+            "rule039.mdx:5:20: [WARN] Remove the draft marker \"TK\" before publishing.",
+        ))
+        .stdout(predicate::str::contains("rule039.mdx:7:1"));
+}
@@ -0,0 +1,21 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule040() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule040/rule040.mdx")
+        .arg("--config")
+        .arg("tests/rule040/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains(
+            "rule040.mdx:5:23: [WARN] Use commas to separate thousands: \"5,000,000\".",
+        ))
+        .stdout(predicate::str::contains(
+            "rule040.mdx:5:58: [WARN] Write dates as Month D, YYYY: \"February 1, 2024\".",
+        ));
+}
@@ -0,0 +1,41 @@
+use std::{fs, process::Command};
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn integration_test_rule042() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule042/rule042.mdx")
+        .arg("--config")
+        .arg("tests/rule042/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains(
+            "Use \"**\" for strong emphasis instead of \"__\".",
+        ))
+        .stdout(predicate::str::contains(
+            "Use \"*\" for emphasis instead of \"_\".",
+        ));
+}
+
+#[test]
+fn integration_test_rule042_fix() {
+    let tempdir = TempDir::new().unwrap();
+    let path = tempdir.path().join("test.mdx");
+    fs::copy("tests/rule042/rule042.mdx", &path).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(&path)
+        .arg("--config")
+        .arg("tests/rule042/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert().success();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "# Emphasis markers\n\nUse *emphasis* and **strong emphasis**.\n\nLater, *emphasis* and **strong emphasis** again.\n"
+    );
+}
@@ -0,0 +1,21 @@
+use std::process::Command;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[test]
+fn integration_test_rule043() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule043/rule043.mdx")
+        .arg("--config")
+        .arg("tests/rule043/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 warnings"))
+        .stdout(predicate::str::contains(
+            "Link text \"click here\" doesn't say where the link goes. Describe the destination instead, such as \"Setting up auth\".",
+        ))
+        .stdout(predicate::str::contains(
+            "Link text \"read more\" doesn't say where the link goes. Describe the destination instead, such as \"Configuring providers\".",
+        ));
+}
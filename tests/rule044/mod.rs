@@ -0,0 +1,43 @@
+use std::{fs, process::Command};
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn integration_test_rule044() {
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg("tests/rule044/rule044.mdx")
+        .arg("--config")
+        .arg("tests/rule044/supa-mdx-lint.config.toml");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("3 warnings"))
+        .stdout(predicate::str::contains(
+            "Remove the \":\" at the end of the heading.",
+        ))
+        .stdout(predicate::str::contains(
+            "Remove the \".\" at the end of the heading.",
+        ))
+        .stdout(predicate::str::contains(
+            "Shorten this heading to 70 characters or fewer. It has 80.",
+        ));
+}
+
+#[test]
+fn integration_test_rule044_fix() {
+    let tempdir = TempDir::new().unwrap();
+    let path = tempdir.path().join("test.mdx");
+    fs::copy("tests/rule044/rule044.mdx", &path).unwrap();
+
+    let mut cmd = Command::cargo_bin("supa-mdx-lint").unwrap();
+    cmd.arg(&path)
+        .arg("--config")
+        .arg("tests/rule044/supa-mdx-lint.config.toml")
+        .arg("--fix");
+    cmd.assert().success();
+
+    let fixed = fs::read_to_string(&path).unwrap();
+    assert!(fixed.contains("## Before you begin\n"));
+    assert!(fixed.contains("## Set up the client\n"));
+}
@@ -2,3 +2,41 @@ mod rule002;
 mod rule003;
 mod rule004;
 mod rule006;
+mod rule007;
+mod rule008;
+mod rule009;
+mod rule010;
+mod rule011;
+mod rule012;
+mod rule013;
+mod rule014;
+mod rule015;
+mod rule016;
+mod rule017;
+mod rule018;
+mod rule019;
+mod rule020;
+mod rule021;
+mod rule022;
+mod rule023;
+mod rule024;
+mod rule025;
+mod rule026;
+mod rule027;
+mod rule028;
+mod rule029;
+mod rule030;
+mod rule031;
+mod rule032;
+mod rule033;
+mod rule034;
+mod rule035;
+mod rule036;
+mod rule037;
+mod rule038;
+mod rule039;
+mod rule040;
+mod rule041;
+mod rule042;
+mod rule043;
+mod rule044;